@@ -21,7 +21,173 @@ use std::str::FromStr;
 use ash::vk;
 
 use crate::to_cstring;
-use crate::errors::{AttributeLayoutError, ExtensionError};
+use crate::errors::{AttributeLayoutError, ColourSpaceError, ExtensionError};
+use crate::auxillary::flags::ImageAspectFlags;
+use crate::auxillary::structs::Extent2D;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_format_round_trip() {
+        // All the core Vulkan 1.0 formats (which are contiguous)
+        let mut formats: Vec<vk::Format> = (vk::Format::UNDEFINED.as_raw()..=vk::Format::ASTC_12X12_SRGB_BLOCK.as_raw()).map(vk::Format::from_raw).collect();
+        // Formats promoted to core in later versions, and formats from extensions
+        formats.extend([
+            vk::Format::G8B8G8R8_422_UNORM, vk::Format::G8_B8R8_2PLANE_420_UNORM, vk::Format::G16_B16_R16_3PLANE_444_UNORM, vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+            vk::Format::G8_B8R8_2PLANE_444_UNORM, vk::Format::A4R4G4B4_UNORM_PACK16, vk::Format::A4B4G4R4_UNORM_PACK16, vk::Format::ASTC_4X4_SFLOAT_BLOCK, vk::Format::ASTC_12X12_SFLOAT_BLOCK,
+            vk::Format::PVRTC1_2BPP_UNORM_BLOCK_IMG, vk::Format::PVRTC2_4BPP_SRGB_BLOCK_IMG, vk::Format::ASTC_3X3X3_UNORM_BLOCK_EXT, vk::Format::ASTC_6X6X6_SFLOAT_BLOCK_EXT, vk::Format::R16G16_S10_5_NV,
+        ]);
+
+        // Make sure they all convert there and back again without panicking
+        for format in formats {
+            let ours: ImageFormat = format.into();
+            assert_eq!(vk::Format::from(ours), format, "{:?} did not round-trip (went through {})", format, ours);
+        }
+
+        // Spot-check that the depth formats are recognised as such
+        assert!(ImageFormat::from(vk::Format::D16_UNORM).is_depth());
+        assert!(ImageFormat::from(vk::Format::X8_D24_UNORM_PACK32).is_depth());
+        assert!(ImageFormat::from(vk::Format::D32_SFLOAT_S8_UINT).is_stencil());
+        assert_eq!(ImageFormat::from(vk::Format::G8B8G8R8_422_UNORM).block_extent(), (2, 1));
+    }
+
+    #[test]
+    fn test_block_size() {
+        // Uncompressed formats are sized per texel
+        assert_eq!(ImageFormat::R8G8B8A8UNorm.block_size(), 4);
+        assert_eq!(ImageFormat::R8G8B8A8UNorm.block_extent(), (1, 1));
+        assert_eq!(ImageFormat::R32G32B32A32SFloat.block_size(), 16);
+        assert_eq!(ImageFormat::D32SFloatS8UInt.block_size(), 5);
+
+        // Compressed formats are sized per block
+        assert_eq!(ImageFormat::BC1RGBAUNormBlock.block_size(), 8);
+        assert_eq!(ImageFormat::BC1RGBAUNormBlock.block_extent(), (4, 4));
+        assert_eq!(ImageFormat::BC7UNormBlock.block_size(), 16);
+        assert_eq!(ImageFormat::ASTC8X8UNormBlock.block_size(), 16);
+        assert_eq!(ImageFormat::ASTC8X8UNormBlock.block_extent(), (8, 8));
+
+        // Multi-planar formats cannot be sized as a whole
+        assert_eq!(ImageFormat::G8B8R8_3Plane420UNorm.block_size(), 0);
+    }
+
+    #[test]
+    fn test_image_layout_round_trip() {
+        let layouts: [vk::ImageLayout; 9] = [
+            vk::ImageLayout::GENERAL, vk::ImageLayout::PRESENT_SRC_KHR, vk::ImageLayout::SHARED_PRESENT_KHR, vk::ImageLayout::ATTACHMENT_OPTIMAL_KHR, vk::ImageLayout::READ_ONLY_OPTIMAL_KHR,
+            vk::ImageLayout::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL, vk::ImageLayout::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL, vk::ImageLayout::DEPTH_ATTACHMENT_OPTIMAL, vk::ImageLayout::STENCIL_ATTACHMENT_OPTIMAL,
+        ];
+        for layout in layouts {
+            assert_eq!(vk::ImageLayout::from(ImageLayout::from(layout)), layout);
+        }
+
+        // Only the generic layouts need synchronization2
+        assert!(ImageLayout::AttachmentOptimal.requires_synchronization2());
+        assert!(ImageLayout::ReadOnlyOptimal.requires_synchronization2());
+        assert!(!ImageLayout::ColourAttachment.requires_synchronization2());
+    }
+
+    #[test]
+    fn test_image_format_aspects() {
+        // Colour formats have neither
+        assert!(!ImageFormat::R8G8B8A8UNorm.is_depth());
+        assert!(!ImageFormat::R8G8B8A8UNorm.is_stencil());
+        assert_eq!(ImageFormat::R8G8B8A8UNorm.default_aspect(), ImageAspectFlags::COLOUR);
+
+        // Depth- or stencil-only formats have one of them
+        assert!(ImageFormat::D32SFloat.is_depth());
+        assert!(!ImageFormat::D32SFloat.is_stencil());
+        assert_eq!(ImageFormat::D16UNorm.default_aspect(), ImageAspectFlags::DEPTH);
+        assert_eq!(ImageFormat::S8UInt.default_aspect(), ImageAspectFlags::STENCIL);
+
+        // Combined formats have both
+        assert!(ImageFormat::D24UNormS8UInt.is_depth());
+        assert!(ImageFormat::D24UNormS8UInt.is_stencil());
+        assert_eq!(ImageFormat::D32SFloatS8UInt.default_aspect(), ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL);
+        assert_eq!(vk::ImageAspectFlags::from(ImageFormat::D32SFloatS8UInt.default_aspect()), vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL);
+    }
+
+    /// Tests which ImageFormats may be copied to each other
+    #[test]
+    fn test_image_format_copy_compatible() {
+        // Colour formats of the same size are compatible, also if their type differs
+        assert!(ImageFormat::R8G8B8A8UNorm.is_copy_compatible(ImageFormat::R8G8B8A8UNorm));
+        assert!(ImageFormat::R8G8B8A8UNorm.is_copy_compatible(ImageFormat::B8G8R8A8SRgb));
+        assert!(ImageFormat::R8G8B8A8UNorm.is_copy_compatible(ImageFormat::R32UInt));
+        assert!(!ImageFormat::R8G8B8A8UNorm.is_copy_compatible(ImageFormat::R16G16B16A16SFloat));
+
+        // Depth/stencil formats only match themselves
+        assert!(ImageFormat::D32SFloat.is_copy_compatible(ImageFormat::D32SFloat));
+        assert!(!ImageFormat::D32SFloat.is_copy_compatible(ImageFormat::R32SFloat));
+        assert!(!ImageFormat::R32SFloat.is_copy_compatible(ImageFormat::D32SFloat));
+
+        // Undefined never matches
+        assert!(!ImageFormat::Undefined.is_copy_compatible(ImageFormat::Undefined));
+    }
+
+    #[test]
+    fn test_instance_extension_names() {
+        use std::ffi::CStr;
+        use ash::extensions::{khr, mvk};
+
+        // Compare our names with those known to ash, and make sure we can parse them back
+        let extensions: [(InstanceExtension, &CStr); 6] = [
+            (InstanceExtension::Surface, khr::Surface::name()),
+            (InstanceExtension::Win32Surface, khr::Win32Surface::name()),
+            (InstanceExtension::MacOSSurface, mvk::MacOSSurface::name()),
+            (InstanceExtension::XlibSurface, khr::XlibSurface::name()),
+            (InstanceExtension::XcbSurface, khr::XcbSurface::name()),
+            (InstanceExtension::WaylandSurface, khr::WaylandSurface::name()),
+        ];
+        for (extension, name) in extensions {
+            assert_eq!(extension.as_str(), name.to_str().unwrap());
+            assert_eq!(InstanceExtension::from_str(extension.as_str()).unwrap(), extension);
+        }
+    }
+
+    #[test]
+    fn test_attribute_layout_round_trip() {
+        use std::convert::TryFrom;
+
+        // Every layout should survive a round-trip through its format
+        for layout in [ AttributeLayout::Float, AttributeLayout::Float2, AttributeLayout::Float3, AttributeLayout::Float4, AttributeLayout::Int, AttributeLayout::Int2, AttributeLayout::Int3, AttributeLayout::Int4, AttributeLayout::UInt, AttributeLayout::UInt2, AttributeLayout::UInt3, AttributeLayout::UInt4 ] {
+            assert_eq!(AttributeLayout::try_from(vk::Format::from(layout)).unwrap(), layout);
+        }
+        assert_eq!(vk::Format::from(AttributeLayout::Float4), vk::Format::R32G32B32A32_SFLOAT);
+
+        // Other formats are still illegal
+        assert!(matches!(AttributeLayout::try_from(vk::Format::R8G8B8A8_UNORM), Err(AttributeLayoutError::IllegalFormatValue{ value: vk::Format::R8G8B8A8_UNORM })));
+    }
+
+    #[test]
+    fn test_component_swizzle_round_trip() {
+        // Every swizzle should map to its own Vulkan counterpart and back again
+        let swizzles: [(ComponentSwizzle, vk::ComponentSwizzle); 7] = [
+            (ComponentSwizzle::Identity, vk::ComponentSwizzle::IDENTITY),
+            (ComponentSwizzle::One, vk::ComponentSwizzle::ONE),
+            (ComponentSwizzle::Zero, vk::ComponentSwizzle::ZERO),
+            (ComponentSwizzle::Red, vk::ComponentSwizzle::R),
+            (ComponentSwizzle::Green, vk::ComponentSwizzle::G),
+            (ComponentSwizzle::Blue, vk::ComponentSwizzle::B),
+            (ComponentSwizzle::Alpha, vk::ComponentSwizzle::A),
+        ];
+        for (swizzle, vk_swizzle) in swizzles {
+            assert_eq!(vk::ComponentSwizzle::from(swizzle), vk_swizzle);
+            assert_eq!(ComponentSwizzle::from(vk_swizzle), swizzle);
+        }
+
+        // Make sure a mapping with its channels moved around survives as well
+        let mapping = vk::ComponentMapping { r: vk::ComponentSwizzle::B, g: vk::ComponentSwizzle::G, b: vk::ComponentSwizzle::R, a: vk::ComponentSwizzle::ONE };
+        let ours: crate::auxillary::structs::ComponentMapping = mapping.into();
+        assert_eq!(ours.green, ComponentSwizzle::Green);
+        assert_eq!(ours.blue, ComponentSwizzle::Red);
+        let back: vk::ComponentMapping = ours.into();
+        assert_eq!((back.r, back.g, back.b, back.a), (mapping.r, mapping.g, mapping.b, mapping.a));
+    }
+}
 
 
 /***** HELPER MACROS *****/
@@ -83,10 +249,23 @@ macro_rules! enum_from {
 
 /***** INSTANCE *****/
 /// An enum that describes instance extensions used in the Game.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum InstanceExtension {
     /// The instance portability extension, used on macOS
     PortabilityEnumeration,
+
+    /// The platform-agnostic surface extension, required by all of the platform surface extensions
+    Surface,
+    /// The surface extension for Win32 windows
+    Win32Surface,
+    /// The surface extension for Cocoa views on macOS (through MoltenVK)
+    MacOSSurface,
+    /// The surface extension for X11 windows through Xlib
+    XlibSurface,
+    /// The surface extension for X11 windows through XCB
+    XcbSurface,
+    /// The surface extension for Wayland surfaces
+    WaylandSurface,
 }
 
 impl InstanceExtension {
@@ -96,6 +275,13 @@ impl InstanceExtension {
         use InstanceExtension::*;
         match self {
             PortabilityEnumeration => "VK_KHR_portability_enumeration",
+
+            Surface        => "VK_KHR_surface",
+            Win32Surface   => "VK_KHR_win32_surface",
+            MacOSSurface   => "VK_MVK_macos_surface",
+            XlibSurface    => "VK_KHR_xlib_surface",
+            XcbSurface     => "VK_KHR_xcb_surface",
+            WaylandSurface => "VK_KHR_wayland_surface",
         }
     }
 }
@@ -120,6 +306,14 @@ impl FromStr for InstanceExtension {
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
             "VK_KHR_portability_enumeration" => Ok(InstanceExtension::PortabilityEnumeration),
+
+            "VK_KHR_surface"                 => Ok(InstanceExtension::Surface),
+            "VK_KHR_win32_surface"           => Ok(InstanceExtension::Win32Surface),
+            "VK_MVK_macos_surface"           => Ok(InstanceExtension::MacOSSurface),
+            "VK_KHR_xlib_surface"            => Ok(InstanceExtension::XlibSurface),
+            "VK_KHR_xcb_surface"             => Ok(InstanceExtension::XcbSurface),
+            "VK_KHR_wayland_surface"         => Ok(InstanceExtension::WaylandSurface),
+
             value                            => Err(ExtensionError::UnknownInstanceExtension{ got: value.into() }),
         }
     }
@@ -243,7 +437,7 @@ enum_from!(impl From<vk::PhysicalDeviceType> for DeviceKind {
 
 
 /// An enum that describes device extensions used in the Game.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum DeviceExtension {
     /// The Swapchain device extension.
     Swapchain,
@@ -251,6 +445,26 @@ pub enum DeviceExtension {
     PortabilitySubset,
     /// The 8-bit index extension.
     SmallIndices,
+    /// The extension that allows attachments to not be touched at all when loading or storing them.
+    LoadStoreOpNone,
+    /// The extension that allows pipelines to use task and mesh shaders instead of vertex input.
+    MeshShader,
+    /// The extension that allows rendering without RenderPasses or Framebuffers.
+    DynamicRendering,
+    /// The extension that adds Semaphores with a monotonically increasing counter (core in Vulkan 1.2).
+    TimelineSemaphore,
+    /// The extension that adds extensible versions of the RenderPass structs, e.g., to reference only a single aspect of an attachment (core in Vulkan 1.2).
+    CreateRenderPass2,
+    /// The extension that adds the reworked synchronization commands and the generic `ATTACHMENT_OPTIMAL`/`READ_ONLY_OPTIMAL` image layouts (core in Vulkan 1.3).
+    Synchronization2,
+    /// The extension that allows presenting only the regions of a Swapchain image that have changed.
+    IncrementalPresent,
+    /// The extension that allows shaders to use 16-bit floats and 8-bit integers (core in Vulkan 1.2).
+    ShaderFloat16Int8,
+    /// The extension that allows a Swapchain to present a single image that is shared between the application and the presentation engine.
+    SharedPresentableImage,
+    /// The extension that reports how long creating a Pipeline (and each of its stages) took, and whether it hit the PipelineCache (core in Vulkan 1.3).
+    PipelineCreationFeedback,
 }
 
 impl DeviceExtension {
@@ -259,9 +473,19 @@ impl DeviceExtension {
     pub const fn as_str(&self) -> &'static str {
         use DeviceExtension::*;
         match self {
-            Swapchain         => "VK_KHR_swapchain",
-            PortabilitySubset => "VK_KHR_portability_subset",
-            SmallIndices      => "VK_EXT_index_type_uint8",
+            Swapchain                => "VK_KHR_swapchain",
+            PortabilitySubset        => "VK_KHR_portability_subset",
+            SmallIndices             => "VK_EXT_index_type_uint8",
+            LoadStoreOpNone          => "VK_EXT_load_store_op_none",
+            MeshShader               => "VK_EXT_mesh_shader",
+            DynamicRendering         => "VK_KHR_dynamic_rendering",
+            TimelineSemaphore        => "VK_KHR_timeline_semaphore",
+            CreateRenderPass2        => "VK_KHR_create_renderpass2",
+            Synchronization2         => "VK_KHR_synchronization2",
+            IncrementalPresent       => "VK_KHR_incremental_present",
+            ShaderFloat16Int8        => "VK_KHR_shader_float16_int8",
+            SharedPresentableImage   => "VK_KHR_shared_presentable_image",
+            PipelineCreationFeedback => "VK_EXT_pipeline_creation_feedback",
         }
     }
 }
@@ -285,51 +509,24 @@ impl FromStr for DeviceExtension {
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "VK_KHR_swapchain"          => Ok(DeviceExtension::Swapchain),
-            "VK_KHR_portability_subset" => Ok(DeviceExtension::PortabilitySubset),
-            "VK_EXT_index_type_uint8"   => Ok(DeviceExtension::SmallIndices),
-            value                       => Err(ExtensionError::UnknownDeviceExtension{ got: value.into() }),
-        }
-    }
-}
-
-
-
-/// An enum that describes device layers used in the Game.
-#[derive(Copy, Clone, Debug)]
-pub enum DeviceLayer {
-    /// A dummy extension as a temporary placeholder
-    Dummy,
-}
-
-impl DeviceLayer {
-    /// Constant function to get the string value of the DeviceLayer.
-    #[inline]
-    pub const fn as_str(&self) -> &'static str {
-        use DeviceLayer::*;
-        match self {
-            Dummy => "dummy",
+            "VK_KHR_swapchain"                  => Ok(DeviceExtension::Swapchain),
+            "VK_KHR_portability_subset"         => Ok(DeviceExtension::PortabilitySubset),
+            "VK_EXT_index_type_uint8"           => Ok(DeviceExtension::SmallIndices),
+            "VK_EXT_load_store_op_none"         => Ok(DeviceExtension::LoadStoreOpNone),
+            "VK_EXT_mesh_shader"                => Ok(DeviceExtension::MeshShader),
+            "VK_KHR_dynamic_rendering"          => Ok(DeviceExtension::DynamicRendering),
+            "VK_KHR_timeline_semaphore"         => Ok(DeviceExtension::TimelineSemaphore),
+            "VK_KHR_create_renderpass2"         => Ok(DeviceExtension::CreateRenderPass2),
+            "VK_KHR_synchronization2"           => Ok(DeviceExtension::Synchronization2),
+            "VK_KHR_incremental_present"        => Ok(DeviceExtension::IncrementalPresent),
+            "VK_KHR_shader_float16_int8"        => Ok(DeviceExtension::ShaderFloat16Int8),
+            "VK_KHR_shared_presentable_image"   => Ok(DeviceExtension::SharedPresentableImage),
+            "VK_EXT_pipeline_creation_feedback" => Ok(DeviceExtension::PipelineCreationFeedback),
+            value                               => Err(ExtensionError::UnknownDeviceExtension{ got: value.into() }),
         }
     }
 }
 
-impl Display for DeviceLayer {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
-        write!(f, "{}", self.as_str())
-    }
-}
-
-impl FromStr for DeviceLayer {
-    type Err = ExtensionError;
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match value {
-            "dummy" => Ok(DeviceLayer::Dummy),
-            value   => Err(ExtensionError::UnknownDeviceLayer{ got: value.into() }),
-        }
-    }
-}
 
 
 
@@ -426,13 +623,21 @@ pub enum AttachmentLoadOp {
     /// - For colour attachments, this uses the `VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT` operation.
     /// - For depth / stencil attachments, this uses the `VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT` operation.
     Load,
+    /// The attachment is not accessed at all when loading, so its contents are left as-is (but are undefined within the pass).
+    /// 
+    /// Requires the `DeviceExtension::LoadStoreOpNone` extension to be enabled.
+    /// 
+    /// # Synchronization
+    /// - No access is performed, so no synchronization is required.
+    None,
 }
 
 enum_from!(impl From<vk::AttachmentLoadOp> for AttachmentLoadOp {
     vk::AttachmentLoadOp::DONT_CARE => AttachmentLoadOp::DontCare,
 
-    vk::AttachmentLoadOp::CLEAR => AttachmentLoadOp::Clear,
-    vk::AttachmentLoadOp::LOAD  => AttachmentLoadOp::Load,
+    vk::AttachmentLoadOp::CLEAR    => AttachmentLoadOp::Clear,
+    vk::AttachmentLoadOp::LOAD     => AttachmentLoadOp::Load,
+    vk::AttachmentLoadOp::NONE_EXT => AttachmentLoadOp::None,
 });
 
 
@@ -453,17 +658,25 @@ pub enum AttachmentStoreOp {
     /// - For colour attachments, this uses the `VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT` operation.
     /// - For depth / stencil attachments, this uses the `VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT` operation.
     Store,
+    /// The attachment is not accessed at all when storing, so its contents are preserved as they were before the pass (if it was not written to).
+    /// 
+    /// Requires the `DeviceExtension::LoadStoreOpNone` extension to be enabled (or dynamic rendering / Vulkan 1.3).
+    /// 
+    /// # Synchronization
+    /// - No access is performed, so no synchronization is required.
+    None,
 }
 
 enum_from!(impl From<vk::AttachmentStoreOp> for AttachmentStoreOp {
     vk::AttachmentStoreOp::DONT_CARE => AttachmentStoreOp::DontCare,
-    vk::AttachmentStoreOp::STORE => AttachmentStoreOp::Store,
+    vk::AttachmentStoreOp::STORE     => AttachmentStoreOp::Store,
+    vk::AttachmentStoreOp::NONE_EXT  => AttachmentStoreOp::None,
 });
 
 
 
 /// The point where a subpass will be attached to the pipeline.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum BindPoint {
     /// The subpass will be attached in the graphics-part of the pipeline.
     Graphics,
@@ -471,6 +684,17 @@ pub enum BindPoint {
     Compute,
 }
 
+impl Display for BindPoint {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use BindPoint::*;
+        match self {
+            Graphics => write!(f, "Graphics"),
+            Compute  => write!(f, "Compute"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::PipelineBindPoint> for BindPoint {
     vk::PipelineBindPoint::GRAPHICS => BindPoint::Graphics,
     vk::PipelineBindPoint::COMPUTE  => BindPoint::Compute,
@@ -482,12 +706,34 @@ enum_from!(impl From<vk::PipelineBindPoint> for BindPoint {
 
 /***** PIPELINE *****/
 /// Defines the possible layouts for an attribute
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum AttributeLayout {
+    /// A single 32-bit floating-point number
+    Float,
     /// A two-dimensional vector of 32-bit floating-point numbers
     Float2,
     /// A three-dimensional vector of 32-bit floating-point numbers
     Float3,
+    /// A four-dimensional vector of 32-bit floating-point numbers
+    Float4,
+
+    /// A single 32-bit signed integer
+    Int,
+    /// A two-dimensional vector of 32-bit signed integers
+    Int2,
+    /// A three-dimensional vector of 32-bit signed integers
+    Int3,
+    /// A four-dimensional vector of 32-bit signed integers
+    Int4,
+
+    /// A single 32-bit unsigned integer
+    UInt,
+    /// A two-dimensional vector of 32-bit unsigned integers
+    UInt2,
+    /// A three-dimensional vector of 32-bit unsigned integers
+    UInt3,
+    /// A four-dimensional vector of 32-bit unsigned integers
+    UInt4,
 }
 
 impl TryFrom<vk::Format> for AttributeLayout {
@@ -495,9 +741,22 @@ impl TryFrom<vk::Format> for AttributeLayout {
 
     fn try_from(value: vk::Format) -> Result<Self, Self::Error> {
         match value {
-            vk::Format::R32G32_SFLOAT    => Ok(AttributeLayout::Float2),
-            vk::Format::R32G32B32_SFLOAT => Ok(AttributeLayout::Float3),
-            value                        => Err(AttributeLayoutError::IllegalFormatValue{ value }),
+            vk::Format::R32_SFLOAT          => Ok(AttributeLayout::Float),
+            vk::Format::R32G32_SFLOAT       => Ok(AttributeLayout::Float2),
+            vk::Format::R32G32B32_SFLOAT    => Ok(AttributeLayout::Float3),
+            vk::Format::R32G32B32A32_SFLOAT => Ok(AttributeLayout::Float4),
+
+            vk::Format::R32_SINT          => Ok(AttributeLayout::Int),
+            vk::Format::R32G32_SINT       => Ok(AttributeLayout::Int2),
+            vk::Format::R32G32B32_SINT    => Ok(AttributeLayout::Int3),
+            vk::Format::R32G32B32A32_SINT => Ok(AttributeLayout::Int4),
+
+            vk::Format::R32_UINT          => Ok(AttributeLayout::UInt),
+            vk::Format::R32G32_UINT       => Ok(AttributeLayout::UInt2),
+            vk::Format::R32G32B32_UINT    => Ok(AttributeLayout::UInt3),
+            vk::Format::R32G32B32A32_UINT => Ok(AttributeLayout::UInt4),
+
+            value => Err(AttributeLayoutError::IllegalFormatValue{ value }),
         }
     }
 }
@@ -505,8 +764,20 @@ impl TryFrom<vk::Format> for AttributeLayout {
 impl From<AttributeLayout> for vk::Format {
     fn from(value: AttributeLayout) -> Self {
         match value {
+            AttributeLayout::Float  => vk::Format::R32_SFLOAT,
             AttributeLayout::Float2 => vk::Format::R32G32_SFLOAT,
             AttributeLayout::Float3 => vk::Format::R32G32B32_SFLOAT,
+            AttributeLayout::Float4 => vk::Format::R32G32B32A32_SFLOAT,
+
+            AttributeLayout::Int  => vk::Format::R32_SINT,
+            AttributeLayout::Int2 => vk::Format::R32G32_SINT,
+            AttributeLayout::Int3 => vk::Format::R32G32B32_SINT,
+            AttributeLayout::Int4 => vk::Format::R32G32B32A32_SINT,
+
+            AttributeLayout::UInt  => vk::Format::R32_UINT,
+            AttributeLayout::UInt2 => vk::Format::R32G32_UINT,
+            AttributeLayout::UInt3 => vk::Format::R32G32B32_UINT,
+            AttributeLayout::UInt4 => vk::Format::R32G32B32A32_UINT,
         }
     }
 }
@@ -581,6 +852,15 @@ pub enum VertexTopology {
     PatchList,
 }
 
+impl VertexTopology {
+    /// Returns whether this topology connects consecutive primitives (i.e., is one of the strip or fan topologies).
+    #[inline]
+    pub fn is_strip(&self) -> bool {
+        use VertexTopology::*;
+        matches!(self, LineStrip | LineStripAdjacency | TriangleStrip | TriangleFan | TriangleStripAdjacency)
+    }
+}
+
 enum_from!(impl From<vk::PrimitiveTopology> for VertexTopology {
     vk::PrimitiveTopology::POINT_LIST => VertexTopology::PointList,
 
@@ -613,6 +893,19 @@ pub enum CullMode {
     None,
 }
 
+impl Display for CullMode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CullMode::*;
+        match self {
+            FrontAndBack => write!(f, "Front and back"),
+            Front        => write!(f, "Front"),
+            Back         => write!(f, "Back"),
+            None         => write!(f, "None"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::CullModeFlags> for CullMode {
     vk::CullModeFlags::FRONT_AND_BACK => CullMode::FrontAndBack,
     vk::CullModeFlags::FRONT          => CullMode::Front,
@@ -631,6 +924,17 @@ pub enum FrontFace {
     CounterClockwise,
 }
 
+impl Display for FrontFace {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use FrontFace::*;
+        match self {
+            Clockwise        => write!(f, "Clockwise"),
+            CounterClockwise => write!(f, "Counter-clockwise"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::FrontFace> for FrontFace {
     vk::FrontFace::CLOCKWISE         => FrontFace::Clockwise,
     vk::FrontFace::COUNTER_CLOCKWISE => FrontFace::CounterClockwise,
@@ -649,6 +953,18 @@ pub enum DrawMode {
     Fill,
 }
 
+impl Display for DrawMode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use DrawMode::*;
+        match self {
+            Point => write!(f, "Point"),
+            Line  => write!(f, "Line"),
+            Fill  => write!(f, "Fill"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::PolygonMode> for DrawMode {
     vk::PolygonMode::POINT => DrawMode::Point,
     vk::PolygonMode::LINE  => DrawMode::Line,
@@ -680,6 +996,25 @@ pub enum StencilOp {
     DecrementWrap,
 }
 
+impl Display for StencilOp {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use StencilOp::*;
+        match self {
+            Keep    => write!(f, "Keep"),
+            Zero    => write!(f, "Zero"),
+            Replace => write!(f, "Replace"),
+            Invert  => write!(f, "Invert"),
+
+            IncrementClamp => write!(f, "Increment (clamp)"),
+            DecrementClamp => write!(f, "Decrement (clamp)"),
+
+            IncrementWrap => write!(f, "Increment (wrap)"),
+            DecrementWrap => write!(f, "Decrement (wrap)"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::StencilOp> for StencilOp {
     vk::StencilOp::KEEP    => StencilOp::Keep,
     vk::StencilOp::ZERO    => StencilOp::Zero,
@@ -717,6 +1052,24 @@ pub enum CompareOp {
     NotEqual,
 }
 
+impl Display for CompareOp {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CompareOp::*;
+        match self {
+            Always => write!(f, "Always"),
+            Never  => write!(f, "Never"),
+
+            Less      => write!(f, "Less (<)"),
+            LessEq    => write!(f, "Less or equal (<=)"),
+            Greater   => write!(f, "Greater (>)"),
+            GreaterEq => write!(f, "Greater or equal (>=)"),
+            Equal     => write!(f, "Equal (==)"),
+            NotEqual  => write!(f, "Not equal (!=)"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::CompareOp> for CompareOp {
     vk::CompareOp::ALWAYS => CompareOp::Always,
     vk::CompareOp::NEVER  => CompareOp::Never,
@@ -772,6 +1125,35 @@ pub enum LogicOp {
     NOr,
 }
 
+impl Display for LogicOp {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use LogicOp::*;
+        match self {
+            NoOp    => write!(f, "No-op (d = d)"),
+            Clear   => write!(f, "Clear (d = 0)"),
+            Set     => write!(f, "Set (d = ~0)"),
+            Copy    => write!(f, "Copy (d = s)"),
+            CopyInv => write!(f, "Copy inverted (d = ~s)"),
+
+            Not => write!(f, "Not (d = ~d)"),
+
+            And    => write!(f, "And (d = s & d)"),
+            AndInv => write!(f, "And inverted (d = ~s & d)"),
+            AndRev => write!(f, "And reverse (d = s & ~d)"),
+            NAnd   => write!(f, "Nand (d = ~(s & d))"),
+
+            Xor  => write!(f, "Xor (d = s ^ d)"),
+            NXor => write!(f, "Nxor (d = ~(s ^ d))"),
+
+            Or    => write!(f, "Or (d = s | d)"),
+            OrInv => write!(f, "Or inverted (d = ~s | d)"),
+            OrRev => write!(f, "Or reverse (d = s | ~d)"),
+            NOr   => write!(f, "Nor (d = ~(s | d))"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::LogicOp> for LogicOp {
     vk::LogicOp::NO_OP         => LogicOp::NoOp,
     vk::LogicOp::CLEAR         => LogicOp::Clear,
@@ -845,6 +1227,48 @@ pub enum BlendFactor {
     SrcAlphaSaturate,
 }
 
+impl BlendFactor {
+    /// Returns whether this factor uses the second source channel (i.e., requires dual-source blending).
+    #[inline]
+    pub fn is_dual_source(&self) -> bool {
+        use BlendFactor::*;
+        matches!(self, SrcColour2 | OneMinusSrcColour2 | SrcAlpha2 | OneMinusSrcAlpha2)
+    }
+}
+
+impl Display for BlendFactor {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use BlendFactor::*;
+        match self {
+            Zero => write!(f, "Zero"),
+            One  => write!(f, "One"),
+
+            SrcColour         => write!(f, "Source colour"),
+            OneMinusSrcColour => write!(f, "One minus source colour"),
+            DstColour         => write!(f, "Destination colour"),
+            OneMinusDstColour => write!(f, "One minus destination colour"),
+
+            SrcAlpha         => write!(f, "Source alpha"),
+            OneMinusSrcAlpha => write!(f, "One minus source alpha"),
+            DstAlpha         => write!(f, "Destination alpha"),
+            OneMinusDstAlpha => write!(f, "One minus destination alpha"),
+
+            ConstColour         => write!(f, "Constant colour"),
+            OneMinusConstColour => write!(f, "One minus constant colour"),
+            ConstAlpha          => write!(f, "Constant alpha"),
+            OneMinusConstAlpha  => write!(f, "One minus constant alpha"),
+
+            SrcColour2         => write!(f, "Second source colour"),
+            OneMinusSrcColour2 => write!(f, "One minus second source colour"),
+            SrcAlpha2          => write!(f, "Second source alpha"),
+            OneMinusSrcAlpha2  => write!(f, "One minus second source alpha"),
+
+            SrcAlphaSaturate => write!(f, "Source alpha saturate"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::BlendFactor> for BlendFactor {
     vk::BlendFactor::ZERO => BlendFactor::Zero,
     vk::BlendFactor::ONE  => BlendFactor::One,
@@ -925,6 +1349,21 @@ pub enum BlendOp {
     Max,
 }
 
+impl Display for BlendOp {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use BlendOp::*;
+        match self {
+            Add    => write!(f, "Add"),
+            Sub    => write!(f, "Subtract"),
+            SubRev => write!(f, "Reverse subtract"),
+
+            Min => write!(f, "Min"),
+            Max => write!(f, "Max"),
+        }
+    }
+}
+
 enum_from!(impl From<vk::BlendOp> for BlendOp {
     vk::BlendOp::ADD              => BlendOp::Add,
     vk::BlendOp::SUBTRACT         => BlendOp::Sub,
@@ -937,7 +1376,7 @@ enum_from!(impl From<vk::BlendOp> for BlendOp {
 
 
 /// Determines whether certain states of the pipeline may later be overridden.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DynamicState {
     /// The output viewport is dynamic.
     Viewport,
@@ -992,6 +1431,22 @@ enum_from!(impl From<vk::CommandBufferLevel> for CommandBufferLevel {
 
 
 
+/// Determines how the commands within a RenderPass (or dynamic rendering scope) are provided.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SubpassContents {
+    /// The commands are recorded directly in the primary CommandBuffer.
+    Inline,
+    /// The commands are recorded in secondary CommandBuffers, which are executed with `CommandBuffer::execute_commands()`.
+    SecondaryCommandBuffers,
+}
+
+enum_from!(impl From<vk::SubpassContents> for SubpassContents {
+    vk::SubpassContents::INLINE                    => SubpassContents::Inline,
+    vk::SubpassContents::SECONDARY_COMMAND_BUFFERS => SubpassContents::SecondaryCommandBuffers,
+});
+
+
+
 
 
 /***** MEMORY POOLS *****/
@@ -1089,11 +1544,36 @@ impl Display for MemoryAllocatorKind {
 
 
 
+/// Determines which free block a BlockPool picks to serve a new allocation from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FitStrategy {
+    /// Picks the first free block that is large enough. This is the fastest, but tends to split up large blocks early.
+    First,
+    /// Picks the smallest free block that is large enough, which keeps fragmentation low. This is the default.
+    #[default]
+    Best,
+    /// Picks the largest free block, which leaves leftover blocks that are as large as possible.
+    Worst,
+}
+
+impl Display for FitStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use FitStrategy::*;
+        match self {
+            First => write!(f, "First-fit"),
+            Best  => write!(f, "Best-fit"),
+            Worst => write!(f, "Worst-fit"),
+        }
+    }
+}
+
+
+
 
 
 /***** IMAGES *****/
 /// Defines how to re-map components.
-#[derive(Debug, Clone)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ComponentSwizzle {
     /// Do not swizzle anything
     Identity,
@@ -1117,8 +1597,8 @@ enum_from!(impl From<vk::ComponentSwizzle> for ComponentSwizzle {
     vk::ComponentSwizzle::ONE      => ComponentSwizzle::One,
     vk::ComponentSwizzle::ZERO     => ComponentSwizzle::Zero,
     vk::ComponentSwizzle::R        => ComponentSwizzle::Red,
-    vk::ComponentSwizzle::B        => ComponentSwizzle::Blue,
     vk::ComponentSwizzle::G        => ComponentSwizzle::Green,
+    vk::ComponentSwizzle::B        => ComponentSwizzle::Blue,
     vk::ComponentSwizzle::A        => ComponentSwizzle::Alpha,
 });
 
@@ -1182,7 +1662,7 @@ enum_from!(impl From<vk::ImageViewType> for ImageViewKind {
 
 
 /// The format of an Image.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ImageFormat {
     /// The format is unknown
     Undefined,
@@ -1555,6 +2035,199 @@ pub enum ImageFormat {
     ASTC12X12UNormBlock,
     /// ASTC_12X12_SRGB_BLOCK
     ASTC12X12SRgbBlock,
+
+    /// PVRTC1_2BPP_UNORM_BLOCK_IMG
+    PVRTC1_2BppUNormBlockIMG,
+    /// PVRTC1_4BPP_UNORM_BLOCK_IMG
+    PVRTC1_4BppUNormBlockIMG,
+    /// PVRTC2_2BPP_UNORM_BLOCK_IMG
+    PVRTC2_2BppUNormBlockIMG,
+    /// PVRTC2_4BPP_UNORM_BLOCK_IMG
+    PVRTC2_4BppUNormBlockIMG,
+    /// PVRTC1_2BPP_SRGB_BLOCK_IMG
+    PVRTC1_2BppSRgbBlockIMG,
+    /// PVRTC1_4BPP_SRGB_BLOCK_IMG
+    PVRTC1_4BppSRgbBlockIMG,
+    /// PVRTC2_2BPP_SRGB_BLOCK_IMG
+    PVRTC2_2BppSRgbBlockIMG,
+    /// PVRTC2_4BPP_SRGB_BLOCK_IMG
+    PVRTC2_4BppSRgbBlockIMG,
+
+    /// ASTC_4X4_SFLOAT_BLOCK
+    ASTC4X4SFloatBlock,
+    /// ASTC_5X4_SFLOAT_BLOCK
+    ASTC5X4SFloatBlock,
+    /// ASTC_5X5_SFLOAT_BLOCK
+    ASTC5X5SFloatBlock,
+    /// ASTC_6X5_SFLOAT_BLOCK
+    ASTC6X5SFloatBlock,
+    /// ASTC_6X6_SFLOAT_BLOCK
+    ASTC6X6SFloatBlock,
+    /// ASTC_8X5_SFLOAT_BLOCK
+    ASTC8X5SFloatBlock,
+    /// ASTC_8X6_SFLOAT_BLOCK
+    ASTC8X6SFloatBlock,
+    /// ASTC_8X8_SFLOAT_BLOCK
+    ASTC8X8SFloatBlock,
+    /// ASTC_10X5_SFLOAT_BLOCK
+    ASTC10X5SFloatBlock,
+    /// ASTC_10X6_SFLOAT_BLOCK
+    ASTC10X6SFloatBlock,
+    /// ASTC_10X8_SFLOAT_BLOCK
+    ASTC10X8SFloatBlock,
+    /// ASTC_10X10_SFLOAT_BLOCK
+    ASTC10X10SFloatBlock,
+    /// ASTC_12X10_SFLOAT_BLOCK
+    ASTC12X10SFloatBlock,
+    /// ASTC_12X12_SFLOAT_BLOCK
+    ASTC12X12SFloatBlock,
+
+    /// G8B8G8R8_422_UNORM
+    G8B8G8R8_422UNorm,
+    /// B8G8R8G8_422_UNORM
+    B8G8R8G8_422UNorm,
+    /// G8_B8_R8_3PLANE_420_UNORM
+    G8B8R8_3Plane420UNorm,
+    /// G8_B8R8_2PLANE_420_UNORM
+    G8B8R8_2Plane420UNorm,
+    /// G8_B8_R8_3PLANE_422_UNORM
+    G8B8R8_3Plane422UNorm,
+    /// G8_B8R8_2PLANE_422_UNORM
+    G8B8R8_2Plane422UNorm,
+    /// G8_B8_R8_3PLANE_444_UNORM
+    G8B8R8_3Plane444UNorm,
+    /// R10X6_UNORM_PACK16
+    R10X6UNormPack16,
+    /// R10X6G10X6_UNORM_2PACK16
+    R10X6G10X6UNorm2Pack16,
+    /// R10X6G10X6B10X6A10X6_UNORM_4PACK16
+    R10X6G10X6B10X6A10X6UNorm4Pack16,
+    /// G10X6B10X6G10X6R10X6_422_UNORM_4PACK16
+    G10X6B10X6G10X6R10X6_422UNorm4Pack16,
+    /// B10X6G10X6R10X6G10X6_422_UNORM_4PACK16
+    B10X6G10X6R10X6G10X6_422UNorm4Pack16,
+    /// G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+    G10X6B10X6R10X6_3Plane420UNorm3Pack16,
+    /// G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+    G10X6B10X6R10X6_2Plane420UNorm3Pack16,
+    /// G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+    G10X6B10X6R10X6_3Plane422UNorm3Pack16,
+    /// G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+    G10X6B10X6R10X6_2Plane422UNorm3Pack16,
+    /// G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16
+    G10X6B10X6R10X6_3Plane444UNorm3Pack16,
+    /// R12X4_UNORM_PACK16
+    R12X4UNormPack16,
+    /// R12X4G12X4_UNORM_2PACK16
+    R12X4G12X4UNorm2Pack16,
+    /// R12X4G12X4B12X4A12X4_UNORM_4PACK16
+    R12X4G12X4B12X4A12X4UNorm4Pack16,
+    /// G12X4B12X4G12X4R12X4_422_UNORM_4PACK16
+    G12X4B12X4G12X4R12X4_422UNorm4Pack16,
+    /// B12X4G12X4R12X4G12X4_422_UNORM_4PACK16
+    B12X4G12X4R12X4G12X4_422UNorm4Pack16,
+    /// G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+    G12X4B12X4R12X4_3Plane420UNorm3Pack16,
+    /// G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+    G12X4B12X4R12X4_2Plane420UNorm3Pack16,
+    /// G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+    G12X4B12X4R12X4_3Plane422UNorm3Pack16,
+    /// G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+    G12X4B12X4R12X4_2Plane422UNorm3Pack16,
+    /// G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16
+    G12X4B12X4R12X4_3Plane444UNorm3Pack16,
+    /// G16B16G16R16_422_UNORM
+    G16B16G16R16_422UNorm,
+    /// B16G16R16G16_422_UNORM
+    B16G16R16G16_422UNorm,
+    /// G16_B16_R16_3PLANE_420_UNORM
+    G16B16R16_3Plane420UNorm,
+    /// G16_B16R16_2PLANE_420_UNORM
+    G16B16R16_2Plane420UNorm,
+    /// G16_B16_R16_3PLANE_422_UNORM
+    G16B16R16_3Plane422UNorm,
+    /// G16_B16R16_2PLANE_422_UNORM
+    G16B16R16_2Plane422UNorm,
+    /// G16_B16_R16_3PLANE_444_UNORM
+    G16B16R16_3Plane444UNorm,
+
+    /// ASTC_3X3X3_UNORM_BLOCK_EXT
+    ASTC3X3X3UNormBlockEXT,
+    /// ASTC_3X3X3_SRGB_BLOCK_EXT
+    ASTC3X3X3SRgbBlockEXT,
+    /// ASTC_3X3X3_SFLOAT_BLOCK_EXT
+    ASTC3X3X3SFloatBlockEXT,
+    /// ASTC_4X3X3_UNORM_BLOCK_EXT
+    ASTC4X3X3UNormBlockEXT,
+    /// ASTC_4X3X3_SRGB_BLOCK_EXT
+    ASTC4X3X3SRgbBlockEXT,
+    /// ASTC_4X3X3_SFLOAT_BLOCK_EXT
+    ASTC4X3X3SFloatBlockEXT,
+    /// ASTC_4X4X3_UNORM_BLOCK_EXT
+    ASTC4X4X3UNormBlockEXT,
+    /// ASTC_4X4X3_SRGB_BLOCK_EXT
+    ASTC4X4X3SRgbBlockEXT,
+    /// ASTC_4X4X3_SFLOAT_BLOCK_EXT
+    ASTC4X4X3SFloatBlockEXT,
+    /// ASTC_4X4X4_UNORM_BLOCK_EXT
+    ASTC4X4X4UNormBlockEXT,
+    /// ASTC_4X4X4_SRGB_BLOCK_EXT
+    ASTC4X4X4SRgbBlockEXT,
+    /// ASTC_4X4X4_SFLOAT_BLOCK_EXT
+    ASTC4X4X4SFloatBlockEXT,
+    /// ASTC_5X4X4_UNORM_BLOCK_EXT
+    ASTC5X4X4UNormBlockEXT,
+    /// ASTC_5X4X4_SRGB_BLOCK_EXT
+    ASTC5X4X4SRgbBlockEXT,
+    /// ASTC_5X4X4_SFLOAT_BLOCK_EXT
+    ASTC5X4X4SFloatBlockEXT,
+    /// ASTC_5X5X4_UNORM_BLOCK_EXT
+    ASTC5X5X4UNormBlockEXT,
+    /// ASTC_5X5X4_SRGB_BLOCK_EXT
+    ASTC5X5X4SRgbBlockEXT,
+    /// ASTC_5X5X4_SFLOAT_BLOCK_EXT
+    ASTC5X5X4SFloatBlockEXT,
+    /// ASTC_5X5X5_UNORM_BLOCK_EXT
+    ASTC5X5X5UNormBlockEXT,
+    /// ASTC_5X5X5_SRGB_BLOCK_EXT
+    ASTC5X5X5SRgbBlockEXT,
+    /// ASTC_5X5X5_SFLOAT_BLOCK_EXT
+    ASTC5X5X5SFloatBlockEXT,
+    /// ASTC_6X5X5_UNORM_BLOCK_EXT
+    ASTC6X5X5UNormBlockEXT,
+    /// ASTC_6X5X5_SRGB_BLOCK_EXT
+    ASTC6X5X5SRgbBlockEXT,
+    /// ASTC_6X5X5_SFLOAT_BLOCK_EXT
+    ASTC6X5X5SFloatBlockEXT,
+    /// ASTC_6X6X5_UNORM_BLOCK_EXT
+    ASTC6X6X5UNormBlockEXT,
+    /// ASTC_6X6X5_SRGB_BLOCK_EXT
+    ASTC6X6X5SRgbBlockEXT,
+    /// ASTC_6X6X5_SFLOAT_BLOCK_EXT
+    ASTC6X6X5SFloatBlockEXT,
+    /// ASTC_6X6X6_UNORM_BLOCK_EXT
+    ASTC6X6X6UNormBlockEXT,
+    /// ASTC_6X6X6_SRGB_BLOCK_EXT
+    ASTC6X6X6SRgbBlockEXT,
+    /// ASTC_6X6X6_SFLOAT_BLOCK_EXT
+    ASTC6X6X6SFloatBlockEXT,
+
+    /// G8_B8R8_2PLANE_444_UNORM
+    G8B8R8_2Plane444UNorm,
+    /// G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16
+    G10X6B10X6R10X6_2Plane444UNorm3Pack16,
+    /// G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16
+    G12X4B12X4R12X4_2Plane444UNorm3Pack16,
+    /// G16_B16R16_2PLANE_444_UNORM
+    G16B16R16_2Plane444UNorm,
+
+    /// A4R4G4B4_UNORM_PACK16
+    A4R4G4B4UNormPack16,
+    /// A4B4G4R4_UNORM_PACK16
+    A4B4G4R4UNormPack16,
+
+    /// R16G16_S10_5_NV
+    R16G16S10_5NV,
 }
 
 impl Display for ImageFormat {
@@ -1748,8 +2421,333 @@ impl Display for ImageFormat {
             ASTC12X10SRgbBlock => write!(f, "ASTC12X10SRgbBlock"),
             ASTC12X12UNormBlock => write!(f, "ASTC12X12UNormBlock"),
             ASTC12X12SRgbBlock => write!(f, "ASTC12X12SRgbBlock"),
+
+            PVRTC1_2BppUNormBlockIMG => write!(f, "PVRTC1_2BppUNormBlockIMG"),
+            PVRTC1_4BppUNormBlockIMG => write!(f, "PVRTC1_4BppUNormBlockIMG"),
+            PVRTC2_2BppUNormBlockIMG => write!(f, "PVRTC2_2BppUNormBlockIMG"),
+            PVRTC2_4BppUNormBlockIMG => write!(f, "PVRTC2_4BppUNormBlockIMG"),
+            PVRTC1_2BppSRgbBlockIMG => write!(f, "PVRTC1_2BppSRgbBlockIMG"),
+            PVRTC1_4BppSRgbBlockIMG => write!(f, "PVRTC1_4BppSRgbBlockIMG"),
+            PVRTC2_2BppSRgbBlockIMG => write!(f, "PVRTC2_2BppSRgbBlockIMG"),
+            PVRTC2_4BppSRgbBlockIMG => write!(f, "PVRTC2_4BppSRgbBlockIMG"),
+
+            ASTC4X4SFloatBlock => write!(f, "ASTC4X4SFloatBlock"),
+            ASTC5X4SFloatBlock => write!(f, "ASTC5X4SFloatBlock"),
+            ASTC5X5SFloatBlock => write!(f, "ASTC5X5SFloatBlock"),
+            ASTC6X5SFloatBlock => write!(f, "ASTC6X5SFloatBlock"),
+            ASTC6X6SFloatBlock => write!(f, "ASTC6X6SFloatBlock"),
+            ASTC8X5SFloatBlock => write!(f, "ASTC8X5SFloatBlock"),
+            ASTC8X6SFloatBlock => write!(f, "ASTC8X6SFloatBlock"),
+            ASTC8X8SFloatBlock => write!(f, "ASTC8X8SFloatBlock"),
+            ASTC10X5SFloatBlock => write!(f, "ASTC10X5SFloatBlock"),
+            ASTC10X6SFloatBlock => write!(f, "ASTC10X6SFloatBlock"),
+            ASTC10X8SFloatBlock => write!(f, "ASTC10X8SFloatBlock"),
+            ASTC10X10SFloatBlock => write!(f, "ASTC10X10SFloatBlock"),
+            ASTC12X10SFloatBlock => write!(f, "ASTC12X10SFloatBlock"),
+            ASTC12X12SFloatBlock => write!(f, "ASTC12X12SFloatBlock"),
+
+            G8B8G8R8_422UNorm => write!(f, "G8B8G8R8_422UNorm"),
+            B8G8R8G8_422UNorm => write!(f, "B8G8R8G8_422UNorm"),
+            G8B8R8_3Plane420UNorm => write!(f, "G8B8R8_3Plane420UNorm"),
+            G8B8R8_2Plane420UNorm => write!(f, "G8B8R8_2Plane420UNorm"),
+            G8B8R8_3Plane422UNorm => write!(f, "G8B8R8_3Plane422UNorm"),
+            G8B8R8_2Plane422UNorm => write!(f, "G8B8R8_2Plane422UNorm"),
+            G8B8R8_3Plane444UNorm => write!(f, "G8B8R8_3Plane444UNorm"),
+            R10X6UNormPack16 => write!(f, "R10X6UNormPack16"),
+            R10X6G10X6UNorm2Pack16 => write!(f, "R10X6G10X6UNorm2Pack16"),
+            R10X6G10X6B10X6A10X6UNorm4Pack16 => write!(f, "R10X6G10X6B10X6A10X6UNorm4Pack16"),
+            G10X6B10X6G10X6R10X6_422UNorm4Pack16 => write!(f, "G10X6B10X6G10X6R10X6_422UNorm4Pack16"),
+            B10X6G10X6R10X6G10X6_422UNorm4Pack16 => write!(f, "B10X6G10X6R10X6G10X6_422UNorm4Pack16"),
+            G10X6B10X6R10X6_3Plane420UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_3Plane420UNorm3Pack16"),
+            G10X6B10X6R10X6_2Plane420UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_2Plane420UNorm3Pack16"),
+            G10X6B10X6R10X6_3Plane422UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_3Plane422UNorm3Pack16"),
+            G10X6B10X6R10X6_2Plane422UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_2Plane422UNorm3Pack16"),
+            G10X6B10X6R10X6_3Plane444UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_3Plane444UNorm3Pack16"),
+            R12X4UNormPack16 => write!(f, "R12X4UNormPack16"),
+            R12X4G12X4UNorm2Pack16 => write!(f, "R12X4G12X4UNorm2Pack16"),
+            R12X4G12X4B12X4A12X4UNorm4Pack16 => write!(f, "R12X4G12X4B12X4A12X4UNorm4Pack16"),
+            G12X4B12X4G12X4R12X4_422UNorm4Pack16 => write!(f, "G12X4B12X4G12X4R12X4_422UNorm4Pack16"),
+            B12X4G12X4R12X4G12X4_422UNorm4Pack16 => write!(f, "B12X4G12X4R12X4G12X4_422UNorm4Pack16"),
+            G12X4B12X4R12X4_3Plane420UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_3Plane420UNorm3Pack16"),
+            G12X4B12X4R12X4_2Plane420UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_2Plane420UNorm3Pack16"),
+            G12X4B12X4R12X4_3Plane422UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_3Plane422UNorm3Pack16"),
+            G12X4B12X4R12X4_2Plane422UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_2Plane422UNorm3Pack16"),
+            G12X4B12X4R12X4_3Plane444UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_3Plane444UNorm3Pack16"),
+            G16B16G16R16_422UNorm => write!(f, "G16B16G16R16_422UNorm"),
+            B16G16R16G16_422UNorm => write!(f, "B16G16R16G16_422UNorm"),
+            G16B16R16_3Plane420UNorm => write!(f, "G16B16R16_3Plane420UNorm"),
+            G16B16R16_2Plane420UNorm => write!(f, "G16B16R16_2Plane420UNorm"),
+            G16B16R16_3Plane422UNorm => write!(f, "G16B16R16_3Plane422UNorm"),
+            G16B16R16_2Plane422UNorm => write!(f, "G16B16R16_2Plane422UNorm"),
+            G16B16R16_3Plane444UNorm => write!(f, "G16B16R16_3Plane444UNorm"),
+
+            ASTC3X3X3UNormBlockEXT => write!(f, "ASTC3X3X3UNormBlockEXT"),
+            ASTC3X3X3SRgbBlockEXT => write!(f, "ASTC3X3X3SRgbBlockEXT"),
+            ASTC3X3X3SFloatBlockEXT => write!(f, "ASTC3X3X3SFloatBlockEXT"),
+            ASTC4X3X3UNormBlockEXT => write!(f, "ASTC4X3X3UNormBlockEXT"),
+            ASTC4X3X3SRgbBlockEXT => write!(f, "ASTC4X3X3SRgbBlockEXT"),
+            ASTC4X3X3SFloatBlockEXT => write!(f, "ASTC4X3X3SFloatBlockEXT"),
+            ASTC4X4X3UNormBlockEXT => write!(f, "ASTC4X4X3UNormBlockEXT"),
+            ASTC4X4X3SRgbBlockEXT => write!(f, "ASTC4X4X3SRgbBlockEXT"),
+            ASTC4X4X3SFloatBlockEXT => write!(f, "ASTC4X4X3SFloatBlockEXT"),
+            ASTC4X4X4UNormBlockEXT => write!(f, "ASTC4X4X4UNormBlockEXT"),
+            ASTC4X4X4SRgbBlockEXT => write!(f, "ASTC4X4X4SRgbBlockEXT"),
+            ASTC4X4X4SFloatBlockEXT => write!(f, "ASTC4X4X4SFloatBlockEXT"),
+            ASTC5X4X4UNormBlockEXT => write!(f, "ASTC5X4X4UNormBlockEXT"),
+            ASTC5X4X4SRgbBlockEXT => write!(f, "ASTC5X4X4SRgbBlockEXT"),
+            ASTC5X4X4SFloatBlockEXT => write!(f, "ASTC5X4X4SFloatBlockEXT"),
+            ASTC5X5X4UNormBlockEXT => write!(f, "ASTC5X5X4UNormBlockEXT"),
+            ASTC5X5X4SRgbBlockEXT => write!(f, "ASTC5X5X4SRgbBlockEXT"),
+            ASTC5X5X4SFloatBlockEXT => write!(f, "ASTC5X5X4SFloatBlockEXT"),
+            ASTC5X5X5UNormBlockEXT => write!(f, "ASTC5X5X5UNormBlockEXT"),
+            ASTC5X5X5SRgbBlockEXT => write!(f, "ASTC5X5X5SRgbBlockEXT"),
+            ASTC5X5X5SFloatBlockEXT => write!(f, "ASTC5X5X5SFloatBlockEXT"),
+            ASTC6X5X5UNormBlockEXT => write!(f, "ASTC6X5X5UNormBlockEXT"),
+            ASTC6X5X5SRgbBlockEXT => write!(f, "ASTC6X5X5SRgbBlockEXT"),
+            ASTC6X5X5SFloatBlockEXT => write!(f, "ASTC6X5X5SFloatBlockEXT"),
+            ASTC6X6X5UNormBlockEXT => write!(f, "ASTC6X6X5UNormBlockEXT"),
+            ASTC6X6X5SRgbBlockEXT => write!(f, "ASTC6X6X5SRgbBlockEXT"),
+            ASTC6X6X5SFloatBlockEXT => write!(f, "ASTC6X6X5SFloatBlockEXT"),
+            ASTC6X6X6UNormBlockEXT => write!(f, "ASTC6X6X6UNormBlockEXT"),
+            ASTC6X6X6SRgbBlockEXT => write!(f, "ASTC6X6X6SRgbBlockEXT"),
+            ASTC6X6X6SFloatBlockEXT => write!(f, "ASTC6X6X6SFloatBlockEXT"),
+
+            G8B8R8_2Plane444UNorm => write!(f, "G8B8R8_2Plane444UNorm"),
+            G10X6B10X6R10X6_2Plane444UNorm3Pack16 => write!(f, "G10X6B10X6R10X6_2Plane444UNorm3Pack16"),
+            G12X4B12X4R12X4_2Plane444UNorm3Pack16 => write!(f, "G12X4B12X4R12X4_2Plane444UNorm3Pack16"),
+            G16B16R16_2Plane444UNorm => write!(f, "G16B16R16_2Plane444UNorm"),
+
+            A4R4G4B4UNormPack16 => write!(f, "A4R4G4B4UNormPack16"),
+            A4B4G4R4UNormPack16 => write!(f, "A4B4G4R4UNormPack16"),
+
+            R16G16S10_5NV => write!(f, "R16G16S10_5NV"),
+        }
+    }
+}
+
+impl ImageFormat {
+    /// Returns whether this format has a depth aspect.
+    #[inline]
+    pub fn is_depth(&self) -> bool {
+        use ImageFormat::*;
+        matches!(self, D16UNorm | X8D24UNormPack32 | D32SFloat | D16UNormS8UInt | D24UNormS8UInt | D32SFloatS8UInt)
+    }
+
+    /// Returns whether this format has a stencil aspect.
+    #[inline]
+    pub fn is_stencil(&self) -> bool {
+        use ImageFormat::*;
+        matches!(self, S8UInt | D16UNormS8UInt | D24UNormS8UInt | D32SFloatS8UInt)
+    }
+
+    /// Returns whether images of this format may be copied to images of the given format with `CommandBuffer::copy_image()`.
+    /// 
+    /// Depth/stencil formats may only be copied to the same format, while other formats may be copied to any format with the same texel block size and block extent (e.g., `R8G8B8A8UNorm` to `R32UInt`). Undefined and multi-planar formats are never compatible.
+    /// 
+    /// # Arguments
+    /// - `other`: The format of the image to copy to.
+    /// 
+    /// # Returns
+    /// True if the formats are compatible, or false otherwise.
+    pub fn is_copy_compatible(&self, other: ImageFormat) -> bool {
+        if self.block_size() == 0 || other.block_size() == 0 { return false; }
+        if self.is_depth() || self.is_stencil() || other.is_depth() || other.is_stencil() { return *self == other; }
+        self.block_size() == other.block_size() && self.block_extent() == other.block_extent()
+    }
+
+    /// Returns the aspects that cover all of the data in images of this format.
+    /// 
+    /// Commands that act on an entire image (transitions, copies, clears) should use these aspects, since e.g. only transitioning the depth aspect of a combined depth/stencil image leaves its stencil aspect in the old layout.
+    /// 
+    /// # Returns
+    /// `ImageAspectFlags::DEPTH` and/or `ImageAspectFlags::STENCIL` for depth/stencil formats (both for combined ones), or `ImageAspectFlags::COLOUR` otherwise.
+    #[inline]
+    pub fn default_aspect(&self) -> ImageAspectFlags {
+        match (self.is_depth(), self.is_stencil()) {
+            (true, true)   => ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL,
+            (true, false)  => ImageAspectFlags::DEPTH,
+            (false, true)  => ImageAspectFlags::STENCIL,
+            (false, false) => ImageAspectFlags::COLOUR,
+        }
+    }
+
+    /// Returns the size (in bytes) of a single texel block of this format.
+    /// 
+    /// For uncompressed formats, a block is a single texel; for block-compressed formats, it is one compressed block (see `ImageFormat::block_extent()`). Combined depth/stencil formats return the size of both aspects together.
+    /// 
+    /// # Returns
+    /// The number of bytes in a single texel block, or `0` for `ImageFormat::Undefined` and multi-planar formats (whose planes have to be sized separately).
+    pub fn block_size(&self) -> usize {
+        use ImageFormat::*;
+        match self {
+            Undefined
+            | G8B8R8_3Plane420UNorm | G8B8R8_2Plane420UNorm | G8B8R8_3Plane422UNorm | G8B8R8_2Plane422UNorm | G8B8R8_3Plane444UNorm | G10X6B10X6R10X6_3Plane420UNorm3Pack16
+            | G10X6B10X6R10X6_2Plane420UNorm3Pack16 | G10X6B10X6R10X6_3Plane422UNorm3Pack16 | G10X6B10X6R10X6_2Plane422UNorm3Pack16 | G10X6B10X6R10X6_3Plane444UNorm3Pack16 | G12X4B12X4R12X4_3Plane420UNorm3Pack16 | G12X4B12X4R12X4_2Plane420UNorm3Pack16
+            | G12X4B12X4R12X4_3Plane422UNorm3Pack16 | G12X4B12X4R12X4_2Plane422UNorm3Pack16 | G12X4B12X4R12X4_3Plane444UNorm3Pack16 | G16B16R16_3Plane420UNorm | G16B16R16_2Plane420UNorm | G16B16R16_3Plane422UNorm
+            | G16B16R16_2Plane422UNorm | G16B16R16_3Plane444UNorm | G8B8R8_2Plane444UNorm | G10X6B10X6R10X6_2Plane444UNorm3Pack16 | G12X4B12X4R12X4_2Plane444UNorm3Pack16 | G16B16R16_2Plane444UNorm
+                => 0,
+            R4G4UNormPack8 | R8UNorm | R8SNorm | R8UScaled | R8SScaled | R8UInt
+            | R8SInt | R8SRgb | S8UInt
+                => 1,
+            R4G4B4A4UNormPack16 | B4G4R4A4UNormPack16 | R5G6B5UNormPack16 | B5G6R5UNormPack16 | R5G5B5A1UNormPack16 | B5G5R5A1UNormPack16
+            | A1R5G5B5UNormPack16 | R8G8UNorm | R8G8SNorm | R8G8UScaled | R8G8SScaled | R8G8UInt
+            | R8G8SInt | R8G8SRgb | R16UNorm | R16SNorm | R16UScaled | R16SScaled
+            | R16UInt | R16SInt | R16SFloat | D16UNorm
+            | R10X6UNormPack16 | R12X4UNormPack16 | A4R4G4B4UNormPack16 | A4B4G4R4UNormPack16
+                => 2,
+            R8G8B8UNorm | R8G8B8SNorm | R8G8B8UScaled | R8G8B8SScaled | R8G8B8UInt | R8G8B8SInt
+            | R8G8B8SRgb | B8G8R8UNorm | B8G8R8SNorm | B8G8R8UScaled | B8G8R8SScaled | B8G8R8UInt
+            | B8G8R8SInt | B8G8R8SRgb | D16UNormS8UInt
+                => 3,
+            R8G8B8A8UNorm | R8G8B8A8SNorm | R8G8B8A8UScaled | R8G8B8A8SScaled | R8G8B8A8UInt | R8G8B8A8SInt
+            | R8G8B8A8SRgb | B8G8R8A8UNorm | B8G8R8A8SNorm | B8G8R8A8UScaled | B8G8R8A8SScaled | B8G8R8A8UInt
+            | B8G8R8A8SInt | B8G8R8A8SRgb | A8B8G8R8UNormPack32 | A8B8G8R8SNormPack32 | A8B8G8R8UScaledPack32 | A8B8G8R8SScaledPack32
+            | A8B8G8R8UIntPack32 | A8B8G8R8SIntPack32 | A8B8G8R8SRgbPack32 | A2R10G10B10UNormPack32 | A2R10G10B10SNormPack32 | A2R10G10B10UScaledPack32
+            | A2R10G10B10SScaledPack32 | A2R10G10B10UIntPack32 | A2R10G10B10SIntPack32 | A2B10G10R10UNormPack32 | A2B10G10R10SNormPack32 | A2B10G10R10UScaledPack32
+            | A2B10G10R10SScaledPack32 | A2B10G10R10UIntPack32 | A2B10G10R10SIntPack32 | R16G16UNorm | R16G16SNorm | R16G16UScaled
+            | R16G16SScaled | R16G16UInt | R16G16SInt | R16G16SFloat | R32UInt | R32SInt
+            | R32SFloat | B10G11R11UFloatPack32 | E5B9G9R9UFloatPack32 | X8D24UNormPack32 | D32SFloat | D24UNormS8UInt
+            | G8B8G8R8_422UNorm | B8G8R8G8_422UNorm | R10X6G10X6UNorm2Pack16 | R12X4G12X4UNorm2Pack16 | R16G16S10_5NV
+                => 4,
+            D32SFloatS8UInt
+                => 5,
+            R16G16B16UNorm | R16G16B16SNorm | R16G16B16UScaled | R16G16B16SScaled | R16G16B16UInt | R16G16B16SInt
+            | R16G16B16SFloat
+                => 6,
+            R16G16B16A16UNorm | R16G16B16A16SNorm | R16G16B16A16UScaled | R16G16B16A16SScaled | R16G16B16A16UInt | R16G16B16A16SInt
+            | R16G16B16A16SFloat | R32G32UInt | R32G32SInt | R32G32SFloat | R64UInt | R64SInt
+            | R64SFloat | BC1RGBUNormBlock | BC1RGBSRgbBlock | BC1RGBAUNormBlock | BC1RGBASRgbBlock | BC4UNormBlock
+            | BC4SNormBlock | ETC2R8G8B8UNormBlock | ETC2R8G8B8SRgbBlock | ETC2R8G8B8A1UNormBlock | ETC2R8G8B8A1SRgbBlock | EACR11UNormBlock
+            | EACR11SNormBlock
+            | PVRTC1_2BppUNormBlockIMG | PVRTC1_4BppUNormBlockIMG | PVRTC2_2BppUNormBlockIMG | PVRTC2_4BppUNormBlockIMG | PVRTC1_2BppSRgbBlockIMG | PVRTC1_4BppSRgbBlockIMG
+            | PVRTC2_2BppSRgbBlockIMG | PVRTC2_4BppSRgbBlockIMG | R10X6G10X6B10X6A10X6UNorm4Pack16 | G10X6B10X6G10X6R10X6_422UNorm4Pack16 | B10X6G10X6R10X6G10X6_422UNorm4Pack16 | R12X4G12X4B12X4A12X4UNorm4Pack16
+            | G12X4B12X4G12X4R12X4_422UNorm4Pack16 | B12X4G12X4R12X4G12X4_422UNorm4Pack16 | G16B16G16R16_422UNorm | B16G16R16G16_422UNorm
+                => 8,
+            R32G32B32UInt | R32G32B32SInt | R32G32B32SFloat
+                => 12,
+            R32G32B32A32UInt | R32G32B32A32SInt | R32G32B32A32SFloat | R64G64UInt | R64G64SInt | R64G64SFloat
+            | BC2UNormBlock | BC2SRgbBlock | BC3UNormBlock | BC3SRgbBlock | BC5UNormBlock | BC5SNormBlock
+            | BC6HUFloatBlock | BC6HSFloatBlock | BC7UNormBlock | BC7SRgbBlock | ETC2R8G8B8A8UNormBlock | ETC2R8G8B8A8SRgbBlock
+            | EACR11G11UNormBlock | EACR11G11SNormBlock | ASTC4X4UNormBlock | ASTC4X4SRgbBlock | ASTC5X4UNormBlock | ASTC5X4SRgbBlock
+            | ASTC5X5UNormBlock | ASTC5X5SRgbBlock | ASTC6X5UNormBlock | ASTC6X5SRgbBlock | ASTC6X6UNormBlock | ASTC6X6SRgbBlock
+            | ASTC8X5UNormBlock | ASTC8X5SRgbBlock | ASTC8X6UNormBlock | ASTC8X6SRgbBlock | ASTC8X8UNormBlock | ASTC8X8SRgbBlock
+            | ASTC10X5UNormBlock | ASTC10X5SRgbBlock | ASTC10X6UNormBlock | ASTC10X6SRgbBlock | ASTC10X8UNormBlock | ASTC10X8SRgbBlock
+            | ASTC10X10UNormBlock | ASTC10X10SRgbBlock | ASTC12X10UNormBlock | ASTC12X10SRgbBlock | ASTC12X12UNormBlock | ASTC12X12SRgbBlock
+            | ASTC4X4SFloatBlock | ASTC5X4SFloatBlock | ASTC5X5SFloatBlock | ASTC6X5SFloatBlock | ASTC6X6SFloatBlock | ASTC8X5SFloatBlock
+            | ASTC8X6SFloatBlock | ASTC8X8SFloatBlock | ASTC10X5SFloatBlock | ASTC10X6SFloatBlock | ASTC10X8SFloatBlock | ASTC10X10SFloatBlock
+            | ASTC12X10SFloatBlock | ASTC12X12SFloatBlock | ASTC3X3X3UNormBlockEXT | ASTC3X3X3SRgbBlockEXT | ASTC3X3X3SFloatBlockEXT | ASTC4X3X3UNormBlockEXT
+            | ASTC4X3X3SRgbBlockEXT | ASTC4X3X3SFloatBlockEXT | ASTC4X4X3UNormBlockEXT | ASTC4X4X3SRgbBlockEXT | ASTC4X4X3SFloatBlockEXT | ASTC4X4X4UNormBlockEXT
+            | ASTC4X4X4SRgbBlockEXT | ASTC4X4X4SFloatBlockEXT | ASTC5X4X4UNormBlockEXT | ASTC5X4X4SRgbBlockEXT | ASTC5X4X4SFloatBlockEXT | ASTC5X5X4UNormBlockEXT
+            | ASTC5X5X4SRgbBlockEXT | ASTC5X5X4SFloatBlockEXT | ASTC5X5X5UNormBlockEXT | ASTC5X5X5SRgbBlockEXT | ASTC5X5X5SFloatBlockEXT | ASTC6X5X5UNormBlockEXT
+            | ASTC6X5X5SRgbBlockEXT | ASTC6X5X5SFloatBlockEXT | ASTC6X6X5UNormBlockEXT | ASTC6X6X5SRgbBlockEXT | ASTC6X6X5SFloatBlockEXT | ASTC6X6X6UNormBlockEXT
+            | ASTC6X6X6SRgbBlockEXT | ASTC6X6X6SFloatBlockEXT
+                => 16,
+            R64G64B64UInt | R64G64B64SInt | R64G64B64SFloat
+                => 24,
+            R64G64B64A64UInt | R64G64B64A64SInt | R64G64B64A64SFloat
+                => 32,
+        }
+    }
+
+    /// Returns the dimensions (in texels) of a single texel block of this format.
+    /// 
+    /// # Returns
+    /// A tuple with the width and height of a single block. This is `(1, 1)` for all uncompressed formats, except for the packed `_422` formats (which share one chroma sample between two texels). For 3D ASTC formats, the depth of the block is not included.
+    pub fn block_extent(&self) -> (u32, u32) {
+        use ImageFormat::*;
+        match self {
+            BC1RGBUNormBlock | BC1RGBSRgbBlock | BC1RGBAUNormBlock | BC1RGBASRgbBlock | BC2UNormBlock | BC2SRgbBlock
+            | BC3UNormBlock | BC3SRgbBlock | BC4UNormBlock | BC4SNormBlock | BC5UNormBlock | BC5SNormBlock
+            | BC6HUFloatBlock | BC6HSFloatBlock | BC7UNormBlock | BC7SRgbBlock | ETC2R8G8B8UNormBlock | ETC2R8G8B8SRgbBlock
+            | ETC2R8G8B8A1UNormBlock | ETC2R8G8B8A1SRgbBlock | ETC2R8G8B8A8UNormBlock | ETC2R8G8B8A8SRgbBlock | EACR11UNormBlock | EACR11SNormBlock
+            | EACR11G11UNormBlock | EACR11G11SNormBlock | ASTC4X4UNormBlock | ASTC4X4SRgbBlock
+            | PVRTC1_4BppUNormBlockIMG | PVRTC2_4BppUNormBlockIMG | PVRTC1_4BppSRgbBlockIMG | PVRTC2_4BppSRgbBlockIMG | ASTC4X4SFloatBlock | ASTC4X4X3UNormBlockEXT
+            | ASTC4X4X3SRgbBlockEXT | ASTC4X4X3SFloatBlockEXT | ASTC4X4X4UNormBlockEXT | ASTC4X4X4SRgbBlockEXT | ASTC4X4X4SFloatBlockEXT
+                => (4, 4),
+            ASTC5X4UNormBlock | ASTC5X4SRgbBlock
+            | ASTC5X4SFloatBlock | ASTC5X4X4UNormBlockEXT | ASTC5X4X4SRgbBlockEXT | ASTC5X4X4SFloatBlockEXT
+                => (5, 4),
+            ASTC5X5UNormBlock | ASTC5X5SRgbBlock
+            | ASTC5X5SFloatBlock | ASTC5X5X4UNormBlockEXT | ASTC5X5X4SRgbBlockEXT | ASTC5X5X4SFloatBlockEXT | ASTC5X5X5UNormBlockEXT | ASTC5X5X5SRgbBlockEXT
+            | ASTC5X5X5SFloatBlockEXT
+                => (5, 5),
+            ASTC6X5UNormBlock | ASTC6X5SRgbBlock
+            | ASTC6X5SFloatBlock | ASTC6X5X5UNormBlockEXT | ASTC6X5X5SRgbBlockEXT | ASTC6X5X5SFloatBlockEXT
+                => (6, 5),
+            ASTC6X6UNormBlock | ASTC6X6SRgbBlock
+            | ASTC6X6SFloatBlock | ASTC6X6X5UNormBlockEXT | ASTC6X6X5SRgbBlockEXT | ASTC6X6X5SFloatBlockEXT | ASTC6X6X6UNormBlockEXT | ASTC6X6X6SRgbBlockEXT
+            | ASTC6X6X6SFloatBlockEXT
+                => (6, 6),
+            ASTC8X5UNormBlock | ASTC8X5SRgbBlock
+            | ASTC8X5SFloatBlock
+                => (8, 5),
+            ASTC8X6UNormBlock | ASTC8X6SRgbBlock
+            | ASTC8X6SFloatBlock
+                => (8, 6),
+            ASTC8X8UNormBlock | ASTC8X8SRgbBlock
+            | ASTC8X8SFloatBlock
+                => (8, 8),
+            ASTC10X5UNormBlock | ASTC10X5SRgbBlock
+            | ASTC10X5SFloatBlock
+                => (10, 5),
+            ASTC10X6UNormBlock | ASTC10X6SRgbBlock
+            | ASTC10X6SFloatBlock
+                => (10, 6),
+            ASTC10X8UNormBlock | ASTC10X8SRgbBlock
+            | ASTC10X8SFloatBlock
+                => (10, 8),
+            ASTC10X10UNormBlock | ASTC10X10SRgbBlock
+            | ASTC10X10SFloatBlock
+                => (10, 10),
+            ASTC12X10UNormBlock | ASTC12X10SRgbBlock
+            | ASTC12X10SFloatBlock
+                => (12, 10),
+            ASTC12X12UNormBlock | ASTC12X12SRgbBlock
+            | ASTC12X12SFloatBlock
+                => (12, 12),
+            G8B8G8R8_422UNorm | B8G8R8G8_422UNorm | G10X6B10X6G10X6R10X6_422UNorm4Pack16 | B10X6G10X6R10X6G10X6_422UNorm4Pack16 | G12X4B12X4G12X4R12X4_422UNorm4Pack16 | B12X4G12X4R12X4G12X4_422UNorm4Pack16
+            | G16B16G16R16_422UNorm | B16G16R16G16_422UNorm
+                => (2, 1),
+            ASTC3X3X3UNormBlockEXT | ASTC3X3X3SRgbBlockEXT | ASTC3X3X3SFloatBlockEXT
+                => (3, 3),
+            ASTC4X3X3UNormBlockEXT | ASTC4X3X3SRgbBlockEXT | ASTC4X3X3SFloatBlockEXT
+                => (4, 3),
+            PVRTC1_2BppUNormBlockIMG | PVRTC2_2BppUNormBlockIMG | PVRTC1_2BppSRgbBlockIMG | PVRTC2_2BppSRgbBlockIMG
+                => (8, 4),
+
+            _ => (1, 1),
         }
     }
+
+    /// Computes the number of bytes needed to store a 2D image of this format with the given extent and number of mip levels.
+    /// 
+    /// This is the size needed for a buffer that is tightly packed with all mip levels (in order), e.g., a staging buffer used to upload a texture with `copy_buffer_to_image`. Block-compressed formats are rounded up to a whole number of blocks per level.
+    /// 
+    /// # Arguments
+    /// - `extent`: The size (in texels) of the base mip level.
+    /// - `mip_levels`: The number of mip levels to account for (including the base level).
+    /// 
+    /// # Returns
+    /// The number of bytes needed to store the image across all of its mip levels.
+    pub fn buffer_size(&self, extent: Extent2D<u32>, mip_levels: u32) -> usize {
+        let (block_w, block_h): (u32, u32) = self.block_extent();
+        let block_size: usize = self.block_size();
+
+        // Sum the sizes of each of the mip levels
+        let mut size: usize = 0;
+        for level in 0..mip_levels {
+            // Compute the size of this level, which is never smaller than one texel
+            let Extent2D{ w, h } = extent.mip_level(level);
+
+            // Round up to a whole number of blocks
+            let blocks_w: usize = w.div_ceil(block_w) as usize;
+            let blocks_h: usize = h.div_ceil(block_h) as usize;
+            size += blocks_w * blocks_h * block_size;
+        }
+
+        // Done
+        size
+    }
 }
 
 enum_from!(impl From<vk::Format> for ImageFormat {
@@ -1939,6 +2937,106 @@ enum_from!(impl From<vk::Format> for ImageFormat {
     vk::Format::ASTC_12X10_SRGB_BLOCK => ImageFormat::ASTC12X10SRgbBlock,
     vk::Format::ASTC_12X12_UNORM_BLOCK => ImageFormat::ASTC12X12UNormBlock,
     vk::Format::ASTC_12X12_SRGB_BLOCK => ImageFormat::ASTC12X12SRgbBlock,
+
+    vk::Format::PVRTC1_2BPP_UNORM_BLOCK_IMG => ImageFormat::PVRTC1_2BppUNormBlockIMG,
+    vk::Format::PVRTC1_4BPP_UNORM_BLOCK_IMG => ImageFormat::PVRTC1_4BppUNormBlockIMG,
+    vk::Format::PVRTC2_2BPP_UNORM_BLOCK_IMG => ImageFormat::PVRTC2_2BppUNormBlockIMG,
+    vk::Format::PVRTC2_4BPP_UNORM_BLOCK_IMG => ImageFormat::PVRTC2_4BppUNormBlockIMG,
+    vk::Format::PVRTC1_2BPP_SRGB_BLOCK_IMG => ImageFormat::PVRTC1_2BppSRgbBlockIMG,
+    vk::Format::PVRTC1_4BPP_SRGB_BLOCK_IMG => ImageFormat::PVRTC1_4BppSRgbBlockIMG,
+    vk::Format::PVRTC2_2BPP_SRGB_BLOCK_IMG => ImageFormat::PVRTC2_2BppSRgbBlockIMG,
+    vk::Format::PVRTC2_4BPP_SRGB_BLOCK_IMG => ImageFormat::PVRTC2_4BppSRgbBlockIMG,
+
+    vk::Format::ASTC_4X4_SFLOAT_BLOCK => ImageFormat::ASTC4X4SFloatBlock,
+    vk::Format::ASTC_5X4_SFLOAT_BLOCK => ImageFormat::ASTC5X4SFloatBlock,
+    vk::Format::ASTC_5X5_SFLOAT_BLOCK => ImageFormat::ASTC5X5SFloatBlock,
+    vk::Format::ASTC_6X5_SFLOAT_BLOCK => ImageFormat::ASTC6X5SFloatBlock,
+    vk::Format::ASTC_6X6_SFLOAT_BLOCK => ImageFormat::ASTC6X6SFloatBlock,
+    vk::Format::ASTC_8X5_SFLOAT_BLOCK => ImageFormat::ASTC8X5SFloatBlock,
+    vk::Format::ASTC_8X6_SFLOAT_BLOCK => ImageFormat::ASTC8X6SFloatBlock,
+    vk::Format::ASTC_8X8_SFLOAT_BLOCK => ImageFormat::ASTC8X8SFloatBlock,
+    vk::Format::ASTC_10X5_SFLOAT_BLOCK => ImageFormat::ASTC10X5SFloatBlock,
+    vk::Format::ASTC_10X6_SFLOAT_BLOCK => ImageFormat::ASTC10X6SFloatBlock,
+    vk::Format::ASTC_10X8_SFLOAT_BLOCK => ImageFormat::ASTC10X8SFloatBlock,
+    vk::Format::ASTC_10X10_SFLOAT_BLOCK => ImageFormat::ASTC10X10SFloatBlock,
+    vk::Format::ASTC_12X10_SFLOAT_BLOCK => ImageFormat::ASTC12X10SFloatBlock,
+    vk::Format::ASTC_12X12_SFLOAT_BLOCK => ImageFormat::ASTC12X12SFloatBlock,
+
+    vk::Format::G8B8G8R8_422_UNORM => ImageFormat::G8B8G8R8_422UNorm,
+    vk::Format::B8G8R8G8_422_UNORM => ImageFormat::B8G8R8G8_422UNorm,
+    vk::Format::G8_B8_R8_3PLANE_420_UNORM => ImageFormat::G8B8R8_3Plane420UNorm,
+    vk::Format::G8_B8R8_2PLANE_420_UNORM => ImageFormat::G8B8R8_2Plane420UNorm,
+    vk::Format::G8_B8_R8_3PLANE_422_UNORM => ImageFormat::G8B8R8_3Plane422UNorm,
+    vk::Format::G8_B8R8_2PLANE_422_UNORM => ImageFormat::G8B8R8_2Plane422UNorm,
+    vk::Format::G8_B8_R8_3PLANE_444_UNORM => ImageFormat::G8B8R8_3Plane444UNorm,
+    vk::Format::R10X6_UNORM_PACK16 => ImageFormat::R10X6UNormPack16,
+    vk::Format::R10X6G10X6_UNORM_2PACK16 => ImageFormat::R10X6G10X6UNorm2Pack16,
+    vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16 => ImageFormat::R10X6G10X6B10X6A10X6UNorm4Pack16,
+    vk::Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => ImageFormat::G10X6B10X6G10X6R10X6_422UNorm4Pack16,
+    vk::Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => ImageFormat::B10X6G10X6R10X6G10X6_422UNorm4Pack16,
+    vk::Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_3Plane420UNorm3Pack16,
+    vk::Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_2Plane420UNorm3Pack16,
+    vk::Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_3Plane422UNorm3Pack16,
+    vk::Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_2Plane422UNorm3Pack16,
+    vk::Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_3Plane444UNorm3Pack16,
+    vk::Format::R12X4_UNORM_PACK16 => ImageFormat::R12X4UNormPack16,
+    vk::Format::R12X4G12X4_UNORM_2PACK16 => ImageFormat::R12X4G12X4UNorm2Pack16,
+    vk::Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16 => ImageFormat::R12X4G12X4B12X4A12X4UNorm4Pack16,
+    vk::Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => ImageFormat::G12X4B12X4G12X4R12X4_422UNorm4Pack16,
+    vk::Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => ImageFormat::B12X4G12X4R12X4G12X4_422UNorm4Pack16,
+    vk::Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_3Plane420UNorm3Pack16,
+    vk::Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_2Plane420UNorm3Pack16,
+    vk::Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_3Plane422UNorm3Pack16,
+    vk::Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_2Plane422UNorm3Pack16,
+    vk::Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_3Plane444UNorm3Pack16,
+    vk::Format::G16B16G16R16_422_UNORM => ImageFormat::G16B16G16R16_422UNorm,
+    vk::Format::B16G16R16G16_422_UNORM => ImageFormat::B16G16R16G16_422UNorm,
+    vk::Format::G16_B16_R16_3PLANE_420_UNORM => ImageFormat::G16B16R16_3Plane420UNorm,
+    vk::Format::G16_B16R16_2PLANE_420_UNORM => ImageFormat::G16B16R16_2Plane420UNorm,
+    vk::Format::G16_B16_R16_3PLANE_422_UNORM => ImageFormat::G16B16R16_3Plane422UNorm,
+    vk::Format::G16_B16R16_2PLANE_422_UNORM => ImageFormat::G16B16R16_2Plane422UNorm,
+    vk::Format::G16_B16_R16_3PLANE_444_UNORM => ImageFormat::G16B16R16_3Plane444UNorm,
+
+    vk::Format::ASTC_3X3X3_UNORM_BLOCK_EXT => ImageFormat::ASTC3X3X3UNormBlockEXT,
+    vk::Format::ASTC_3X3X3_SRGB_BLOCK_EXT => ImageFormat::ASTC3X3X3SRgbBlockEXT,
+    vk::Format::ASTC_3X3X3_SFLOAT_BLOCK_EXT => ImageFormat::ASTC3X3X3SFloatBlockEXT,
+    vk::Format::ASTC_4X3X3_UNORM_BLOCK_EXT => ImageFormat::ASTC4X3X3UNormBlockEXT,
+    vk::Format::ASTC_4X3X3_SRGB_BLOCK_EXT => ImageFormat::ASTC4X3X3SRgbBlockEXT,
+    vk::Format::ASTC_4X3X3_SFLOAT_BLOCK_EXT => ImageFormat::ASTC4X3X3SFloatBlockEXT,
+    vk::Format::ASTC_4X4X3_UNORM_BLOCK_EXT => ImageFormat::ASTC4X4X3UNormBlockEXT,
+    vk::Format::ASTC_4X4X3_SRGB_BLOCK_EXT => ImageFormat::ASTC4X4X3SRgbBlockEXT,
+    vk::Format::ASTC_4X4X3_SFLOAT_BLOCK_EXT => ImageFormat::ASTC4X4X3SFloatBlockEXT,
+    vk::Format::ASTC_4X4X4_UNORM_BLOCK_EXT => ImageFormat::ASTC4X4X4UNormBlockEXT,
+    vk::Format::ASTC_4X4X4_SRGB_BLOCK_EXT => ImageFormat::ASTC4X4X4SRgbBlockEXT,
+    vk::Format::ASTC_4X4X4_SFLOAT_BLOCK_EXT => ImageFormat::ASTC4X4X4SFloatBlockEXT,
+    vk::Format::ASTC_5X4X4_UNORM_BLOCK_EXT => ImageFormat::ASTC5X4X4UNormBlockEXT,
+    vk::Format::ASTC_5X4X4_SRGB_BLOCK_EXT => ImageFormat::ASTC5X4X4SRgbBlockEXT,
+    vk::Format::ASTC_5X4X4_SFLOAT_BLOCK_EXT => ImageFormat::ASTC5X4X4SFloatBlockEXT,
+    vk::Format::ASTC_5X5X4_UNORM_BLOCK_EXT => ImageFormat::ASTC5X5X4UNormBlockEXT,
+    vk::Format::ASTC_5X5X4_SRGB_BLOCK_EXT => ImageFormat::ASTC5X5X4SRgbBlockEXT,
+    vk::Format::ASTC_5X5X4_SFLOAT_BLOCK_EXT => ImageFormat::ASTC5X5X4SFloatBlockEXT,
+    vk::Format::ASTC_5X5X5_UNORM_BLOCK_EXT => ImageFormat::ASTC5X5X5UNormBlockEXT,
+    vk::Format::ASTC_5X5X5_SRGB_BLOCK_EXT => ImageFormat::ASTC5X5X5SRgbBlockEXT,
+    vk::Format::ASTC_5X5X5_SFLOAT_BLOCK_EXT => ImageFormat::ASTC5X5X5SFloatBlockEXT,
+    vk::Format::ASTC_6X5X5_UNORM_BLOCK_EXT => ImageFormat::ASTC6X5X5UNormBlockEXT,
+    vk::Format::ASTC_6X5X5_SRGB_BLOCK_EXT => ImageFormat::ASTC6X5X5SRgbBlockEXT,
+    vk::Format::ASTC_6X5X5_SFLOAT_BLOCK_EXT => ImageFormat::ASTC6X5X5SFloatBlockEXT,
+    vk::Format::ASTC_6X6X5_UNORM_BLOCK_EXT => ImageFormat::ASTC6X6X5UNormBlockEXT,
+    vk::Format::ASTC_6X6X5_SRGB_BLOCK_EXT => ImageFormat::ASTC6X6X5SRgbBlockEXT,
+    vk::Format::ASTC_6X6X5_SFLOAT_BLOCK_EXT => ImageFormat::ASTC6X6X5SFloatBlockEXT,
+    vk::Format::ASTC_6X6X6_UNORM_BLOCK_EXT => ImageFormat::ASTC6X6X6UNormBlockEXT,
+    vk::Format::ASTC_6X6X6_SRGB_BLOCK_EXT => ImageFormat::ASTC6X6X6SRgbBlockEXT,
+    vk::Format::ASTC_6X6X6_SFLOAT_BLOCK_EXT => ImageFormat::ASTC6X6X6SFloatBlockEXT,
+
+    vk::Format::G8_B8R8_2PLANE_444_UNORM => ImageFormat::G8B8R8_2Plane444UNorm,
+    vk::Format::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16 => ImageFormat::G10X6B10X6R10X6_2Plane444UNorm3Pack16,
+    vk::Format::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16 => ImageFormat::G12X4B12X4R12X4_2Plane444UNorm3Pack16,
+    vk::Format::G16_B16R16_2PLANE_444_UNORM => ImageFormat::G16B16R16_2Plane444UNorm,
+
+    vk::Format::A4R4G4B4_UNORM_PACK16 => ImageFormat::A4R4G4B4UNormPack16,
+    vk::Format::A4B4G4R4_UNORM_PACK16 => ImageFormat::A4B4G4R4UNormPack16,
+
+    vk::Format::R16G16_S10_5_NV => ImageFormat::R16G16S10_5NV,
 });
 
 
@@ -1965,11 +3063,31 @@ pub enum ImageLayout {
     ShaderReadOnly,
     /// Optimal layout for presenting to a swapchain.
     Present,
+    /// Layout for an image that is presented to a shared-presentable swapchain, while it may also be accessed by the application.
+    SharedPresent,
+
+    /// Optimal layout for a depth/stencil image of which the depth aspect is read-only, while the stencil aspect is used as attachment (e.g., to read the depth of a pre-pass while still writing stencil).
+    DepthReadOnlyStencilAttachment,
+    /// Optimal layout for a depth/stencil image of which the depth aspect is used as attachment, while the stencil aspect is read-only.
+    DepthAttachmentStencilReadOnly,
+    /// Optimal layout for the depth aspect of a depth(/stencil) attachment (core in Vulkan 1.2).
+    DepthAttachment,
+    /// Optimal layout for the stencil aspect of a (depth/)stencil attachment (core in Vulkan 1.2).
+    StencilAttachment,
 
     /// Optimal layout for the image data being transferred to another image.
     TransferSrc,
     /// Optimal layout for the image's data being overwritten with transferred data from another image.
     TransferDst,
+
+    /// Optimal layout for any kind of attachment, i.e., the colour or depth/stencil variant depending on the image's format.
+    /// 
+    /// Requires the `synchronization2` DeviceFeature.
+    AttachmentOptimal,
+    /// Optimal layout for any kind of read-only access, i.e., the shader or depth/stencil variant depending on the image's format.
+    /// 
+    /// Requires the `synchronization2` DeviceFeature.
+    ReadOnlyOptimal,
 }
 
 enum_from!(impl From<vk::ImageLayout> for ImageLayout {
@@ -1982,42 +3100,287 @@ enum_from!(impl From<vk::ImageLayout> for ImageLayout {
     vk::ImageLayout::DEPTH_STENCIL_READ_ONLY_OPTIMAL  => ImageLayout::DepthStencilReadOnly,
     vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL         => ImageLayout::ShaderReadOnly,
     vk::ImageLayout::PRESENT_SRC_KHR                  => ImageLayout::Present,
+    vk::ImageLayout::SHARED_PRESENT_KHR               => ImageLayout::SharedPresent,
+
+    vk::ImageLayout::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL => ImageLayout::DepthReadOnlyStencilAttachment,
+    vk::ImageLayout::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL => ImageLayout::DepthAttachmentStencilReadOnly,
+    vk::ImageLayout::DEPTH_ATTACHMENT_OPTIMAL                   => ImageLayout::DepthAttachment,
+    vk::ImageLayout::STENCIL_ATTACHMENT_OPTIMAL                 => ImageLayout::StencilAttachment,
 
     vk::ImageLayout::TRANSFER_SRC_OPTIMAL => ImageLayout::TransferSrc,
     vk::ImageLayout::TRANSFER_DST_OPTIMAL => ImageLayout::TransferDst,
+
+    vk::ImageLayout::ATTACHMENT_OPTIMAL_KHR => ImageLayout::AttachmentOptimal,
+    vk::ImageLayout::READ_ONLY_OPTIMAL_KHR  => ImageLayout::ReadOnlyOptimal,
 });
 
+impl ImageLayout {
+    /// Returns whether this layout may only be used on a Device with the `synchronization2` DeviceFeature enabled.
+    #[inline]
+    pub fn requires_synchronization2(&self) -> bool { matches!(self, Self::AttachmentOptimal | Self::ReadOnlyOptimal) }
+}
 
 
-/// Defines how we might use an Image.
-#[derive(Clone, Copy, Debug)]
-pub enum ImageAspect {
-    /// The image will be used as a colour attachment.
-    Colour,
-    /// The image will be used as a Depth stencil.
-    Depth,
-    /// The image will be used as a gemeral stencil.
-    Stencil,
-    /// The image will be used to carry metadata.
-    Metadata,
-}
-
-impl Display for ImageAspect {
+
+
+
+/***** SAMPLERS *****/
+/// Defines how texels are filtered when a Sampler looks them up.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Filter {
+    /// Takes the nearest texel.
+    Nearest,
+    /// Linearly interpolates between the nearest texels.
+    Linear,
+}
+
+impl Display for Filter {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Filter::*;
+        match self {
+            Nearest => write!(f, "Nearest"),
+            Linear  => write!(f, "Linear"),
+        }
+    }
+}
+
+enum_from!(impl From<vk::Filter> for Filter {
+    vk::Filter::NEAREST => Filter::Nearest,
+    vk::Filter::LINEAR  => Filter::Linear,
+});
+
+
+
+/// Defines how a Sampler chooses between mip levels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SamplerMipmapMode {
+    /// Takes the nearest mip level.
+    Nearest,
+    /// Linearly interpolates between the two nearest mip levels.
+    Linear,
+}
+
+impl Display for SamplerMipmapMode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use SamplerMipmapMode::*;
+        match self {
+            Nearest => write!(f, "Nearest"),
+            Linear  => write!(f, "Linear"),
+        }
+    }
+}
+
+enum_from!(impl From<vk::SamplerMipmapMode> for SamplerMipmapMode {
+    vk::SamplerMipmapMode::NEAREST => SamplerMipmapMode::Nearest,
+    vk::SamplerMipmapMode::LINEAR  => SamplerMipmapMode::Linear,
+});
+
+
+
+/// Defines what a Sampler does with coordinates outside of the image.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SamplerAddressMode {
+    /// Repeats the image.
+    Repeat,
+    /// Repeats the image, but mirrors it every other repetition.
+    MirroredRepeat,
+    /// Uses the texels on the edge of the image.
+    ClampToEdge,
+    /// Uses the border colour of the Sampler.
+    ClampToBorder,
+    /// Mirrors the image once, after which the texels on the (opposite) edge are used.
+    MirrorClampToEdge,
+}
+
+impl Display for SamplerAddressMode {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
-        use ImageAspect::*;
+        use SamplerAddressMode::*;
         match self {
-            Colour   => write!(f, "Colour"),
-            Depth    => write!(f, "Depth"),
-            Stencil  => write!(f, "Stencil"),
-            Metadata => write!(f, "Metadata"),
+            Repeat            => write!(f, "Repeat"),
+            MirroredRepeat    => write!(f, "Mirrored repeat"),
+            ClampToEdge       => write!(f, "Clamp to edge"),
+            ClampToBorder     => write!(f, "Clamp to border"),
+            MirrorClampToEdge => write!(f, "Mirror clamp to edge"),
         }
     }
 }
 
-enum_from!(impl From<vk::ImageAspectFlags> for ImageAspect {
-    vk::ImageAspectFlags::COLOR    => ImageAspect::Colour,
-    vk::ImageAspectFlags::DEPTH    => ImageAspect::Depth,
-    vk::ImageAspectFlags::STENCIL  => ImageAspect::Stencil,
-    vk::ImageAspectFlags::METADATA => ImageAspect::Metadata,
+enum_from!(impl From<vk::SamplerAddressMode> for SamplerAddressMode {
+    vk::SamplerAddressMode::REPEAT               => SamplerAddressMode::Repeat,
+    vk::SamplerAddressMode::MIRRORED_REPEAT      => SamplerAddressMode::MirroredRepeat,
+    vk::SamplerAddressMode::CLAMP_TO_EDGE        => SamplerAddressMode::ClampToEdge,
+    vk::SamplerAddressMode::CLAMP_TO_BORDER      => SamplerAddressMode::ClampToBorder,
+    vk::SamplerAddressMode::MIRROR_CLAMP_TO_EDGE => SamplerAddressMode::MirrorClampToEdge,
 });
+
+
+
+
+
+/***** SWAPCHAINS *****/
+/// The policy with which a Swapchain presents images to its Surface.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PresentMode {
+    /// Images are presented immediately, which may cause tearing.
+    Immediate,
+    /// Images are presented at the next vertical blank, replacing any image still waiting (i.e., triple buffering without blocking).
+    Mailbox,
+    /// Images are queued and presented at each vertical blank, blocking when the queue is full. Always supported.
+    Fifo,
+    /// Like `PresentMode::Fifo`, except that a late image is presented immediately (which may cause tearing).
+    FifoRelaxed,
+    /// A single image is shared with the presentation engine, which only updates the display after the image is presented again (`VK_KHR_shared_presentable_image`).
+    SharedDemandRefresh,
+    /// A single image is shared with the presentation engine, which periodically updates the display from it without any further presents (`VK_KHR_shared_presentable_image`).
+    SharedContinuousRefresh,
+}
+
+impl PresentMode {
+    /// Returns whether this is one of the shared present modes, i.e., whether the Swapchain has only a single image that is continuously shared with the presentation engine.
+    #[inline]
+    pub fn is_shared(&self) -> bool { matches!(self, Self::SharedDemandRefresh | Self::SharedContinuousRefresh) }
+}
+
+impl Display for PresentMode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use PresentMode::*;
+        match self {
+            Immediate               => write!(f, "Immediate"),
+            Mailbox                 => write!(f, "Mailbox"),
+            Fifo                    => write!(f, "FIFO"),
+            FifoRelaxed             => write!(f, "FIFO (relaxed)"),
+            SharedDemandRefresh     => write!(f, "Shared (demand refresh)"),
+            SharedContinuousRefresh => write!(f, "Shared (continuous refresh)"),
+        }
+    }
+}
+
+enum_from!(impl From<vk::PresentModeKHR> for PresentMode {
+    vk::PresentModeKHR::IMMEDIATE                 => PresentMode::Immediate,
+    vk::PresentModeKHR::MAILBOX                   => PresentMode::Mailbox,
+    vk::PresentModeKHR::FIFO                      => PresentMode::Fifo,
+    vk::PresentModeKHR::FIFO_RELAXED              => PresentMode::FifoRelaxed,
+    vk::PresentModeKHR::SHARED_DEMAND_REFRESH     => PresentMode::SharedDemandRefresh,
+    vk::PresentModeKHR::SHARED_CONTINUOUS_REFRESH => PresentMode::SharedContinuousRefresh,
+});
+
+
+
+/// The colour space in which a Swapchain presents its images to its Surface.
+/// 
+/// Anything but `ColourSpace::SrgbNonlinear` requires the `VK_EXT_swapchain_colorspace` instance extension (or `VK_AMD_display_native_hdr` for `ColourSpace::DisplayNative`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColourSpace {
+    /// The sRGB colour space, with the sRGB (non-linear) transfer function. Always supported.
+    SrgbNonlinear,
+    /// The Display-P3 colour space, with the sRGB transfer function.
+    DisplayP3Nonlinear,
+    /// The extended sRGB colour space, with a linear transfer function.
+    ExtendedSrgbLinear,
+    /// The extended sRGB colour space, with the sRGB transfer function.
+    ExtendedSrgbNonlinear,
+    /// The Display-P3 colour space, with a linear transfer function.
+    DisplayP3Linear,
+    /// The DCI-P3 colour space, with the DCI-P3 transfer function.
+    DciP3Nonlinear,
+    /// The BT.709 colour space, with a linear transfer function.
+    Bt709Linear,
+    /// The BT.709 colour space, with the SMPTE 170M transfer function.
+    Bt709Nonlinear,
+    /// The BT.2020 colour space, with a linear transfer function.
+    Bt2020Linear,
+    /// The HDR10 (BT.2020) colour space, with the SMPTE ST 2084 perceptual quantizer transfer function.
+    Hdr10St2084,
+    /// The Dolby Vision (BT.2020) colour space, with a proprietary transfer function.
+    DolbyVision,
+    /// The HDR10 (BT.2020) colour space, with the hybrid log-gamma transfer function.
+    Hdr10Hlg,
+    /// The Adobe RGB colour space, with a linear transfer function.
+    AdobeRgbLinear,
+    /// The Adobe RGB colour space, with the gamma 2.2 transfer function.
+    AdobeRgbNonlinear,
+    /// Colour components are passed to the display as-is.
+    PassThrough,
+    /// The native colour space of the display (`VK_AMD_display_native_hdr`).
+    DisplayNative,
+}
+
+impl Display for ColourSpace {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use ColourSpace::*;
+        match self {
+            SrgbNonlinear         => write!(f, "sRGB (non-linear)"),
+            DisplayP3Nonlinear    => write!(f, "Display-P3 (non-linear)"),
+            ExtendedSrgbLinear    => write!(f, "Extended sRGB (linear)"),
+            ExtendedSrgbNonlinear => write!(f, "Extended sRGB (non-linear)"),
+            DisplayP3Linear       => write!(f, "Display-P3 (linear)"),
+            DciP3Nonlinear        => write!(f, "DCI-P3 (non-linear)"),
+            Bt709Linear           => write!(f, "BT.709 (linear)"),
+            Bt709Nonlinear        => write!(f, "BT.709 (non-linear)"),
+            Bt2020Linear          => write!(f, "BT.2020 (linear)"),
+            Hdr10St2084           => write!(f, "HDR10 (ST 2084)"),
+            DolbyVision           => write!(f, "Dolby Vision"),
+            Hdr10Hlg              => write!(f, "HDR10 (HLG)"),
+            AdobeRgbLinear        => write!(f, "Adobe RGB (linear)"),
+            AdobeRgbNonlinear     => write!(f, "Adobe RGB (non-linear)"),
+            PassThrough           => write!(f, "Pass-through"),
+            DisplayNative         => write!(f, "Display native"),
+        }
+    }
+}
+
+impl TryFrom<vk::ColorSpaceKHR> for ColourSpace {
+    type Error = ColourSpaceError;
+
+    fn try_from(value: vk::ColorSpaceKHR) -> Result<Self, Self::Error> {
+        match value {
+            vk::ColorSpaceKHR::SRGB_NONLINEAR              => Ok(ColourSpace::SrgbNonlinear),
+            vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT    => Ok(ColourSpace::DisplayP3Nonlinear),
+            vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT    => Ok(ColourSpace::ExtendedSrgbLinear),
+            vk::ColorSpaceKHR::EXTENDED_SRGB_NONLINEAR_EXT => Ok(ColourSpace::ExtendedSrgbNonlinear),
+            vk::ColorSpaceKHR::DISPLAY_P3_LINEAR_EXT       => Ok(ColourSpace::DisplayP3Linear),
+            vk::ColorSpaceKHR::DCI_P3_NONLINEAR_EXT        => Ok(ColourSpace::DciP3Nonlinear),
+            vk::ColorSpaceKHR::BT709_LINEAR_EXT            => Ok(ColourSpace::Bt709Linear),
+            vk::ColorSpaceKHR::BT709_NONLINEAR_EXT         => Ok(ColourSpace::Bt709Nonlinear),
+            vk::ColorSpaceKHR::BT2020_LINEAR_EXT           => Ok(ColourSpace::Bt2020Linear),
+            vk::ColorSpaceKHR::HDR10_ST2084_EXT            => Ok(ColourSpace::Hdr10St2084),
+            vk::ColorSpaceKHR::DOLBYVISION_EXT             => Ok(ColourSpace::DolbyVision),
+            vk::ColorSpaceKHR::HDR10_HLG_EXT               => Ok(ColourSpace::Hdr10Hlg),
+            vk::ColorSpaceKHR::ADOBERGB_LINEAR_EXT         => Ok(ColourSpace::AdobeRgbLinear),
+            vk::ColorSpaceKHR::ADOBERGB_NONLINEAR_EXT      => Ok(ColourSpace::AdobeRgbNonlinear),
+            vk::ColorSpaceKHR::PASS_THROUGH_EXT            => Ok(ColourSpace::PassThrough),
+            vk::ColorSpaceKHR::DISPLAY_NATIVE_AMD          => Ok(ColourSpace::DisplayNative),
+
+            value => Err(ColourSpaceError::IllegalColourSpaceValue{ value }),
+        }
+    }
+}
+
+impl From<ColourSpace> for vk::ColorSpaceKHR {
+    #[inline]
+    fn from(value: ColourSpace) -> Self {
+        match value {
+            ColourSpace::SrgbNonlinear         => vk::ColorSpaceKHR::SRGB_NONLINEAR,
+            ColourSpace::DisplayP3Nonlinear    => vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT,
+            ColourSpace::ExtendedSrgbLinear    => vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT,
+            ColourSpace::ExtendedSrgbNonlinear => vk::ColorSpaceKHR::EXTENDED_SRGB_NONLINEAR_EXT,
+            ColourSpace::DisplayP3Linear       => vk::ColorSpaceKHR::DISPLAY_P3_LINEAR_EXT,
+            ColourSpace::DciP3Nonlinear        => vk::ColorSpaceKHR::DCI_P3_NONLINEAR_EXT,
+            ColourSpace::Bt709Linear           => vk::ColorSpaceKHR::BT709_LINEAR_EXT,
+            ColourSpace::Bt709Nonlinear        => vk::ColorSpaceKHR::BT709_NONLINEAR_EXT,
+            ColourSpace::Bt2020Linear          => vk::ColorSpaceKHR::BT2020_LINEAR_EXT,
+            ColourSpace::Hdr10St2084           => vk::ColorSpaceKHR::HDR10_ST2084_EXT,
+            ColourSpace::DolbyVision           => vk::ColorSpaceKHR::DOLBYVISION_EXT,
+            ColourSpace::Hdr10Hlg              => vk::ColorSpaceKHR::HDR10_HLG_EXT,
+            ColourSpace::AdobeRgbLinear        => vk::ColorSpaceKHR::ADOBERGB_LINEAR_EXT,
+            ColourSpace::AdobeRgbNonlinear     => vk::ColorSpaceKHR::ADOBERGB_NONLINEAR_EXT,
+            ColourSpace::PassThrough           => vk::ColorSpaceKHR::PASS_THROUGH_EXT,
+            ColourSpace::DisplayNative         => vk::ColorSpaceKHR::DISPLAY_NATIVE_AMD,
+        }
+    }
+}
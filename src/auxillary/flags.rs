@@ -19,6 +19,55 @@ use std::ops::{BitOr, BitOrAssign};
 use ash::vk;
 
 
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_memory_type_flags() {
+        // Intersecting keeps only the types in both
+        let reqs: DeviceMemoryTypeFlags = DeviceMemoryTypeFlags::from(0b1011u32);
+        assert_eq!(reqs.intersect(DeviceMemoryTypeFlags::from(0b0110u32)), DeviceMemoryTypeFlags::from(0b0010u32));
+        assert_eq!(reqs.intersect(DeviceMemoryTypeFlags::empty()), DeviceMemoryTypeFlags::empty());
+        assert_eq!(reqs.intersect(DeviceMemoryTypeFlags::all()), reqs);
+
+        // Iterating yields the indices of the set bits, not the bits themselves
+        assert_eq!(reqs.iter_indices().collect::<Vec<u32>>(), vec![ 0, 1, 3 ]);
+        assert_eq!(DeviceMemoryTypeFlags::empty().iter_indices().count(), 0);
+        assert_eq!(DeviceMemoryTypeFlags::all().iter_indices().count(), 32);
+        assert_eq!(DeviceMemoryTypeFlags::from(0x80000000u32).iter_indices().collect::<Vec<u32>>(), vec![ 31 ]);
+    }
+
+    #[test]
+    fn test_sample_count_flags_max() {
+        assert_eq!(SampleCountFlags::empty().max(), None);
+        assert_eq!(SampleCountFlags::from(SampleCount::ONE).max(), Some(SampleCount::ONE));
+        assert_eq!(SampleCountFlags::from_raw(0x0D).max(), Some(SampleCount::EIGHT));
+        assert_eq!(SampleCountFlags::all().max(), Some(SampleCount::SIXTY_FOUR));
+        assert_eq!(SampleCountFlags::from_raw(0x80).max(), None);
+        assert_eq!(SampleCountFlags::from_raw(0x11).intersect(SampleCountFlags::from_raw(0x05)).max(), Some(SampleCount::ONE));
+    }
+
+    #[test]
+    fn test_image_aspect_flags() {
+        // Combined aspects survive the round-trip through Vulkan
+        let depth_stencil: ImageAspectFlags = ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL;
+        assert_eq!(vk::ImageAspectFlags::from(depth_stencil), vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL);
+        assert_eq!(ImageAspectFlags::from(vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL), depth_stencil);
+        assert_eq!(ImageAspectFlags::from(ImageAspect::COLOUR), ImageAspectFlags::COLOUR);
+        assert_eq!(vk::ImageAspectFlags::from(ImageAspect::METADATA), vk::ImageAspectFlags::METADATA);
+
+        // Every aspect that is set is listed
+        assert_eq!(format!("{}", depth_stencil), "Depth, Stencil");
+        assert_eq!(format!("{}", ImageAspectFlags::COLOUR), "Colour");
+    }
+}
+
+
+
+
+
 /***** HELPER MACROS *****/
 /// Macro that generates the base Flags implementation based on the given Flags values.
 macro_rules! flags_new {
@@ -743,6 +792,10 @@ flags_single_new!(
         FRAGMENT                = 0x0010,
         /// The Compute stage
         COMPUTE                 = 0x0020,
+        /// The Task stage, which optionally precedes the Mesh stage (requires `VK_EXT_mesh_shader`)
+        TASK                    = 0x0040,
+        /// The Mesh stage, which replaces the vertex input and vertex, tesselation and geometry stages (requires `VK_EXT_mesh_shader`)
+        MESH                    = 0x0080,
     },
     {
         VERTEX                  => "Vertex",
@@ -751,16 +804,20 @@ flags_single_new!(
         GEOMETRY                => "Geometry",
         FRAGMENT                => "Fragment",
         COMPUTE                 => "Compute",
+        TASK                    => "Task",
+        MESH                    => "Mesh",
     },
 );
 
 flags_single_from!(vk::ShaderStageFlags, ShaderStage, ShaderStageFlags,
-    VERTEX                  => VERTEX,
-    TESSELLATION_CONTROL    => TESSELLATION_CONTROL,
-    TESSELLATION_EVALUATION => TESSELLATION_EVALUATION,
-    GEOMETRY                => GEOMETRY,
-    FRAGMENT                => FRAGMENT,
-    COMPUTE                 => COMPUTE,
+    vk::ShaderStageFlags::VERTEX                  => VERTEX,
+    vk::ShaderStageFlags::TESSELLATION_CONTROL    => TESSELLATION_CONTROL,
+    vk::ShaderStageFlags::TESSELLATION_EVALUATION => TESSELLATION_EVALUATION,
+    vk::ShaderStageFlags::GEOMETRY                => GEOMETRY,
+    vk::ShaderStageFlags::FRAGMENT                => FRAGMENT,
+    vk::ShaderStageFlags::COMPUTE                 => COMPUTE,
+    vk::ShaderStageFlags::TASK_EXT                => TASK,
+    vk::ShaderStageFlags::MESH_EXT                => MESH,
 );
 
 
@@ -874,9 +931,17 @@ flags_from!(vk::DependencyFlags, DependencyFlags,
 
 flags_single_new!(
     /// The Pipeline stage where a shader or a resource lives.
+    /// 
+    /// Note that, to express that a dependency waits on or blocks no stage at all, `PipelineStage::NONE` should be used instead of `TOP_OF_PIPE` (as source) or `BOTTOM_OF_PIPE` (as destination); the latter usage is deprecated by synchronization2.
     PipelineStage(u32), PipelineStageFlags,
     {
+        /// Explicitly refers to no stage at all (e.g., for a barrier that only performs a layout transition or does not need to wait on anything).
+        /// 
+        /// Requires the synchronization2 DeviceFeature (core in Vulkan 1.3); `RenderPass` creation and `CommandBuffer::execution_barrier()` reject it otherwise.
+        NONE                           = 0x00000,
         /// Defines the stage before anything of the pipeline is run.
+        /// 
+        /// Only use this to refer to the start of the pipeline itself; to denote "no stage", use `PipelineStage::NONE` instead.
         TOP_OF_PIPE                    = 0x00001,
         /// The indirect draw stage.
         DRAW_INDIRECT                  = 0x00002,
@@ -903,6 +968,8 @@ flags_single_new!(
         /// The stage where any data is transferred to and from buffers and images (all copy commands, blit, resolve and clear commands (except vkCmdClearAttachments).
         TRANSFER                       = 0x01000,
         /// Defines the stage after the entire pipeline has been completed.
+        /// 
+        /// Only use this to refer to the end of the pipeline itself; to denote "no stage", use `PipelineStage::NONE` instead.
         BOTTOM_OF_PIPE                 = 0x02000,
         /// A (pseudo-)stage where host access to a device is performed.
         HOST                           = 0x04000,
@@ -910,8 +977,13 @@ flags_single_new!(
         ALL_GRAPHICS                   = 0x08000,
         /// Collection for all commandbuffer-invoked stages _supported on the executing queue_.
         ALL_COMMANDS                   = 0x10000,
+        /// The stage where any task shaders may be processed (requires `VK_EXT_mesh_shader`).
+        TASK_SHADER                    = 0x20000,
+        /// The stage where any mesh shaders may be processed (requires `VK_EXT_mesh_shader`).
+        MESH_SHADER                    = 0x40000,
     },
     {
+        NONE                           => "NONE",
         TOP_OF_PIPE                    => "TOP_OF_PIPE",
         DRAW_INDIRECT                  => "DRAW_INDIRECT",
         VERTEX_INPUT                   => "VERTEX_INPUT",
@@ -929,10 +1001,13 @@ flags_single_new!(
         HOST                           => "HOST",
         ALL_GRAPHICS                   => "ALL_GRAPHICS",
         ALL_COMMANDS                   => "ALL_COMMANDS",
+        TASK_SHADER                    => "TASK_SHADER",
+        MESH_SHADER                    => "MESH_SHADER",
     },
 );
 
 flags_single_from!(vk::PipelineStageFlags, PipelineStage, PipelineStageFlags,
+    vk::PipelineStageFlags::NONE                           => NONE,
     vk::PipelineStageFlags::TOP_OF_PIPE                    => TOP_OF_PIPE,
     vk::PipelineStageFlags::DRAW_INDIRECT                  => DRAW_INDIRECT,
     vk::PipelineStageFlags::VERTEX_INPUT                   => VERTEX_INPUT,
@@ -950,6 +1025,8 @@ flags_single_from!(vk::PipelineStageFlags, PipelineStage, PipelineStageFlags,
     vk::PipelineStageFlags::HOST                           => HOST,
     vk::PipelineStageFlags::ALL_GRAPHICS                   => ALL_GRAPHICS,
     vk::PipelineStageFlags::ALL_COMMANDS                   => ALL_COMMANDS,
+    vk::PipelineStageFlags::TASK_SHADER_EXT                => TASK_SHADER,
+    vk::PipelineStageFlags::MESH_SHADER_EXT                => MESH_SHADER,
 );
 
 
@@ -1026,6 +1103,22 @@ flags_from!(vk::MemoryPropertyFlags, MemoryPropertyFlags,
     vk::MemoryPropertyFlags::PROTECTED        => MemoryPropertyFlags::PROTECTED,
 );
 
+impl MemoryPropertyFlags {
+    /// Returns the properties for memory that is only accessed by the GPU (e.g., render targets or static vertex buffers).
+    #[inline]
+    pub const fn gpu_only() -> Self { Self::DEVICE_LOCAL }
+
+    /// Returns the properties for memory that the CPU writes and the GPU reads (e.g., staging buffers or per-frame uniforms).
+    #[inline]
+    pub const fn cpu_to_gpu() -> Self { Self(Self::HOST_VISIBLE.0 | Self::HOST_COHERENT.0) }
+
+    /// Returns the properties for memory that the GPU writes and the CPU reads back (e.g., screenshots or query results).
+    /// 
+    /// Since such memory is typically not coherent, remember to invalidate it before reading.
+    #[inline]
+    pub const fn gpu_to_cpu() -> Self { Self(Self::HOST_VISIBLE.0 | Self::HOST_CACHED.0) }
+}
+
 
 
 
@@ -1099,6 +1192,30 @@ impl Display for DeviceMemoryType {
     }
 }
 
+impl DeviceMemoryTypeFlags {
+    /// Returns the memory types that are in both this set and the given one.
+    /// 
+    /// # Arguments
+    /// - `other`: The other set of memory types to intersect with.
+    /// 
+    /// # Returns
+    /// A new DeviceMemoryTypeFlags with only the types that occur in both.
+    #[inline]
+    pub const fn intersect(&self, other: Self) -> Self { Self(self.0 & other.0) }
+
+    /// Returns an iterator over the indices of the memory types in this set.
+    /// 
+    /// Note that the flags are a mask (as in `VkMemoryRequirements::memoryTypeBits`), so the index of a memory type is the position of its bit.
+    /// 
+    /// # Returns
+    /// An iterator that yields the memory type indices in ascending order.
+    #[inline]
+    pub fn iter_indices(&self) -> impl Iterator<Item = u32> {
+        let bits: u32 = self.0;
+        (0..u32::BITS).filter(move |i| bits & (1 << i) != 0)
+    }
+}
+
 impl Display for DeviceMemoryTypeFlags {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1211,3 +1328,106 @@ flags_single_from!(vk::SampleCountFlags, SampleCount, SampleCountFlags,
     vk::SampleCountFlags::TYPE_32 => THIRTY_TWO,
     vk::SampleCountFlags::TYPE_64 => SIXTY_FOUR,
 );
+
+impl SampleCountFlags {
+    /// Returns the sample counts that are in both this set and the given one.
+    /// 
+    /// # Arguments
+    /// - `other`: The other set of sample counts to intersect with.
+    /// 
+    /// # Returns
+    /// A new SampleCountFlags with only the sample counts that occur in both.
+    #[inline]
+    pub const fn intersect(&self, other: Self) -> Self { Self(self.0 & other.0) }
+
+    /// Returns the highest sample count in this set.
+    /// 
+    /// Any bits that do not correspond to a SampleCount (i.e., above `SampleCount::SIXTY_FOUR`) are ignored.
+    /// 
+    /// # Returns
+    /// The highest SampleCount, or `None` if the set has no valid sample counts.
+    #[inline]
+    pub const fn max(&self) -> Option<SampleCount> {
+        let counts: u8 = self.0 & 0x7F;
+        if counts == 0 { return None; }
+        Some(SampleCount::from_raw(1 << (7 - counts.leading_zeros())))
+    }
+}
+
+
+
+flags_new!(
+    /// The ImageUsageFlags that determine what we can use an image for.
+    ImageUsageFlags(u8),
+    {
+        /// The image may be used as a source image in a memory transfer operation.
+        TRANSFER_SRC = 0x01,
+        /// The image may be used as a target image in a memory transfer operation.
+        TRANSFER_DST = 0x02,
+        /// The image may be sampled from in a shader.
+        SAMPLED = 0x04,
+        /// The image may be used as a storage image in a shader.
+        STORAGE = 0x08,
+        /// The image may be used as a colour attachment in a framebuffer.
+        COLOUR_ATTACHMENT = 0x10,
+        /// The image may be used as a depth/stencil attachment in a framebuffer.
+        DEPTH_STENCIL_ATTACHMENT = 0x20,
+        /// The image may be used as a transient attachment in a framebuffer (i.e., its memory may be lazily allocated).
+        TRANSIENT_ATTACHMENT = 0x40,
+        /// The image may be used as an input attachment in a framebuffer.
+        INPUT_ATTACHMENT = 0x80,
+    },
+    {
+        TRANSFER_SRC             => "Transfer (source)",
+        TRANSFER_DST             => "Transfer (destination)",
+        SAMPLED                  => "Sampled",
+        STORAGE                  => "Storage",
+        COLOUR_ATTACHMENT        => "Colour attachment",
+        DEPTH_STENCIL_ATTACHMENT => "Depth/stencil attachment",
+        TRANSIENT_ATTACHMENT     => "Transient attachment",
+        INPUT_ATTACHMENT         => "Input attachment",
+    },
+);
+
+flags_from!(vk::ImageUsageFlags, ImageUsageFlags,
+    vk::ImageUsageFlags::TRANSFER_SRC             => ImageUsageFlags::TRANSFER_SRC,
+    vk::ImageUsageFlags::TRANSFER_DST             => ImageUsageFlags::TRANSFER_DST,
+    vk::ImageUsageFlags::SAMPLED                  => ImageUsageFlags::SAMPLED,
+    vk::ImageUsageFlags::STORAGE                  => ImageUsageFlags::STORAGE,
+    vk::ImageUsageFlags::COLOR_ATTACHMENT         => ImageUsageFlags::COLOUR_ATTACHMENT,
+    vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT => ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT,
+    vk::ImageUsageFlags::TRANSIENT_ATTACHMENT     => ImageUsageFlags::TRANSIENT_ATTACHMENT,
+    vk::ImageUsageFlags::INPUT_ATTACHMENT         => ImageUsageFlags::INPUT_ATTACHMENT,
+);
+
+
+
+flags_single_new!(
+    /// Defines the aspects of an image, i.e., which parts of its data are accessed.
+    /// 
+    /// Combined depth/stencil images have two aspects, which can be referred to at once using `ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL`.
+    ImageAspect(u8), ImageAspectFlags,
+    {
+        /// The colour data of the image.
+        COLOUR   = 0x01,
+        /// The depth data of the image.
+        DEPTH    = 0x02,
+        /// The stencil data of the image.
+        STENCIL  = 0x04,
+        /// The metadata of a sparse image.
+        METADATA = 0x08,
+    },
+    {
+        COLOUR   => "Colour",
+        DEPTH    => "Depth",
+        STENCIL  => "Stencil",
+        METADATA => "Metadata",
+    },
+);
+
+flags_single_from!(vk::ImageAspectFlags, ImageAspect, ImageAspectFlags,
+    vk::ImageAspectFlags::COLOR    => COLOUR,
+    vk::ImageAspectFlags::DEPTH    => DEPTH,
+    vk::ImageAspectFlags::STENCIL  => STENCIL,
+    vk::ImageAspectFlags::METADATA => METADATA,
+);
@@ -17,18 +17,17 @@ use std::ffi::{CStr, CString};
 use std::fmt::{Display, Formatter, Result as FResult};
 use std::ops::Range;
 use std::ptr;
-use std::rc::Rc;
 use std::slice;
 
 use ash::vk;
 
-use crate::errors::QueueError;
+use crate::errors::{ColourSpaceError, QueueError};
 use crate::{to_cstring, vec_as_ptr};
-use crate::spec::{ApiVersion, DriverVersion};
+use crate::spec::{ApiVersion, DriverVersion, Shared};
 use crate::auxillary::enums::{
     AttachmentLoadOp, AttachmentStoreOp, AttributeLayout,
     BindPoint, BlendFactor, BlendOp,
-    CompareOp, ComponentSwizzle, CullMode,
+    ColourSpace, CompareOp, ComponentSwizzle, CullMode,
     DescriptorKind, DeviceKind, DrawMode,
     FrontFace,
     ImageFormat, ImageLayout,
@@ -44,16 +43,364 @@ use crate::auxillary::flags::{
     ColourComponentFlags,
     DependencyFlags, DeviceMemoryTypeFlags,
     HeapPropertyFlags,
+    ImageAspectFlags,
     MemoryPropertyFlags,
     PipelineStage,
-    SampleCount, SampleCountFlags, ShaderStage,
+    SampleCount, SampleCountFlags, ShaderStage, ShaderStageFlags,
 };
 use crate::instance::Instance;
 
 
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests Rect2D's `intersect` function
+    #[test]
+    fn test_rect2d_intersect() {
+        // Overlapping rectangles
+        assert_eq!(Rect2D::<i32, u32>::new(0, 0, 10, 10).intersect(&Rect2D::new(5, 5, 10, 10)), Some(Rect2D::new(5, 5, 5, 5)));
+        assert_eq!(Rect2D::<i32, u32>::new(-5, -5, 10, 10).intersect(&Rect2D::new(0, 0, 10, 10)), Some(Rect2D::new(0, 0, 5, 5)));
+        // Contained rectangles
+        assert_eq!(Rect2D::<i32, u32>::new(0, 0, 10, 10).intersect(&Rect2D::new(2, 3, 4, 5)), Some(Rect2D::new(2, 3, 4, 5)));
+        // Touching and disjoint rectangles
+        assert_eq!(Rect2D::<i32, u32>::new(0, 0, 10, 10).intersect(&Rect2D::new(10, 0, 10, 10)), None);
+        assert_eq!(Rect2D::<i32, u32>::new(0, 0, 10, 10).intersect(&Rect2D::new(20, 20, 10, 10)), None);
+    }
+
+    /// Tests Rect2D's `contains` function
+    #[test]
+    fn test_rect2d_contains() {
+        let rect: Rect2D<i32, u32> = Rect2D::new(-2, 3, 4, 5);
+        assert!(rect.contains(Offset2D::new(-2, 3)));
+        assert!(rect.contains(Offset2D::new(1, 7)));
+        assert!(!rect.contains(Offset2D::new(2, 7)));
+        assert!(!rect.contains(Offset2D::new(1, 8)));
+        assert!(!rect.contains(Offset2D::new(-3, 3)));
+    }
+
+    /// Tests Rect2D's `union` function
+    #[test]
+    fn test_rect2d_union() {
+        assert_eq!(Rect2D::<i32, u32>::new(0, 0, 10, 10).union(&Rect2D::new(5, 5, 10, 10)), Rect2D::new(0, 0, 15, 15));
+        assert_eq!(Rect2D::<i32, u32>::new(-5, 20, 1, 1).union(&Rect2D::new(5, 5, 10, 10)), Rect2D::new(-5, 5, 20, 16));
+        assert_eq!(Rect2D::<u32>::new(2, 3, 4, 5).union(&Rect2D::new(2, 3, 4, 5)), Rect2D::new(2, 3, 4, 5));
+    }
+
+    /// Tests that QueueFamilyInfo prefers dedicated families, especially for compute
+    #[test]
+    fn test_queue_family_select() {
+        let family = |queue_flags: vk::QueueFlags| vk::QueueFamilyProperties{ queue_flags, queue_count: 1, ..Default::default() };
+
+        // A single family that does everything
+        let info = QueueFamilyInfo::select(&[ family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER) ]).unwrap();
+        assert_eq!((info.graphics, info.memory, info.present, info.compute), (0, 0, 0, 0));
+
+        // An AMD-like layout with a dedicated async-compute and transfer family
+        let info = QueueFamilyInfo::select(&[
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER),
+            family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER),
+            family(vk::QueueFlags::TRANSFER),
+        ]).unwrap();
+        assert_eq!((info.graphics, info.memory, info.present, info.compute), (0, 2, 0, 1));
+
+        // A non-graphics compute family is preferred even if it supports more operations
+        let info = QueueFamilyInfo::select(&[
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE),
+            family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER),
+        ]).unwrap();
+        assert_eq!((info.graphics, info.memory, info.compute), (0, 1, 1));
+
+        // Missing operations are reported
+        assert_eq!(QueueFamilyInfo::select(&[ family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER) ]).unwrap_err(), vk::QueueFlags::GRAPHICS);
+        assert_eq!(QueueFamilyInfo::select(&[ family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::TRANSFER) ]).unwrap_err(), vk::QueueFlags::COMPUTE);
+    }
+
+    /// Tests that primitive restart is only accepted for strip and fan topologies
+    #[test]
+    fn test_vertex_assembly_valid() {
+        let state = |topology: VertexTopology, restart_primitive: bool| VertexAssemblyState{ topology, restart_primitive };
+
+        // Without restart, everything goes
+        assert!(state(VertexTopology::PointList, false).is_valid());
+        assert!(state(VertexTopology::TriangleList, false).is_valid());
+        assert!(state(VertexTopology::TriangleStrip, false).is_valid());
+
+        // With restart, only strips and fans
+        assert!(state(VertexTopology::LineStrip, true).is_valid());
+        assert!(state(VertexTopology::LineStripAdjacency, true).is_valid());
+        assert!(state(VertexTopology::TriangleStrip, true).is_valid());
+        assert!(state(VertexTopology::TriangleFan, true).is_valid());
+        assert!(state(VertexTopology::TriangleStripAdjacency, true).is_valid());
+        assert!(!state(VertexTopology::PointList, true).is_valid());
+        assert!(!state(VertexTopology::LineList, true).is_valid());
+        assert!(!state(VertexTopology::TriangleList, true).is_valid());
+        assert!(!state(VertexTopology::TriangleListAdjacency, true).is_valid());
+        assert!(!state(VertexTopology::PatchList, true).is_valid());
+    }
+
+    /// Tests the mip level functions of Extent2D and Extent3D
+    #[test]
+    fn test_extent_mip_level() {
+        // Sizes of levels are halved, rounded down, but never below 1
+        let extent: Extent2D<u32> = Extent2D::new(1024, 300);
+        assert_eq!(extent.mip_level(0), extent);
+        assert_eq!(extent.mip_level(1), Extent2D::new(512, 150));
+        assert_eq!(extent.mip_level(3), Extent2D::new(128, 37));
+        assert_eq!(extent.mip_level(9), Extent2D::new(2, 1));
+        assert_eq!(extent.mip_level(10), Extent2D::new(1, 1));
+        assert_eq!(extent.mip_level(40), Extent2D::new(1, 1));
+        assert_eq!(Extent3D::<u32>::new(16, 8, 2).mip_level(2), Extent3D::new(4, 2, 1));
+
+        // The number of levels is based on the largest dimension
+        assert_eq!(extent.mip_level_count(), 11);
+        assert_eq!(Extent2D::<u32>::new(1, 1).mip_level_count(), 1);
+        assert_eq!(Extent2D::<u32>::new(3, 1).mip_level_count(), 2);
+        assert_eq!(Extent2D::<u32>::new(0, 0).mip_level_count(), 1);
+        assert_eq!(Extent2D::<u32>::new(u32::MAX, 1).mip_level_count(), 32);
+        assert_eq!(Extent3D::<u32>::new(4, 8, 255).mip_level_count(), 8);
+    }
+
+    /// Tests that DeviceMemoryProperties::describe_types() lists the selected types
+    #[test]
+    fn test_describe_memory_types() {
+        let props: DeviceMemoryProperties = DeviceMemoryProperties {
+            heaps : vec![],
+            types : vec![
+                DeviceMemoryTypeInfo{ heap_index: 0, props: MemoryPropertyFlags::DEVICE_LOCAL },
+                DeviceMemoryTypeInfo{ heap_index: 1, props: MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT },
+                DeviceMemoryTypeInfo{ heap_index: 1, props: MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_CACHED },
+            ],
+        };
+
+        // Types 1 and 2 are selected, as well as type 5, which does not exist
+        let described: Vec<(u32, MemoryPropertyFlags)> = props.describe_types(DeviceMemoryTypeFlags::from(0b100110u32));
+        assert_eq!(described, vec![
+            (1, MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT),
+            (2, MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_CACHED),
+        ]);
+        assert!(props.describe_types(DeviceMemoryTypeFlags::empty()).is_empty());
+    }
+
+    /// Tests the conversions of Offset3D and the promotion of Extent2D to Extent3D
+    #[test]
+    fn test_3d_geometry() {
+        let offset: Offset3D<i32> = (1, -2, 3).into();
+        let vk_offset: vk::Offset3D = offset.clone().into();
+        assert_eq!((vk_offset.x, vk_offset.y, vk_offset.z), (1, -2, 3));
+        assert_eq!(format!("{}", offset), "(1, -2, 3)");
+        assert_eq!(Offset3D::<i64>::from(vk_offset), offset.cast::<i64>());
+
+        let extent: Extent3D<u32> = Extent2D::new(800, 600).to_3d(1);
+        assert_eq!(extent, Extent3D::new(800, 600, 1));
+        let vk_extent: vk::Extent3D = extent.into();
+        assert_eq!((vk_extent.width, vk_extent.height, vk_extent.depth), (800, 600, 1));
+    }
+
+    /// Tests that the base features survive a round-trip through vk::PhysicalDeviceFeatures
+    #[test]
+    fn test_device_features_base() {
+        let features: DeviceFeatures = DeviceFeatures {
+            robust_buffer_access        : true,
+            fragment_stores_and_atomics : true,
+            ..Default::default()
+        };
+
+        // Convert to Vulkan and back
+        let vk_features: vk::PhysicalDeviceFeatures = (&features).into();
+        assert_eq!(vk_features.robust_buffer_access, vk::TRUE);
+        assert_eq!(vk_features.vertex_pipeline_stores_and_atomics, vk::FALSE);
+        assert_eq!(vk_features.fragment_stores_and_atomics, vk::TRUE);
+        let back: DeviceFeatures = vk_features.into();
+        assert!(back.robust_buffer_access && !back.vertex_pipeline_stores_and_atomics && back.fragment_stores_and_atomics);
+
+        // Unsupported base features are reported
+        assert!(features.missing_from(&back).is_empty());
+        assert_eq!(features.missing_from(&DeviceFeatures::default()), vec![ "robustBufferAccess", "fragmentStoresAndAtomics" ]);
+
+        // The commonly used optional features are passed through as well
+        let features: DeviceFeatures = DeviceFeatures {
+            sampler_anisotropy  : true,
+            fill_mode_non_solid : true,
+            geometry_shader     : true,
+            alpha_to_one        : true,
+            ..Default::default()
+        };
+        let vk_features: vk::PhysicalDeviceFeatures = (&features).into();
+        assert_eq!((vk_features.sampler_anisotropy, vk_features.fill_mode_non_solid, vk_features.geometry_shader, vk_features.alpha_to_one), (vk::TRUE, vk::TRUE, vk::TRUE, vk::TRUE));
+        assert_eq!((vk_features.wide_lines, vk_features.tessellation_shader, vk_features.robust_buffer_access), (vk::FALSE, vk::FALSE, vk::FALSE));
+        let back: DeviceFeatures = vk_features.into();
+        assert!(back.sampler_anisotropy && back.fill_mode_non_solid && back.geometry_shader && back.alpha_to_one && !back.wide_lines);
+        assert_eq!(features.missing_from(&DeviceFeatures{ sampler_anisotropy: true, ..Default::default() }), vec![ "geometryShader", "fillModeNonSolid", "alphaToOne" ]);
+    }
+
+    /// Tests that the 16-bit storage and float16/int8 features are passed through the p_next-chain
+    #[test]
+    fn test_device_features_reduced_precision() {
+        let features: DeviceFeatures = DeviceFeatures {
+            storage_buffer_16bit_access : true,
+            shader_float16              : true,
+            ..Default::default()
+        };
+
+        // The structs should be set and linked
+        let chain: Box<DeviceFeaturesChain> = DeviceFeaturesChain::enable(&features);
+        assert_eq!(chain.storage_16bit.storage_buffer16_bit_access, vk::TRUE);
+        assert_eq!(chain.storage_16bit.storage_push_constant16, vk::FALSE);
+        assert_eq!((chain.float16_int8.shader_float16, chain.float16_int8.shader_int8), (vk::TRUE, vk::FALSE));
+        assert_eq!(chain.vk().p_next, &chain.storage_16bit as *const _ as *mut std::ffi::c_void);
+        assert_eq!(chain.storage_16bit.p_next, &chain.float16_int8 as *const _ as *mut std::ffi::c_void);
+        assert!(chain.float16_int8.p_next.is_null());
+
+        // They survive the trip back
+        let back: DeviceFeatures = DeviceFeatures::from(&*chain);
+        assert!(back.storage_buffer_16bit_access && back.shader_float16 && !back.shader_int8 && !back.storage_input_output16);
+        assert_eq!(features.missing_from(&DeviceFeatures{ shader_float16: true, ..Default::default() }), vec![ "storageBuffer16BitAccess" ]);
+
+        // Nothing is linked if they are not used
+        let chain: Box<DeviceFeaturesChain> = DeviceFeaturesChain::enable(&DeviceFeatures::default());
+        assert!(chain.vk().p_next.is_null());
+    }
+
+    /// Tests that the TessellationState is passed to Vulkan as-is
+    #[test]
+    fn test_tessellation_state() {
+        let vk_state: vk::PipelineTessellationStateCreateInfo = TessellationState{ patch_control_points: 4 }.into();
+        assert_eq!(vk_state.s_type, vk::StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO);
+        assert_eq!(vk_state.patch_control_points, 4);
+        assert_eq!(TessellationState::from(vk_state).patch_control_points, 4);
+    }
+
+    /// Tests that only valid pipeline creation feedback is reported
+    #[test]
+    fn test_pipeline_creation_feedback() {
+        let feedback = |flags: vk::PipelineCreationFeedbackFlags, duration: u64| vk::PipelineCreationFeedback{ flags, duration };
+        assert_eq!(PipelineCreationFeedback::from_vk(feedback(vk::PipelineCreationFeedbackFlags::empty(), 42)), None);
+        assert_eq!(PipelineCreationFeedback::from_vk(feedback(vk::PipelineCreationFeedbackFlags::APPLICATION_PIPELINE_CACHE_HIT, 42)), None);
+        assert_eq!(PipelineCreationFeedback::from_vk(feedback(vk::PipelineCreationFeedbackFlags::VALID, 1500)), Some(PipelineCreationFeedback{ duration_ns: 1500, cache_hit: false }));
+        assert_eq!(PipelineCreationFeedback::from_vk(feedback(vk::PipelineCreationFeedbackFlags::VALID | vk::PipelineCreationFeedbackFlags::APPLICATION_PIPELINE_CACHE_HIT, 20)), Some(PipelineCreationFeedback{ duration_ns: 20, cache_hit: true }));
+    }
+
+    /// Tests that the MultisampleState is passed to Vulkan as-is
+    #[test]
+    fn test_multisample_state() {
+        // The default should be a single sample without anything else
+        let vk_state: vk::PipelineMultisampleStateCreateInfo = MultisampleState::default().into();
+        assert_eq!(vk_state.rasterization_samples, vk::SampleCountFlags::TYPE_1);
+        assert_eq!((vk_state.sample_shading_enable, vk_state.alpha_to_coverage_enable, vk_state.alpha_to_one_enable), (vk::FALSE, vk::FALSE, vk::FALSE));
+
+        // A 4x MSAA state with sample shading survives a round-trip
+        let state: MultisampleState = MultisampleState{ samples: SampleCount::FOUR, sample_shading: Some(0.25), alpha_to_coverage: true, alpha_to_one: false };
+        let vk_state: vk::PipelineMultisampleStateCreateInfo = state.into();
+        assert_eq!(vk_state.rasterization_samples, vk::SampleCountFlags::TYPE_4);
+        assert_eq!((vk_state.sample_shading_enable, vk_state.min_sample_shading), (vk::TRUE, 0.25));
+        assert_eq!((vk_state.alpha_to_coverage_enable, vk_state.alpha_to_one_enable), (vk::TRUE, vk::FALSE));
+        let back: MultisampleState = vk_state.into();
+        assert!(back.samples == SampleCount::FOUR && back.sample_shading == Some(0.25) && back.alpha_to_coverage && !back.alpha_to_one);
+    }
+
+    /// Tests the detection of dual-source blending
+    #[test]
+    fn test_dual_source_blending() {
+        // Regular alpha blending does not use it
+        let alpha: AttachmentBlendState = AttachmentBlendState {
+            enable_blend : true,
+            src_colour : BlendFactor::SrcAlpha, dst_colour : BlendFactor::OneMinusSrcAlpha, colour_op : BlendOp::Add,
+            src_alpha  : BlendFactor::One, dst_alpha : BlendFactor::Zero, alpha_op : BlendOp::Add,
+            write_mask : ColourComponentFlags::all(),
+        };
+        assert!(!alpha.is_dual_source());
+
+        // But any second-source factor does, if blending is enabled
+        let dual: AttachmentBlendState = AttachmentBlendState{ dst_alpha: BlendFactor::OneMinusSrcAlpha2, ..alpha.clone() };
+        assert!(dual.is_dual_source());
+        assert!(!AttachmentBlendState{ enable_blend: false, ..dual.clone() }.is_dual_source());
+
+        // Count them in a ColourBlendState
+        let mut state: ColourBlendState = ColourBlendState{ enable_logic: false, logic_op: LogicOp::Copy, attachment_states: vec![ dual.clone(), alpha, dual ], blend_constants: [ 0.0; 4 ] };
+        assert_eq!(state.dual_source_count(), 2);
+        state.enable_logic = true;
+        assert_eq!(state.dual_source_count(), 0);
+    }
+
+    /// Tests that the flipped viewport has its origin at the bottom and a negative height
+    #[test]
+    fn test_viewport_state_flipped() {
+        let state: ViewportState = ViewportState::from_extent_flipped(&Extent2D::new(800, 600));
+        let viewport: vk::Viewport = state.clone().into();
+        assert_eq!((viewport.x, viewport.y, viewport.width, viewport.height), (0.0, 600.0, 800.0, -600.0));
+        assert_eq!((state.scissor.x(), state.scissor.y(), state.scissor.w(), state.scissor.h()), (0, 0, 800, 600));
+        assert_eq!(state.depth, 0.0..1.0);
+    }
+
+    /// Tests that an AttachmentRef's aspect ends up in the VkAttachmentReference2
+    #[test]
+    fn test_attachment_ref_aspect() {
+        let stencil: vk::AttachmentReference2 = AttachmentRef{ index: 1, layout: ImageLayout::ShaderReadOnly, aspect: Some(ImageAspectFlags::STENCIL) }.into();
+        assert_eq!((stencil.attachment, stencil.aspect_mask), (1, vk::ImageAspectFlags::STENCIL));
+        let any: vk::AttachmentReference2 = AttachmentRef{ index: 2, layout: ImageLayout::ShaderReadOnly, aspect: None }.into();
+        assert_eq!((any.attachment, any.aspect_mask), (2, vk::ImageAspectFlags::empty()));
+
+        // The description of a subpass references the converted attachments
+        let subpass = SubpassDescription {
+            bind_point        : BindPoint::Graphics,
+            input_attaches    : vec![ AttachmentRef{ index: 0, layout: ImageLayout::DepthStencilReadOnly, aspect: Some(ImageAspectFlags::DEPTH) } ],
+            colour_attaches   : vec![],
+            resolve_attaches  : vec![],
+            preserve_attaches : vec![],
+            depth_stencil     : None,
+        };
+        let (desc, mem): (vk::SubpassDescription2, SubpassDescriptionMem<vk::AttachmentReference2>) = subpass.into();
+        assert_eq!(desc.input_attachment_count, 1);
+        assert_eq!(unsafe { (*desc.p_input_attachments).aspect_mask }, vk::ImageAspectFlags::DEPTH);
+        assert_eq!(mem.0.len(), 1);
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Computes the size of a single dimension at the given mip level.
+/// 
+/// # Arguments
+/// - `value`: The size of the dimension at the base level.
+/// - `level`: The mip level to compute the size for.
+/// 
+/// # Returns
+/// The size halved `level` times, but never smaller than 1.
+#[inline]
+fn mip_dimension<T: Into<u64> + TryFrom<u64>>(value: T, level: u32) -> T {
+    let value: u64 = value.into().checked_shr(level).unwrap_or(0).max(1);
+    // The result is never larger than the original (or 1), so it always fits
+    match T::try_from(value) {
+        Ok(value) => value,
+        Err(_)    => { unreachable!(); }
+    }
+}
+
+/// Computes the number of mip levels needed to go from the given size to 1.
+/// 
+/// # Arguments
+/// - `max_dimension`: The largest dimension of the base level.
+/// 
+/// # Returns
+/// `floor(log2(max_dimension)) + 1`, or 1 if the dimension is 0.
+#[inline]
+fn mip_level_count(max_dimension: u64) -> u32 {
+    u64::BITS - max_dimension.max(1).leading_zeros()
+}
+
+
+
+
+
 /***** GEOMETRY *****/
 /// Defines a 2-dimensional offset with data type T.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Offset2D<T> {
     /// The X-coordinate of the offset.
     pub x : T,
@@ -157,8 +504,101 @@ impl<T> From<Offset2D<T>> for winit::dpi::PhysicalPosition<T> {
 
 
 
+/// Defines a 3-dimensional offset with data type T.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offset3D<T> {
+    /// The X-coordinate of the offset.
+    pub x : T,
+    /// The Y-coordinate of the offset.
+    pub y : T,
+    /// The Z-coordinate of the offset.
+    pub z : T,
+}
+
+impl<T> Offset3D<T> {
+    /// Constructor for the Offset3D.
+    /// 
+    /// # Generic arguments
+    /// - `T`: The data type of the coordinates.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the offset.
+    /// - `y`: The Y-coordinate of the offset.
+    /// - `z`: The Z-coordinate of the offset.
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+
+
+    /// Casts this Offset3D to another Offset3D with convertible types
+    #[inline]
+    pub fn cast<U: From<T>>(self) -> Offset3D<U> {
+        Offset3D::new(U::from(self.x), U::from(self.y), U::from(self.z))
+    }
+}
+
+impl<T> Display for Offset3D<T>
+where
+    T: Display
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<vk::Offset3D> for Offset3D<T>
+where
+    T: From<i32>
+{
+    #[inline]
+    fn from(value: vk::Offset3D) -> Self {
+        Self {
+            x : T::from(value.x),
+            y : T::from(value.y),
+            z : T::from(value.z),
+        }
+    }
+}
+
+impl<T> From<Offset3D<T>> for vk::Offset3D
+where
+    T: Into<i32>
+{
+    #[inline]
+    fn from(value: Offset3D<T>) -> Self {
+        Self {
+            x : value.x.into(),
+            y : value.y.into(),
+            z : value.z.into(),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Offset3D<T> {
+    #[inline]
+    fn from(value: (T, T, T)) -> Self {
+        Self {
+            x : value.0,
+            y : value.1,
+            z : value.2,
+        }
+    }
+}
+
+impl<T> From<Offset3D<T>> for (T, T, T) {
+    #[inline]
+    fn from(value: Offset3D<T>) -> Self {
+        (value.x, value.y, value.z)
+    }
+}
+
+
+
 /// Defines a 2-dimensional extent with data type T.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Extent2D<T> {
     /// The width of the extent.
     pub w : T,
@@ -187,6 +627,38 @@ impl<T> Extent2D<T> {
     pub fn cast<U: From<T>>(self) -> Extent2D<U> {
         Extent2D::new(U::from(self.w), U::from(self.h))
     }
+
+    /// Promotes this Extent2D to an Extent3D with the given depth (e.g., `1` for the extent of a 2D image in image operations).
+    #[inline]
+    pub fn to_3d(self, depth: T) -> Extent3D<T> {
+        Extent3D::new(self.w, self.h, depth)
+    }
+}
+
+impl<T> Extent2D<T>
+where
+    T: Copy + Into<u64> + TryFrom<u64>,
+{
+    /// Computes the extent of the given mip level if this is the extent of the base level.
+    /// 
+    /// # Arguments
+    /// - `level`: The mip level to compute the extent of, where `0` is the base level.
+    /// 
+    /// # Returns
+    /// A new Extent2D where every dimension is halved `level` times (rounding down), but never smaller than 1.
+    #[inline]
+    pub fn mip_level(&self, level: u32) -> Self {
+        Self::new(mip_dimension(self.w, level), mip_dimension(self.h, level))
+    }
+
+    /// Computes the number of mip levels in a full mip chain for this extent.
+    /// 
+    /// # Returns
+    /// The number of levels (including the base level) until all dimensions are 1, i.e., `floor(log2(max(w, h))) + 1`.
+    #[inline]
+    pub fn mip_level_count(&self) -> u32 {
+        mip_level_count(self.w.into().max(self.h.into()))
+    }
 }
 
 impl<T> Display for Extent2D<T>
@@ -262,8 +734,127 @@ impl<T> From<Extent2D<T>> for winit::dpi::PhysicalSize<T> {
 
 
 
+/// Defines a 3-dimensional extent with data type T.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extent3D<T> {
+    /// The width of the extent.
+    pub w : T,
+    /// The height of the extent.
+    pub h : T,
+    /// The depth of the extent.
+    pub d : T,
+}
+
+impl<T> Extent3D<T> {
+    /// Constructor for the Extent3D.
+    /// 
+    /// # Generic arguments
+    /// - `T`: The data type of the dimensions.
+    /// 
+    /// # Arguments
+    /// - `w`: The width of the extent.
+    /// - `h`: The height of the extent.
+    /// - `d`: The depth of the extent.
+    #[inline]
+    pub fn new(w: T, h: T, d: T) -> Self {
+        Self { w, h, d }
+    }
+
+
+
+    /// Casts this Extent3D to another Extent3D with convertible types
+    #[inline]
+    pub fn cast<U: From<T>>(self) -> Extent3D<U> {
+        Extent3D::new(U::from(self.w), U::from(self.h), U::from(self.d))
+    }
+}
+
+impl<T> Extent3D<T>
+where
+    T: Copy + Into<u64> + TryFrom<u64>,
+{
+    /// Computes the extent of the given mip level if this is the extent of the base level.
+    /// 
+    /// # Arguments
+    /// - `level`: The mip level to compute the extent of, where `0` is the base level.
+    /// 
+    /// # Returns
+    /// A new Extent3D where every dimension is halved `level` times (rounding down), but never smaller than 1.
+    #[inline]
+    pub fn mip_level(&self, level: u32) -> Self {
+        Self::new(mip_dimension(self.w, level), mip_dimension(self.h, level), mip_dimension(self.d, level))
+    }
+
+    /// Computes the number of mip levels in a full mip chain for this extent.
+    /// 
+    /// # Returns
+    /// The number of levels (including the base level) until all dimensions are 1, i.e., `floor(log2(max(w, h, d))) + 1`.
+    #[inline]
+    pub fn mip_level_count(&self) -> u32 {
+        mip_level_count(self.w.into().max(self.h.into()).max(self.d.into()))
+    }
+}
+
+impl<T> Display for Extent3D<T>
+where
+    T: Display
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "({}, {}, {})", self.w, self.h, self.d)
+    }
+}
+
+impl<T> From<vk::Extent3D> for Extent3D<T>
+where
+    T: From<u32>
+{
+    #[inline]
+    fn from(value: vk::Extent3D) -> Self {
+        Self {
+            w : T::from(value.width),
+            h : T::from(value.height),
+            d : T::from(value.depth),
+        }
+    }
+}
+
+impl<T> From<Extent3D<T>> for vk::Extent3D
+where
+    T: Into<u32>
+{
+    #[inline]
+    fn from(value: Extent3D<T>) -> Self {
+        Self {
+            width  : value.w.into(),
+            height : value.h.into(),
+            depth  : value.d.into(),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Extent3D<T> {
+    #[inline]
+    fn from(value: (T, T, T)) -> Self {
+        Self {
+            w : value.0,
+            h : value.1,
+            d : value.2,
+        }
+    }
+}
+
+impl<T> From<Extent3D<T>> for (T, T, T) {
+    #[inline]
+    fn from(value: Extent3D<T>) -> Self {
+        (value.w, value.h, value.d)
+    }
+}
+
+
+
 /// Defines a 2-dimensional rectangle with an offset (of datatype T) and an extent (of datatype U).
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Rect2D<T, U = T> {
     /// The offset of the top-left corner of the rectangle.
     pub offset : Offset2D<T>,
@@ -335,6 +926,77 @@ impl<T, U> Rect2D<T, U> {
     pub fn h(&self) -> U where U: Copy { self.extent.h }
 }
 
+impl<T, U> Rect2D<T, U>
+where
+    T: Copy + Into<i64> + TryFrom<i64>,
+    U: Copy + Into<i64> + TryFrom<i64>,
+{
+    /// Computes the overlapping area of this rectangle and the given one.
+    /// 
+    /// # Arguments
+    /// - `other`: The other rectangle to intersect with.
+    /// 
+    /// # Returns
+    /// A new Rect2D that describes the overlapping area, or `None` if the rectangles do not overlap (touching edges do not count as overlap).
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        // Compute the bounds of the overlapping area
+        let (x0, y0): (i64, i64) = (self.offset.x.into().max(other.offset.x.into()), self.offset.y.into().max(other.offset.y.into()));
+        let (x1, y1): (i64, i64) = (self.right().min(other.right()), self.bottom().min(other.bottom()));
+        if x1 <= x0 || y1 <= y0 { return None; }
+
+        // Convert back; this always fits, since the area lies within both rectangles
+        Some(Self::new(T::try_from(x0).ok()?, T::try_from(y0).ok()?, U::try_from(x1 - x0).ok()?, U::try_from(y1 - y0).ok()?))
+    }
+
+    /// Checks if the given point lies within this rectangle.
+    /// 
+    /// The rectangle is considered half-open, i.e., points on its left and top edges are inside, but points on its right and bottom edges are not.
+    /// 
+    /// # Arguments
+    /// - `point`: The point to check.
+    /// 
+    /// # Returns
+    /// True if the point lies within this rectangle, or false otherwise.
+    pub fn contains(&self, point: Offset2D<T>) -> bool {
+        let (x, y): (i64, i64) = (point.x.into(), point.y.into());
+        x >= self.offset.x.into() && x < self.right() && y >= self.offset.y.into() && y < self.bottom()
+    }
+
+    /// Computes the smallest rectangle that covers both this rectangle and the given one.
+    /// 
+    /// # Arguments
+    /// - `other`: The other rectangle to cover.
+    /// 
+    /// # Returns
+    /// A new Rect2D that bounds both rectangles.
+    /// 
+    /// # Panics
+    /// This function panics if the resulting extent does not fit in `U` (e.g., when two rectangles with `u32` extents are very far apart).
+    pub fn union(&self, other: &Self) -> Self {
+        // Compute the bounds of the covering area
+        let (x0, y0): (i64, i64) = (self.offset.x.into().min(other.offset.x.into()), self.offset.y.into().min(other.offset.y.into()));
+        let (x1, y1): (i64, i64) = (self.right().max(other.right()), self.bottom().max(other.bottom()));
+
+        // Convert back; the offset always fits, since it's one of the original ones
+        Self::new(
+            if x0 == self.offset.x.into() { self.offset.x } else { other.offset.x },
+            if y0 == self.offset.y.into() { self.offset.y } else { other.offset.y },
+            U::try_from(x1 - x0).unwrap_or_else(|_| panic!("Width of union of rectangles ({}) does not fit in the extent's data type", x1 - x0)),
+            U::try_from(y1 - y0).unwrap_or_else(|_| panic!("Height of union of rectangles ({}) does not fit in the extent's data type", y1 - y0)),
+        )
+    }
+
+
+
+    /// Returns the (exclusive) X-coordinate of the right edge of the rectangle.
+    #[inline]
+    fn right(&self) -> i64 { self.offset.x.into() + self.extent.w.into() }
+
+    /// Returns the (exclusive) Y-coordinate of the bottom edge of the rectangle.
+    #[inline]
+    fn bottom(&self) -> i64 { self.offset.y.into() + self.extent.h.into() }
+}
+
 impl<T, U> From<vk::Rect2D> for Rect2D<T, U>
 where
     T: From<i32>,
@@ -554,6 +1216,28 @@ pub struct PhysicalDeviceLimits {
     pub non_coherent_atom_size : vk::DeviceSize,
 }
 
+impl PhysicalDeviceLimits {
+    /// Returns the highest sample count that is supported for framebuffers with both a colour and a depth attachment.
+    /// 
+    /// # Returns
+    /// The highest SampleCount in both `framebuffer_color_sample_counts` and `framebuffer_depth_sample_counts`, or `SampleCount::ONE` if there is none (which should not happen, as Vulkan requires it to be supported).
+    #[inline]
+    pub fn max_framebuffer_sample_count(&self) -> SampleCount {
+        self.framebuffer_color_sample_counts.intersect(self.framebuffer_depth_sample_counts).max().unwrap_or(SampleCount::ONE)
+    }
+
+    /// Returns the highest sample count that is supported for storage images.
+    /// 
+    /// Note that multisampled storage images additionally require the `shaderStorageImageMultisample` device feature.
+    /// 
+    /// # Returns
+    /// The highest SampleCount in `storage_image_sample_counts`, or `SampleCount::ONE` if there is none (which should not happen, as Vulkan requires it to be supported).
+    #[inline]
+    pub fn max_storage_image_sample_count(&self) -> SampleCount {
+        self.storage_image_sample_counts.max().unwrap_or(SampleCount::ONE)
+    }
+}
+
 impl From<vk::PhysicalDeviceLimits> for PhysicalDeviceLimits {
     fn from(value: vk::PhysicalDeviceLimits) -> Self {
         Self {
@@ -901,6 +1585,21 @@ pub struct DeviceMemoryProperties {
     pub types : Vec<DeviceMemoryTypeInfo>,
 }
 
+impl DeviceMemoryProperties {
+    /// Describes the memory types selected by the given mask, e.g., to find out which memory types a MemoryRequirements allows.
+    /// 
+    /// # Arguments
+    /// - `mask`: The DeviceMemoryTypeFlags that select the memory types to describe.
+    /// 
+    /// # Returns
+    /// The index and property flags of every selected memory type, in ascending order of memory type index. Bits in the mask that do not correspond to a memory type of this device are ignored.
+    pub fn describe_types(&self, mask: DeviceMemoryTypeFlags) -> Vec<(u32, MemoryPropertyFlags)> {
+        mask.iter_indices()
+            .filter_map(|index| self.types.get(index as usize).map(|info| (index, info.props)))
+            .collect()
+    }
+}
+
 impl From<vk::PhysicalDeviceMemoryProperties> for DeviceMemoryProperties {
     #[inline]
     fn from(value: vk::PhysicalDeviceMemoryProperties) -> Self {
@@ -1030,16 +1729,260 @@ impl From<&DeviceMemoryTypeInfo> for vk::MemoryType {
 
 
 /// The features that we can enable on a Device.
+/// 
+/// Next to the features in the base `vk::PhysicalDeviceFeatures`, this also models the features that live in separate structs (e.g., `vk::PhysicalDeviceTimelineSemaphoreFeatures`) which are chained in the `p_next`-chain when creating the Device.
+/// 
+/// Note that, since the Instance targets Vulkan 1.1, most of the extended features also require their matching device extension to be enabled (e.g., `VK_KHR_timeline_semaphore` for `timeline_semaphore`).
 #[derive(Clone, Debug)]
 pub struct DeviceFeatures {
-    
+    /// Whether out-of-bounds buffer accesses are bounds-checked, i.e., are guaranteed to not crash or read from other resources.
+    pub robust_buffer_access               : bool,
+    /// Whether storage buffers and images may be written to (and used atomically) in the vertex, tessellation and geometry shader stages.
+    pub vertex_pipeline_stores_and_atomics : bool,
+    /// Whether storage buffers and images may be written to (and used atomically) in the fragment shader stage.
+    pub fragment_stores_and_atomics        : bool,
+    /// Whether the full 32-bit range of indices may be used for indexed draws (instead of only up to `maxDrawIndexedIndexValue`).
+    pub full_draw_index_uint32             : bool,
+    /// Whether image views of cube arrays may be created.
+    pub image_cube_array                   : bool,
+    /// Whether colour blending may be configured differently for every colour attachment.
+    pub independent_blend                  : bool,
+    /// Whether pipelines may use geometry shaders.
+    pub geometry_shader                    : bool,
+    /// Whether pipelines may use tessellation control and evaluation shaders.
+    pub tessellation_shader                : bool,
+    /// Whether fragments may be shaded per-sample instead of per-pixel.
+    pub sample_rate_shading                : bool,
+    /// Whether blend operations may take two sources.
+    pub dual_src_blend                     : bool,
+    /// Whether pipelines may apply logic operations to their colour attachments.
+    pub logic_op                           : bool,
+    /// Whether indirect draws may issue more than one draw at once.
+    pub multi_draw_indirect                : bool,
+    /// Whether pipelines may clamp fragment depths instead of clipping primitives.
+    pub depth_clamp                        : bool,
+    /// Whether the depth bias of a pipeline may be clamped.
+    pub depth_bias_clamp                   : bool,
+    /// Whether pipelines may draw polygons as lines or points (e.g., for wireframes).
+    pub fill_mode_non_solid                : bool,
+    /// Whether pipelines may use the depth bounds test.
+    pub depth_bounds                       : bool,
+    /// Whether lines may be drawn with a width other than 1.0.
+    pub wide_lines                         : bool,
+    /// Whether points may be drawn with a size other than 1.0.
+    pub large_points                       : bool,
+    /// Whether pipelines may replace the alpha component of their first colour output with one (see `MultisampleState::alpha_to_one`).
+    pub alpha_to_one                       : bool,
+    /// Whether pipelines may use more than one viewport.
+    pub multi_viewport                     : bool,
+    /// Whether Samplers may use anisotropic filtering.
+    pub sampler_anisotropy                 : bool,
+    /// Whether images may use the BC block-compressed formats.
+    pub texture_compression_bc             : bool,
+    /// Whether shaders may use 64-bit floats.
+    pub shader_float64                     : bool,
+    /// Whether shaders may use 64-bit integers.
+    pub shader_int64                       : bool,
+
+    /// Whether storage buffers may contain 16-bit integers and floats (core in Vulkan 1.1).
+    pub storage_buffer_16bit_access             : bool,
+    /// Whether uniform and storage buffers may contain 16-bit integers and floats (core in Vulkan 1.1).
+    pub uniform_and_storage_buffer_16bit_access : bool,
+    /// Whether push constants may contain 16-bit integers and floats (core in Vulkan 1.1).
+    pub storage_push_constant16                 : bool,
+    /// Whether shader inputs and outputs may be 16-bit integers and floats (core in Vulkan 1.1).
+    pub storage_input_output16                  : bool,
+
+    /// Whether shaders may use 16-bit floats in arithmetic (`VK_KHR_shader_float16_int8`, core in Vulkan 1.2).
+    pub shader_float16 : bool,
+    /// Whether shaders may use 8-bit integers in arithmetic (`VK_KHR_shader_float16_int8`, core in Vulkan 1.2).
+    pub shader_int8    : bool,
+
+    /// Whether to enable timeline semaphores (`VK_KHR_timeline_semaphore`, core in Vulkan 1.2).
+    pub timeline_semaphore : bool,
+
+    /// Whether descriptor bindings may be left partially unbound if they are not dynamically used (`VK_EXT_descriptor_indexing`, core in Vulkan 1.2).
+    pub descriptor_binding_partially_bound                 : bool,
+    /// Whether the last binding in a descriptor set may have a variable size (`VK_EXT_descriptor_indexing`, core in Vulkan 1.2).
+    pub descriptor_binding_variable_descriptor_count       : bool,
+    /// Whether sampled image descriptors may be updated after they have been bound (`VK_EXT_descriptor_indexing`, core in Vulkan 1.2).
+    pub descriptor_binding_sampled_image_update_after_bind : bool,
+    /// Whether shaders may declare runtime-sized descriptor arrays (`VK_EXT_descriptor_indexing`, core in Vulkan 1.2).
+    pub runtime_descriptor_array                           : bool,
+    /// Whether shaders may index arrays of sampled images with non-uniform indices (`VK_EXT_descriptor_indexing`, core in Vulkan 1.2).
+    pub shader_sampled_image_array_non_uniform_indexing    : bool,
+
+    /// Whether shaders may access buffers through their device address (`VK_KHR_buffer_device_address`, core in Vulkan 1.2).
+    pub buffer_device_address : bool,
+
+    /// Whether to enable rendering without RenderPasses or Framebuffers (`VK_KHR_dynamic_rendering`, core in Vulkan 1.3).
+    pub dynamic_rendering : bool,
+
+    /// Whether to enable the reworked synchronization commands and the `ImageLayout::AttachmentOptimal`/`ImageLayout::ReadOnlyOptimal` layouts (`VK_KHR_synchronization2`, core in Vulkan 1.3).
+    pub synchronization2 : bool,
+
+    /// Whether pipelines may use task shaders (`VK_EXT_mesh_shader`).
+    pub task_shader : bool,
+    /// Whether pipelines may use mesh shaders (`VK_EXT_mesh_shader`).
+    pub mesh_shader : bool,
 }
 
 impl DeviceFeatures {
     /// Constant default() function.
     #[inline]
     pub const fn cdefault() -> Self {
-        Self {}
+        Self {
+            robust_buffer_access               : false,
+            vertex_pipeline_stores_and_atomics : false,
+            fragment_stores_and_atomics        : false,
+            full_draw_index_uint32             : false,
+            image_cube_array                   : false,
+            independent_blend                  : false,
+            geometry_shader                    : false,
+            tessellation_shader                : false,
+            sample_rate_shading                : false,
+            dual_src_blend                     : false,
+            logic_op                           : false,
+            multi_draw_indirect                : false,
+            depth_clamp                        : false,
+            depth_bias_clamp                   : false,
+            fill_mode_non_solid                : false,
+            depth_bounds                       : false,
+            wide_lines                         : false,
+            large_points                       : false,
+            alpha_to_one                       : false,
+            multi_viewport                     : false,
+            sampler_anisotropy                 : false,
+            texture_compression_bc             : false,
+            shader_float64                     : false,
+            shader_int64                       : false,
+
+            storage_buffer_16bit_access             : false,
+            uniform_and_storage_buffer_16bit_access : false,
+            storage_push_constant16                 : false,
+            storage_input_output16                  : false,
+
+            shader_float16 : false,
+            shader_int8    : false,
+
+            timeline_semaphore : false,
+
+            descriptor_binding_partially_bound                 : false,
+            descriptor_binding_variable_descriptor_count       : false,
+            descriptor_binding_sampled_image_update_after_bind : false,
+            runtime_descriptor_array                           : false,
+            shader_sampled_image_array_non_uniform_indexing    : false,
+
+            buffer_device_address : false,
+
+            dynamic_rendering : false,
+
+            synchronization2 : false,
+
+            task_shader : false,
+            mesh_shader : false,
+        }
+    }
+
+
+
+    /// Returns the features that are supported by the given physical device.
+    /// 
+    /// This includes the extended features, although these may still require their matching device extension to be enabled.
+    /// 
+    /// # Arguments
+    /// - `instance`: The Instance on which the physical device lives.
+    /// - `physical_device`: The VkPhysicalDevice to query.
+    /// 
+    /// # Returns
+    /// A new DeviceFeatures with every feature that the physical device supports enabled.
+    pub fn supported_by(instance: &Shared<Instance>, physical_device: vk::PhysicalDevice) -> Self {
+        let mut chain: Box<DeviceFeaturesChain> = DeviceFeaturesChain::query();
+        unsafe { instance.get_physical_device_features2(physical_device, chain.vk_mut()); }
+        Self::from(&*chain)
+    }
+
+    /// Returns the names of the features that are enabled in this DeviceFeatures but not in the given one.
+    /// 
+    /// # Arguments
+    /// - `supported`: The DeviceFeatures that describe which features are supported (see `DeviceFeatures::supported_by()`).
+    /// 
+    /// # Returns
+    /// The Vulkan names of all unsupported features, which is empty if all of the enabled features are supported.
+    pub fn missing_from(&self, supported: &DeviceFeatures) -> Vec<&'static str> {
+        // Simply go through them one-by-one
+        let mut missing: Vec<&'static str> = vec![];
+        if self.robust_buffer_access && !supported.robust_buffer_access { missing.push("robustBufferAccess"); }
+        if self.vertex_pipeline_stores_and_atomics && !supported.vertex_pipeline_stores_and_atomics { missing.push("vertexPipelineStoresAndAtomics"); }
+        if self.fragment_stores_and_atomics && !supported.fragment_stores_and_atomics { missing.push("fragmentStoresAndAtomics"); }
+        if self.full_draw_index_uint32 && !supported.full_draw_index_uint32 { missing.push("fullDrawIndexUint32"); }
+        if self.image_cube_array && !supported.image_cube_array { missing.push("imageCubeArray"); }
+        if self.independent_blend && !supported.independent_blend { missing.push("independentBlend"); }
+        if self.geometry_shader && !supported.geometry_shader { missing.push("geometryShader"); }
+        if self.tessellation_shader && !supported.tessellation_shader { missing.push("tessellationShader"); }
+        if self.sample_rate_shading && !supported.sample_rate_shading { missing.push("sampleRateShading"); }
+        if self.dual_src_blend && !supported.dual_src_blend { missing.push("dualSrcBlend"); }
+        if self.logic_op && !supported.logic_op { missing.push("logicOp"); }
+        if self.multi_draw_indirect && !supported.multi_draw_indirect { missing.push("multiDrawIndirect"); }
+        if self.depth_clamp && !supported.depth_clamp { missing.push("depthClamp"); }
+        if self.depth_bias_clamp && !supported.depth_bias_clamp { missing.push("depthBiasClamp"); }
+        if self.fill_mode_non_solid && !supported.fill_mode_non_solid { missing.push("fillModeNonSolid"); }
+        if self.depth_bounds && !supported.depth_bounds { missing.push("depthBounds"); }
+        if self.wide_lines && !supported.wide_lines { missing.push("wideLines"); }
+        if self.large_points && !supported.large_points { missing.push("largePoints"); }
+        if self.alpha_to_one && !supported.alpha_to_one { missing.push("alphaToOne"); }
+        if self.multi_viewport && !supported.multi_viewport { missing.push("multiViewport"); }
+        if self.sampler_anisotropy && !supported.sampler_anisotropy { missing.push("samplerAnisotropy"); }
+        if self.texture_compression_bc && !supported.texture_compression_bc { missing.push("textureCompressionBC"); }
+        if self.shader_float64 && !supported.shader_float64 { missing.push("shaderFloat64"); }
+        if self.shader_int64 && !supported.shader_int64 { missing.push("shaderInt64"); }
+
+        if self.storage_buffer_16bit_access && !supported.storage_buffer_16bit_access { missing.push("storageBuffer16BitAccess"); }
+        if self.uniform_and_storage_buffer_16bit_access && !supported.uniform_and_storage_buffer_16bit_access { missing.push("uniformAndStorageBuffer16BitAccess"); }
+        if self.storage_push_constant16 && !supported.storage_push_constant16 { missing.push("storagePushConstant16"); }
+        if self.storage_input_output16 && !supported.storage_input_output16 { missing.push("storageInputOutput16"); }
+
+        if self.shader_float16 && !supported.shader_float16 { missing.push("shaderFloat16"); }
+        if self.shader_int8 && !supported.shader_int8 { missing.push("shaderInt8"); }
+
+        if self.timeline_semaphore && !supported.timeline_semaphore { missing.push("timelineSemaphore"); }
+
+        if self.descriptor_binding_partially_bound && !supported.descriptor_binding_partially_bound { missing.push("descriptorBindingPartiallyBound"); }
+        if self.descriptor_binding_variable_descriptor_count && !supported.descriptor_binding_variable_descriptor_count { missing.push("descriptorBindingVariableDescriptorCount"); }
+        if self.descriptor_binding_sampled_image_update_after_bind && !supported.descriptor_binding_sampled_image_update_after_bind { missing.push("descriptorBindingSampledImageUpdateAfterBind"); }
+        if self.runtime_descriptor_array && !supported.runtime_descriptor_array { missing.push("runtimeDescriptorArray"); }
+        if self.shader_sampled_image_array_non_uniform_indexing && !supported.shader_sampled_image_array_non_uniform_indexing { missing.push("shaderSampledImageArrayNonUniformIndexing"); }
+
+        if self.buffer_device_address && !supported.buffer_device_address { missing.push("bufferDeviceAddress"); }
+
+        if self.dynamic_rendering && !supported.dynamic_rendering { missing.push("dynamicRendering"); }
+
+        if self.synchronization2 && !supported.synchronization2 { missing.push("synchronization2"); }
+
+        if self.task_shader && !supported.task_shader { missing.push("taskShader"); }
+        if self.mesh_shader && !supported.mesh_shader { missing.push("meshShader"); }
+
+        // Done
+        missing
+    }
+
+    /// Returns whether any of the 16-bit storage features are enabled.
+    #[inline]
+    fn storage_16bit(&self) -> bool {
+        self.storage_buffer_16bit_access
+            || self.uniform_and_storage_buffer_16bit_access
+            || self.storage_push_constant16
+            || self.storage_input_output16
+    }
+
+    /// Returns whether any of the descriptor indexing features are enabled.
+    #[inline]
+    fn descriptor_indexing(&self) -> bool {
+        self.descriptor_binding_partially_bound
+            || self.descriptor_binding_variable_descriptor_count
+            || self.descriptor_binding_sampled_image_update_after_bind
+            || self.runtime_descriptor_array
+            || self.shader_sampled_image_array_non_uniform_indexing
     }
 }
 
@@ -1058,9 +2001,35 @@ impl From<vk::PhysicalDeviceFeatures> for DeviceFeatures {
 
 impl From<&vk::PhysicalDeviceFeatures> for DeviceFeatures {
     #[inline]
-    fn from(_value: &vk::PhysicalDeviceFeatures) -> Self {
+    fn from(value: &vk::PhysicalDeviceFeatures) -> Self {
         Self {
-            
+            robust_buffer_access               : value.robust_buffer_access != vk::FALSE,
+            vertex_pipeline_stores_and_atomics : value.vertex_pipeline_stores_and_atomics != vk::FALSE,
+            fragment_stores_and_atomics        : value.fragment_stores_and_atomics != vk::FALSE,
+            full_draw_index_uint32             : value.full_draw_index_uint32 != vk::FALSE,
+            image_cube_array                   : value.image_cube_array != vk::FALSE,
+            independent_blend                  : value.independent_blend != vk::FALSE,
+            geometry_shader                    : value.geometry_shader != vk::FALSE,
+            tessellation_shader                : value.tessellation_shader != vk::FALSE,
+            sample_rate_shading                : value.sample_rate_shading != vk::FALSE,
+            dual_src_blend                     : value.dual_src_blend != vk::FALSE,
+            logic_op                           : value.logic_op != vk::FALSE,
+            multi_draw_indirect                : value.multi_draw_indirect != vk::FALSE,
+            depth_clamp                        : value.depth_clamp != vk::FALSE,
+            depth_bias_clamp                   : value.depth_bias_clamp != vk::FALSE,
+            fill_mode_non_solid                : value.fill_mode_non_solid != vk::FALSE,
+            depth_bounds                       : value.depth_bounds != vk::FALSE,
+            wide_lines                         : value.wide_lines != vk::FALSE,
+            large_points                       : value.large_points != vk::FALSE,
+            alpha_to_one                       : value.alpha_to_one != vk::FALSE,
+            multi_viewport                     : value.multi_viewport != vk::FALSE,
+            sampler_anisotropy                 : value.sampler_anisotropy != vk::FALSE,
+            texture_compression_bc             : value.texture_compression_bc != vk::FALSE,
+            shader_float64                     : value.shader_float64 != vk::FALSE,
+            shader_int64                       : value.shader_int64 != vk::FALSE,
+
+            // The extended features do not live in this struct
+            ..Self::cdefault()
         }
     }
 }
@@ -1075,14 +2044,203 @@ impl From<DeviceFeatures> for vk::PhysicalDeviceFeatures {
 
 impl From<&DeviceFeatures> for vk::PhysicalDeviceFeatures {
     #[inline]
-    fn from(_value: &DeviceFeatures) -> Self {
+    fn from(value: &DeviceFeatures) -> Self {
         Self {
+            robust_buffer_access               : value.robust_buffer_access as vk::Bool32,
+            vertex_pipeline_stores_and_atomics : value.vertex_pipeline_stores_and_atomics as vk::Bool32,
+            fragment_stores_and_atomics        : value.fragment_stores_and_atomics as vk::Bool32,
+            full_draw_index_uint32             : value.full_draw_index_uint32 as vk::Bool32,
+            image_cube_array                   : value.image_cube_array as vk::Bool32,
+            independent_blend                  : value.independent_blend as vk::Bool32,
+            geometry_shader                    : value.geometry_shader as vk::Bool32,
+            tessellation_shader                : value.tessellation_shader as vk::Bool32,
+            sample_rate_shading                : value.sample_rate_shading as vk::Bool32,
+            dual_src_blend                     : value.dual_src_blend as vk::Bool32,
+            logic_op                           : value.logic_op as vk::Bool32,
+            multi_draw_indirect                : value.multi_draw_indirect as vk::Bool32,
+            depth_clamp                        : value.depth_clamp as vk::Bool32,
+            depth_bias_clamp                   : value.depth_bias_clamp as vk::Bool32,
+            fill_mode_non_solid                : value.fill_mode_non_solid as vk::Bool32,
+            depth_bounds                       : value.depth_bounds as vk::Bool32,
+            wide_lines                         : value.wide_lines as vk::Bool32,
+            large_points                       : value.large_points as vk::Bool32,
+            alpha_to_one                       : value.alpha_to_one as vk::Bool32,
+            multi_viewport                     : value.multi_viewport as vk::Bool32,
+            sampler_anisotropy                 : value.sampler_anisotropy as vk::Bool32,
+            texture_compression_bc             : value.texture_compression_bc as vk::Bool32,
+            shader_float64                     : value.shader_float64 as vk::Bool32,
+            shader_int64                       : value.shader_int64 as vk::Bool32,
+
             // Set the rest to off
             ..Default::default()
         }
     }
 }
 
+impl From<&DeviceFeaturesChain> for DeviceFeatures {
+    fn from(value: &DeviceFeaturesChain) -> Self {
+        // Start with the base features
+        let mut result = Self::from(&value.features2.features);
+
+        // Add the extended ones
+        result.storage_buffer_16bit_access             = value.storage_16bit.storage_buffer16_bit_access != vk::FALSE;
+        result.uniform_and_storage_buffer_16bit_access = value.storage_16bit.uniform_and_storage_buffer16_bit_access != vk::FALSE;
+        result.storage_push_constant16                 = value.storage_16bit.storage_push_constant16 != vk::FALSE;
+        result.storage_input_output16                  = value.storage_16bit.storage_input_output16 != vk::FALSE;
+
+        result.shader_float16 = value.float16_int8.shader_float16 != vk::FALSE;
+        result.shader_int8    = value.float16_int8.shader_int8 != vk::FALSE;
+
+        result.timeline_semaphore = value.timeline_semaphore.timeline_semaphore != vk::FALSE;
+
+        result.descriptor_binding_partially_bound                 = value.descriptor_indexing.descriptor_binding_partially_bound != vk::FALSE;
+        result.descriptor_binding_variable_descriptor_count       = value.descriptor_indexing.descriptor_binding_variable_descriptor_count != vk::FALSE;
+        result.descriptor_binding_sampled_image_update_after_bind = value.descriptor_indexing.descriptor_binding_sampled_image_update_after_bind != vk::FALSE;
+        result.runtime_descriptor_array                           = value.descriptor_indexing.runtime_descriptor_array != vk::FALSE;
+        result.shader_sampled_image_array_non_uniform_indexing    = value.descriptor_indexing.shader_sampled_image_array_non_uniform_indexing != vk::FALSE;
+
+        result.buffer_device_address = value.buffer_device_address.buffer_device_address != vk::FALSE;
+
+        result.dynamic_rendering = value.dynamic_rendering.dynamic_rendering != vk::FALSE;
+
+        result.synchronization2 = value.synchronization2.synchronization2 != vk::FALSE;
+
+        result.task_shader = value.mesh_shader.task_shader != vk::FALSE;
+        result.mesh_shader = value.mesh_shader.mesh_shader != vk::FALSE;
+
+        // Done
+        result
+    }
+}
+
+
+
+/// Owns the `p_next`-chain of Vulkan feature structs that is used to query or enable DeviceFeatures.
+/// 
+/// Because the chain consists of pointers into the struct itself, it is always kept behind a Box.
+pub(crate) struct DeviceFeaturesChain {
+    /// The root of the chain, which also carries the base features.
+    features2             : vk::PhysicalDeviceFeatures2,
+    /// The 16-bit storage features.
+    storage_16bit         : vk::PhysicalDevice16BitStorageFeatures,
+    /// The 16-bit float & 8-bit integer arithmetic features.
+    float16_int8          : vk::PhysicalDeviceShaderFloat16Int8Features,
+    /// The timeline semaphore features.
+    timeline_semaphore    : vk::PhysicalDeviceTimelineSemaphoreFeatures,
+    /// The descriptor indexing features.
+    descriptor_indexing   : vk::PhysicalDeviceDescriptorIndexingFeatures,
+    /// The buffer device address features.
+    buffer_device_address : vk::PhysicalDeviceBufferDeviceAddressFeatures,
+    /// The dynamic rendering features.
+    dynamic_rendering     : vk::PhysicalDeviceDynamicRenderingFeatures,
+    /// The synchronization2 features.
+    synchronization2      : vk::PhysicalDeviceSynchronization2Features,
+    /// The task & mesh shader features.
+    mesh_shader           : vk::PhysicalDeviceMeshShaderFeaturesEXT,
+}
+
+impl DeviceFeaturesChain {
+    /// Constructor for the DeviceFeaturesChain that links all of the structs, such that it can be used to query the supported features of a physical device.
+    /// 
+    /// # Returns
+    /// A new DeviceFeaturesChain with everything set to false, already wrapped in a Box.
+    pub(crate) fn query() -> Box<Self> {
+        let mut chain = Box::new(Self::blank());
+        chain.link(true, true, true, true, true, true, true, true);
+        chain
+    }
+
+    /// Constructor for the DeviceFeaturesChain that describes the given DeviceFeatures, such that it can be used to enable them on a new Device.
+    /// 
+    /// Only the structs that enable at least one feature are linked, so that we do not pass structs of (possibly) unsupported extensions.
+    /// 
+    /// # Arguments
+    /// - `features`: The DeviceFeatures to enable.
+    /// 
+    /// # Returns
+    /// A new DeviceFeaturesChain, already wrapped in a Box.
+    pub(crate) fn enable(features: &DeviceFeatures) -> Box<Self> {
+        let mut chain = Box::new(Self::blank());
+
+        // Set the features
+        chain.features2.features = features.into();
+        chain.storage_16bit.storage_buffer16_bit_access             = features.storage_buffer_16bit_access as vk::Bool32;
+        chain.storage_16bit.uniform_and_storage_buffer16_bit_access = features.uniform_and_storage_buffer_16bit_access as vk::Bool32;
+        chain.storage_16bit.storage_push_constant16                 = features.storage_push_constant16 as vk::Bool32;
+        chain.storage_16bit.storage_input_output16                  = features.storage_input_output16 as vk::Bool32;
+        chain.float16_int8.shader_float16 = features.shader_float16 as vk::Bool32;
+        chain.float16_int8.shader_int8    = features.shader_int8 as vk::Bool32;
+        chain.timeline_semaphore.timeline_semaphore = features.timeline_semaphore as vk::Bool32;
+        chain.descriptor_indexing.descriptor_binding_partially_bound                 = features.descriptor_binding_partially_bound as vk::Bool32;
+        chain.descriptor_indexing.descriptor_binding_variable_descriptor_count       = features.descriptor_binding_variable_descriptor_count as vk::Bool32;
+        chain.descriptor_indexing.descriptor_binding_sampled_image_update_after_bind = features.descriptor_binding_sampled_image_update_after_bind as vk::Bool32;
+        chain.descriptor_indexing.runtime_descriptor_array                           = features.runtime_descriptor_array as vk::Bool32;
+        chain.descriptor_indexing.shader_sampled_image_array_non_uniform_indexing    = features.shader_sampled_image_array_non_uniform_indexing as vk::Bool32;
+        chain.buffer_device_address.buffer_device_address = features.buffer_device_address as vk::Bool32;
+        chain.dynamic_rendering.dynamic_rendering = features.dynamic_rendering as vk::Bool32;
+        chain.synchronization2.synchronization2 = features.synchronization2 as vk::Bool32;
+        chain.mesh_shader.task_shader = features.task_shader as vk::Bool32;
+        chain.mesh_shader.mesh_shader = features.mesh_shader as vk::Bool32;
+
+        // Link only what we need
+        chain.link(features.storage_16bit(), features.shader_float16 || features.shader_int8, features.timeline_semaphore, features.descriptor_indexing(), features.buffer_device_address, features.dynamic_rendering, features.synchronization2, features.task_shader || features.mesh_shader);
+        chain
+    }
+
+    /// Returns a DeviceFeaturesChain with all structs initialized to their defaults (i.e., all features disabled) and nothing linked.
+    fn blank() -> Self {
+        Self {
+            features2             : Default::default(),
+            storage_16bit         : Default::default(),
+            float16_int8          : Default::default(),
+            timeline_semaphore    : Default::default(),
+            descriptor_indexing   : Default::default(),
+            buffer_device_address : Default::default(),
+            dynamic_rendering     : Default::default(),
+            synchronization2      : Default::default(),
+            mesh_shader           : Default::default(),
+        }
+    }
+
+    /// Links the given structs in the `p_next`-chain of the root struct.
+    /// 
+    /// Must only be called once the DeviceFeaturesChain lives at its final memory location.
+    /// 
+    /// # Arguments
+    /// - `storage_16bit`: Whether to link the 16-bit storage struct.
+    /// - `float16_int8`: Whether to link the 16-bit float & 8-bit integer struct.
+    /// - `timeline_semaphore`: Whether to link the timeline semaphore struct.
+    /// - `descriptor_indexing`: Whether to link the descriptor indexing struct.
+    /// - `buffer_device_address`: Whether to link the buffer device address struct.
+    /// - `dynamic_rendering`: Whether to link the dynamic rendering struct.
+    /// - `synchronization2`: Whether to link the synchronization2 struct.
+    /// - `mesh_shader`: Whether to link the task & mesh shader struct.
+    #[allow(clippy::too_many_arguments)]
+    fn link(&mut self, storage_16bit: bool, float16_int8: bool, timeline_semaphore: bool, descriptor_indexing: bool, buffer_device_address: bool, dynamic_rendering: bool, synchronization2: bool, mesh_shader: bool) {
+        // Build the chain back-to-front
+        let mut next: *mut std::ffi::c_void = ptr::null_mut();
+        if mesh_shader           { self.mesh_shader.p_next = next; next = &mut self.mesh_shader as *mut _ as *mut std::ffi::c_void; }
+        if synchronization2      { self.synchronization2.p_next = next; next = &mut self.synchronization2 as *mut _ as *mut std::ffi::c_void; }
+        if dynamic_rendering     { self.dynamic_rendering.p_next = next; next = &mut self.dynamic_rendering as *mut _ as *mut std::ffi::c_void; }
+        if buffer_device_address { self.buffer_device_address.p_next = next; next = &mut self.buffer_device_address as *mut _ as *mut std::ffi::c_void; }
+        if descriptor_indexing   { self.descriptor_indexing.p_next = next; next = &mut self.descriptor_indexing as *mut _ as *mut std::ffi::c_void; }
+        if timeline_semaphore    { self.timeline_semaphore.p_next = next; next = &mut self.timeline_semaphore as *mut _ as *mut std::ffi::c_void; }
+        if float16_int8          { self.float16_int8.p_next = next; next = &mut self.float16_int8 as *mut _ as *mut std::ffi::c_void; }
+        if storage_16bit         { self.storage_16bit.p_next = next; next = &mut self.storage_16bit as *mut _ as *mut std::ffi::c_void; }
+        self.features2.p_next = next;
+    }
+
+
+
+    /// Returns the root of the chain, e.g., to pass to `vkGetPhysicalDeviceFeatures2`.
+    #[inline]
+    pub(crate) fn vk_mut(&mut self) -> &mut vk::PhysicalDeviceFeatures2 { &mut self.features2 }
+
+    /// Returns the root of the chain, e.g., to pass as the `p_next` of a `VkDeviceCreateInfo`.
+    #[inline]
+    pub(crate) fn vk(&self) -> &vk::PhysicalDeviceFeatures2 { &self.features2 }
+}
+
 
 
 
@@ -1113,14 +2271,31 @@ impl QueueFamilyInfo {
     /// 
     /// # Returns
     /// The new QueueFamilyInfo struct on success, or else a QueueError::OperationNotSupported error if the given device does not support all required queue family types.
-    pub(crate) fn new(instance: &Rc<Instance>, physical_device: vk::PhysicalDevice, physical_device_index: usize, physical_device_name: &str) -> Result<Self, QueueError> {
+    pub(crate) fn new(instance: &Shared<Instance>, physical_device: vk::PhysicalDevice, physical_device_index: usize, physical_device_name: &str) -> Result<Self, QueueError> {
+        // Get the queue families and select the ones to use
+        let families = unsafe { instance.get_physical_device_queue_family_properties(physical_device) };
+        match Self::select(&families) {
+            Ok(info)       => Ok(info),
+            Err(operation) => Err(QueueError::OperationUnsupported{ index: physical_device_index, name: physical_device_name.to_string(), operation }),
+        }
+    }
+
+    /// Maps the given queue families to their usage.
+    /// 
+    /// Every slot prefers the family that supports the fewest operations, so that we use as many different queue families as possible. On top of that, the compute slot prefers families that do not support graphics operations (i.e., dedicated async-compute families).
+    /// 
+    /// # Arguments
+    /// - `families`: The properties of the queue families of a physical device.
+    /// 
+    /// # Returns
+    /// The new QueueFamilyInfo struct on success, or else the operation that none of the given families supports.
+    fn select(families: &[vk::QueueFamilyProperties]) -> Result<Self, vk::QueueFlags> {
         // Prepare placeholders for the different queues
-        let mut graphics : Option<(u32, usize)> = None;
-        let mut memory   : Option<(u32, usize)> = None;
-        let mut compute  : Option<(u32, usize)> = None;
+        let mut graphics : Option<(u32, usize)>       = None;
+        let mut memory   : Option<(u32, usize)>       = None;
+        let mut compute  : Option<(u32, bool, usize)> = None;
 
         // Iterate over the queue families
-        let families = unsafe { instance.get_physical_device_queue_family_properties(physical_device) };
         for (i, family) in families.iter().enumerate() {
             // We need at least one queue in each family, obviously
             if family.queue_count == 0 { continue; }
@@ -1138,23 +2313,27 @@ impl QueueFamilyInfo {
             if supports_memory && (memory.is_none() || n_operations < memory.as_ref().unwrap().1) {
                 memory = Some((i as u32, n_operations));
             }
-            if supports_compute && (compute.is_none() || n_operations < compute.as_ref().unwrap().1) {
-                compute = Some((i as u32, n_operations));
+            // For compute, a family without graphics always beats one with graphics
+            if supports_compute && match compute {
+                Some((_, dedicated, n)) => (!supports_graphics && !dedicated) || (supports_graphics != dedicated && n_operations < n),
+                None                    => true,
+            } {
+                compute = Some((i as u32, !supports_graphics, n_operations));
             }
         }
 
         // If we didn't find one of the queues, error
         let graphics = match graphics {
             Some(graphics) => graphics.0,
-            None           => { return Err(QueueError::OperationUnsupported{ index: physical_device_index, name: physical_device_name.to_string(), operation: vk::QueueFlags::GRAPHICS }); }
+            None           => { return Err(vk::QueueFlags::GRAPHICS); }
         };
         let memory = match memory {
             Some(memory) => memory.0,
-            None         => { return Err(QueueError::OperationUnsupported{ index: physical_device_index, name: physical_device_name.to_string(), operation: vk::QueueFlags::TRANSFER }); }
+            None         => { return Err(vk::QueueFlags::TRANSFER); }
         };
         let compute = match compute {
             Some(compute) => compute.0,
-            None          => { return Err(QueueError::OperationUnsupported{ index: physical_device_index, name: physical_device_name.to_string(), operation: vk::QueueFlags::COMPUTE }); }
+            None          => { return Err(vk::QueueFlags::COMPUTE); }
         };
 
         // Otherwise, we can populate ourselves!
@@ -1261,16 +2440,71 @@ impl<'a> Iterator for QueueFamilyInfoUniqueIterator<'a> {
 
 
 
-/***** SURFACES *****/
-/// Collects information about the SwapchainSupport for this device.
-#[derive(Clone, Debug)]
-pub struct SwapchainSupport {
-    /// Lists the capabilities of the chosen device/surface combo.
-    pub capabilities  : vk::SurfaceCapabilitiesKHR,
-    /// Lists the formats supported by the chosen device/surface combo.
-    pub formats       : Vec<vk::SurfaceFormatKHR>,
-    /// Lists the present modes supported by the chosen device/surface combo.
-    pub present_modes : Vec<vk::PresentModeKHR>,
+/***** SURFACES *****/
+/// Collects information about the SwapchainSupport for this device.
+#[derive(Clone, Debug)]
+pub struct SwapchainSupport {
+    /// Lists the capabilities of the chosen device/surface combo.
+    pub capabilities  : vk::SurfaceCapabilitiesKHR,
+    /// Lists the formats supported by the chosen device/surface combo.
+    pub formats       : Vec<vk::SurfaceFormatKHR>,
+    /// Lists the present modes supported by the chosen device/surface combo.
+    pub present_modes : Vec<vk::PresentModeKHR>,
+}
+
+
+
+/// Defines a format in which a Swapchain may present its images, i.e., a combination of an image format and a colour space.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SurfaceFormat {
+    /// The format of the Swapchain images.
+    pub format       : ImageFormat,
+    /// The colour space in which the images are presented.
+    pub colour_space : ColourSpace,
+}
+
+impl SurfaceFormat {
+    /// Constructor for the SurfaceFormat.
+    /// 
+    /// # Arguments
+    /// - `format`: The format of the Swapchain images.
+    /// - `colour_space`: The colour space in which the images are presented.
+    #[inline]
+    pub fn new(format: ImageFormat, colour_space: ColourSpace) -> Self {
+        Self {
+            format,
+            colour_space,
+        }
+    }
+}
+
+impl Display for SurfaceFormat {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write!(f, "{} in {}", self.format, self.colour_space)
+    }
+}
+
+impl TryFrom<vk::SurfaceFormatKHR> for SurfaceFormat {
+    type Error = ColourSpaceError;
+
+    #[inline]
+    fn try_from(value: vk::SurfaceFormatKHR) -> Result<Self, Self::Error> {
+        Ok(Self {
+            format       : value.format.into(),
+            colour_space : value.color_space.try_into()?,
+        })
+    }
+}
+
+impl From<SurfaceFormat> for vk::SurfaceFormatKHR {
+    #[inline]
+    fn from(value: SurfaceFormat) -> Self {
+        Self {
+            format      : value.format.into(),
+            color_space : value.colour_space.into(),
+        }
+    }
 }
 
 
@@ -1336,7 +2570,66 @@ impl From<&DescriptorBinding> for vk::DescriptorSetLayoutBinding {
 
 
 
+/***** PIPELINE LAYOUTS *****/
+/// Defines a single range of push constants for the PipelineLayout.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PushConstantRange {
+    /// The shader stage(s) that may access this range.
+    pub stage  : ShaderStageFlags,
+    /// The start of the range (in bytes).
+    pub offset : u32,
+    /// The size of the range (in bytes).
+    pub size   : u32,
+}
+
+impl From<vk::PushConstantRange> for PushConstantRange {
+    #[inline]
+    fn from(value: vk::PushConstantRange) -> Self {
+        // Use the reference one instead
+        Self::from(&value)
+    }
+}
+
+impl From<&vk::PushConstantRange> for PushConstantRange {
+    #[inline]
+    fn from(value: &vk::PushConstantRange) -> Self {
+        Self {
+            stage  : value.stage_flags.into(),
+            offset : value.offset,
+            size   : value.size,
+        }
+    }
+}
+
+impl From<PushConstantRange> for vk::PushConstantRange {
+    #[inline]
+    fn from(value: PushConstantRange) -> Self {
+        // Use the reference one instead
+        Self::from(&value)
+    }
+}
+
+impl From<&PushConstantRange> for vk::PushConstantRange {
+    #[inline]
+    fn from(value: &PushConstantRange) -> Self {
+        Self {
+            stage_flags : value.stage.into(),
+            offset      : value.offset,
+            size        : value.size,
+        }
+    }
+}
+
+
+
+
+
 /***** RENDER PASSES *****/
+/// The memory referenced by a Vulkan subpass description, which has to be kept alive for as long as the description is used.
+/// 
+/// In order, this is a vector with the input attachments, one with the colour attachments, one with the resolve attachments, one with the preserve attachments (as unsigned integers) and a box with the depth stencil attachment. `R` is the Vulkan attachment reference type (i.e., `vk::AttachmentReference` or `vk::AttachmentReference2`).
+pub type SubpassDescriptionMem<R> = (Vec<R>, Vec<R>, Vec<R>, Vec<u32>, Option<Box<R>>);
+
 /// Describes a single attachment
 #[derive(Clone, Debug)]
 pub struct AttachmentDescription {
@@ -1421,6 +2714,43 @@ impl From<&AttachmentDescription> for vk::AttachmentDescription {
     }
 }
 
+impl From<AttachmentDescription> for vk::AttachmentDescription2 {
+    #[inline]
+    fn from(value: AttachmentDescription) -> Self {
+        // Use the reference edition
+        Self::from(&value)
+    }
+}
+
+impl From<&AttachmentDescription> for vk::AttachmentDescription2 {
+    #[inline]
+    fn from(value: &AttachmentDescription) -> Self {
+        // Base it on the original struct
+        let desc: vk::AttachmentDescription = value.into();
+        Self {
+            // Do the default stuff
+            s_type : vk::StructureType::ATTACHMENT_DESCRIPTION_2,
+            p_next : ptr::null(),
+            flags  : desc.flags,
+
+            // Set some image attachment properties
+            format  : desc.format,
+            samples : desc.samples,
+
+            // Define what to do when loading and storing this attachment
+            load_op  : desc.load_op,
+            store_op : desc.store_op,
+
+            // Define what to do when loading and storing the stencil part of this attachment
+            stencil_load_op  : desc.stencil_load_op,
+            stencil_store_op : desc.stencil_store_op,
+
+            initial_layout : desc.initial_layout,
+            final_layout   : desc.final_layout,
+        }
+    }
+}
+
 
 
 /// References an attachment.
@@ -1430,6 +2760,10 @@ pub struct AttachmentRef {
     pub index  : u32,
     /// The layout of the attachment at the time this reference is used (will be transitioned appropriately).
     pub layout : ImageLayout,
+    /// If given, restricts the reference to only these aspects of the attachment (e.g., to read only the depth or only the stencil aspect of an input attachment).
+    /// 
+    /// Note that setting this on any reference makes the RenderPassBuilder create the RenderPass with `VK_KHR_create_renderpass2`.
+    pub aspect : Option<ImageAspectFlags>,
 }
 
 impl From<vk::AttachmentReference> for AttachmentRef {
@@ -1446,6 +2780,7 @@ impl From<&vk::AttachmentReference> for AttachmentRef {
         Self {
             index  : value.attachment,
             layout : value.layout.into(),
+            aspect : None,
         }
     }
 }
@@ -1468,6 +2803,29 @@ impl From<&AttachmentRef> for vk::AttachmentReference {
     }
 }
 
+impl From<AttachmentRef> for vk::AttachmentReference2 {
+    #[inline]
+    fn from(value: AttachmentRef) -> Self {
+        // Simply use the reference version
+        Self::from(&value)
+    }
+}
+
+impl From<&AttachmentRef> for vk::AttachmentReference2 {
+    #[inline]
+    fn from(value: &AttachmentRef) -> Self {
+        Self {
+            // Do the default stuff
+            s_type : vk::StructureType::ATTACHMENT_REFERENCE_2,
+            p_next : ptr::null(),
+
+            attachment  : value.index,
+            layout      : value.layout.into(),
+            aspect_mask : value.aspect.map(|aspect| aspect.into()).unwrap_or(vk::ImageAspectFlags::empty()),
+        }
+    }
+}
+
 
 
 /// Describes a single subpass
@@ -1521,7 +2879,7 @@ impl From<vk::SubpassDescription> for SubpassDescription {
     }
 }
 
-impl Into<(vk::SubpassDescription, (Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<u32>, Option<Box<vk::AttachmentReference>>))> for SubpassDescription {
+impl Into<(vk::SubpassDescription, SubpassDescriptionMem<vk::AttachmentReference>)> for SubpassDescription {
     /// Converts the ColourBlendState into a VkPipelineColorBlendStateCreateInfo.
     /// 
     /// However, due to the external references made in the VkPipelineColorBlendStateCreateInfo struct, it also returns one Vec that manages the external memory referenced.
@@ -1535,7 +2893,7 @@ impl Into<(vk::SubpassDescription, (Vec<vk::AttachmentReference>, Vec<vk::Attach
     ///   - A vector with the resolve attachments (same length as the colour attachments)
     ///   - A vector with the preserve attachments (as unsigned integers)
     ///   - A box with the depth stencil attachment
-    fn into(self) -> (vk::SubpassDescription, (Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<u32>, Option<Box<vk::AttachmentReference>>)) {
+    fn into(self) -> (vk::SubpassDescription, SubpassDescriptionMem<vk::AttachmentReference>) {
         // Cast the vectors of self to the appropriate type
         let input_attaches: Vec<vk::AttachmentReference>        = self.input_attaches.iter().map(|attach_ref| attach_ref.into()).collect();
         let colour_attaches: Vec<vk::AttachmentReference>       = self.colour_attaches.iter().map(|attach_ref| attach_ref.into()).collect();
@@ -1582,6 +2940,70 @@ impl Into<(vk::SubpassDescription, (Vec<vk::AttachmentReference>, Vec<vk::Attach
     }
 }
 
+impl Into<(vk::SubpassDescription2, SubpassDescriptionMem<vk::AttachmentReference2>)> for SubpassDescription {
+    /// Converts the SubpassDescription into a VkSubpassDescription2 (as used by `VK_KHR_create_renderpass2`).
+    /// 
+    /// However, due to the external references made in the VkSubpassDescription2 struct, it also returns the memory referenced.
+    /// 
+    /// # Returns
+    /// A tuple with:
+    /// - The new VkSubpassDescription2 instance
+    /// - A tuple with the referenced memory:
+    ///   - A vector with the input attachments
+    ///   - A vector with the colour attachments
+    ///   - A vector with the resolve attachments (same length as the colour attachments)
+    ///   - A vector with the preserve attachments (as unsigned integers)
+    ///   - A box with the depth stencil attachment
+    fn into(self) -> (vk::SubpassDescription2, SubpassDescriptionMem<vk::AttachmentReference2>) {
+        // Cast the vectors of self to the appropriate type
+        let input_attaches: Vec<vk::AttachmentReference2>        = self.input_attaches.iter().map(|attach_ref| attach_ref.into()).collect();
+        let colour_attaches: Vec<vk::AttachmentReference2>       = self.colour_attaches.iter().map(|attach_ref| attach_ref.into()).collect();
+        let resolve_attaches: Vec<vk::AttachmentReference2>      = self.resolve_attaches.iter().map(|attach_ref| attach_ref.into()).collect();
+        let preserve_attaches: Vec<u32>                          = self.preserve_attaches.clone();
+        let depth_stencil: Option<Box<vk::AttachmentReference2>> = self.depth_stencil.map(|attach_ref| Box::new(attach_ref.into()));
+
+        // Create the Vulkan struct with the references
+        let result = vk::SubpassDescription2 {
+            // Do the default stuff
+            s_type    : vk::StructureType::SUBPASS_DESCRIPTION_2,
+            p_next    : ptr::null(),
+            flags     : vk::SubpassDescriptionFlags::empty(),
+            view_mask : 0,
+
+            // Set the bind point
+            pipeline_bind_point : self.bind_point.into(),
+
+            // Set the input attachments
+            input_attachment_count : input_attaches.len() as u32,
+            p_input_attachments    : vec_as_ptr!(input_attaches),
+
+            // Set the colour & associated resolve attachments
+            color_attachment_count : colour_attaches.len() as u32,
+            p_color_attachments    : vec_as_ptr!(colour_attaches),
+            p_resolve_attachments  : vec_as_ptr!(resolve_attaches),
+
+            // Set the preserve attachments
+            preserve_attachment_count : preserve_attaches.len() as u32,
+            p_preserve_attachments    : vec_as_ptr!(preserve_attaches),
+
+            // Set the depth stencil
+            p_depth_stencil_attachment : match depth_stencil.as_ref() {
+                Some(depth_stencil) => &**depth_stencil,
+                None                => ptr::null(),
+            },
+        };
+
+        // Done - return it and its memory managers
+        (result, (
+            input_attaches,
+            colour_attaches,
+            resolve_attaches,
+            preserve_attaches,
+            depth_stencil,
+        ))
+    }
+}
+
 
 
 /// Describes a dependency between two subpasses
@@ -1658,6 +3080,37 @@ impl From<&SubpassDependency> for vk::SubpassDependency {
     }
 }
 
+impl From<SubpassDependency> for vk::SubpassDependency2 {
+    #[inline]
+    fn from(value: SubpassDependency) -> Self {
+        // Use the reference version
+        Self::from(&value)
+    }
+}
+
+impl From<&SubpassDependency> for vk::SubpassDependency2 {
+    #[inline]
+    fn from(value: &SubpassDependency) -> Self {
+        Self {
+            // Do the default stuff
+            s_type      : vk::StructureType::SUBPASS_DEPENDENCY_2,
+            p_next      : ptr::null(),
+            view_offset : 0,
+
+            src_subpass : value.from,
+            dst_subpass : value.to,
+
+            src_stage_mask : value.from_stage.into(),
+            dst_stage_mask : value.to_stage.into(),
+
+            src_access_mask : value.from_access.into(),
+            dst_access_mask : value.to_access.into(),
+
+            dependency_flags : value.dependency_flags.into(),
+        }
+    }
+}
+
 
 
 
@@ -1842,6 +3295,39 @@ impl Into<(vk::PipelineVertexInputStateCreateInfo, (Vec<vk::VertexInputAttribute
 
 
 
+/// Defines how patches are formed for the tessellation stages.
+#[derive(Clone, Debug)]
+pub struct TessellationState {
+    /// The number of control points per patch (must be at least 1 and at most the `maxTessellationPatchSize` limit).
+    pub patch_control_points : u32,
+}
+
+impl From<vk::PipelineTessellationStateCreateInfo> for TessellationState {
+    #[inline]
+    fn from(value: vk::PipelineTessellationStateCreateInfo) -> Self {
+        Self {
+            patch_control_points : value.patch_control_points,
+        }
+    }
+}
+
+impl From<TessellationState> for vk::PipelineTessellationStateCreateInfo {
+    #[inline]
+    fn from(value: TessellationState) -> Self {
+        Self {
+            // Do the default stuff
+            s_type : vk::StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+            p_next : ptr::null(),
+            flags  : vk::PipelineTessellationStateCreateFlags::empty(),
+
+            // Set the patch size
+            patch_control_points : value.patch_control_points,
+        }
+    }
+}
+
+
+
 /// Defines how to construct primitives from the input vertices.
 #[derive(Clone, Debug)]
 pub struct VertexAssemblyState {
@@ -1851,6 +3337,12 @@ pub struct VertexAssemblyState {
     pub restart_primitive : bool,
 }
 
+impl VertexAssemblyState {
+    /// Returns whether this state is valid, i.e., primitive restart is only enabled for strip and fan topologies.
+    #[inline]
+    pub fn is_valid(&self) -> bool { !self.restart_primitive || self.topology.is_strip() }
+}
+
 impl From<vk::PipelineInputAssemblyStateCreateInfo> for VertexAssemblyState {
     #[inline]
     fn from(value: vk::PipelineInputAssemblyStateCreateInfo) -> Self {
@@ -1896,6 +3388,28 @@ pub struct ViewportState {
     pub depth    : Range<f32>,
 }
 
+impl ViewportState {
+    /// Constructor for a ViewportState that covers the given extent, but with the Y-axis flipped to match OpenGL's conventions (i.e., Y pointing up).
+    /// 
+    /// This is done by using a negative-height viewport whose origin lies at the bottom of the frame, so that shaders ported from OpenGL render upright. The scissor still covers the whole extent, and the depth range is set to `0.0..1.0`.
+    /// 
+    /// Note that negative-height viewports require Vulkan 1.1 or the `VK_KHR_maintenance1` device extension.
+    /// 
+    /// # Arguments
+    /// - `extent`: The Extent2D of the frame to render to (typically the Swapchain's extent).
+    /// 
+    /// # Returns
+    /// A new ViewportState with the flipped viewport.
+    #[inline]
+    pub fn from_extent_flipped(extent: &Extent2D<u32>) -> Self {
+        Self {
+            viewport : Rect2D::new(0.0, extent.h as f32, extent.w as f32, -(extent.h as f32)),
+            scissor  : Rect2D::new(0, 0, extent.w, extent.h),
+            depth    : 0.0..1.0,
+        }
+    }
+}
+
 impl From<&vk::PipelineViewportStateCreateInfo> for ViewportState {
     #[inline]
     fn from(value: &vk::PipelineViewportStateCreateInfo) -> Self {
@@ -2064,18 +3578,45 @@ impl From<RasterizerState> for vk::PipelineRasterizationStateCreateInfo {
 
 /// Defines if and how to multisample for a Pipeline
 #[derive(Clone, Debug)]
-pub struct MultisampleState {}
+pub struct MultisampleState {
+    /// The number of samples to rasterize with. Must match the number of samples of the colour and depth attachments in the pipeline's subpass.
+    pub samples           : SampleCount,
+    /// If given, shades fragments per-sample with the given minimum fraction (between 0.0 and 1.0) of samples shaded uniquely. Requires the `sample_rate_shading` DeviceFeature.
+    pub sample_shading    : Option<f32>,
+    /// Whether to generate a temporary coverage value from the alpha component of the first colour output.
+    pub alpha_to_coverage : bool,
+    /// Whether to replace the alpha component of the first colour output with one. Requires the `alpha_to_one` DeviceFeature.
+    pub alpha_to_one      : bool,
+}
+
+impl Default for MultisampleState {
+    /// Returns a MultisampleState that rasterizes with a single sample and does not do anything else.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            samples           : SampleCount::ONE,
+            sample_shading    : None,
+            alpha_to_coverage : false,
+            alpha_to_one      : false,
+        }
+    }
+}
 
 impl From<vk::PipelineMultisampleStateCreateInfo> for MultisampleState {
     #[inline]
-    fn from(_value: vk::PipelineMultisampleStateCreateInfo) -> Self {
-        Self {}
+    fn from(value: vk::PipelineMultisampleStateCreateInfo) -> Self {
+        Self {
+            samples           : value.rasterization_samples.into(),
+            sample_shading    : if value.sample_shading_enable != vk::FALSE { Some(value.min_sample_shading) } else { None },
+            alpha_to_coverage : value.alpha_to_coverage_enable != vk::FALSE,
+            alpha_to_one      : value.alpha_to_one_enable != vk::FALSE,
+        }
     }
 }
 
 impl From<MultisampleState> for vk::PipelineMultisampleStateCreateInfo {
     #[inline]
-    fn from(_value: MultisampleState) -> Self {
+    fn from(value: MultisampleState) -> Self {
         Self {
             // Set the default values
             s_type : vk::StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
@@ -2083,18 +3624,18 @@ impl From<MultisampleState> for vk::PipelineMultisampleStateCreateInfo {
             flags  : vk::PipelineMultisampleStateCreateFlags::empty(),
             
             // Set the number of samples
-            rasterization_samples : vk::SampleCountFlags::TYPE_1,
+            rasterization_samples : value.samples.into(),
 
             // Set whether to shade the samples
-            sample_shading_enable : vk::FALSE,
-            min_sample_shading    : 0.0,
+            sample_shading_enable : value.sample_shading.is_some() as vk::Bool32,
+            min_sample_shading    : value.sample_shading.unwrap_or(0.0),
 
             // Set a possible mask for the different samples
             p_sample_mask : ptr::null(),
 
             // Set some alpha properties for the samples
-            alpha_to_one_enable      : vk::FALSE,
-            alpha_to_coverage_enable : vk::FALSE,
+            alpha_to_one_enable      : value.alpha_to_one as vk::Bool32,
+            alpha_to_coverage_enable : value.alpha_to_coverage as vk::Bool32,
         }
     }
 }
@@ -2257,6 +3798,12 @@ pub struct AttachmentBlendState {
     pub write_mask : ColourComponentFlags,
 }
 
+impl AttachmentBlendState {
+    /// Returns whether this state uses dual-source blending, i.e., blending is enabled with any of the second-source BlendFactors.
+    #[inline]
+    pub fn is_dual_source(&self) -> bool { self.enable_blend && [ self.src_colour, self.dst_colour, self.src_alpha, self.dst_alpha ].iter().any(|factor| factor.is_dual_source()) }
+}
+
 impl From<vk::PipelineColorBlendAttachmentState> for AttachmentBlendState {
     #[inline]
     fn from(value: vk::PipelineColorBlendAttachmentState) -> Self {
@@ -2329,6 +3876,12 @@ pub struct ColourBlendState {
     pub blend_constants   : [f32; 4],
 }
 
+impl ColourBlendState {
+    /// Returns the number of attachments that use dual-source blending (see `AttachmentBlendState::is_dual_source()`).
+    #[inline]
+    pub fn dual_source_count(&self) -> usize { if self.enable_logic { 0 } else { self.attachment_states.iter().filter(|state| state.is_dual_source()).count() } }
+}
+
 impl From<&vk::PipelineColorBlendStateCreateInfo> for ColourBlendState {
     fn from(value: &vk::PipelineColorBlendStateCreateInfo) -> Self {
         // Collect the raw pointers in a slice
@@ -2385,25 +3938,71 @@ impl Into<(vk::PipelineColorBlendStateCreateInfo, Vec<vk::PipelineColorBlendAtta
 
 
 
+/// Describes how long the creation of a Pipeline (or one of its stages) took, as reported by `VK_EXT_pipeline_creation_feedback`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PipelineCreationFeedback {
+    /// The time (in nanoseconds) it took to create the Pipeline or stage.
+    pub duration_ns : u64,
+    /// Whether the Pipeline or stage was found in the PipelineCache without having to compile it.
+    pub cache_hit   : bool,
+}
+
+impl PipelineCreationFeedback {
+    /// Converts the given VkPipelineCreationFeedback to a PipelineCreationFeedback, if the implementation filled it in.
+    /// 
+    /// # Arguments
+    /// - `value`: The VkPipelineCreationFeedback to convert.
+    /// 
+    /// # Returns
+    /// The PipelineCreationFeedback, or `None` if the feedback is not marked as valid.
+    #[inline]
+    pub fn from_vk(value: vk::PipelineCreationFeedback) -> Option<Self> {
+        if !value.flags.contains(vk::PipelineCreationFeedbackFlags::VALID) { return None; }
+        Some(Self {
+            duration_ns : value.duration,
+            cache_hit   : value.flags.contains(vk::PipelineCreationFeedbackFlags::APPLICATION_PIPELINE_CACHE_HIT),
+        })
+    }
+}
+
+
+
+/// Collects the PipelineCreationFeedback of a whole Pipeline and of each of its shader stages.
+#[derive(Clone, Debug)]
+pub struct PipelineFeedback {
+    /// The feedback for the Pipeline as a whole, or `None` if the implementation did not provide it.
+    pub pipeline : Option<PipelineCreationFeedback>,
+    /// The feedback for each shader stage (in the order they were given to the PipelineBuilder), or `None` for stages the implementation did not provide it for.
+    pub stages   : Vec<(ShaderStage, Option<PipelineCreationFeedback>)>,
+}
+
+
+
 /***** MEMORY POOLS *****/
 /// Defines the memory requirements of a buffer or image.
 #[derive(Clone, Debug)]
 pub struct MemoryRequirements {
     /// The minimum size of the required memory block.
-    pub size  : usize,
+    pub size   : usize,
     /// The alignment (in bytes) of the start of the required memory block. Must be a multiple of two.
-    pub align : u64,
+    pub align  : u64,
     /// The device memory types that are supported by the buffer or image for this particular usage.
-    pub types : DeviceMemoryTypeFlags,
+    pub types  : DeviceMemoryTypeFlags,
+    /// Whether the memory is for a linear resource (a buffer or a linear-tiling image) or a non-linear one (an optimal-tiling image).
+    /// 
+    /// Pools use this to keep linear and non-linear resources out of each other's `bufferImageGranularity` pages.
+    pub linear : bool,
 }
 
 impl From<vk::MemoryRequirements> for MemoryRequirements {
     #[inline]
     fn from(value: vk::MemoryRequirements) -> Self {
         Self {
-            size  : value.size as usize,
-            align : value.alignment as u64,
-            types : value.memory_type_bits.into(),
+            size   : value.size as usize,
+            align  : value.alignment as u64,
+            types  : value.memory_type_bits.into(),
+            // Vulkan doesn't know what the requirements are for; resources that are not linear have to say so themselves
+            linear : true,
         }
     }
 }
@@ -2490,3 +4089,77 @@ impl From<ComponentMapping> for vk::ComponentMapping {
         }
     }
 }
+
+
+
+/// Defines the part of an image (i.e., an aspect, mip level and range of array layers) that a copy reads or writes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ImageSubresourceLayers {
+    /// The aspect(s) of the image.
+    pub aspect      : ImageAspectFlags,
+    /// The mip level of the image.
+    pub mip_level   : u32,
+    /// The first array layer of the image.
+    pub base_layer  : u32,
+    /// The number of array layers.
+    pub layer_count : u32,
+}
+
+impl ImageSubresourceLayers {
+    /// Constructor for the ImageSubresourceLayers that references the first array layer of the given mip level.
+    /// 
+    /// # Arguments
+    /// - `aspect`: The aspect(s) of the image.
+    /// - `mip_level`: The mip level of the image.
+    #[inline]
+    pub fn new(aspect: ImageAspectFlags, mip_level: u32) -> Self {
+        Self {
+            aspect,
+            mip_level,
+            base_layer  : 0,
+            layer_count : 1,
+        }
+    }
+}
+
+impl From<ImageSubresourceLayers> for vk::ImageSubresourceLayers {
+    #[inline]
+    fn from(value: ImageSubresourceLayers) -> Self {
+        Self {
+            aspect_mask      : value.aspect.into(),
+            mip_level        : value.mip_level,
+            base_array_layer : value.base_layer,
+            layer_count      : value.layer_count,
+        }
+    }
+}
+
+
+
+/// Defines a single region to copy from one image to another with `CommandBuffer::copy_image()`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageCopy {
+    /// The part of the source image to copy from.
+    pub src_subresource : ImageSubresourceLayers,
+    /// The offset (in texels) in the source image where the region starts.
+    pub src_offset      : Offset3D<i32>,
+    /// The part of the destination image to copy to.
+    pub dst_subresource : ImageSubresourceLayers,
+    /// The offset (in texels) in the destination image where the region starts.
+    pub dst_offset      : Offset3D<i32>,
+    /// The size (in texels) of the region.
+    pub extent          : Extent3D<u32>,
+}
+
+impl From<ImageCopy> for vk::ImageCopy {
+    #[inline]
+    fn from(value: ImageCopy) -> Self {
+        Self {
+            src_subresource : value.src_subresource.into(),
+            src_offset      : value.src_offset.into(),
+            dst_subresource : value.dst_subresource.into(),
+            dst_offset      : value.dst_offset.into(),
+            extent          : value.extent.into(),
+        }
+    }
+}
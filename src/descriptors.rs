@@ -12,15 +12,21 @@
 //!   Contains the definitions for a DescriptorSet and a
 // 
 
+use std::cell::{RefCell, RefMut};
 use std::ptr;
 use std::rc::Rc;
 
 use ash::vk;
 
 pub use crate::errors::DescriptorError as Error;
-use crate::log_destroy;
+use crate::{log_destroy, warn};
+use crate::auxillary::enums::{DescriptorKind, ImageLayout};
+use crate::auxillary::flags::ImageUsageFlags;
 use crate::auxillary::structs::DescriptorBinding;
 use crate::device::Device;
+use crate::image::View;
+use crate::pools::memory::Buffer;
+use crate::spec::Shared;
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -45,6 +51,56 @@ fn populate_layout_info(bindings: &[vk::DescriptorSetLayoutBinding]) -> vk::Desc
     }
 }
 
+/// Populates a new VkDescriptorPoolCreateInfo struct with the given parameters.
+/// 
+/// # Arguments
+/// - `flags`: The VkDescriptorPoolCreateFlags to create the pool with.
+/// - `sizes`: The list of VkDescriptorPoolSizes that determine how many descriptors of each type the pool can allocate.
+/// - `max_sets`: The maximum number of sets that may be allocated from the pool at once.
+/// 
+/// # Returns
+/// A new VkDescriptorPoolCreateInfo struct with the same lifetime as the given reference.
+#[inline]
+fn populate_pool_info(flags: vk::DescriptorPoolCreateFlags, sizes: &[vk::DescriptorPoolSize], max_sets: u32) -> vk::DescriptorPoolCreateInfo {
+    vk::DescriptorPoolCreateInfo {
+        // Set the default stuff
+        s_type : vk::StructureType::DESCRIPTOR_POOL_CREATE_INFO,
+        p_next : ptr::null(),
+        flags,
+
+        // Attach the sizes
+        p_pool_sizes    : sizes.as_ptr(),
+        pool_size_count : sizes.len() as u32,
+
+        // Set the maximum number of sets
+        max_sets,
+    }
+}
+
+/// Populates a new VkDescriptorSetAllocateInfo struct with the given parameters.
+/// 
+/// # Arguments
+/// - `pool`: The VkDescriptorPool to allocate the set(s) from.
+/// - `layouts`: The VkDescriptorSetLayouts of the sets to allocate (one set is allocated per layout).
+/// 
+/// # Returns
+/// A new VkDescriptorSetAllocateInfo struct with the same lifetime as the given reference.
+#[inline]
+fn populate_set_info(pool: vk::DescriptorPool, layouts: &[vk::DescriptorSetLayout]) -> vk::DescriptorSetAllocateInfo {
+    vk::DescriptorSetAllocateInfo {
+        // Set the default stuff
+        s_type : vk::StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+        p_next : ptr::null(),
+
+        // Set the pool to allocate from
+        descriptor_pool : pool,
+
+        // Attach the layouts
+        p_set_layouts        : layouts.as_ptr(),
+        descriptor_set_count : layouts.len() as u32,
+    }
+}
+
 
 
 
@@ -53,9 +109,11 @@ fn populate_layout_info(bindings: &[vk::DescriptorSetLayoutBinding]) -> vk::Desc
 /// Defines the DescriptorSetLayout, which describes one type of resource in the pipeline.
 pub struct DescriptorSetLayout {
     /// The parent device for this layout.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The VkDescriptorSetLayout itself.
-    layout : vk::DescriptorSetLayout,
+    layout   : vk::DescriptorSetLayout,
+    /// The bindings with which this layout was created.
+    bindings : Vec<DescriptorBinding>,
 }
 
 impl DescriptorSetLayout {
@@ -70,12 +128,12 @@ impl DescriptorSetLayout {
     /// 
     /// # Errors
     /// This function errors if the underlying Vulkan backend failed to create a new DescriptorSetLayout.
-    pub fn new(device: Rc<Device>, bindings: &[DescriptorBinding]) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, bindings: &[DescriptorBinding]) -> Result<Rc<Self>, Error> {
         // Cast the bindings to their Vulkan counterparts.
-        let bindings: Vec<vk::DescriptorSetLayoutBinding> = bindings.iter().map(|binding| binding.into()).collect();
+        let vk_bindings: Vec<vk::DescriptorSetLayoutBinding> = bindings.iter().map(|binding| binding.into()).collect();
 
         // Populate the create info based on the bindings.
-        let layout_info = populate_layout_info(&bindings);
+        let layout_info = populate_layout_info(&vk_bindings);
 
         // Create the layout with that
         let layout = unsafe {
@@ -89,6 +147,7 @@ impl DescriptorSetLayout {
         Ok(Rc::new(Self {
             device,
             layout,
+            bindings : bindings.to_vec(),
         }))
     }
 
@@ -96,7 +155,11 @@ impl DescriptorSetLayout {
 
     /// Returns the parent device of this DescriptorSetLayout.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns the bindings with which this DescriptorSetLayout was created.
+    #[inline]
+    pub fn bindings(&self) -> &[DescriptorBinding] { &self.bindings }
 
     /// Returns the underlying VkDescriptorSetLayout struct.
     #[inline]
@@ -112,7 +175,420 @@ impl Drop for DescriptorSetLayout {
 
 
 
+/// Defines the DescriptorPool, from which DescriptorSets are allocated.
+pub struct DescriptorPool {
+    /// The parent device for this pool.
+    device    : Shared<Device>,
+    /// The VkDescriptorPool itself.
+    pool      : vk::DescriptorPool,
+    /// Whether individual sets may be freed from this pool.
+    free_sets  : bool,
+    /// The number of times this pool has been reset, so DescriptorSets can tell if their VkDescriptorSet is still alive.
+    generation : u64,
+}
+
+impl DescriptorPool {
+    /// Constructor for the DescriptorPool.
+    /// 
+    /// # Arguments
+    /// - `device`: The parent device for this pool.
+    /// - `sizes`: The number of descriptors of each kind that this pool can allocate in total.
+    /// - `max_sets`: The maximum number of DescriptorSets that may be allocated from this pool at once.
+    /// - `free_sets`: Whether individual DescriptorSets may be freed (i.e., `VK_DESCRIPTOR_POOL_CREATE_FREE_DESCRIPTOR_SET_BIT`). If false, sets are only reclaimed when the pool is reset, which may be more efficient.
+    /// 
+    /// # Returns
+    /// A new DescriptorPool on success, already wrapped in an Rc and a RefCell.
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend failed to create a new DescriptorPool.
+    pub fn new(device: Shared<Device>, sizes: &[(DescriptorKind, u32)], max_sets: u32, free_sets: bool) -> Result<Rc<RefCell<Self>>, Error> {
+        // Cast the sizes to their Vulkan counterparts.
+        let sizes: Vec<vk::DescriptorPoolSize> = sizes.iter().map(|(kind, count)| vk::DescriptorPoolSize {
+            ty               : (*kind).into(),
+            descriptor_count : *count,
+        }).collect();
+
+        // Populate the create info
+        let pool_info = populate_pool_info(
+            if free_sets { vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET } else { vk::DescriptorPoolCreateFlags::empty() },
+            &sizes,
+            max_sets,
+        );
+
+        // Create the pool with that
+        let pool = unsafe {
+            match device.create_descriptor_pool(&pool_info, None) {
+                Ok(pool) => pool,
+                Err(err) => { return Err(Error::DescriptorPoolCreateError{ err }); }
+            }
+        };
+
+        // Return it wrapped in the struct
+        Ok(Rc::new(RefCell::new(Self {
+            device,
+            pool,
+            free_sets,
+            generation : 0,
+        })))
+    }
+
+
+
+    /// Allocates a new VkDescriptorSet from this pool.
+    /// 
+    /// # Arguments
+    /// - `layout`: The DescriptorSetLayout of the new set.
+    /// 
+    /// # Returns
+    /// The new VkDescriptorSet on success.
+    /// 
+    /// # Errors
+    /// This function errors if the pool has no more space or the underlying Vulkan backend failed otherwise.
+    pub fn allocate(&mut self, layout: &Rc<DescriptorSetLayout>) -> Result<vk::DescriptorSet, Error> {
+        // Populate the allocate info
+        let layouts: [vk::DescriptorSetLayout; 1] = [ layout.vk() ];
+        let set_info = populate_set_info(self.pool, &layouts);
+
+        // Allocate the set
+        unsafe {
+            match self.device.allocate_descriptor_sets(&set_info) {
+                Ok(sets) => Ok(sets[0]),
+                Err(err) => Err(Error::DescriptorSetAllocateError{ err }),
+            }
+        }
+    }
+
+    /// Frees the given VkDescriptorSets, returning their descriptors to this pool.
+    /// 
+    /// # Arguments
+    /// - `sets`: The VkDescriptorSets to free. They must all have been allocated from this pool.
+    /// 
+    /// # Errors
+    /// This function errors if the pool was not created with `free_sets` enabled, or if the underlying Vulkan backend failed to free the sets.
+    pub fn free(&mut self, sets: &[vk::DescriptorSet]) -> Result<(), Error> {
+        // Make sure we are allowed to do this
+        if !self.free_sets { return Err(Error::DescriptorSetFreeUnsupported); }
+
+        // Free the sets
+        unsafe {
+            match self.device.free_descriptor_sets(self.pool, sets) {
+                Ok(_)    => Ok(()),
+                Err(err) => Err(Error::DescriptorSetFreeError{ n: sets.len(), err }),
+            }
+        }
+    }
+
+    /// Resets the DescriptorPool.
+    /// 
+    /// Doing this means that _all_ of the allocated sets will become invalid. DescriptorSets that are still alive will not try to free their (now reclaimed) VkDescriptorSet anymore.
+    /// 
+    /// # Errors
+    /// Errors if the underlying Vulkan backend does.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        unsafe {
+            match self.device.reset_descriptor_pool(self.pool, vk::DescriptorPoolResetFlags::empty()) {
+                Ok(_)    => { self.generation += 1; Ok(()) },
+                Err(err) => Err(Error::DescriptorPoolResetError{ err }),
+            }
+        }
+    }
+
+
+
+    /// Returns the parent device of this DescriptorPool.
+    #[inline]
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns whether individual DescriptorSets may be freed from this pool.
+    #[inline]
+    pub fn can_free(&self) -> bool { self.free_sets }
+
+    /// Returns the number of times this DescriptorPool has been reset.
+    #[inline]
+    pub fn generation(&self) -> u64 { self.generation }
+
+    /// Returns the underlying VkDescriptorPool struct.
+    #[inline]
+    pub fn vk(&self) -> vk::DescriptorPool { self.pool }
+}
+
+impl Drop for DescriptorPool {
+    fn drop(&mut self) {
+        log_destroy!(self, DescriptorPool);
+        unsafe { self.device.destroy_descriptor_pool(self.pool, None); }
+    }
+}
+
+
+
 /// Defines the DescriptorSet, which describes one resource in the pipeline.
 pub struct DescriptorSet {
-    
+    /// The parent pool where this set was allocated from.
+    pool   : Rc<RefCell<DescriptorPool>>,
+    /// The layout of this set.
+    layout : Rc<DescriptorSetLayout>,
+    /// The VkDescriptorSet itself.
+    set        : vk::DescriptorSet,
+    /// The generation of the pool when this set was allocated. If the pool's generation differs, the pool has been reset and the set is no longer valid.
+    generation : u64,
+}
+
+impl DescriptorSet {
+    /// Constructor for the DescriptorSet, which allocates it from the given pool.
+    /// 
+    /// # Arguments
+    /// - `pool`: The DescriptorPool to allocate the set from.
+    /// - `layout`: The DescriptorSetLayout of the new set.
+    /// 
+    /// # Returns
+    /// A new DescriptorSet on success.
+    /// 
+    /// # Errors
+    /// This function errors if the pool has no more space or the underlying Vulkan backend failed otherwise.
+    pub fn new(pool: Rc<RefCell<DescriptorPool>>, layout: Rc<DescriptorSetLayout>) -> Result<Self, Error> {
+        // Allocate a new vk::DescriptorSet
+        let (set, generation): (vk::DescriptorSet, u64) = {
+            // Get a lock on the pool
+            let mut lock: RefMut<DescriptorPool> = pool.borrow_mut();
+
+            // Do the allocation
+            (lock.allocate(&layout)?, lock.generation())
+        };
+
+        // Wrap it in a struct of ourselves and done
+        Ok(Self {
+            pool,
+            layout,
+            set,
+            generation,
+        })
+    }
+
+
+
+    /// Frees this DescriptorSet, returning its descriptors to the parent pool.
+    /// 
+    /// If the pool was not created with `free_sets` enabled, the set's descriptors are only reclaimed once the pool is reset. Simply dropping the DescriptorSet is then sufficient. If the pool has been reset since this set was allocated, the set was already reclaimed and this function does nothing.
+    /// 
+    /// # Errors
+    /// This function errors if the parent pool was not created with `free_sets` enabled, or if the underlying Vulkan backend failed to free the set.
+    pub fn free(mut self) -> Result<(), Error> {
+        // Free the set in the pool (unless a reset already did so)
+        if !self.is_reset() { self.pool.borrow_mut().free(&[self.set])?; }
+
+        // Make sure the set isn't freed again when dropped
+        self.set = vk::DescriptorSet::null();
+        Ok(())
+    }
+
+
+
+    /// Writes the given View to an input attachment descriptor in this set (i.e., a binding of kind `DescriptorKind::InputAttachment`).
+    /// 
+    /// Note that the set must not be in use by the GPU while it is updated.
+    /// 
+    /// # Arguments
+    /// - `binding`: The binding index of the input attachment descriptor.
+    /// - `view`: The View of the attachment to read. Its Image must have been created with `ImageUsageFlags::INPUT_ATTACHMENT` (see `Image::new_input_attachment()`).
+    /// - `layout`: The ImageLayout the Image will be in while the descriptor is read (typically `ImageLayout::ShaderReadOnly`).
+    /// 
+    /// # Errors
+    /// This function errors if the View's Image was not created with input attachment usage.
+    pub fn write_input_attachment(&self, binding: u32, view: &Rc<View>, layout: ImageLayout) -> Result<(), Error> {
+        // Make sure the image supports being read as an input attachment
+        let usage: ImageUsageFlags = view.image().usage();
+        if !usage.check(ImageUsageFlags::INPUT_ATTACHMENT) { return Err(Error::InputAttachmentUsageMissing{ binding, usage }); }
+
+        // Populate the write
+        let image_info = vk::DescriptorImageInfo {
+            sampler      : vk::Sampler::null(),
+            image_view   : view.vk(),
+            image_layout : layout.into(),
+        };
+        let write = vk::WriteDescriptorSet {
+            // Set the default stuff
+            s_type : vk::StructureType::WRITE_DESCRIPTOR_SET,
+            p_next : ptr::null(),
+
+            // Set the descriptor to write
+            dst_set           : self.set,
+            dst_binding       : binding,
+            dst_array_element : 0,
+            descriptor_count  : 1,
+            descriptor_type   : DescriptorKind::InputAttachment.into(),
+
+            // Set the image to write to it
+            p_image_info        : &image_info,
+            p_buffer_info       : ptr::null(),
+            p_texel_buffer_view : ptr::null(),
+        };
+
+        // Write it
+        unsafe { self.layout.device().update_descriptor_sets(&[ write ], &[]); }
+        Ok(())
+    }
+
+
+
+    /// Writes (a range of) the given Buffer to a buffer descriptor in this set (i.e., a binding of kind `DescriptorKind::UniformBuffer`, `DescriptorKind::StorageBuffer` or one of their dynamic variants).
+    /// 
+    /// Note that the set must not be in use by the GPU while it is updated.
+    /// 
+    /// # Arguments
+    /// - `binding`: The binding index of the buffer descriptor.
+    /// - `kind`: The DescriptorKind of the binding.
+    /// - `buffer`: The Buffer to write.
+    /// - `offset`: The offset (in bytes, relative to the start of the Buffer) of the range to write.
+    /// - `size`: The size (in bytes) of the range to write, or `None` to write the rest of the Buffer (i.e., `VK_WHOLE_SIZE`).
+    /// 
+    /// # Errors
+    /// This function errors if the kind is not a uniform or storage buffer, or if the range is empty or out of bounds for the Buffer.
+    pub fn write_buffer(&self, binding: u32, kind: DescriptorKind, buffer: &dyn Buffer, offset: u64, size: Option<u64>) -> Result<(), Error> {
+        // Make sure this is a buffer descriptor
+        if !matches!(kind, DescriptorKind::UniformBuffer | DescriptorKind::StorageBuffer | DescriptorKind::UniformDynamicBuffer | DescriptorKind::StorageDynamicBuffer) { return Err(Error::NotABufferDescriptor{ binding, kind }); }
+
+        // Resolve the range
+        let buffer_info: vk::DescriptorBufferInfo = match buffer.vk_info(offset, size) {
+            Ok(info) => info,
+            Err(err) => { return Err(Error::BufferRangeError{ binding, err }); },
+        };
+
+        // Populate the write
+        let write = vk::WriteDescriptorSet {
+            // Set the default stuff
+            s_type : vk::StructureType::WRITE_DESCRIPTOR_SET,
+            p_next : ptr::null(),
+
+            // Set the descriptor to write
+            dst_set           : self.set,
+            dst_binding       : binding,
+            dst_array_element : 0,
+            descriptor_count  : 1,
+            descriptor_type   : kind.into(),
+
+            // Set the buffer to write to it
+            p_image_info        : ptr::null(),
+            p_buffer_info       : &buffer_info,
+            p_texel_buffer_view : ptr::null(),
+        };
+
+        // Write it
+        unsafe { self.layout.device().update_descriptor_sets(&[ write ], &[]); }
+        Ok(())
+    }
+
+
+
+    /// Returns the parent pool of this DescriptorSet.
+    #[inline]
+    pub fn pool(&self) -> &Rc<RefCell<DescriptorPool>> { &self.pool }
+
+    /// Returns the layout of this DescriptorSet.
+    #[inline]
+    pub fn layout(&self) -> &Rc<DescriptorSetLayout> { &self.layout }
+
+    /// Returns the underlying VkDescriptorSet struct.
+    #[inline]
+    pub fn vk(&self) -> vk::DescriptorSet { self.set }
+
+    /// Returns whether the parent pool has been reset since this set was allocated, in which case the set is no longer valid.
+    #[inline]
+    pub fn is_reset(&self) -> bool { self.pool.borrow().generation() != self.generation }
+}
+
+impl Drop for DescriptorSet {
+    fn drop(&mut self) {
+        log_destroy!(self, DescriptorSet);
+
+        // Only free the set if it hasn't been already (either by us or by a pool reset) and the pool supports it; otherwise, it is reclaimed when the pool is reset or destroyed
+        if self.set == vk::DescriptorSet::null() { return; }
+        let mut pool: RefMut<DescriptorPool> = self.pool.borrow_mut();
+        if pool.can_free() && pool.generation() == self.generation {
+            #[allow(unused_variables)]
+            if let Err(err) = pool.free(&[self.set]) { warn!("Could not free DescriptorSet: {}", err); }
+        }
+    }
+}
+
+
+
+/// The DescriptorSetRing owns one DescriptorSet per frame-in-flight, so that the set of the current frame can be updated while the GPU may still read the sets of the others.
+/// 
+/// The intended use is to update and bind `current()` while recording a frame, and to call `advance()` once per frame.
+pub struct DescriptorSetRing {
+    /// The DescriptorSets in this ring, one per frame-in-flight.
+    sets    : Vec<DescriptorSet>,
+    /// The index of the set of the current frame.
+    current : usize,
+}
+
+impl DescriptorSetRing {
+    /// Constructor for the DescriptorSetRing, which allocates all of its sets from the given pool.
+    /// 
+    /// # Arguments
+    /// - `pool`: The DescriptorPool to allocate the sets from.
+    /// - `layout`: The DescriptorSetLayout of the new sets.
+    /// - `frames_in_flight`: The number of sets to allocate, i.e., the number of frames that may be in flight at the same time.
+    /// 
+    /// # Returns
+    /// A new DescriptorSetRing on success, of which the first set is the current one.
+    /// 
+    /// # Errors
+    /// This function errors if the pool has no more space for all of the sets or the underlying Vulkan backend failed otherwise.
+    /// 
+    /// # Panics
+    /// This function panics if `frames_in_flight` is zero.
+    pub fn new(pool: Rc<RefCell<DescriptorPool>>, layout: Rc<DescriptorSetLayout>, frames_in_flight: usize) -> Result<Self, Error> {
+        if frames_in_flight == 0 { panic!("Cannot create a DescriptorSetRing without any frames in flight"); }
+
+        // Allocate the sets one-by-one
+        let mut sets: Vec<DescriptorSet> = Vec::with_capacity(frames_in_flight);
+        for _ in 0..frames_in_flight {
+            sets.push(DescriptorSet::new(pool.clone(), layout.clone())?);
+        }
+
+        // Done
+        Ok(Self {
+            sets,
+            current : 0,
+        })
+    }
+
+
+
+    /// Moves on to the set of the next frame, wrapping around after the last one.
+    /// 
+    /// # Returns
+    /// The DescriptorSet of the new current frame.
+    #[inline]
+    pub fn advance(&mut self) -> &DescriptorSet {
+        self.current = (self.current + 1) % self.sets.len();
+        &self.sets[self.current]
+    }
+
+
+
+    /// Returns the DescriptorSet of the current frame.
+    #[inline]
+    pub fn current(&self) -> &DescriptorSet { &self.sets[self.current] }
+
+    /// Returns the index of the current frame (i.e., of its set in this ring).
+    #[inline]
+    pub fn frame(&self) -> usize { self.current }
+
+    /// Returns the DescriptorSet of the given frame, or `None` if the index is out of range.
+    #[inline]
+    pub fn get(&self, frame: usize) -> Option<&DescriptorSet> { self.sets.get(frame) }
+
+    /// Returns all the DescriptorSets in this ring, ordered by frame.
+    #[inline]
+    pub fn sets(&self) -> &[DescriptorSet] { &self.sets }
+
+    /// Returns the number of sets (i.e., frames-in-flight) in this ring.
+    #[inline]
+    pub fn len(&self) -> usize { self.sets.len() }
+
+    /// Returns whether this ring has no sets. This can never be true, but is here for completeness.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.sets.is_empty() }
 }
@@ -13,37 +13,67 @@
 //!   logical
 // 
 
-use std::ffi::{CStr, CString};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
 use std::ops::Deref;
 use std::ptr;
 use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 use ash::vk;
+use ash::extensions::{ext, khr};
 
-use crate::{debug, to_cstring};
+use crate::{debug, to_cstring, warn};
 pub use crate::errors::DeviceError as Error;
 use crate::log_destroy;
-use crate::auxillary::enums::{DeviceKind, QueueKind};
-use crate::auxillary::structs::{DeviceFeatures, DeviceInfo, PhysicalDeviceProperties, QueueFamilyInfo, SwapchainSupport};
+use crate::auxillary::enums::{DeviceExtension, DeviceKind, QueueKind};
+use crate::auxillary::flags::{BufferUsageFlags, CommandBufferFlags, CommandBufferUsageFlags};
+use crate::auxillary::structs::{DeviceFeatures, DeviceFeaturesChain, DeviceInfo, PhysicalDeviceProperties, QueueFamilyInfo, SwapchainSupport};
 use crate::instance::Instance;
+use crate::layout::{PipelineLayout, PipelineLayoutKey};
 use crate::surface::Surface;
 use crate::queue::Queues;
+use crate::pools::command::{Buffer as CommandBuffer, Pool as CommandPool};
+use crate::pools::memory::{Buffer, DeviceBuffer, HostBuffer, MappedMemory, MemoryPool, StagingBuffer, TransferBuffer};
+use crate::spec::{Shared, SharedWeak};
+
+
+/***** UNIT TESTS *****/
+#[cfg(all(test, feature = "arc"))]
+mod tests {
+    use super::*;
+
+    /// Only compiles if the given type may be shared between threads.
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_send_sync() {
+        assert_send_sync::<Instance>();
+        assert_send_sync::<Device>();
+        assert_send_sync::<Shared<Device>>();
+        assert_send_sync::<Shared<PipelineLayout>>();
+    }
+}
+
+
+
 
 
 /***** HELPER FUNCTIONS *****/
-/// Checks if the given physical device supports the given lists of device extensions, device layers and device features.
+/// Checks if the given physical device supports the given lists of device extensions and device features.
 /// 
 /// # Errors
 /// 
-/// This function returns errors if the given device does not support all of the required extensions, layers and features.
+/// This function returns errors if the given device does not support all of the required extensions and features.
 fn supports(
-    instance: &Rc<Instance>,
+    instance: &Shared<Instance>,
     physical_device: vk::PhysicalDevice,
     physical_device_index: usize,
     physical_device_name: &str,
     p_device_extensions: &[*const i8],
-    p_device_layers: &[*const i8],
-    _features: &vk::PhysicalDeviceFeatures,
+    features: &DeviceFeatures,
 ) -> Result<(), Error> {
     // Test if all of the given extensions are supported on this device
     let avail_extensions = match unsafe { instance.enumerate_device_extension_properties(physical_device) } {
@@ -68,33 +98,12 @@ fn supports(
         if !found { return Err(Error::UnsupportedDeviceExtension{ index: physical_device_index, name: physical_device_name.to_string(), extension: req_ext.to_owned() }); }
     }
 
-    // Next, test if all layers are supported
-    let avail_layers = match unsafe { instance.enumerate_device_layer_properties(physical_device) } {
-        Ok(layers) => layers,
-        Err(err)   => { return Err(Error::DeviceLayerEnumerateError{ err }); }
-    };
-    for req_lay in p_device_layers {
-        // Cast it to a CStr
-        let req_lay: &CStr = unsafe { &CStr::from_ptr(*req_lay) };
-
-        // Iterate through the available extensions
-        let mut found = false;
-        for avail_lay in &avail_layers {
-            // Make sure it's a CStr
-            let avail_lay: &CStr = unsafe { &CStr::from_ptr(avail_lay.layer_name.as_ptr()) };
-
-            // Compare them
-            if req_lay == avail_lay { found = true; break; }
-        }
-
-        // If still not found, error
-        if !found { return Err(Error::UnsupportedDeviceLayer{ index: physical_device_index, name: physical_device_name.to_string(), layer: req_lay.to_owned() }); }
+    // Next, test if features are supported (including those in the p_next-chain)
+    let missing: Vec<&'static str> = features.missing_from(&DeviceFeatures::supported_by(instance, physical_device));
+    if !missing.is_empty() {
+        return Err(Error::UnsupportedFeatures{ index: physical_device_index, name: physical_device_name.to_string(), features: missing });
     }
 
-    // Finally, test if features are supported
-    let _avail_features: vk::PhysicalDeviceFeatures = unsafe { instance.get_physical_device_features(physical_device) };
-    /* TODO */
-
     // We support it
     Ok(())
 }
@@ -127,29 +136,31 @@ fn populate_queue_info(family_index: u32, queue_priorities: &[f32]) -> vk::Devic
 
 /// Populates a DeviceCreateInfo struct.
 /// 
-/// Uses the given properties to initialize a DeviceCreateInfo struct. Some checks are done beforehand, like if all extensions / layers / features are supported on this device.
+/// Uses the given properties to initialize a DeviceCreateInfo struct. Some checks are done beforehand, like if all extensions / features are supported on this device.
+/// 
+/// The features are passed as a `p_next`-chain (rooted in a VkPhysicalDeviceFeatures2) instead of via `p_enabled_features`, so that features outside of the base VkPhysicalDeviceFeatures can be enabled too.
 /// 
 /// # Errors
 /// 
-/// Error only occur when the given device does not support all of the given extensions / layers / features.
+/// Error only occur when the given device does not support all of the given extensions / features.
 fn populate_device_info(
-    instance: &Rc<Instance>,
+    instance: &Shared<Instance>,
     physical_device: vk::PhysicalDevice,
     physical_device_index: usize,
     physical_device_name: &str,
     queue_infos: &[vk::DeviceQueueCreateInfo],
     p_device_extensions: &[*const i8],
-    p_device_layers: &[*const i8],
-    features: &vk::PhysicalDeviceFeatures,
+    features: &DeviceFeatures,
+    features_chain: &DeviceFeaturesChain,
 ) -> Result<vk::DeviceCreateInfo, Error> {
     // Make sure that the physical device supports everything
-    supports(instance, physical_device, physical_device_index, physical_device_name, p_device_extensions, p_device_layers, features)?;
+    supports(instance, physical_device, physical_device_index, physical_device_name, p_device_extensions, features)?;
 
     // With the checks complete, throw everything in the resulting struct
     Ok(vk::DeviceCreateInfo {
         // Do the standard stuff
         s_type : vk::StructureType::DEVICE_CREATE_INFO,
-        p_next : ptr::null(),
+        p_next : features_chain.vk() as *const vk::PhysicalDeviceFeatures2 as *const c_void,
         flags  : vk::DeviceCreateFlags::empty(),
 
         // Define the queue create infos
@@ -160,28 +171,133 @@ fn populate_device_info(
         pp_enabled_extension_names : p_device_extensions.as_ptr(),
         enabled_extension_count    : p_device_extensions.len() as u32,
 
-        // Define the layer
-        pp_enabled_layer_names : p_device_layers.as_ptr(),
-        enabled_layer_count    : p_device_layers.len() as u32,
+        // Finally, define the features (which are given in the p_next-chain instead)
+        p_enabled_features : ptr::null(),
 
-        // Finally, define the features
-        p_enabled_features : features,
+        // Device layers are deprecated (and ignored by the loader), so leave them empty
+        ..Default::default()
     })
 }
 
+/// Populates a VkDebugUtilsObjectNameInfoEXT struct.
+/// 
+/// # Arguments
+/// - `object_type`: The VkObjectType of the object to name.
+/// - `object_handle`: The raw handle of the object to name.
+/// - `name`: The name to give the object.
+#[inline]
+fn populate_object_name_info(object_type: vk::ObjectType, object_handle: u64, name: &CStr) -> vk::DebugUtilsObjectNameInfoEXT {
+    vk::DebugUtilsObjectNameInfoEXT {
+        // Do the standard stuff
+        s_type : vk::StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+        p_next : ptr::null(),
+
+        // Set the object to name
+        object_type,
+        object_handle,
+
+        // Set the name itself
+        p_object_name : name.as_ptr(),
+    }
+}
+
+
+/// Populates a VkBufferMemoryBarrier struct that covers an entire buffer.
+/// 
+/// # Arguments
+/// - `buffer`: The VkBuffer to put the barrier on.
+/// - `src_access`: The VkAccessFlags that must be available before the barrier.
+/// - `dst_access`: The VkAccessFlags that are made visible after the barrier.
+/// - `src_family`: The queue family that releases the buffer (or `vk::QUEUE_FAMILY_IGNORED` if ownership is not transferred).
+/// - `dst_family`: The queue family that acquires the buffer (or `vk::QUEUE_FAMILY_IGNORED` if ownership is not transferred).
+#[inline]
+fn populate_buffer_barrier(buffer: vk::Buffer, src_access: vk::AccessFlags, dst_access: vk::AccessFlags, src_family: u32, dst_family: u32) -> vk::BufferMemoryBarrier {
+    vk::BufferMemoryBarrier {
+        // Do the standard stuff
+        s_type : vk::StructureType::BUFFER_MEMORY_BARRIER,
+        p_next : ptr::null(),
+
+        // Set the accesses to synchronize
+        src_access_mask : src_access,
+        dst_access_mask : dst_access,
+
+        // Set the (optional) ownership transfer
+        src_queue_family_index : src_family,
+        dst_queue_family_index : dst_family,
+
+        // Set the range (i.e., everything)
+        buffer,
+        offset : 0,
+        size   : vk::WHOLE_SIZE,
+    }
+}
+
+
+
+
+
+/***** AUXILLARY STRUCTS *****/
+/// The function that a DeviceSelector uses to score a GPU.
+type ScoreFn = dyn Fn(&DeviceInfo) -> Option<u32>;
+
+/// Decides which GPU `Device::auto_select()` chooses, by scoring each of the supported GPUs.
+/// 
+/// The GPU with the highest score is chosen (or the first one found, in case of a tie). GPUs that are scored `None` are excluded entirely. For example, to only consider GPUs with a heap larger than 4 GiB:
+/// `DeviceSelector::new(|info| if info.mem_props.heaps.iter().any(|heap| heap.size > 4 * 1024 * 1024 * 1024) { Some(info.kind.score()) } else { None })`
+pub struct DeviceSelector {
+    /// The function that scores a GPU, or returns None to exclude it.
+    score : Box<ScoreFn>,
+}
+
+impl DeviceSelector {
+    /// Constructor for the DeviceSelector.
+    /// 
+    /// # Arguments
+    /// - `score`: The function that scores a GPU. A higher score is preferred, and `None` excludes the GPU entirely.
+    #[inline]
+    pub fn new(score: impl 'static + Fn(&DeviceInfo) -> Option<u32>) -> Self {
+        Self {
+            score : Box::new(score),
+        }
+    }
+
+
+
+    /// Scores the given GPU.
+    /// 
+    /// # Arguments
+    /// - `info`: The DeviceInfo describing the GPU to score.
+    /// 
+    /// # Returns
+    /// The score of the GPU, or None if it should not be chosen.
+    #[inline]
+    pub fn score(&self, info: &DeviceInfo) -> Option<u32> { (self.score)(info) }
+}
+
+impl Default for DeviceSelector {
+    /// Prefers GPUs by their `DeviceKind::score()`, i.e., discrete over integrated over virtual over CPU GPUs.
+    #[inline]
+    fn default() -> Self {
+        Self::new(|info| Some(info.kind.score()))
+    }
+}
+
 
 
 
 
 /***** LIBRARY *****/
 /// The Device struct provides logic to work with both Vulkan's PhysicalDevices and Devices.
+/// 
+/// # Thread safety
+/// By default, the Device is shared through `Rc`s, and is thus neither `Send` nor `Sync`; all of its resources live on the thread that created it. With the `arc` feature enabled, `Shared` becomes an `Arc` and the Device itself is `Send + Sync`, so it can be handed to worker threads. Its Queues are then guarded by internal locks; other resources (like CommandPools) are still externally synchronized. See `CommandPool` for what that means for multithreaded recording.
 pub struct Device {
     /// The instance used to initialize the device
-    instance        : Rc<Instance>,
+    instance        : Shared<Instance>,
     /// The PhysicalDevice around which we wrap.
     physical_device : vk::PhysicalDevice,
     /// The logical Device around which we wrap.
-    device          : Rc<ash::Device>,
+    device          : Shared<ash::Device>,
     /// The queues for the internal device.
     queues          : Queues,
 
@@ -195,6 +311,25 @@ pub struct Device {
     // kind     : DeviceKind,
     /// The QueueFamilyInfo that describes the queue families for this device.
     families : QueueFamilyInfo,
+
+    /// The (known) extensions that are enabled on this device.
+    extensions : Vec<DeviceExtension>,
+    /// The features that are enabled on this device.
+    features   : DeviceFeatures,
+
+    /// The loader for the `VK_EXT_mesh_shader` functions, if that extension is enabled.
+    mesh_shader         : Option<ext::MeshShader>,
+    /// The loader for the `VK_KHR_dynamic_rendering` functions, if that extension is enabled.
+    dynamic_rendering   : Option<khr::DynamicRendering>,
+    /// The loader for the `VK_KHR_timeline_semaphore` functions, if that extension is enabled.
+    timeline_semaphore  : Option<khr::TimelineSemaphore>,
+    /// The loader for the `VK_KHR_create_renderpass2` functions, if that extension is enabled.
+    create_render_pass2 : Option<khr::CreateRenderPass2>,
+    /// Whether we own the VkDevice (and should thus destroy it when dropped).
+    owned               : bool,
+
+    /// Caches the PipelineLayouts created with `PipelineLayout::get_or_create()`. These are weak references to avoid cyclic references to the Device.
+    layouts : Mutex<HashMap<PipelineLayoutKey, SharedWeak<PipelineLayout>>>,
 }
 
 impl Device {
@@ -206,12 +341,11 @@ impl Device {
     /// - `instance`: An Rc of the global instance that we may use to initialize the device.
     /// - `physical_device_index`: The index of the physical device we want to wrap around. Can be obtained by using Device::auto_select().
     /// - `device_extensions`: A slice of Device extensions to enable on the Device.
-    /// - `device_layers`: A slice of Device layers to enable on the Device.
     /// - `device_features`: A DeviceFeatures struct that describes the features to enable on the Device.
     /// 
     /// # Returns
     /// Returns a new Device instance on success, or else an Error describing what went wrong if the Device creation failed.
-    pub fn new(instance: Rc<Instance>, physical_device_index: usize, device_extensions: &[&str], device_layers: &[&str], device_features: &DeviceFeatures) -> Result<Rc<Self>, Error> {
+    pub fn new(instance: Shared<Instance>, physical_device_index: usize, device_extensions: &[&str], device_features: &DeviceFeatures) -> Result<Shared<Self>, Error> {
         // We enumerate through all the physical devices to find the appropriate one
         let physical_devices = match unsafe { instance.enumerate_physical_devices() } {
             Ok(devices) => devices,
@@ -267,7 +401,16 @@ impl Device {
 
 
 
-        // Map the given device extensions and layers to pointers
+        // Remember which of the given extensions we know about
+        let enabled_extensions: Vec<DeviceExtension> = device_extensions.iter().filter_map(|extension| DeviceExtension::from_str(extension).ok()).collect();
+        #[cfg(target_os = "macos")]
+        let enabled_extensions = {
+            let mut enabled_extensions = enabled_extensions;
+            enabled_extensions.push(DeviceExtension::PortabilitySubset);
+            enabled_extensions
+        };
+
+        // Map the given device extensions to pointers
         let device_extensions: Vec<CString> = device_extensions.iter().map(|extension| to_cstring!(extension)).collect();
         #[cfg(target_os = "macos")]
         let device_extensions = {
@@ -275,15 +418,13 @@ impl Device {
             device_extensions.push(DeviceExtension::PortabilitySubset.into());
             device_extensions
         };
-        let device_layers: Vec<CString>     = device_layers.iter().map(|layer| to_cstring!(layer)).collect();
         let p_device_extensions: Vec<*const i8> = (0..device_extensions.len()).map(|i| device_extensions[i].as_ptr()).collect();
-        let p_device_layers: Vec<*const i8>     = (0..device_layers.len()).map(|i| device_layers[i].as_ptr()).collect();
 
 
 
         // Create the DeviceCreateInfo with all this
-        let vk_device_features: vk::PhysicalDeviceFeatures = device_features.into();
-        let device_info = populate_device_info(&instance, physical_device, physical_device_index, &device_properties.name, &queue_infos, &p_device_extensions, &p_device_layers, &vk_device_features)?;
+        let device_features_chain: Box<DeviceFeaturesChain> = DeviceFeaturesChain::enable(device_features);
+        let device_info = populate_device_info(&instance, physical_device, physical_device_index, &device_properties.name, &queue_infos, &p_device_extensions, device_features, &device_features_chain)?;
 
         // Use that to create the device
         debug!("Initializing device...");
@@ -295,13 +436,19 @@ impl Device {
         };
 
         // Get the queues
-        let device = Rc::new(device);
+        let device = Shared::new(device);
         let queues = Queues::new(&device, &family_info);
 
+        // Load the functions of any extensions that need it
+        let mesh_shader: Option<ext::MeshShader> = if enabled_extensions.contains(&DeviceExtension::MeshShader) { Some(ext::MeshShader::new(instance.vk(), &device)) } else { None };
+        let dynamic_rendering: Option<khr::DynamicRendering> = if enabled_extensions.contains(&DeviceExtension::DynamicRendering) { Some(khr::DynamicRendering::new(instance.vk(), &device)) } else { None };
+        let timeline_semaphore: Option<khr::TimelineSemaphore> = if enabled_extensions.contains(&DeviceExtension::TimelineSemaphore) { Some(khr::TimelineSemaphore::new(instance.vk(), &device)) } else { None };
+        let create_render_pass2: Option<khr::CreateRenderPass2> = if enabled_extensions.contains(&DeviceExtension::CreateRenderPass2) { Some(khr::CreateRenderPass2::new(instance.vk(), &device)) } else { None };
+
 
 
         // Done! Return the new GPU
-        Ok(Rc::new(Self {
+        Ok(Shared::new(Self {
             instance,
             physical_device,
             device,
@@ -310,6 +457,79 @@ impl Device {
             index    : physical_device_index,
             props    : device_properties,
             families : family_info,
+
+            extensions : enabled_extensions,
+            features   : device_features.clone(),
+
+            mesh_shader,
+            dynamic_rendering,
+            timeline_semaphore,
+            create_render_pass2,
+            owned : true,
+
+            layouts : Mutex::new(HashMap::new()),
+        }))
+    }
+
+    /// Constructor for the Device that wraps around an already existing VkDevice (e.g., one created by another library or an OpenXR runtime).
+    /// 
+    /// Since we do not know which extensions and features were enabled on the VkDevice, `Device::enabled_extensions()` and `Device::enabled_features()` report none for such a Device.
+    /// 
+    /// # Arguments
+    /// - `instance`: The Instance with which the VkDevice was created.
+    /// - `device`: The VkDevice to wrap.
+    /// - `physical_device`: The VkPhysicalDevice on which the VkDevice was created.
+    /// - `families`: The QueueFamilyInfo that describes which queue families to use. The VkDevice must have been created with (at least) one queue in each of these families.
+    /// - `owned`: Whether the new Device takes ownership of the VkDevice. If false, it is never destroyed by us, and must outlive the returned Device (and anything created from it).
+    /// 
+    /// # Returns
+    /// A new Device, already wrapped in a Shared pointer.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to enumerate the physical devices or the given one is not one of them.
+    /// 
+    /// # Safety
+    /// The given `device` must be a valid VkDevice that was created from the given `physical_device` and `instance`, with queues as described by `families`.
+    pub unsafe fn from_raw(instance: Shared<Instance>, device: vk::Device, physical_device: vk::PhysicalDevice, families: QueueFamilyInfo, owned: bool) -> Result<Shared<Self>, Error> {
+        // Find the index of the physical device
+        let physical_devices = match instance.enumerate_physical_devices() {
+            Ok(devices) => devices,
+            Err(err)    => { return Err(Error::PhysicalDeviceEnumerateError{ err }); }
+        };
+        let physical_device_index: usize = match physical_devices.iter().position(|pdev| *pdev == physical_device) {
+            Some(index) => index,
+            None        => { return Err(Error::PhysicalDeviceHandleNotFound{ handle: physical_device }); }
+        };
+
+        // Get the properties of this device
+        let device_properties: PhysicalDeviceProperties = instance.get_physical_device_properties(physical_device).into();
+        debug!("Wrapping existing device on physical device {} '{}' ({})", physical_device_index, &device_properties.name, &device_properties.kind);
+
+        // Load the device functions for the handle and get the queues
+        let device: Shared<ash::Device> = Shared::new(ash::Device::load(instance.vk().fp_v1_0(), device));
+        let queues = Queues::new(&device, &families);
+
+        // Done
+        Ok(Shared::new(Self {
+            instance,
+            physical_device,
+            device,
+            queues,
+
+            index    : physical_device_index,
+            props    : device_properties,
+            families,
+
+            extensions : vec![],
+            features   : DeviceFeatures::default(),
+
+            mesh_shader         : None,
+            dynamic_rendering   : None,
+            timeline_semaphore  : None,
+            create_render_pass2 : None,
+            owned,
+
+            layouts : Mutex::new(HashMap::new()),
         }))
     }
 
@@ -328,34 +548,176 @@ impl Device {
             Some(QueueKind::Present)  => self.queues.present.drain().map_err(|err| Error::QueueIdleError{ err }),
             Some(QueueKind::Compute)  => self.queues.compute.drain().map_err(|err| Error::QueueIdleError{ err }),
 
-            // Otherwise, wait for the device
-            None => match unsafe { self.device.device_wait_idle() } {
+            // Otherwise, wait for the device (which requires exclusive access to all of its queues)
+            None => {
+                let _guards = self.queues.lock_all();
+                match unsafe { self.device.device_wait_idle() } {
+                    Ok(_)    => Ok(()),
+                    Err(err) => Err(Error::DeviceIdleError{ err }),
+                }
+            },
+        }
+    }
+
+    /// Creates a new CommandPool for the calling thread.
+    /// 
+    /// Since Vulkan command pools are externally synchronized, multithreaded recording should use one CommandPool per thread. Call this function on each (recording) thread, using a Device that is shared between them (which requires the `arc` feature). The CommandPool itself is not `Sync`, so it cannot be shared with other threads afterwards.
+    /// 
+    /// # Returns
+    /// A new CommandPool that lives on this Device.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create the CommandPool.
+    #[inline]
+    pub fn create_command_pool_for_thread(self: &Shared<Self>) -> Result<Rc<RefCell<CommandPool>>, Error> {
+        CommandPool::new(self.clone()).map_err(|err| Error::CommandPoolCreateError{ err })
+    }
+
+    /// Creates a new, device-local Buffer that is filled with the given data.
+    /// 
+    /// The data is uploaded through a temporary StagingBuffer. If the Device has a dedicated memory (transfer) queue family, the copy is done on that queue, after which ownership of the Buffer is transferred to the graphics queue family. Otherwise, the copy is simply done on the graphics queue. This function blocks until the upload is complete.
+    /// 
+    /// # Arguments
+    /// - `pool`: The MemoryPool where the Buffer's memory (and that of the temporary StagingBuffer) will be allocated.
+    /// - `uploader`: The CommandPool that is used to get the command buffer(s) that upload the data.
+    /// - `data`: The contents of the new Buffer.
+    /// - `usage`: The BufferUsageFlags that determine how we may use the new Buffer.
+    /// 
+    /// # Returns
+    /// A new DeviceBuffer with the given data, owned by the graphics queue family.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create either of the buffers, or to record, submit or wait for the upload.
+    pub fn upload_buffer(self: &Shared<Self>, pool: &Rc<RefCell<dyn MemoryPool>>, uploader: &Rc<RefCell<CommandPool>>, data: &[u8], usage: BufferUsageFlags) -> Result<Rc<DeviceBuffer>, Error> {
+        // Create the buffer itself
+        let buffer: Rc<DeviceBuffer> = match DeviceBuffer::new(self.clone(), pool.clone(), data.len(), usage) {
+            Ok(buffer) => buffer,
+            Err(err)   => { return Err(Error::UploadBufferError{ err }); }
+        };
+
+        // Fill a staging buffer with the data
+        let staging: Rc<StagingBuffer> = match StagingBuffer::new(self.clone(), pool.clone(), data.len()) {
+            Ok(staging) => staging,
+            Err(err)    => { return Err(Error::UploadBufferError{ err }); }
+        };
+        {
+            let mut mapped: MappedMemory = match staging.map() {
+                Ok(mapped) => mapped,
+                Err(err)   => { return Err(Error::UploadBufferError{ err }); }
+            };
+            if let Err(err) = mapped.write_slice(0, data) { return Err(Error::UploadBufferError{ err }); }
+            if let Err(err) = mapped.flush(0, Some(data.len() as u64)) { return Err(Error::UploadBufferError{ err }); }
+        }
+
+        // Decide whether we use a dedicated transfer queue (which then has to hand the buffer over to the graphics queue)
+        let (transfer, graphics): (u32, u32) = (self.families.memory, self.families.graphics);
+        let dedicated: bool = transfer != graphics;
+        let (src_family, dst_family): (u32, u32) = if dedicated { (transfer, graphics) } else { (vk::QUEUE_FAMILY_IGNORED, vk::QUEUE_FAMILY_IGNORED) };
+
+        // Record the copy (and release, if needed) on the transfer queue
+        let cmd: Rc<CommandBuffer> = match CommandBuffer::new(self.clone(), uploader.clone(), transfer, CommandBufferFlags::TRANSIENT) {
+            Ok(cmd)  => cmd,
+            Err(err) => { return Err(Error::UploadCommandError{ err }); }
+        };
+        if let Err(err) = cmd.begin(CommandBufferUsageFlags::ONE_TIME_SUBMIT) { return Err(Error::UploadCommandError{ err }); }
+        let target: Rc<dyn TransferBuffer> = buffer.clone();
+        staging.schedule_copyto_range(&cmd, &target, 0, 0, data.len());
+        unsafe {
+            self.device.cmd_pipeline_barrier(cmd.vk(), vk::PipelineStageFlags::TRANSFER, if dedicated { vk::PipelineStageFlags::BOTTOM_OF_PIPE } else { vk::PipelineStageFlags::ALL_COMMANDS }, vk::DependencyFlags::empty(), &[], &[
+                populate_buffer_barrier(buffer.vk(), vk::AccessFlags::TRANSFER_WRITE, if dedicated { vk::AccessFlags::empty() } else { vk::AccessFlags::MEMORY_READ }, src_family, dst_family),
+            ], &[]);
+        }
+        if let Err(err) = cmd.end() { return Err(Error::UploadCommandError{ err }); }
+        if let Err(err) = self.queues.memory.submit(&cmd, &[], &[], &[], &[], None) { return Err(Error::UploadSubmitError{ err }); }
+        if let Err(err) = self.queues.memory.drain() { return Err(Error::QueueIdleError{ err }); }
+
+        // If we used a dedicated queue, acquire the buffer on the graphics queue
+        if dedicated {
+            let cmd: Rc<CommandBuffer> = match CommandBuffer::new(self.clone(), uploader.clone(), graphics, CommandBufferFlags::TRANSIENT) {
+                Ok(cmd)  => cmd,
+                Err(err) => { return Err(Error::UploadCommandError{ err }); }
+            };
+            if let Err(err) = cmd.begin(CommandBufferUsageFlags::ONE_TIME_SUBMIT) { return Err(Error::UploadCommandError{ err }); }
+            unsafe {
+                self.device.cmd_pipeline_barrier(cmd.vk(), vk::PipelineStageFlags::TOP_OF_PIPE, vk::PipelineStageFlags::ALL_COMMANDS, vk::DependencyFlags::empty(), &[], &[
+                    populate_buffer_barrier(buffer.vk(), vk::AccessFlags::empty(), vk::AccessFlags::MEMORY_READ, src_family, dst_family),
+                ], &[]);
+            }
+            if let Err(err) = cmd.end() { return Err(Error::UploadCommandError{ err }); }
+            if let Err(err) = self.queues.graphics.submit(&cmd, &[], &[], &[], &[], None) { return Err(Error::UploadSubmitError{ err }); }
+            if let Err(err) = self.queues.graphics.drain() { return Err(Error::QueueIdleError{ err }); }
+        }
+
+        // Done
+        Ok(buffer)
+    }
+
+    /// Gives the given Vulkan object a name, which shows up in validation messages and debuggers such as RenderDoc.
+    /// 
+    /// This is a no-op if debugging (i.e., `VK_EXT_debug_utils`) is not enabled on the parent Instance.
+    /// 
+    /// # Arguments
+    /// - `handle`: The handle of the object to name. Should be created on this Device.
+    /// - `name`: The name to give the object.
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend failed to set the name.
+    /// 
+    /// # Panics
+    /// This function panics if `name` contains a NULL-byte.
+    pub fn set_object_name<H: vk::Handle>(&self, handle: H, name: &str) -> Result<(), Error> {
+        // Only do something if we can
+        let loader: &ext::DebugUtils = match self.instance.debug_utils() {
+            Some(loader) => loader,
+            None         => { return Ok(()); }
+        };
+
+        // Populate the name info
+        let cname: CString = to_cstring!(name);
+        let name_info = populate_object_name_info(H::TYPE, handle.as_raw(), &cname);
+
+        // Set it
+        unsafe {
+            match loader.set_debug_utils_object_name(self.device.handle(), &name_info) {
                 Ok(_)    => Ok(()),
-                Err(err) => Err(Error::DeviceIdleError{ err }),
+                Err(err) => Err(Error::ObjectNameError{ name: name.into(), err }),
             }
         }
     }
 
+    /// Gives the given Vulkan object a name if one is given, only warning if that fails.
+    /// 
+    /// This is used by constructors that accept an optional debug name, which should not fail just because the name could not be set.
+    /// 
+    /// # Arguments
+    /// - `handle`: The handle of the object to name. Should be created on this Device.
+    /// - `name`: The name to give the object, if any.
+    #[inline]
+    pub(crate) fn try_set_object_name<H: vk::Handle>(&self, handle: H, name: Option<&str>) {
+        if let Some(name) = name {
+            #[allow(unused_variables)]
+            if let Err(err) = self.set_object_name(handle, name) { warn!("{}", err); }
+        }
+    }
+
 
 
     /// Tries to automatically select the best GPU.
     /// 
-    /// Iterates through all the GPUs that can be found in the given instance, and then tries to select the most appropriate one for the Game.
+    /// Iterates through all the GPUs that can be found in the given instance, and then selects the supported one that the given DeviceSelector scores highest.
     /// 
     /// # Arguments
     /// - `instance`: The Instance object to seRch for GPUs in.
     /// - `device_extensions`: A slice of extensions that the GPU should support.
-    /// - `device_layers`: A slice of layers that the GPU should support.
     /// - `device_features`: A struct of features that the GPU should support.
+    /// - `selector`: The DeviceSelector that scores the supported GPUs. Use `DeviceSelector::default()` to prefer the most discrete GPU.
     /// 
     /// # Returns
-    /// The index of the chosen GPU if we could find one, or, either if we did not find one or we failed otherwise, an Error detailing what went wrong.
-    pub fn auto_select(instance: Rc<Instance>, device_extensions: &[&str], device_layers: &[&str], device_features: &DeviceFeatures) -> Result<usize, Error> {
-        // Map the given device extensions and layers to pointers
+    /// The index of the chosen GPU if we could find one, or, either if we did not find one (including when the selector excluded all of them) or we failed otherwise, an Error detailing what went wrong.
+    pub fn auto_select(instance: Shared<Instance>, device_extensions: &[&str], device_features: &DeviceFeatures, selector: &DeviceSelector) -> Result<usize, Error> {
+        // Map the given device extensions to pointers
         let device_extensions: Vec<CString> = device_extensions.iter().map(|extension| to_cstring!(extension)).collect();
-        let device_layers: Vec<CString>     = device_layers.iter().map(|layer| to_cstring!(layer)).collect();
         let p_device_extensions: Vec<*const i8> = (0..device_extensions.len()).map(|i| device_extensions[i].as_ptr()).collect();
-        let p_device_layers: Vec<*const i8>     = (0..device_layers.len()).map(|i| device_layers[i].as_ptr()).collect();
 
         // Iterate over all physical devices
         let physical_devices = match unsafe { instance.enumerate_physical_devices() } {
@@ -374,11 +736,22 @@ impl Device {
             };
 
             // Check if this device is supported
-            let vk_device_features: vk::PhysicalDeviceFeatures = device_features.into();
-            if supports(&instance, *physical_device, i, &device_name, &p_device_extensions, &p_device_layers, &vk_device_features).is_err() { continue; }
+            if supports(&instance, *physical_device, i, &device_name, &p_device_extensions, device_features).is_err() { continue; }
+
+            // Score it, skipping it if the selector excludes it
+            let device_mem_props: vk::PhysicalDeviceMemoryProperties = unsafe { instance.get_physical_device_memory_properties(*physical_device) };
+            let device_ranking: u32 = match selector.score(&DeviceInfo {
+                index : i,
+                name  : device_name,
+                kind  : DeviceKind::from(device_properties.device_type),
+
+                mem_props : device_mem_props.into(),
+            }) {
+                Some(ranking) => ranking,
+                None          => { continue; },
+            };
 
-            // Select it as best if the first or has a better CPU disconnectedness score
-            let device_ranking = DeviceKind::from(device_properties.device_type).score();
+            // Select it as best if the first or has a better score
             if best_device.is_none() || (device_ranking > best_device.as_ref().unwrap().1) {
                 best_device = Some((i, device_ranking));
             }
@@ -396,17 +769,14 @@ impl Device {
     /// # Arguments
     /// - `instance`: The Instance object to seRch for GPUs in.
     /// - `device_extensions`: A slice of extensions that the GPU should support to be marked as 'supported'.
-    /// - `device_layers`: A slice of layers that the GPU should support to be marked as 'supported'.
     /// - `device_features`: A struct of features that the GPU should support to be marked as 'supported'.
     /// 
     /// # Returns
     /// Two vectors of (index, name, kind) tuples describing each GPU on success (0 = supported, 1 = unsupported), or else an Error describing the failure on a failure.
-    pub fn list(instance: Rc<Instance>, device_extensions: &[&str], device_layers: &[&str], device_features: &DeviceFeatures) -> Result<(Vec<DeviceInfo>, Vec<DeviceInfo>), Error> {
-        // Map the given device extensions and layers to pointers
+    pub fn list(instance: Shared<Instance>, device_extensions: &[&str], device_features: &DeviceFeatures) -> Result<(Vec<DeviceInfo>, Vec<DeviceInfo>), Error> {
+        // Map the given device extensions to pointers
         let device_extensions: Vec<CString> = device_extensions.iter().map(|extension| to_cstring!(extension)).collect();
-        let device_layers: Vec<CString>     = device_layers.iter().map(|layer| to_cstring!(layer)).collect();
         let p_device_extensions: Vec<*const i8> = (0..device_extensions.len()).map(|i| device_extensions[i].as_ptr()).collect();
-        let p_device_layers: Vec<*const i8>     = (0..device_layers.len()).map(|i| device_layers[i].as_ptr()).collect();
 
         // Iterate over all physical devices
         let physical_devices = match unsafe { instance.enumerate_physical_devices() } {
@@ -430,8 +800,7 @@ impl Device {
             let device_mem_props: vk::PhysicalDeviceMemoryProperties = unsafe { instance.get_physical_device_memory_properties(*physical_device) };
 
             // Determine to which list to add it
-            let vk_device_features: vk::PhysicalDeviceFeatures = device_features.into();
-            if supports(&instance, *physical_device, i, &device_name, &p_device_extensions, &p_device_layers, &vk_device_features).is_ok() {
+            if supports(&instance, *physical_device, i, &device_name, &p_device_extensions, device_features).is_ok() {
                 supported_devices.push(DeviceInfo {
                     index : i,
                     name  : device_name,
@@ -522,7 +891,7 @@ impl Device {
 
     /// Returns the instance around which this Device is wrapped
     #[inline]
-    pub fn instance(&self) -> &Rc<Instance> { &self.instance }    
+    pub fn instance(&self) -> &Shared<Instance> { &self.instance }    
 
     /// Returns the internal device.
     #[inline]
@@ -553,11 +922,44 @@ impl Device {
     /// Returns information about the QueueFamilies for this device.
     #[inline]
     pub fn families(&self) -> &QueueFamilyInfo { &self.families }
+
+    /// Returns the extensions that are enabled on this device.
+    /// 
+    /// Note that this only lists extensions known to this crate (see `DeviceExtension`); any other extensions given at construction time are enabled but not listed here.
+    #[inline]
+    pub fn enabled_extensions(&self) -> &[DeviceExtension] { &self.extensions }
+
+    /// Returns the features that are enabled on this device.
+    #[inline]
+    pub fn enabled_features(&self) -> &DeviceFeatures { &self.features }
+
+    /// Returns the loader for the `VK_EXT_mesh_shader` functions, or `None` if that extension is not enabled.
+    #[inline]
+    pub fn mesh_shader(&self) -> Option<&ext::MeshShader> { self.mesh_shader.as_ref() }
+
+    /// Returns the loader for the `VK_KHR_dynamic_rendering` functions, or `None` if that extension is not enabled.
+    #[inline]
+    pub fn dynamic_rendering(&self) -> Option<&khr::DynamicRendering> { self.dynamic_rendering.as_ref() }
+
+    /// Returns the loader for the `VK_KHR_timeline_semaphore` functions, or `None` if that extension is not enabled.
+    #[inline]
+    pub fn timeline_semaphore(&self) -> Option<&khr::TimelineSemaphore> { self.timeline_semaphore.as_ref() }
+
+    /// Returns the loader for the `VK_KHR_create_renderpass2` functions, or `None` if that extension is not enabled.
+    #[inline]
+    pub fn create_render_pass2(&self) -> Option<&khr::CreateRenderPass2> { self.create_render_pass2.as_ref() }
+
+    /// Locks and returns the cache of PipelineLayouts that are shared through `PipelineLayout::get_or_create()`.
+    /// 
+    /// The cache only holds weak references, so it is still consistent if another thread panicked while holding the lock.
+    #[inline]
+    pub(crate) fn layout_cache(&self) -> MutexGuard<'_, HashMap<PipelineLayoutKey, SharedWeak<PipelineLayout>>> { self.layouts.lock().unwrap_or_else(PoisonError::into_inner) }
 }
 
 impl Drop for Device {
     fn drop(&mut self) {
-        // Destroy the internal device
+        // Destroy the internal device, if it's ours
+        if !self.owned { return; }
         log_destroy!(self, Device);
         unsafe { self.device.destroy_device(None); };
     }
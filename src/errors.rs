@@ -30,8 +30,6 @@ pub enum ExtensionError {
     UnknownInstanceLayer{ got: String },
     /// The given string value was not a valid one for the DeviceExtension.
     UnknownDeviceExtension{ got: String },
-    /// The given string value was not a valid one for the DeviceLayer.
-    UnknownDeviceLayer{ got: String },
 }
 
 impl Display for ExtensionError {
@@ -42,7 +40,6 @@ impl Display for ExtensionError {
             UnknownInstanceExtension{ got } => write!(f, "Unknown instance extension '{}'", got),
             UnknownInstanceLayer{ got }     => write!(f, "Unknown instance layer '{}'", got),
             UnknownDeviceExtension{ got }   => write!(f, "Unknown device extension '{}'", got),
-            UnknownDeviceLayer{ got }       => write!(f, "Unknown device layer '{}'", got),
         }
     }
 }
@@ -71,11 +68,33 @@ impl Error for AttributeLayoutError {}
 
 
 
+/// Defines errors relating to going back and forth between ColourSpaces and vk::ColorSpaceKHRs.
+#[derive(Clone, Debug)]
+pub enum ColourSpaceError {
+    /// Given vk::ColorSpaceKHR value is not (yet) known as a ColourSpace
+    IllegalColourSpaceValue{ value: vk::ColorSpaceKHR },
+}
+
+impl Display for ColourSpaceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use ColourSpaceError::*;
+        match self {
+            IllegalColourSpaceValue{ value } => write!(f, "Encountered vk::ColorSpaceKHR value '{}' ({:?}), which is not a known ColourSpace", value.as_raw(), value),
+        }
+    }
+}
+
+impl Error for ColourSpaceError {}
+
+
+
 /// Defines errors that occur when setting up an Instance.
 #[derive(Debug)]
 pub enum InstanceError {
     /// Could not load the Vulkan library at runtime
     LoadError{ err: ash::LoadingError },
+    /// Could not load the Vulkan library at runtime from the given path
+    LoadFromError{ path: PathBuf, err: ash::LoadingError },
     /// Could not enumerate the extension properties (possible the extensions from a certain layer)
     ExtensionEnumerateError{ layer: Option<CString>, err: ash::vk::Result },
     /// Could not enumerate the layer properties
@@ -96,6 +115,7 @@ impl Display for InstanceError {
         use InstanceError::*;
         match self {
             LoadError{ err }                      => write!(f, "Could not load the Vulkan library: {}", err),
+            LoadFromError{ path, err }            => write!(f, "Could not load the Vulkan library from '{}': {}", path.display(), err),
             ExtensionEnumerateError{ layer, err } => write!(f, "Could not enumerate extensions properties{}: {}", if let Some(layer) = layer { format!(" for layer '{:?}'", layer) } else { String::new() }, err),
             LayerEnumerateError{ err }            => write!(f, "Could not enumerate layer properties: {}", err),
             UnknownExtension{ extension }         => write!(f, "Extension '{:?}' is not found in local Vulkan installation", extension),
@@ -112,23 +132,21 @@ impl Error for InstanceError {}
 
 
 /// Defines errors that occur when setting up an Instance.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum DeviceError {
     /// Could not enumerate over the available device extensions
     DeviceExtensionEnumerateError{ err: ash::vk::Result },
     /// The given device extension was not supported by the given device
     UnsupportedDeviceExtension{ index: usize, name: String, extension: CString },
-    /// Could not enumerate over the available device layers
-    DeviceLayerEnumerateError{ err: ash::vk::Result },
-    /// The given device layer was not supported by the given device
-    UnsupportedDeviceLayer{ index: usize, name: String, layer: CString },
-    /// The given device feature was not supported by the given device
-    UnsupportedFeature{ index: usize, name: String, feature: &'static str },
+    /// The given device features were not supported by the given device
+    UnsupportedFeatures{ index: usize, name: String, features: Vec<&'static str> },
 
     /// Could not get the iterator over the physical devices
     PhysicalDeviceEnumerateError{ err: ash::vk::Result },
     /// Did not find the given physical device
     PhysicalDeviceNotFound{ index: usize },
+    /// Did not find the physical device with the given handle
+    PhysicalDeviceHandleNotFound{ handle: vk::PhysicalDevice },
     /// Could not convert the raw name of the device to a String
     PhysicalDeviceNameError{ index: usize, err: std::str::Utf8Error },
     /// Could not get the family info of the device.
@@ -140,6 +158,17 @@ pub enum DeviceError {
     QueueIdleError{ err: QueueError },
     /// Could not wait for the Device to be idle
     DeviceIdleError{ err: ash::vk::Result },
+    /// Could not set the debug name of an object
+    ObjectNameError{ name: String, err: ash::vk::Result },
+    /// Could not create a CommandPool for a (recording) thread
+    CommandPoolCreateError{ err: crate::pools::errors::CommandPoolError },
+
+    /// Could not create or fill one of the buffers involved in an upload
+    UploadBufferError{ err: crate::pools::errors::MemoryPoolError },
+    /// Could not create or record one of the command buffers involved in an upload
+    UploadCommandError{ err: crate::pools::errors::CommandPoolError },
+    /// Could not submit one of the command buffers involved in an upload
+    UploadSubmitError{ err: QueueError },
 
     /// None of the found devices support this application
     NoSupportedPhysicalDevices,
@@ -162,18 +191,23 @@ impl Display for DeviceError {
         match self {
             DeviceExtensionEnumerateError{ err }                 => write!(f, "Could not enumerate device extension properties: {}", err),
             UnsupportedDeviceExtension{ index, name, extension } => write!(f, "Physical device {} ({}) does not support extension '{:?}'; choose another device", index, name, extension),
-            DeviceLayerEnumerateError{ err }                     => write!(f, "Could not enumerate device layer properties: {}", err),
-            UnsupportedDeviceLayer{ index, name, layer }         => write!(f, "Physical device {} ({}) does not support layer '{:?}'; choose another device", index, name, layer),
-            UnsupportedFeature{ index, name, feature }           => write!(f, "Physical device {} ({}) does not support feature '{}'; choose another device", index, name, feature),
+            UnsupportedFeatures{ index, name, features }         => write!(f, "Physical device {} ({}) does not support feature(s) '{}'; choose another device", index, name, features.join("', '")),
 
-            PhysicalDeviceEnumerateError{ err }   => write!(f, "Could not enumerate physical devices: {}", err),
-            PhysicalDeviceNotFound{ index }       => write!(f, "Could not find physical device '{}'; see the list of available devices by running 'list'", index),
-            PhysicalDeviceNameError{ index, err } => write!(f, "Could not parse name of device {} as UTF-8: {}", index, err),
-            QueueFamilyError{ index, err }        => write!(f, "Could not get the queue family info of device {}: {}", index, err),
-            DeviceCreateError{ err }              => write!(f, "Could not create logical device: {}", err),
+            PhysicalDeviceEnumerateError{ err }    => write!(f, "Could not enumerate physical devices: {}", err),
+            PhysicalDeviceNotFound{ index }        => write!(f, "Could not find physical device '{}'; see the list of available devices by running 'list'", index),
+            PhysicalDeviceHandleNotFound{ handle } => write!(f, "Could not find physical device with handle {:?} in the Instance", handle),
+            PhysicalDeviceNameError{ index, err }  => write!(f, "Could not parse name of device {} as UTF-8: {}", index, err),
+            QueueFamilyError{ index, err }         => write!(f, "Could not get the queue family info of device {}: {}", index, err),
+            DeviceCreateError{ err }               => write!(f, "Could not create logical device: {}", err),
 
-            QueueIdleError{ err }  => write!(f, "Could not wait for queue to be idle: {}", err),
-            DeviceIdleError{ err } => write!(f, "Could not wait for device to be idle: {}", err),
+            QueueIdleError{ err }         => write!(f, "Could not wait for queue to be idle: {}", err),
+            DeviceIdleError{ err }        => write!(f, "Could not wait for device to be idle: {}", err),
+            ObjectNameError{ name, err }  => write!(f, "Could not set debug name of object to '{}': {}", name, err),
+            CommandPoolCreateError{ err } => write!(f, "Could not create CommandPool for thread: {}", err),
+
+            UploadBufferError{ err }  => write!(f, "Could not prepare buffer for upload: {}", err),
+            UploadCommandError{ err } => write!(f, "Could not record upload command buffer: {}", err),
+            UploadSubmitError{ err }  => write!(f, "Could not submit upload command buffer: {}", err),
 
             NoSupportedPhysicalDevices => write!(f, "No device found that supports this application"),
 
@@ -200,6 +234,10 @@ pub enum QueueError {
     FenceResetError{ err: SyncError },
     /// Could not submit the command buffer for rendering
     SubmitError{ err: ash::vk::Result },
+    /// A timeline Semaphore was given where a binary one was expected (the index counts the wait Semaphores first, then the done Semaphores)
+    UnexpectedTimelineSemaphore{ index: usize },
+    /// A binary Semaphore was given where a timeline one was expected (the index counts the timeline waits first, then the timeline signals)
+    ExpectedTimelineSemaphore{ index: usize },
 
     /// Could not wait for the queue to be idle
     IdleError{ err: ash::vk::Result },
@@ -211,8 +249,10 @@ impl Display for QueueError {
         match self {
             OperationUnsupported{ index, name, operation } => write!(f, "Physical device {} ({}) does not have queues that support '{:?}'; choose another device", index, name, operation),
 
-            FenceResetError{ err } => write!(f, "Could not reset Fence: {}", err),
-            SubmitError{ err }     => write!(f, "Could not submit command buffer: {}", err),
+            FenceResetError{ err }               => write!(f, "Could not reset Fence: {}", err),
+            SubmitError{ err }                   => write!(f, "Could not submit command buffer: {}", err),
+            UnexpectedTimelineSemaphore{ index } => write!(f, "Binary Semaphore {} is a timeline Semaphore; give it as a timeline wait or signal instead", index),
+            ExpectedTimelineSemaphore{ index }   => write!(f, "Timeline Semaphore {} is a binary Semaphore; give it as a wait or done Semaphore instead", index),
 
             IdleError{ err } => write!(f, "Could not wait for queue to become idle: {}", err),
         }
@@ -236,6 +276,9 @@ pub enum SurfaceError {
     X11SurfaceKHRCreateError{ err: ash::vk::Result },
     /// Could not create a new Wayland surface
     WaylandSurfaceCreateError{ err: ash::vk::Result },
+
+    /// The Instance does not have the surface extension enabled that we need for the window.
+    ExtensionNotEnabled{ extension: crate::auxillary::enums::InstanceExtension },
 }
 
 impl Display for SurfaceError {
@@ -247,6 +290,8 @@ impl Display for SurfaceError {
             UnsupportedWindowSystem             => write!(f, "Target window is not an X11 or Wayland window; other window systems are not supported"),
             X11SurfaceKHRCreateError{ err }     => write!(f, "Could not create new X11 SurfaceKHR: {}", err),
             WaylandSurfaceCreateError{ err }    => write!(f, "Could not create new Wayland SurfaceKHR: {}", err),
+
+            ExtensionNotEnabled{ extension } => write!(f, "Cannot create a Surface for the given window without enabling the {} extension on the Instance", extension),
         }
     }
 }
@@ -256,7 +301,7 @@ impl Error for SurfaceError {}
 
 
 /// Defines errors that occur when setting up a Surface.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum SwapchainError {
     /// The given surface was not supported at all by the given GPU.
     DeviceSurfaceSupportError{ index: usize, name: String, err: DeviceError },
@@ -266,16 +311,26 @@ pub enum SwapchainError {
     SwapchainDeduceError{ err: Box<Self> },
     /// Could not create a new swapchain
     SwapchainCreateError{ err: ash::vk::Result },
+    /// The Surface of the swapchain was lost (e.g., because its window was destroyed), so it must be re-created before a new swapchain can be made.
+    SurfaceLost,
     /// Could not get the images from the swapchain
     SwapchainImagesError{ err: ash::vk::Result },
     /// Could not create an Image around one of the swapchain's images.
     ImageError{ err: ImageError },
+    /// Could not create a render finished Semaphore for one of the swapchain's images.
+    SemaphoreCreateError{ err: SyncError },
 
+    /// The swapchain no longer matches its Surface (e.g., because its window was resized) and has to be re-created (see `Swapchain::recreate()`) before images can be acquired or presented again.
+    OutOfDate,
     /// Could not get the next available image in the swapchain
     SwapchainNextImageError{ err: ash::vk::Result },
 
     /// Could not present a given image in the swapchain.
     SwapchainPresentError{ index: u32, err: ash::vk::Result },
+    /// The Device does not have the incremental present extension enabled.
+    IncrementalPresentNotEnabled,
+    /// A shared present mode was requested, but the Device does not have the shared presentable image extension enabled.
+    SharedPresentNotEnabled{ present_mode: crate::auxillary::enums::PresentMode },
 
     /// Could not wait for the device to become idle.
     DeviceIdleError{ err: DeviceError },
@@ -289,12 +344,17 @@ impl Display for SwapchainError {
             NoFormatFound                                 => write!(f, "No suitable formats found for swapchain; try choosing another device."),
             SwapchainDeduceError{ err }                   => write!(f, "Could not deduce Swapchain properties: {}", err),
             SwapchainCreateError{ err }                   => write!(f, "Could not create Swapchain: {}", err),
+            SurfaceLost                                   => write!(f, "Could not create Swapchain: the Surface was lost"),
             SwapchainImagesError{ err }                   => write!(f, "Could not get Swapchain images: {}", err),
             ImageError{ err }                             => write!(f, "Could not create Image from swapchain image: {}", err),
+            SemaphoreCreateError{ err }                   => write!(f, "Could not create render finished Semaphore for swapchain image: {}", err),
 
+            OutOfDate                      => write!(f, "Swapchain is out-of-date with its Surface and has to be re-created"),
             SwapchainNextImageError{ err } => write!(f, "Could not get next swapchain image: {}", err),
 
-            SwapchainPresentError{ index, err } => write!(f, "Could not present swapchain image {}: {}", index, err),
+            SwapchainPresentError{ index, err }     => write!(f, "Could not present swapchain image {}: {}", index, err),
+            IncrementalPresentNotEnabled            => write!(f, "Cannot present only regions of a swapchain image without enabling the {} extension on the Device", crate::auxillary::enums::DeviceExtension::IncrementalPresent),
+            SharedPresentNotEnabled{ present_mode } => write!(f, "Cannot create a Swapchain with present mode {} without enabling the {} extension on the Device", present_mode, crate::auxillary::enums::DeviceExtension::SharedPresentableImage),
 
             DeviceIdleError{ err } => write!(f, "{}", err),
         }
@@ -318,6 +378,11 @@ pub enum ShaderError {
 
     /// Could not unpack an embedded file
     EmbeddedError,
+
+    /// A specialization constant was given more than once
+    DuplicateSpecializationConstant{ id: u32 },
+    /// A specialization constant was given a value of the wrong size
+    SpecializationSizeMismatch{ id: u32, expected: usize, got: usize },
 }
 
 impl Display for ShaderError {
@@ -330,6 +395,9 @@ impl Display for ShaderError {
             FileReadError{ path, err } => write!(f, "Could not read given SPIR-V shader file '{}': {}", path.display(), err),
 
             EmbeddedError => write!(f, "Could not load embedded shader code"),
+
+            DuplicateSpecializationConstant{ id }           => write!(f, "Specialization constant {} is given more than once", id),
+            SpecializationSizeMismatch{ id, expected, got } => write!(f, "Specialization constant {} is declared with a size of {} bytes, but given a value of {} bytes", id, expected, got),
         }
     }
 }
@@ -338,11 +406,30 @@ impl Error for ShaderError {}
 
 
 
-/// Defines errors that relate to DescriptorSets and DescriptorSetLayouts.
-#[derive(Clone, Debug)]
+/// Defines errors that relate to DescriptorSets, DescriptorSetLayouts and DescriptorPools.
+#[derive(Debug)]
 pub enum DescriptorError {
     /// Could not create a new layout
     DescriptorSetLayoutCreateError{ err: ash::vk::Result },
+
+    /// Could not create a new pool
+    DescriptorPoolCreateError{ err: ash::vk::Result },
+    /// Could not reset the pool
+    DescriptorPoolResetError{ err: ash::vk::Result },
+
+    /// Could not allocate a new set
+    DescriptorSetAllocateError{ err: ash::vk::Result },
+    /// Attempted to free individual sets from a pool that was not created to support it
+    DescriptorSetFreeUnsupported,
+    /// Could not free one or more sets
+    DescriptorSetFreeError{ n: usize, err: ash::vk::Result },
+
+    /// A View was written to an input attachment descriptor while its Image was not created with `ImageUsageFlags::INPUT_ATTACHMENT`.
+    InputAttachmentUsageMissing{ binding: u32, usage: crate::auxillary::flags::ImageUsageFlags },
+    /// A Buffer was written to a descriptor that is not a (dynamic) uniform or storage buffer.
+    NotABufferDescriptor{ binding: u32, kind: crate::auxillary::enums::DescriptorKind },
+    /// The range of a Buffer written to a descriptor is invalid (e.g., empty or out of bounds).
+    BufferRangeError{ binding: u32, err: crate::pools::errors::MemoryPoolError },
 }
 
 impl Display for DescriptorError {
@@ -350,6 +437,17 @@ impl Display for DescriptorError {
         use DescriptorError::*;
         match self {
             DescriptorSetLayoutCreateError{ err } => write!(f, "Could not create new DescriptorSetLayout: {}", err),
+
+            DescriptorPoolCreateError{ err } => write!(f, "Could not create new DescriptorPool: {}", err),
+            DescriptorPoolResetError{ err }  => write!(f, "Could not reset DescriptorPool: {}", err),
+
+            DescriptorSetAllocateError{ err }  => write!(f, "Could not allocate new DescriptorSet: {}", err),
+            DescriptorSetFreeUnsupported       => write!(f, "Cannot free individual DescriptorSets from a DescriptorPool that was not created with `free_sets` enabled"),
+            DescriptorSetFreeError{ n, err }   => write!(f, "Could not free {} DescriptorSet{}: {}", n, if *n == 1 { "" } else { "s" }, err),
+
+            InputAttachmentUsageMissing{ binding, usage } => write!(f, "Cannot write View to input attachment binding {}, since its Image was not created with input attachment usage (got {})", binding, usage),
+            NotABufferDescriptor{ binding, kind }         => write!(f, "Cannot write Buffer to binding {} of kind {:?} (expected a uniform or storage buffer)", binding, kind),
+            BufferRangeError{ binding, err }              => write!(f, "Cannot write Buffer range to binding {}: {}", binding, err),
         }
     }
 }
@@ -361,6 +459,8 @@ impl Error for DescriptorError {}
 /// Defines errors that relate to a PipelineLayout.
 #[derive(Clone, Debug)]
 pub enum PipelineLayoutError {
+    /// A push constant range ends beyond the push constant space of the device
+    PushConstantRangeTooLarge{ index: usize, offset: u32, size: u32, max: u32 },
     /// Could not create the PipelineLayout struct
     PipelineLayoutCreateError{ err: ash::vk::Result },
 }
@@ -369,7 +469,8 @@ impl Display for PipelineLayoutError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use PipelineLayoutError::*;
         match self {
-            PipelineLayoutCreateError{ err }      => write!(f, "Could not create new PipelineLayout: {}", err),
+            PushConstantRangeTooLarge{ index, offset, size, max } => write!(f, "Push constant range {} (offset {}, size {}) exceeds the maximum push constant size of {} bytes", index, offset, size, max),
+            PipelineLayoutCreateError{ err }                      => write!(f, "Could not create new PipelineLayout: {}", err),
         }
     }
 }
@@ -383,13 +484,27 @@ impl Error for PipelineLayoutError {}
 pub enum RenderPassError {
     /// Could not create a RenderPass.
     RenderPassCreateError{ err: ash::vk::Result },
+    /// The RenderPass must be created with `VK_KHR_create_renderpass2` (e.g., because an AttachmentRef specified an aspect, or because `RenderPass::new2()` was used), but it is not enabled on the Device.
+    CreateRenderPass2NotEnabled,
+    /// A SubpassDependency uses `PipelineStage::NONE`, but the Device does not have the synchronization2 feature enabled.
+    NoneStageNotEnabled{ dependency: usize },
+
+    /// The given subpass does not exist in the RenderPass.
+    UnknownSubpass{ subpass: u32, count: usize },
+    /// The given subpass references an attachment that does not exist in the RenderPass.
+    UnknownAttachment{ subpass: u32, attachment: u32, count: usize },
 }
 
 impl Display for RenderPassError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use RenderPassError::*;
         match self {
-            RenderPassCreateError{ err } => write!(f, "Could not create new RenderPass: {}", err),
+            RenderPassCreateError{ err }      => write!(f, "Could not create new RenderPass: {}", err),
+            CreateRenderPass2NotEnabled       => write!(f, "Cannot create a RenderPass that requires vkCreateRenderPass2 (e.g., for aspect-specific attachment references) without enabling the {} extension on the Device", crate::auxillary::enums::DeviceExtension::CreateRenderPass2),
+            NoneStageNotEnabled{ dependency } => write!(f, "Subpass dependency {} uses PipelineStage::NONE, which requires the synchronization2 feature to be enabled on the Device", dependency),
+
+            UnknownSubpass{ subpass, count }                => write!(f, "Subpass {} does not exist in RenderPass with {} subpasses", subpass, count),
+            UnknownAttachment{ subpass, attachment, count } => write!(f, "Subpass {} references attachment {}, which does not exist in RenderPass with {} attachments", subpass, attachment, count),
         }
     }
 }
@@ -412,8 +527,52 @@ pub enum PipelineError {
     PipelineCacheError{ err: Box<Self> },
     /// The given Shader result was not a success
     ShaderError{ err: ShaderError },
+    /// The stage of the given Shader could not be inferred from its SPIR-V code
+    UnknownShaderStage,
+    /// The given Shader has multiple entry points for the same stage, so we cannot infer which one to use
+    AmbiguousEntryPoint{ stage: crate::auxillary::flags::ShaderStage },
+    /// The given Shader does not have the given entry point for the given stage
+    UnknownEntryPoint{ stage: crate::auxillary::flags::ShaderStage, name: String },
+    /// The given entry point name could not be converted into a C-string
+    IllegalEntryPointName{ name: String },
     /// Could not create the final Pipeline struct
     PipelineCreateError{ err: ash::vk::Result },
+
+    /// The sample count of the pipeline does not match that of an attachment in the RenderPass
+    SampleCountMismatch{ pipeline: crate::auxillary::flags::SampleCount, attachment: crate::auxillary::flags::SampleCount },
+    /// Could not get the attachments of the subpass that the pipeline targets
+    SubpassError{ err: RenderPassError },
+
+    /// Primitive restart was enabled for a topology that is not a strip or fan
+    PrimitiveRestartUnsupported{ topology: crate::auxillary::enums::VertexTopology },
+
+    /// Alpha-to-one was enabled in the MultisampleState, but the Device does not have the `alphaToOne` feature enabled
+    AlphaToOneNotEnabled,
+    /// A dual-source BlendFactor was used, but the Device does not have the `dualSrcBlend` feature enabled
+    DualSrcBlendNotEnabled,
+    /// More attachments use dual-source blending than the Device supports
+    TooManyDualSrcAttachments{ got: usize, max: u32 },
+
+    /// A Task or Mesh shader was given, but the Device does not have `VK_EXT_mesh_shader` enabled
+    MeshShaderNotEnabled,
+    /// A Task shader was given without a Mesh shader
+    MeshShaderMissing,
+    /// A Mesh shader was given together with a stage that it replaces
+    MeshShaderIncompatibleStage{ stage: crate::auxillary::flags::ShaderStage },
+
+    /// A Geometry shader was given, but the Device does not have the `geometryShader` feature enabled
+    GeometryShaderNotEnabled,
+    /// A tessellation shader was given, but the Device does not have the `tessellationShader` feature enabled
+    TessellationShaderNotEnabled,
+    /// Only one of the tessellation control and evaluation shaders was given
+    TessellationShaderMissing{ stage: crate::auxillary::flags::ShaderStage },
+    /// Tessellation shaders were given, but the pipeline does not use the patch list topology
+    TessellationTopologyMismatch{ topology: crate::auxillary::enums::VertexTopology },
+    /// Tessellation shaders were given with a patch size of 0 (or none at all) or one that is larger than the Device supports
+    IllegalPatchControlPoints{ got: u32, max: u32 },
+
+    /// Creation feedback was requested, but the Device does not have `VK_EXT_pipeline_creation_feedback` enabled
+    CreationFeedbackNotEnabled,
 }
 
 impl Display for PipelineError {
@@ -426,7 +585,33 @@ impl Display for PipelineError {
 
             PipelineCacheError{ err }  => write!(f, "Given PipelineCache constructor call was a fail: {}", err),
             ShaderError{ err }         => write!(f, "Given Shader constructor call was a fail: {}", err),
+            UnknownShaderStage         => write!(f, "Could not infer the stage of the given Shader from its SPIR-V code; give it explicitly instead"),
             PipelineCreateError{ err } => write!(f, "Could not create new Pipeline: {}", err),
+
+            AmbiguousEntryPoint{ stage }     => write!(f, "Given Shader has multiple entry points for the {} stage; give the entry point explicitly instead", stage),
+            UnknownEntryPoint{ stage, name } => write!(f, "Given Shader does not have an entry point '{}' for the {} stage", name, stage),
+            IllegalEntryPointName{ name }    => write!(f, "Entry point name '{}' contains a nul-character", name),
+
+            SampleCountMismatch{ pipeline, attachment } => write!(f, "Pipeline uses {} sample(s), but the RenderPass has an attachment with {} sample(s)", pipeline, attachment),
+            SubpassError{ err }                         => write!(f, "Could not get the attachments of the Pipeline's subpass: {}", err),
+
+            PrimitiveRestartUnsupported{ topology } => write!(f, "Cannot enable primitive restart for topology {:?} (only strip and fan topologies support it)", topology),
+
+            AlphaToOneNotEnabled                  => write!(f, "Cannot enable alpha-to-one multisampling without enabling the alphaToOne feature on the Device"),
+            DualSrcBlendNotEnabled                => write!(f, "Cannot use dual-source blend factors without enabling the dualSrcBlend feature on the Device"),
+            TooManyDualSrcAttachments{ got, max } => write!(f, "{} attachments use dual-source blending, but the Device supports at most {}", got, max),
+
+            MeshShaderNotEnabled                 => write!(f, "Cannot build a Pipeline with Task or Mesh shaders without enabling the {} extension on the Device", crate::auxillary::enums::DeviceExtension::MeshShader),
+            MeshShaderMissing                    => write!(f, "Cannot build a Pipeline with a Task shader but without a Mesh shader"),
+            MeshShaderIncompatibleStage{ stage } => write!(f, "Cannot build a Pipeline with both a Mesh shader and a {} shader", stage),
+
+            GeometryShaderNotEnabled                => write!(f, "Cannot build a Pipeline with a Geometry shader without enabling the geometryShader feature on the Device"),
+            TessellationShaderNotEnabled            => write!(f, "Cannot build a Pipeline with tessellation shaders without enabling the tessellationShader feature on the Device"),
+            TessellationShaderMissing{ stage }      => write!(f, "Cannot build a Pipeline with only one tessellation shader; missing a {} shader", stage),
+            TessellationTopologyMismatch{ topology } => write!(f, "Cannot build a Pipeline with tessellation shaders for topology {:?} (only VertexTopology::PatchList is supported)", topology),
+            IllegalPatchControlPoints{ got, max }   => write!(f, "Cannot build a Pipeline with {} control points per patch (must be at least 1 and at most {}; see PipelineBuilder::tessellation_state())", got, max),
+
+            CreationFeedbackNotEnabled => write!(f, "Cannot request Pipeline creation feedback without enabling the {} extension on the Device", crate::auxillary::enums::DeviceExtension::PipelineCreationFeedback),
         }
     }
 }
@@ -436,10 +621,30 @@ impl Error for PipelineError {}
 
 
 /// Defines errors that relate to an Image.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum ImageError {
     /// Temporary placeholder error
     Temp,
+
+    /// Could not create the new VkImage.
+    ImageCreateError{ err: ash::vk::Result },
+    /// Could not allocate memory for the new VkImage.
+    MemoryAllocateError{ err: crate::pools::errors::MemoryPoolError },
+    /// Could not bind the VkImage to its allocated memory.
+    ImageBindError{ err: ash::vk::Result },
+
+    /// The data given to fill an Image with is of the wrong size.
+    DataSizeError{ got: usize, expected: usize },
+    /// Cannot fill an Image of the given combined depth/stencil format with data in one go, since a copy may only write one of its aspects.
+    CombinedAspectUpload{ format: crate::auxillary::enums::ImageFormat },
+    /// Cannot generate mips for an Image of the given format, since it does not support (linearly filtered) blits.
+    MipGenerationUnsupported{ format: crate::auxillary::enums::ImageFormat },
+    /// Could not create or fill the StagingBuffer used to upload data to an Image.
+    StagingError{ err: crate::pools::errors::MemoryPoolError },
+    /// Could not allocate or record the CommandBuffer used to upload data to an Image.
+    CommandBufferError{ err: crate::pools::errors::CommandPoolError },
+    /// Could not submit (or wait for) the upload of data to an Image.
+    SubmitError{ err: QueueError },
 }
 
 impl Display for ImageError {
@@ -447,6 +652,17 @@ impl Display for ImageError {
         use ImageError::*;
         match self {
             Temp => write!(f, "<TEMP>"),
+
+            ImageCreateError{ err }    => write!(f, "Could not create Image: {}", err),
+            MemoryAllocateError{ err } => write!(f, "Could not allocate memory for Image: {}", err),
+            ImageBindError{ err }      => write!(f, "Could not bind Image to memory: {}", err),
+
+            DataSizeError{ got, expected }     => write!(f, "Image data has size {} bytes, but the Image needs {} bytes", got, expected),
+            CombinedAspectUpload{ format }     => write!(f, "Cannot upload data to an Image of combined depth/stencil format {}; upload its aspects separately", format),
+            MipGenerationUnsupported{ format } => write!(f, "Cannot generate mips for an Image of format {}, since it does not support linearly filtered blits", format),
+            StagingError{ err }                => write!(f, "Could not prepare staging buffer for Image upload: {}", err),
+            CommandBufferError{ err }          => write!(f, "Could not record Image upload: {}", err),
+            SubmitError{ err }                 => write!(f, "Could not submit Image upload: {}", err),
         }
     }
 }
@@ -475,9 +691,65 @@ impl Error for ImageViewError {}
 
 
 
+/// Defines errors that relate to a Sampler.
+#[derive(Clone, Debug)]
+pub enum SamplerError {
+    /// Could not construct the sampler
+    SamplerCreateError{ err: ash::vk::Result },
+}
+
+impl Display for SamplerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use SamplerError::*;
+        match self {
+            SamplerCreateError{ err } => write!(f, "Could not create Sampler: {}", err),
+        }
+    }
+}
+
+impl Error for SamplerError {}
+
+
+
+/// Defines errors that relate to a RenderTarget.
+#[derive(Debug)]
+pub enum RenderTargetError {
+    /// The given format is a depth and/or stencil format, but RenderTargets only support colour formats
+    NonColourFormat{ format: crate::auxillary::enums::ImageFormat },
+    /// Could not (re)build the backing Swapchain
+    SwapchainError{ err: SwapchainError },
+    /// Could not create one of the offscreen Images
+    ImageError{ err: ImageError },
+    /// Could not create the View for one of the Images
+    ViewError{ err: ImageViewError },
+}
+
+impl Display for RenderTargetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use RenderTargetError::*;
+        match self {
+            NonColourFormat{ format } => write!(f, "Cannot create a RenderTarget with format {}, since it is not a colour format", format),
+            SwapchainError{ err }     => write!(f, "Could not rebuild Swapchain: {}", err),
+            ImageError{ err }         => write!(f, "Could not create offscreen Image: {}", err),
+            ViewError{ err }          => write!(f, "Could not create ImageView for RenderTarget: {}", err),
+        }
+    }
+}
+
+impl Error for RenderTargetError {}
+
+
+
 /// Defines errors that relate to framebuffers
 #[derive(Clone, Debug)]
 pub enum FramebufferError {
+    /// The number of attachments does not match the number of attachments in the RenderPass
+    AttachmentCountMismatch{ got: usize, expected: usize },
+    /// The format of the given attachment does not match that of the corresponding attachment in the RenderPass
+    AttachmentFormatMismatch{ index: usize, got: crate::auxillary::enums::ImageFormat, expected: crate::auxillary::enums::ImageFormat },
+    /// The sample count of the given attachment does not match that of the corresponding attachment in the RenderPass
+    AttachmentSamplesMismatch{ index: usize, got: crate::auxillary::flags::SampleCount, expected: crate::auxillary::flags::SampleCount },
+
     /// Could not create a new Framebuffer
     FramebufferCreateError{ err: ash::vk::Result },
 }
@@ -487,6 +759,10 @@ impl Display for FramebufferError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use FramebufferError::*;
         match self {
+            AttachmentCountMismatch{ got, expected }          => write!(f, "Got {} attachments, but the RenderPass has {} attachments", got, expected),
+            AttachmentFormatMismatch{ index, got, expected }  => write!(f, "Attachment {} has format {}, but the RenderPass expects format {}", index, got, expected),
+            AttachmentSamplesMismatch{ index, got, expected } => write!(f, "Attachment {} has {} sample(s), but the RenderPass expects {} sample(s)", index, got, expected),
+
             FramebufferCreateError{ err } => write!(f, "Could not create Framebuffer: {}", err),
         }
     }
@@ -504,13 +780,26 @@ pub enum SyncError {
     /// Could not create a new Fence
     FenceCreateError{ err: ash::vk::Result },
 
-    /// The given Fence has timed-out.
-    FenceTimeout{ timeout: u64 },
+    /// The Fence at the given index lives on another Device than the first Fence it is waited for with.
+    FenceDeviceMismatch{ index: usize },
     /// Could not wait for a Fence.
     FenceWaitError{ err: ash::vk::Result },
 
     /// Could not reset a Fence.
     FenceResetError{ err: ash::vk::Result },
+    /// Could not get the status of a Fence.
+    FenceStatusError{ err: ash::vk::Result },
+
+    /// The Device does not have the timeline semaphore extension enabled.
+    TimelineSemaphoreNotEnabled,
+    /// A timeline operation was performed on a binary Semaphore.
+    NotATimelineSemaphore,
+    /// Could not get the counter value of a timeline Semaphore.
+    SemaphoreValueError{ err: ash::vk::Result },
+    /// Could not wait for a timeline Semaphore.
+    SemaphoreWaitError{ err: ash::vk::Result },
+    /// Could not signal a timeline Semaphore.
+    SemaphoreSignalError{ err: ash::vk::Result },
 }
 
 impl Display for SyncError {
@@ -521,10 +810,17 @@ impl Display for SyncError {
             SemaphoreCreateError{ err } => write!(f, "Could not create Sempahore: {}", err),
             FenceCreateError{ err }     => write!(f, "Could not create Fence: {}", err),
             
-            FenceTimeout{ timeout } => write!(f, "Fence timed-out after {} milliseconds", timeout),
-            FenceWaitError{ err }   => write!(f, "Could not wait for Fence: {}", err),
+            FenceDeviceMismatch{ index } => write!(f, "Cannot wait for Fence {} together with the other Fences, since it lives on another Device", index),
+            FenceWaitError{ err }        => write!(f, "Could not wait for Fence: {}", err),
             
-            FenceResetError{ err } => write!(f, "Could not reset Fence: {}", err),
+            FenceResetError{ err }  => write!(f, "Could not reset Fence: {}", err),
+            FenceStatusError{ err } => write!(f, "Could not get Fence status: {}", err),
+
+            TimelineSemaphoreNotEnabled => write!(f, "Cannot use timeline Semaphores without enabling the {} extension on the Device", crate::auxillary::enums::DeviceExtension::TimelineSemaphore),
+            NotATimelineSemaphore       => write!(f, "Cannot perform timeline operations on a binary Semaphore"),
+            SemaphoreValueError{ err }  => write!(f, "Could not get timeline Semaphore value: {}", err),
+            SemaphoreWaitError{ err }   => write!(f, "Could not wait for timeline Semaphore: {}", err),
+            SemaphoreSignalError{ err } => write!(f, "Could not signal timeline Semaphore: {}", err),
         }
     }
 }
@@ -23,6 +23,7 @@ use crate::auxillary::structs::Extent2D;
 use crate::device::Device;
 use crate::render_pass::RenderPass;
 use crate::image;
+use crate::spec::Shared;
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -63,7 +64,7 @@ fn populate_framebuffer_info(render_pass: vk::RenderPass, attachments: &Vec<vk::
 /// The Framebuffer defines a wrapper around one or more image views to represent a single renderable target.
 pub struct Framebuffer {
     /// The device where the Framebuffer lives.
-    device      : Rc<Device>,
+    device      : Shared<Device>,
     /// The RenderPass where the Framebuffer is attached to.
     render_pass : Rc<RenderPass>,
     /// The ImageViews that live in this Framebuffer.
@@ -88,8 +89,15 @@ impl Framebuffer {
     /// A new Framebuffer instance on success.
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend does.
-    pub fn new(device: Rc<Device>, render_pass: Rc<RenderPass>, attachments: Vec<Rc<image::View>>, extent: Extent2D<u32>) -> Result<Rc<Self>, Error> {
+    /// This function errors if the number of attachments, or the format or sample count of any of them, does not match the attachments of the RenderPass, or if the underlying Vulkan backend errors.
+    pub fn new(device: Shared<Device>, render_pass: Rc<RenderPass>, attachments: Vec<Rc<image::View>>, extent: Extent2D<u32>) -> Result<Rc<Self>, Error> {
+        // Make sure the attachments are compatible with those of the RenderPass
+        if attachments.len() != render_pass.attachment_count() { return Err(Error::AttachmentCountMismatch{ got: attachments.len(), expected: render_pass.attachment_count() }); }
+        for (i, (view, (format, samples))) in attachments.iter().zip(render_pass.attachment_formats().iter().zip(render_pass.attachment_samples())).enumerate() {
+            if view.format() != *format { return Err(Error::AttachmentFormatMismatch{ index: i, got: view.format(), expected: *format }); }
+            if view.image().samples() != *samples { return Err(Error::AttachmentSamplesMismatch{ index: i, got: view.image().samples(), expected: *samples }); }
+        }
+
         // Cast the attachments to their Vulkan counterparts
         let vk_attachments: Vec<vk::ImageView> = attachments.iter().map(|att| att.vk()).collect();
 
@@ -119,7 +127,7 @@ impl Framebuffer {
 
     /// Returns the parent device.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the render pass where this Framebuffer is bound.
     #[inline]
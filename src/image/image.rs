@@ -4,7 +4,7 @@
 //  Created:
 //    18 Apr 2022, 14:34:47
 //  Last edited:
-//    06 Aug 2022, 10:50:47
+//    17 Oct 2026, 10:41:12
 //  Auto updated?
 //    Yes
 // 
@@ -12,31 +12,479 @@
 //!   Defines a wrapper around Vulkan's Image buffer.
 // 
 
+use std::cell::{RefCell, RefMut};
+use std::ptr;
 use std::rc::Rc;
 
 use ash::vk;
 
 pub use crate::errors::ImageError as Error;
+use crate::{log_destroy, warn};
+use crate::auxillary::enums::{ImageFormat, ImageLayout};
+use crate::auxillary::flags::{CommandBufferFlags, CommandBufferUsageFlags, ImageAspectFlags, ImageUsageFlags, MemoryPropertyFlags, SampleCount};
+use crate::auxillary::structs::{Extent2D, MemoryRequirements};
+use crate::device::Device;
+use crate::pools::command::{Buffer as CommandBuffer, Pool as CommandPool};
+use crate::pools::errors::MemoryPoolError;
+use crate::pools::memory::{MappedMemory, StagingBuffer};
+use crate::pools::memory::spec::{Buffer, GpuPtr, HostBuffer, MemoryPool};
+use crate::spec::Shared;
+
+
+/***** POPULATE FUNCTIONS *****/
+/// Populates the create info for a new Image (VkImageCreateInfo).
+/// 
+/// # Arguments
+/// - `format`: The VkFormat of the Image.
+/// - `extent`: The VkExtent3D that describes the size of the Image.
+/// - `mip_levels`: The number of mip levels in the Image.
+/// - `usage_flags`: The VkImageUsageFlags that determine how to use this image.
+#[inline]
+fn populate_image_info(format: vk::Format, extent: vk::Extent3D, mip_levels: u32, usage_flags: vk::ImageUsageFlags) -> vk::ImageCreateInfo {
+    vk::ImageCreateInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::IMAGE_CREATE_INFO,
+        p_next : ptr::null(),
+        flags  : vk::ImageCreateFlags::empty(),
+
+        // Set the image properties
+        image_type   : vk::ImageType::TYPE_2D,
+        format,
+        extent,
+        mip_levels,
+        array_layers : 1,
+        samples      : vk::SampleCountFlags::TYPE_1,
+        tiling       : vk::ImageTiling::OPTIMAL,
+
+        // Set the usage flags
+        usage : usage_flags,
+
+        // Set the sharing mode (always exclusive for now)
+        sharing_mode             : vk::SharingMode::EXCLUSIVE,
+        queue_family_index_count : 0,
+        p_queue_family_indices   : ptr::null(),
+
+        // Finally, set the layout we start in
+        initial_layout : vk::ImageLayout::UNDEFINED,
+    }
+}
+
+/// Populates a VkBufferImageCopy struct that copies a tightly packed buffer to the first mip level of an image.
+/// 
+/// # Arguments
+/// - `aspect`: The VkImageAspectFlags of the image to copy to. Note that this may only be a single aspect, even for combined depth/stencil images.
+/// - `extent`: The VkExtent3D that describes the size of the Image.
+#[inline]
+fn populate_buffer_image_copy(aspect: vk::ImageAspectFlags, extent: vk::Extent3D) -> vk::BufferImageCopy {
+    vk::BufferImageCopy {
+        // The buffer is tightly packed from its start
+        buffer_offset       : 0,
+        buffer_row_length   : 0,
+        buffer_image_height : 0,
+
+        // Copy to the entire first mip level
+        image_subresource : vk::ImageSubresourceLayers {
+            aspect_mask      : aspect,
+            mip_level        : 0,
+            base_array_layer : 0,
+            layer_count      : 1,
+        },
+        image_offset : vk::Offset3D{ x: 0, y: 0, z: 0 },
+        image_extent : extent,
+    }
+}
+
+/// Populates a VkImageMemoryBarrier struct for a single mip level of an image.
+/// 
+/// # Arguments
+/// - `image`: The VkImage to transition.
+/// - `aspect`: The VkImageAspectFlags of the image to transition.
+/// - `level`: The mip level to transition.
+/// - `old_layout`: The current layout of the mip level.
+/// - `new_layout`: The layout to transition the mip level to.
+/// - `src_access_mask`: The VkAccessFlags that must be finished before the transition.
+/// - `dst_access_mask`: The VkAccessFlags that wait for the transition.
+#[inline]
+fn populate_mip_barrier(image: vk::Image, aspect: vk::ImageAspectFlags, level: u32, old_layout: vk::ImageLayout, new_layout: vk::ImageLayout, src_access_mask: vk::AccessFlags, dst_access_mask: vk::AccessFlags) -> vk::ImageMemoryBarrier {
+    vk::ImageMemoryBarrier {
+        // Do the standard stuff
+        s_type : vk::StructureType::IMAGE_MEMORY_BARRIER,
+        p_next : ptr::null(),
+
+        // Set the access masks
+        src_access_mask,
+        dst_access_mask,
+
+        // Set the layouts
+        old_layout,
+        new_layout,
+
+        // We do not transfer queue ownership
+        src_queue_family_index : vk::QUEUE_FAMILY_IGNORED,
+        dst_queue_family_index : vk::QUEUE_FAMILY_IGNORED,
+
+        // Set the image and the mip level in it
+        image,
+        subresource_range : vk::ImageSubresourceRange {
+            aspect_mask      : aspect,
+            base_mip_level   : level,
+            level_count      : 1,
+            base_array_layer : 0,
+            layer_count      : 1,
+        },
+    }
+}
+
+/// Populates a VkImageBlit struct that scales one mip level of an image to the next.
+/// 
+/// # Arguments
+/// - `aspect`: The VkImageAspectFlags of the image to blit.
+/// - `level`: The mip level to blit to (which is blitted from `level - 1`).
+/// - `src_extent`: The extent of the source mip level.
+/// - `dst_extent`: The extent of the destination mip level.
+#[inline]
+fn populate_mip_blit(aspect: vk::ImageAspectFlags, level: u32, src_extent: Extent2D<u32>, dst_extent: Extent2D<u32>) -> vk::ImageBlit {
+    vk::ImageBlit {
+        // Set the source area
+        src_subresource : vk::ImageSubresourceLayers {
+            aspect_mask      : aspect,
+            mip_level        : level - 1,
+            base_array_layer : 0,
+            layer_count      : 1,
+        },
+        src_offsets : [ vk::Offset3D{ x: 0, y: 0, z: 0 }, vk::Offset3D{ x: src_extent.w as i32, y: src_extent.h as i32, z: 1 } ],
+
+        // Set the destination area
+        dst_subresource : vk::ImageSubresourceLayers {
+            aspect_mask      : aspect,
+            mip_level        : level,
+            base_array_layer : 0,
+            layer_count      : 1,
+        },
+        dst_offsets : [ vk::Offset3D{ x: 0, y: 0, z: 0 }, vk::Offset3D{ x: dst_extent.w as i32, y: dst_extent.h as i32, z: 1 } ],
+    }
+}
+
+
+
 
 
 /***** LIBRARY *****/
 /// Represents an image, which is a kind of buffer that we may render to.
 pub struct Image {
+    /// The Device where the Image lives, if we own the VkImage (i.e., it is not owned by a Swapchain).
+    device : Option<Shared<Device>>,
+    /// The MemoryPool and the pointer in it where the Image's memory is allocated, if we allocated it ourselves.
+    memory : Option<(Rc<RefCell<dyn MemoryPool>>, GpuPtr)>,
+
     /// The VkImage we wrap around.
-    image : vk::Image,
+    image      : vk::Image,
+    /// The format of the VkImage.
+    format     : ImageFormat,
+    /// The size (in pixels) of the VkImage's first mip level.
+    extent     : Extent2D<u32>,
+    /// The number of samples per pixel in the VkImage.
+    samples    : SampleCount,
+    /// The number of mip levels in the VkImage.
+    mip_levels : u32,
+    /// The ImageUsageFlags with which the VkImage was created.
+    usage      : ImageUsageFlags,
 }
 
 impl Image {
+    /// Constructor for the Image, which creates a new 2D VkImage and allocates its memory in the given pool.
+    /// 
+    /// The memory is returned to the pool when the Image is dropped.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Image will live.
+    /// - `pool`: The MemoryPool where the Image's memory will be allocated.
+    /// - `format`: The ImageFormat of the new Image.
+    /// - `extent`: The size (in pixels) of the new Image.
+    /// - `usage_flags`: The ImageUsageFlags that determine how we may use the new Image.
+    /// - `mem_props`: The MemoryPropertyFlags that the Image's memory should satisfy.
+    /// - `name`: If given, a debug name for the Image (see `Device::set_object_name()`).
+    /// 
+    /// # Returns
+    /// A new Image instance, already wrapped in an Rc.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create the VkImage or to allocate (or bind) its memory.
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, format: ImageFormat, extent: Extent2D<u32>, usage_flags: ImageUsageFlags, mem_props: MemoryPropertyFlags, name: Option<&str>) -> Result<Rc<Self>, Error> {
+        // Create the image with a single mip level
+        let image_info = populate_image_info(
+            format.into(),
+            extent.to_3d(1).into(),
+            1,
+            usage_flags.into(),
+        );
+        Self::allocate(device, pool, &image_info, format, mem_props, name)
+    }
+
+    /// Constructor for the Image, which creates a new 2D VkImage that may be rendered to in one subpass and read as an input attachment in the next (e.g., a G-buffer for deferred shading).
+    /// 
+    /// Depending on the format, the Image is created with either `ImageUsageFlags::COLOUR_ATTACHMENT` or `ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT` usage, together with `ImageUsageFlags::INPUT_ATTACHMENT`. Its memory is allocated as device local.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Image will live.
+    /// - `pool`: The MemoryPool where the Image's memory will be allocated.
+    /// - `format`: The ImageFormat of the new Image.
+    /// - `extent`: The size (in pixels) of the new Image.
+    /// - `name`: If given, a debug name for the Image (see `Device::set_object_name()`).
+    /// 
+    /// # Returns
+    /// A new Image instance, already wrapped in an Rc.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create the VkImage or to allocate (or bind) its memory.
+    pub fn new_input_attachment(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, format: ImageFormat, extent: Extent2D<u32>, name: Option<&str>) -> Result<Rc<Self>, Error> {
+        let attachment: ImageUsageFlags = if format.is_depth() || format.is_stencil() { ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT } else { ImageUsageFlags::COLOUR_ATTACHMENT };
+        Self::new(device, pool, format, extent, attachment | ImageUsageFlags::INPUT_ATTACHMENT, MemoryPropertyFlags::DEVICE_LOCAL, name)
+    }
+
+    /// Constructor for the Image, which creates a new, optimally tiled 2D VkImage that is sampled in shaders and fills it with the given data.
+    /// 
+    /// The data is uploaded through a temporary StagingBuffer, after which the Image is transitioned to the `ImageLayout::ShaderReadOnly` layout. This function blocks until the upload is complete.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Image will live.
+    /// - `pool`: The MemoryPool where the Image's memory (and that of the temporary StagingBuffer) will be allocated.
+    /// - `uploader`: The CommandPool that is used to get the command buffer that uploads the data. It is submitted to the graphics queue, since that is the queue that may blit images.
+    /// - `format`: The ImageFormat of the new Image.
+    /// - `extent`: The size (in pixels) of the new Image.
+    /// - `data`: The contents of the Image's first mip level, tightly packed (see `ImageFormat::buffer_size()`).
+    /// - `generate_mips`: If true, creates a full mip chain for the Image that is generated from the given data by repeatedly blitting with a linear filter.
+    /// 
+    /// # Returns
+    /// A new Image instance, already wrapped in an Rc.
+    /// 
+    /// # Errors
+    /// This function errors if the data is of the wrong size, if the format is a combined depth/stencil format (whose aspects have to be uploaded separately), if mips should be generated for a format that does not support linear blitting, or if we failed to create the Image, the StagingBuffer or to upload the data.
+    pub fn with_data(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, uploader: &Rc<RefCell<CommandPool>>, format: ImageFormat, extent: Extent2D<u32>, data: &[u8], generate_mips: bool) -> Result<Rc<Self>, Error> {
+        // Make sure the data matches the image
+        let expected: usize = format.buffer_size(extent.clone(), 1);
+        if data.len() != expected { return Err(Error::DataSizeError{ got: data.len(), expected }); }
+        // A buffer-to-image copy may only write a single aspect, so refuse combined depth/stencil formats
+        let aspect: ImageAspectFlags = format.default_aspect();
+        if aspect == ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL { return Err(Error::CombinedAspectUpload{ format }); }
+
+        // If we generate mips, make sure the format supports the required blits
+        let mip_levels: u32 = if generate_mips { extent.mip_level_count() } else { 1 };
+        if mip_levels > 1 {
+            let required: vk::FormatFeatureFlags = vk::FormatFeatureFlags::BLIT_SRC | vk::FormatFeatureFlags::BLIT_DST | vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR;
+            let props: vk::FormatProperties = unsafe { device.instance().vk().get_physical_device_format_properties(device.physical_device(), format.into()) };
+            if !props.optimal_tiling_features.contains(required) { return Err(Error::MipGenerationUnsupported{ format }); }
+        }
+
+        // Create the image itself
+        let usage_flags: ImageUsageFlags = if mip_levels > 1 {
+            ImageUsageFlags::SAMPLED | ImageUsageFlags::TRANSFER_DST | ImageUsageFlags::TRANSFER_SRC
+        } else {
+            ImageUsageFlags::SAMPLED | ImageUsageFlags::TRANSFER_DST
+        };
+        let vk_extent: vk::Extent3D = extent.clone().to_3d(1).into();
+        let image_info = populate_image_info(format.into(), vk_extent, mip_levels, usage_flags.into());
+        let image: Rc<Self> = Self::allocate(device.clone(), pool.clone(), &image_info, format, MemoryPropertyFlags::DEVICE_LOCAL, None)?;
+
+        // Fill a staging buffer with the data
+        let staging: Rc<StagingBuffer> = match StagingBuffer::new(device.clone(), pool, data.len()) {
+            Ok(staging) => staging,
+            Err(err)    => { return Err(Error::StagingError{ err }); }
+        };
+        {
+            let mut mapped: MappedMemory = match staging.map() {
+                Ok(mapped) => mapped,
+                Err(err)   => { return Err(Error::StagingError{ err }); }
+            };
+            if let Err(err) = mapped.write_slice(0, data) { return Err(Error::StagingError{ err }); }
+            if let Err(err) = mapped.flush(0, Some(data.len() as u64)) { return Err(Error::StagingError{ err }); }
+        }
+
+        // Record the upload (and mip generation)
+        let cmd: Rc<CommandBuffer> = match CommandBuffer::new(device.clone(), uploader.clone(), device.families().graphics, CommandBufferFlags::TRANSIENT) {
+            Ok(cmd)  => cmd,
+            Err(err) => { return Err(Error::CommandBufferError{ err }); }
+        };
+        if let Err(err) = cmd.begin(CommandBufferUsageFlags::ONE_TIME_SUBMIT) { return Err(Error::CommandBufferError{ err }); }
+        cmd.transition_images(&[ (&image, ImageLayout::Undefined, ImageLayout::TransferDst) ]);
+        unsafe { device.cmd_copy_buffer_to_image(cmd.vk(), staging.vk(), image.image, vk::ImageLayout::TRANSFER_DST_OPTIMAL, &[ populate_buffer_image_copy(aspect.into(), vk_extent) ]); }
+        if mip_levels > 1 {
+            for level in 1..mip_levels {
+                // Make the previous level readable, blit it to this one, and then make it shader-readable
+                unsafe {
+                    device.cmd_pipeline_barrier(cmd.vk(), vk::PipelineStageFlags::TRANSFER, vk::PipelineStageFlags::TRANSFER, vk::DependencyFlags::empty(), &[], &[], &[
+                        populate_mip_barrier(image.image, aspect.into(), level - 1, vk::ImageLayout::TRANSFER_DST_OPTIMAL, vk::ImageLayout::TRANSFER_SRC_OPTIMAL, vk::AccessFlags::TRANSFER_WRITE, vk::AccessFlags::TRANSFER_READ),
+                    ]);
+                    device.cmd_blit_image(cmd.vk(), image.image, vk::ImageLayout::TRANSFER_SRC_OPTIMAL, image.image, vk::ImageLayout::TRANSFER_DST_OPTIMAL, &[
+                        populate_mip_blit(aspect.into(), level, extent.mip_level(level - 1), extent.mip_level(level)),
+                    ], vk::Filter::LINEAR);
+                    device.cmd_pipeline_barrier(cmd.vk(), vk::PipelineStageFlags::TRANSFER, vk::PipelineStageFlags::VERTEX_SHADER | vk::PipelineStageFlags::FRAGMENT_SHADER | vk::PipelineStageFlags::COMPUTE_SHADER, vk::DependencyFlags::empty(), &[], &[], &[
+                        populate_mip_barrier(image.image, aspect.into(), level - 1, vk::ImageLayout::TRANSFER_SRC_OPTIMAL, vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL, vk::AccessFlags::TRANSFER_READ, vk::AccessFlags::SHADER_READ),
+                    ]);
+                }
+            }
+
+            // The last level has only been written to, so it still needs to be made shader-readable
+            unsafe {
+                device.cmd_pipeline_barrier(cmd.vk(), vk::PipelineStageFlags::TRANSFER, vk::PipelineStageFlags::VERTEX_SHADER | vk::PipelineStageFlags::FRAGMENT_SHADER | vk::PipelineStageFlags::COMPUTE_SHADER, vk::DependencyFlags::empty(), &[], &[], &[
+                    populate_mip_barrier(image.image, aspect.into(), mip_levels - 1, vk::ImageLayout::TRANSFER_DST_OPTIMAL, vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL, vk::AccessFlags::TRANSFER_WRITE, vk::AccessFlags::SHADER_READ),
+                ]);
+            }
+        } else {
+            cmd.transition_images(&[ (&image, ImageLayout::TransferDst, ImageLayout::ShaderReadOnly) ]);
+        }
+        if let Err(err) = cmd.end() { return Err(Error::CommandBufferError{ err }); }
+
+        // Submit the command buffer and wait until it is completed
+        if let Err(err) = device.queues().graphics.submit(&cmd, &[], &[], &[], &[], None) { return Err(Error::SubmitError{ err }); }
+        if let Err(err) = device.queues().graphics.drain() { return Err(Error::SubmitError{ err }); }
+
+        // Done
+        Ok(image)
+    }
+
+    /// Creates a new VkImage and allocates (and binds) its memory in the given pool.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Image will live.
+    /// - `pool`: The MemoryPool where the Image's memory will be allocated.
+    /// - `image_info`: The VkImageCreateInfo that describes the VkImage to create.
+    /// - `format`: The ImageFormat of the VkImage (which should match the one in `image_info`).
+    /// - `mem_props`: The MemoryPropertyFlags that the Image's memory should satisfy.
+    /// - `name`: If given, a debug name for the Image (see `Device::set_object_name()`).
+    /// 
+    /// # Returns
+    /// A new Image instance, already wrapped in an Rc.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create the VkImage or to allocate (or bind) its memory.
+    fn allocate(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, image_info: &vk::ImageCreateInfo, format: ImageFormat, mem_props: MemoryPropertyFlags, name: Option<&str>) -> Result<Rc<Self>, Error> {
+        // Create the image itself
+        let image: vk::Image = unsafe {
+            match device.create_image(image_info, None) {
+                Ok(image) => image,
+                Err(err)  => { return Err(Error::ImageCreateError{ err }); }
+            }
+        };
+
+        // Get the image memory type requirements
+        let mut requirements: MemoryRequirements = unsafe { device.get_image_memory_requirements(image) }.into();
+        requirements.linear = image_info.tiling == vk::ImageTiling::LINEAR;
+
+        // Allocate the memory in the pool
+        let (memory, pointer): (vk::DeviceMemory, GpuPtr) = {
+            // Get a lock on the pool first
+            let mut lock: RefMut<dyn MemoryPool> = pool.borrow_mut();
+
+            // Reserve the area
+            match lock.allocate(&requirements, mem_props) {
+                Ok(res)  => res,
+                Err(err) => {
+                    unsafe { device.destroy_image(image, None); }
+                    return Err(Error::MemoryAllocateError{ err });
+                }
+            }
+        };
+
+        // Bind the memory
+        unsafe {
+            if let Err(err) = device.bind_image_memory(image, memory, pointer.into()) {
+                device.destroy_image(image, None);
+                if let Err(err) = pool.borrow_mut().free(pointer) { if !matches!(err, MemoryPoolError::FreeUnsupported{ .. }) { warn!("Failed to free image memory: {}", err); } }
+                return Err(Error::ImageBindError{ err });
+            }
+        }
+
+        // Name it if told to do so
+        device.try_set_object_name(image, name);
+
+        // Done, wrap in an instance and return
+        Ok(Rc::new(Self {
+            device : Some(device),
+            memory : Some((pool, pointer)),
+
+            image,
+            format,
+            extent     : Extent2D::new(image_info.extent.width, image_info.extent.height),
+            samples    : image_info.samples.into(),
+            mip_levels : image_info.mip_levels,
+            usage      : image_info.usage.into(),
+        }))
+    }
+
     /// Constructor for the Image, which takes an already existing VkImage and wraps around it.
-    pub(crate) fn from_vk(image: vk::Image) -> Result<Rc<Self>, Error> {
+    /// 
+    /// The VkImage is assumed to be owned by something else (e.g., a Swapchain), and will thus not be destroyed when this Image is dropped.
+    /// 
+    /// # Arguments
+    /// - `image`: The VkImage to wrap around.
+    /// - `format`: The ImageFormat of the VkImage.
+    /// - `extent`: The size (in pixels) of the VkImage.
+    /// - `usage`: The ImageUsageFlags with which the VkImage was created.
+    pub(crate) fn from_vk(image: vk::Image, format: ImageFormat, extent: Extent2D<u32>, usage: ImageUsageFlags) -> Result<Rc<Self>, Error> {
         Ok(Rc::new(Self {
+            device : None,
+            memory : None,
+
             image,
+            format,
+            extent,
+            samples    : SampleCount::ONE,
+            mip_levels : 1,
+            usage,
         }))
     }
 
 
 
+    /// Returns the MemoryPool where this Image's memory is allocated, if it was allocated by us.
+    #[inline]
+    pub fn pool(&self) -> Option<&Rc<RefCell<dyn MemoryPool>>> { self.memory.as_ref().map(|(pool, _)| pool) }
+
+    /// Returns the format of this Image.
+    #[inline]
+    pub fn format(&self) -> ImageFormat { self.format }
+
+    /// Returns the size (in pixels) of this Image's first mip level.
+    #[inline]
+    pub fn extent(&self) -> &Extent2D<u32> { &self.extent }
+
+    /// Returns the number of samples per pixel in this Image.
+    #[inline]
+    pub fn samples(&self) -> SampleCount { self.samples }
+
+    /// Returns the aspects that cover all of this Image's data, as derived from its format (see `ImageFormat::default_aspect()`).
+    #[inline]
+    pub fn aspect(&self) -> ImageAspectFlags { self.format.default_aspect() }
+
+    /// Returns the number of mip levels in this Image.
+    #[inline]
+    pub fn mip_levels(&self) -> u32 { self.mip_levels }
+
+    /// Returns the ImageUsageFlags with which the Image was created.
+    #[inline]
+    pub fn usage(&self) -> ImageUsageFlags { self.usage }
+
     /// Returns the internal VkImage.
     #[inline]
     pub fn vk(&self) -> vk::Image { self.image }
 }
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        // Only destroy the image if we own it
+        if let Some(device) = &self.device {
+            log_destroy!(self, Image);
+
+            // Destroy the image
+            unsafe { device.destroy_image(self.image, None); }
+            // Lock the pool to free the memory
+            if let Some((pool, pointer)) = &self.memory {
+                match pool.borrow_mut().free(*pointer) {
+                    Ok(_) | Err(MemoryPoolError::FreeUnsupported{ .. }) => {},
+                    Err(_err)                                           => { warn!("Failed to free image memory: {}", _err); },
+                }
+            }
+        }
+    }
+}
@@ -19,10 +19,12 @@ use ash::vk;
 
 // pub use crate::errors::ImageError;
 pub use crate::errors::ImageViewError as Error;
-use crate::auxillary::enums::{ImageFormat, ImageAspect, ImageViewKind};
+use crate::auxillary::enums::{ImageFormat, ImageViewKind};
+use crate::auxillary::flags::ImageAspectFlags;
 use crate::auxillary::structs::ComponentMapping;
 use crate::device::Device;
 use crate::image::Image;
+use crate::spec::Shared;
 
 
 /***** AUXILLARY STRUCTS *****/
@@ -36,8 +38,8 @@ pub struct CreateInfo {
     /// Defines the channel mapping for the image
     pub swizzle : ComponentMapping,
 
-    /// Defines the aspect for this image (how it will be used)
-    pub aspect     : ImageAspect,
+    /// Defines the aspect(s) of the image that the view covers (e.g., both `DEPTH` and `STENCIL` for a combined depth/stencil attachment)
+    pub aspect     : ImageAspectFlags,
     /// Defines the base MIP level
     pub base_level : u32,
     /// Defines the number of image MIP levels
@@ -52,7 +54,7 @@ impl Default for CreateInfo {
             format  : ImageFormat::B8G8R8A8SRgb,
             swizzle : ComponentMapping::default(),
 
-            aspect     : ImageAspect::Colour,
+            aspect     : ImageAspectFlags::COLOUR,
             base_level : 0,
             mip_levels : 1,
         }
@@ -67,9 +69,11 @@ impl Default for CreateInfo {
 /// The ImageView class, which wraps around an Image or a VkImage to define how it should be accessed.
 pub struct View {
     /// The parent device for the parent image, who's lifetime we are tied  to
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The parent image for this view
     image  : Rc<Image>,
+    /// The format in which the view interprets the image
+    format : ImageFormat,
 
     /// The image view object itself.
     view  : vk::ImageView,
@@ -90,7 +94,7 @@ impl View {
     /// 
     /// # Errors
     /// This function errors if we failed to allocate the new ImageView for some reason.
-    pub fn new(device: Rc<Device>, image: Rc<Image>, create_info: CreateInfo) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, image: Rc<Image>, create_info: CreateInfo) -> Result<Rc<Self>, Error> {
         // Define the Vulkan create info
         let image_info = vk::ImageViewCreateInfo {
             // Do the default stuff
@@ -130,6 +134,7 @@ impl View {
         Ok(Rc::new(Self {
             device,
             image,
+            format : create_info.format,
 
             view,
         }))
@@ -144,7 +149,7 @@ impl View {
     // /// 
     // /// # Returns
     // /// The new View instance on success, or else an Error.
-    // pub fn from_vk(device: Rc<Device>, image: vk::Image, create_info: CreateInfo) -> Result<Self, Error> {
+    // pub fn from_vk(device: Shared<Device>, image: vk::Image, create_info: CreateInfo) -> Result<Self, Error> {
     //     // Define the create info
     //     let image_info = vk::ImageViewCreateInfo {
     //         // Do the default stuff
@@ -192,12 +197,16 @@ impl View {
 
     /// Returns a reference to the parent GPU
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns a reference to the parent image
     #[inline]
     pub fn image(&self) -> &Rc<Image> { &self.image }
 
+    /// Returns the format in which this view interprets its image
+    #[inline]
+    pub fn format(&self) -> ImageFormat { self.format }
+
 
 
     /// Returns a reference to the internal view
@@ -14,15 +14,17 @@
 
 use std::ffi::{CStr, CString};
 use std::ops::Deref;
+use std::path::Path;
 use std::ptr;
-use std::rc::Rc;
+use std::str::FromStr;
 
 use ash::vk;
-use semver::Version;
 
 use crate::{debug, error, info, warn, to_cstring};
 pub use crate::errors::InstanceError as Error;
 use crate::log_destroy;
+use crate::auxillary::enums::InstanceExtension;
+use crate::spec::{ApiVersion, Shared};
 
 
 /***** HELPER FUNCTIONS *****/
@@ -30,15 +32,12 @@ use crate::log_destroy;
 /// This overload is for Windows.
 /// 
 /// **Returns**  
-/// The list of required extensions, as a list of CStrings.
+/// The list of candidate surface extensions.
 #[cfg(all(windows))]
-fn os_surface_extensions() -> Vec<CString> {
-    // Import the extension into the namespace
-    use ash::extensions::khr::Win32Surface;
-
-    // Return the name of the Windows surface extension
+fn os_surface_extensions() -> Vec<InstanceExtension> {
+    // Return the Windows surface extension
     vec![
-        Win32Surface::name().to_owned()
+        InstanceExtension::Win32Surface,
     ]
 }
 
@@ -46,50 +45,43 @@ fn os_surface_extensions() -> Vec<CString> {
 /// This overload is for macOS.
 /// 
 /// **Returns**  
-/// The list of required extensions, as a list of CStrings.
+/// The list of candidate surface extensions.
 #[cfg(target_os = "macos")]
-fn os_surface_extensions() -> Vec<CString> {
-    // Import the extension into the namespace
-    use ash::extensions::mvk::MacOSSurface;
-
-    // Return the name of the macOS surface extension
+fn os_surface_extensions() -> Vec<InstanceExtension> {
+    // Return the macOS surface extension
     vec![
-        MacOSSurface::name().to_owned()
+        InstanceExtension::MacOSSurface,
     ]
 }
 
 /// Returns the proper extensions for the target OS' window system.  
-/// This overload is for Linux (X11).
+/// This overload is for Linux (X11 and Wayland).
 /// 
 /// **Returns**  
-/// The list of required extensions, as a list of CStrings.
+/// The list of candidate surface extensions.
 #[cfg(all(unix, not(target_os = "android"), not(target_os = "macos")))]
-fn os_surface_extensions() -> Vec<CString> {
-    // Bring the extensions into the namespace
-    use ash::extensions::khr::XlibSurface;
-    use ash::extensions::khr::WaylandSurface;
-
-    // Enable both the X11 and the Wayland extension, as, at compile time, we have no idea which should be the one
+fn os_surface_extensions() -> Vec<InstanceExtension> {
+    // Try both the X11 and the Wayland extensions, as, at compile time, we have no idea which should be the one
     vec![
-        XlibSurface::name().to_owned(),
-        WaylandSurface::name().to_owned(),
+        InstanceExtension::XlibSurface,
+        InstanceExtension::XcbSurface,
+        InstanceExtension::WaylandSurface,
     ]
 }
 
 
 
 
-
 /***** POPULATE FUNCTIONS *****/
 /// Populates an ApplicationInfo struct.
 /// 
 /// This function requires that the given CStrings are alive as long as the ApplicationInfo is.
 /// 
 /// The application version (`version`) and engine version will be converted to a Vulkan version number automatically.
-fn populate_app_info<'a>(name: &'a CStr, version: Version, engine: &'a CStr, engine_version: Version) -> vk::ApplicationInfo {
+fn populate_app_info<'a>(name: &'a CStr, version: ApiVersion, engine: &'a CStr, engine_version: ApiVersion) -> vk::ApplicationInfo {
     // Convert the versions to Vulkan versions
-    let version        = vk::make_api_version(0, version.major as u32, version.minor as u32, version.patch as u32);
-    let engine_version = vk::make_api_version(0, engine_version.major as u32, engine_version.minor as u32, engine_version.patch as u32);
+    let version: u32        = version.into();
+    let engine_version: u32 = engine_version.into();
 
     // Finally, construct the application info
     vk::ApplicationInfo {
@@ -294,6 +286,10 @@ pub struct Instance {
     instance : ash::Instance,
     /// The loader (0) and the messenger (1) for Vulkan's DebugUtils.
     debug_utils : Option<(ash::extensions::ext::DebugUtils, vk::DebugUtilsMessengerEXT)>,
+    /// The extensions (known to this crate) that were enabled on the instance, or `None` if we do not know (i.e., if it was created with `Instance::from_raw()`).
+    extensions  : Option<Vec<InstanceExtension>>,
+    /// Whether we own the VkInstance (and should thus destroy it when dropped).
+    owned       : bool,
 }
 
 impl Instance {
@@ -307,7 +303,7 @@ impl Instance {
     /// 
     /// # Arguments
     /// - `name`: The name of the application to register in the Vulkan driver.
-    /// - `version`: The version of the application to register in the Vulkan driver.
+    /// - `version`: The version of the application to register in the Vulkan driver (see `ApiVersion::from_semver()` to use a semantic version).
     /// - `engine_name`: The name of the application's engine to register in the Vulkan driver.
     /// - `engine_version`: The version of the application's engine to register in the Vulkan driver.
     /// - `additional_extensions`: A slice of additional extensions to enable in the application-global instance.
@@ -315,21 +311,81 @@ impl Instance {
     /// 
     /// # Returns
     /// The new Instance instance on success, or else an Error describing why we failed to create it.
-    pub fn new<'a, 'b, S1: AsRef<str>, S2: AsRef<str>>(name: S1, version: Version, engine: S2, engine_version: Version, additional_extensions: &[&'a str], additional_layers: &[&'b str]) -> Result<Rc<Self>, Error> {
-        // Convert the str-like into &str
-        let name: &str   = name.as_ref();
-        let engine: &str = engine.as_ref();
+    pub fn new<S1: AsRef<str>, S2: AsRef<str>>(name: S1, version: ApiVersion, engine: S2, engine_version: ApiVersion, additional_extensions: &[&str], additional_layers: &[&str]) -> Result<Shared<Self>, Error> {
+        // Create the entry from the default search path
+        let entry = unsafe {
+            match ash::Entry::load() {
+                Ok(entry) => entry,
+                Err(err)  => { return Err(Error::LoadError{ err }); }
+            }
+        };
 
+        // Use the other constructor to do the rest
+        Self::new_with_entry(entry, name, version, engine, engine_version, additional_extensions, additional_layers)
+    }
 
+    /// Constructor for the Instance that loads the Vulkan loader from the given path instead of the default search path.
+    /// 
+    /// This is useful for deployments where the loader is bundled with the application (e.g., `libvulkan` or `libMoltenVK` next to the executable in a macOS app bundle).
+    /// 
+    /// # Generic arguments
+    /// - `P`: The Path-like type of the loader's path.
+    /// - `S1`: The &str-like type of the application's name.
+    /// - `S2`: The &str-like type of the application's engine's name.
+    /// 
+    /// # Arguments
+    /// - `loader_path`: The path to the Vulkan loader (shared) library to load.
+    /// - `name`: The name of the application to register in the Vulkan driver.
+    /// - `version`: The version of the application to register in the Vulkan driver (see `ApiVersion::from_semver()` to use a semantic version).
+    /// - `engine_name`: The name of the application's engine to register in the Vulkan driver.
+    /// - `engine_version`: The version of the application's engine to register in the Vulkan driver.
+    /// - `additional_extensions`: A slice of additional extensions to enable in the application-global instance.
+    /// - `additional_layers`: A slice of additional validation layers to enable in the application-global instance.
+    /// 
+    /// # Returns
+    /// The new Instance instance on success, or else an Error describing why we failed to create it.
+    pub fn new_with_loader_path<P: AsRef<Path>, S1: AsRef<str>, S2: AsRef<str>>(loader_path: P, name: S1, version: ApiVersion, engine: S2, engine_version: ApiVersion, additional_extensions: &[&str], additional_layers: &[&str]) -> Result<Shared<Self>, Error> {
+        // Convert the Path-like into a Path
+        let loader_path: &Path = loader_path.as_ref();
 
-        // Create the entry
+        // Create the entry from the given path
         let entry = unsafe {
-            match ash::Entry::load() {
+            match ash::Entry::load_from(loader_path) {
                 Ok(entry) => entry,
-                Err(err)  => { return Err(Error::LoadError{ err }); }
+                Err(err)  => { return Err(Error::LoadFromError{ path: loader_path.to_path_buf(), err }); }
             }
         };
 
+        // Use the other constructor to do the rest
+        Self::new_with_entry(entry, name, version, engine, engine_version, additional_extensions, additional_layers)
+    }
+
+    /// Constructor for the Instance that uses an already loaded ash Entry.
+    /// 
+    /// This allows the application to load (or link) the Vulkan loader itself, e.g., from a bundled location.
+    /// 
+    /// Besides the given extensions, this enables `VK_KHR_surface` and those of the target OS' surface extensions that are supported by the local Vulkan installation (e.g., on Linux, any of `VK_KHR_xlib_surface`, `VK_KHR_xcb_surface` and `VK_KHR_wayland_surface`).
+    /// 
+    /// # Generic arguments
+    /// - `S1`: The &str-like type of the application's name.
+    /// - `S2`: The &str-like type of the application's engine's name.
+    /// 
+    /// # Arguments
+    /// - `entry`: The ash Entry that determines how we link to the underlying Vulkan library.
+    /// - `name`: The name of the application to register in the Vulkan driver.
+    /// - `version`: The version of the application to register in the Vulkan driver (see `ApiVersion::from_semver()` to use a semantic version).
+    /// - `engine_name`: The name of the application's engine to register in the Vulkan driver.
+    /// - `engine_version`: The version of the application's engine to register in the Vulkan driver.
+    /// - `additional_extensions`: A slice of additional extensions to enable in the application-global instance.
+    /// - `additional_layers`: A slice of additional validation layers to enable in the application-global instance.
+    /// 
+    /// # Returns
+    /// The new Instance instance on success, or else an Error describing why we failed to create it.
+    pub fn new_with_entry<S1: AsRef<str>, S2: AsRef<str>>(entry: ash::Entry, name: S1, version: ApiVersion, engine: S2, engine_version: ApiVersion, additional_extensions: &[&str], additional_layers: &[&str]) -> Result<Shared<Self>, Error> {
+        // Convert the str-like into &str
+        let name: &str   = name.as_ref();
+        let engine: &str = engine.as_ref();
+
 
 
         // Get a CString from the String
@@ -348,15 +404,27 @@ impl Instance {
         let mut additional_extensions: Vec<CString> = (0..additional_extensions.len()).map(|i| to_cstring!(additional_extensions[i])).collect();
         let additional_layers: Vec<CString>         = (0..additional_layers.len()).map(|i| to_cstring!(additional_layers[i])).collect();
 
+        // Only enable the window system extensions that the local Vulkan installation actually supports (e.g., a Wayland-only system may not have the X11 ones)
+        let available_extensions: Vec<CString> = match entry.enumerate_instance_extension_properties(None) {
+            Ok(available) => available.iter().map(|ext| unsafe { CStr::from_ptr(ext.extension_name.as_ptr()) }.to_owned()).collect(),
+            Err(err)      => { return Err(Error::ExtensionEnumerateError{ layer: None, err }); }
+        };
+        let surface_extensions: Vec<InstanceExtension> = os_surface_extensions().into_iter().filter(|extension| {
+            let supported: bool = available_extensions.contains(&CString::from(*extension));
+            if !supported { debug!("Not enabling surface extension '{}', as it is not supported by the local Vulkan installation", extension); }
+            supported
+        }).collect();
+
         // Collect the required extensions
-        let mut extensions: Vec<CString> = vec![ ash::extensions::khr::Surface::name().to_owned() ];
+        let mut extensions: Vec<CString> = vec![ InstanceExtension::Surface.into() ];
         #[cfg(target_os = "macos")]
         { extensions.push(InstanceExtension::PortabilityEnumeration.into()); }
         if debug { extensions.push(ash::extensions::ext::DebugUtils::name().to_owned()); }
-        extensions.append(&mut os_surface_extensions());
+        extensions.extend(surface_extensions.iter().map(|extension| CString::from(*extension)));
 
         // Merge the extensions and the layers
         extensions.append(&mut additional_extensions);
+        let enabled_extensions: Vec<InstanceExtension> = extensions.iter().filter_map(|extension| extension.to_str().ok().and_then(|extension| InstanceExtension::from_str(extension).ok())).collect();
         let layers = additional_layers;
 
 
@@ -411,14 +479,45 @@ impl Instance {
 
 
         // Finally, create the struct!
-        Ok(Rc::new(Self {
+        Ok(Shared::new(Self {
             entry,
 
             instance,
             debug_utils,
+            extensions : Some(enabled_extensions),
+            owned      : true,
         }))
     }
 
+    /// Constructor for the Instance that wraps around an already existing VkInstance (e.g., one created by another library or an OpenXR runtime).
+    /// 
+    /// Note that no debug messenger is installed for such an Instance. Moreover, since we do not know which extensions were enabled on the VkInstance, `Instance::enabled_extensions()` reports none for such an Instance (and `Surface`s will not check for the appropriate surface extension).
+    /// 
+    /// # Arguments
+    /// - `entry`: The ash Entry of the Vulkan library with which the VkInstance was created.
+    /// - `instance`: The VkInstance to wrap.
+    /// - `owned`: Whether the new Instance takes ownership of the VkInstance. If false, it is never destroyed by us, and must outlive the returned Instance (and anything created from it).
+    /// 
+    /// # Returns
+    /// A new Instance, already wrapped in a Shared pointer.
+    /// 
+    /// # Safety
+    /// The given `instance` must be a valid VkInstance that was created with the given `entry`.
+    pub unsafe fn from_raw(entry: ash::Entry, instance: vk::Instance, owned: bool) -> Shared<Self> {
+        // Load the instance functions for the handle
+        let instance: ash::Instance = ash::Instance::load(entry.static_fn(), instance);
+
+        // Wrap it and done
+        Shared::new(Self {
+            entry,
+
+            instance,
+            debug_utils : None,
+            extensions  : None,
+            owned,
+        })
+    }
+
 
 
     /// Returns the internal ash Entry.
@@ -428,6 +527,22 @@ impl Instance {
     /// Returns (an immuteable reference to) the internal Vulkan instance.
     #[inline]
     pub fn vk(&self) -> &ash::Instance { &self.instance }
+
+    /// Returns the loader for the `VK_EXT_debug_utils` functions, or `None` if debugging is not enabled on this Instance.
+    #[inline]
+    pub fn debug_utils(&self) -> Option<&ash::extensions::ext::DebugUtils> { self.debug_utils.as_ref().map(|(loader, _)| loader) }
+
+    /// Returns the extensions that were enabled on this Instance.
+    /// 
+    /// Note that this only lists extensions known to this crate (see `InstanceExtension`); any other extensions given at construction time are enabled but not listed here.
+    #[inline]
+    pub fn enabled_extensions(&self) -> &[InstanceExtension] { self.extensions.as_deref().unwrap_or(&[]) }
+
+    /// Returns whether the given extension is enabled on this Instance.
+    /// 
+    /// For Instances created with `Instance::from_raw()`, we cannot know, and so this function always returns `None` for those.
+    #[inline]
+    pub fn has_extension(&self, extension: InstanceExtension) -> Option<bool> { self.extensions.as_ref().map(|extensions| extensions.contains(&extension)) }
 }
 
 impl Drop for Instance {
@@ -440,7 +555,8 @@ impl Drop for Instance {
             }
         }
 
-        // Destroy the instance
+        // Destroy the instance, if it's ours
+        if !self.owned { return; }
         log_destroy!(self, Instance);
         unsafe { self.instance.destroy_instance(None); }
     }
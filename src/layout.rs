@@ -13,14 +13,77 @@
 // 
 
 use std::ptr;
-use std::rc::Rc;
 
 use ash::vk;
 
 pub use crate::errors::PipelineLayoutError as Error;
-use crate::log_destroy;
+use crate::{log_destroy, vec_as_ptr};
+use crate::auxillary::structs::PushConstantRange;
 use crate::device::Device;
 use crate::descriptors::DescriptorSetLayout;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auxillary::flags::ShaderStageFlags;
+
+    /// Tests whether push constant ranges are checked against the device limit
+    #[test]
+    fn test_validate_push_constants() {
+        // A 64-byte vertex range (and a fragment range behind it) fits in the minimum guaranteed limit
+        let ranges = [ PushConstantRange{ stage: ShaderStageFlags::VERTEX, offset: 0, size: 64 }, PushConstantRange{ stage: ShaderStageFlags::FRAGMENT, offset: 64, size: 64 } ];
+        assert!(validate_push_constants(&ranges, 128).is_ok());
+        assert!(validate_push_constants(&[], 0).is_ok());
+
+        // But not in a smaller one
+        assert!(matches!(validate_push_constants(&ranges, 96), Err(Error::PushConstantRangeTooLarge{ index: 1, offset: 64, size: 64, max: 96 })));
+        assert!(matches!(validate_push_constants(&[ PushConstantRange{ stage: ShaderStageFlags::VERTEX, offset: u32::MAX, size: 4 } ], 128), Err(Error::PushConstantRangeTooLarge{ index: 0, .. })));
+    }
+}
+
+
+
+
+
+/***** HELPER STRUCTS *****/
+/// Identifies a PipelineLayout in the Device's layout cache by the contents of its DescriptorSetLayouts and its push constant ranges.
+/// 
+/// The set layouts are matched on their bindings instead of their handles, since the driver may re-use the handle of a destroyed DescriptorSetLayout for a different one.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct PipelineLayoutKey {
+    /// The bindings of each of the DescriptorSetLayouts of the PipelineLayout, as `(binding, kind, stage, count)`.
+    layouts        : Vec<Vec<(u32, vk::DescriptorType, vk::ShaderStageFlags, u32)>>,
+    /// The push constant ranges of the PipelineLayout, as `(stage, offset, size)`.
+    push_constants : Vec<(vk::ShaderStageFlags, u32, u32)>,
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether the given push constant ranges fit in the push constant space of a device.
+/// 
+/// # Arguments
+/// - `push_constants`: The list of push constant ranges to check.
+/// - `max_size`: The maximum size (in bytes) of the push constant space (see `PhysicalDeviceLimits::max_push_constants_size`).
+/// 
+/// # Errors
+/// This function errors if any range ends beyond the given maximum size.
+fn validate_push_constants(push_constants: &[PushConstantRange], max_size: u32) -> Result<(), Error> {
+    for (i, range) in push_constants.iter().enumerate() {
+        if range.offset.checked_add(range.size).map(|end| end > max_size).unwrap_or(true) {
+            return Err(Error::PushConstantRangeTooLarge{ index: i, offset: range.offset, size: range.size, max: max_size });
+        }
+    }
+    Ok(())
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -28,11 +91,12 @@ use crate::descriptors::DescriptorSetLayout;
 /// 
 /// # Arguments
 /// - `layouts`: The list of DescriptorSetLayouts to attach to the PipelineLayout.
+/// - `push_constants`: The list of push constant ranges to attach to the PipelineLayout.
 /// 
 /// # Returns
 /// A new vk::PipelineLayoutCreateInfo with the same lifetime as the given vectors.
 #[inline]
-fn populate_layout_info(layouts: &[vk::DescriptorSetLayout]) -> vk::PipelineLayoutCreateInfo {
+fn populate_layout_info(layouts: &[vk::DescriptorSetLayout], push_constants: &[vk::PushConstantRange]) -> vk::PipelineLayoutCreateInfo {
     vk::PipelineLayoutCreateInfo {
         // Set the default stuff
         s_type : vk::StructureType::PIPELINE_LAYOUT_CREATE_INFO,
@@ -44,8 +108,8 @@ fn populate_layout_info(layouts: &[vk::DescriptorSetLayout]) -> vk::PipelineLayo
         p_set_layouts    : if layouts.len() > 0 { layouts.as_ptr() } else { ptr::null() },
 
         // Attach the push constants
-        p_push_constant_ranges    : ptr::null(),
-        push_constant_range_count : 0,
+        p_push_constant_ranges    : vec_as_ptr!(push_constants),
+        push_constant_range_count : push_constants.len() as u32,
     }
 }
 
@@ -57,7 +121,7 @@ fn populate_layout_info(layouts: &[vk::DescriptorSetLayout]) -> vk::PipelineLayo
 /// Defines a wrapper around a VkPipelineLayout struct.
 pub struct PipelineLayout {
     /// Reference to the parent device of this layout
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The PipelineLayout we wrap
     layout : vk::PipelineLayout,
 }
@@ -68,19 +132,23 @@ impl PipelineLayout {
     /// # Arguments
     /// - `device`: The Device to build the pipeline layout on.
     /// - `layouts`: A list of DescriptorSetLayouts for this layout.
-    /// - `push_constants`: A list of PushConstants for this layout.
+    /// - `push_constants`: A list of PushConstantRanges for this layout (see `Shader::reflect_push_constants()` to derive these from the shaders).
     /// 
     /// # Returns
     /// A new PipelineLayout instance on success.
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend could not create the new layout.
-    pub fn new(device: Rc<Device>, layouts: &[DescriptorSetLayout]) -> Result<Rc<Self>, Error> {
-        // Cast the layouts to their Vulkan counterparts
-        let layouts: Vec<vk::DescriptorSetLayout> = layouts.iter().map(|layout| layout.vk()).collect();
+    /// This function errors if any of the push constant ranges exceeds the Device's `max_push_constants_size`, or if the underlying Vulkan backend could not create the new layout.
+    pub fn new(device: Shared<Device>, layouts: &[DescriptorSetLayout], push_constants: &[PushConstantRange]) -> Result<Shared<Self>, Error> {
+        // Make sure the push constants fit on the device
+        validate_push_constants(push_constants, device.get_physical_device_props().limits.max_push_constants_size)?;
+
+        // Cast the layouts and ranges to their Vulkan counterparts
+        let layouts: Vec<vk::DescriptorSetLayout>      = layouts.iter().map(|layout| layout.vk()).collect();
+        let push_constants: Vec<vk::PushConstantRange> = push_constants.iter().map(|range| range.into()).collect();
 
         // Create the create info
-        let layout_info = populate_layout_info(&layouts);
+        let layout_info = populate_layout_info(&layouts, &push_constants);
 
         // Create the pipeline layout itself
         let layout = unsafe {
@@ -91,7 +159,7 @@ impl PipelineLayout {
         };
 
         // Wrap it in this struct and done
-        Ok(Rc::new(Self {
+        Ok(Shared::new(Self {
             device,
             layout,
         }))
@@ -99,9 +167,43 @@ impl PipelineLayout {
 
 
 
+    /// Returns a PipelineLayout for the given DescriptorSetLayouts and push constant ranges, re-using one that was created earlier on the same Device if it is still alive.
+    /// 
+    /// This avoids creating many identical VkPipelineLayouts for pipelines that share their set layouts and push constants. Layouts are matched on the bindings of the DescriptorSetLayouts (which makes layouts created from identical DescriptorSetLayouts compatible), not on their handles.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device to build the pipeline layout on.
+    /// - `layouts`: A list of DescriptorSetLayouts for this layout.
+    /// - `push_constants`: A list of PushConstantRanges for this layout.
+    /// 
+    /// # Returns
+    /// A new or shared PipelineLayout instance on success.
+    /// 
+    /// # Errors
+    /// This function errors if any of the push constant ranges exceeds the Device's `max_push_constants_size`, or if the underlying Vulkan backend could not create a new layout.
+    pub fn get_or_create(device: Shared<Device>, layouts: &[DescriptorSetLayout], push_constants: &[PushConstantRange]) -> Result<Shared<Self>, Error> {
+        // Build the key for this layout
+        let key = PipelineLayoutKey {
+            layouts        : layouts.iter().map(|layout| layout.bindings().iter().map(|binding| (binding.binding, binding.kind.into(), binding.stage.into(), binding.count)).collect()).collect(),
+            push_constants : push_constants.iter().map(|range| (range.stage.into(), range.offset, range.size)).collect(),
+        };
+
+        // Return the cached layout if it still exists (keeping the cache locked, so that other threads cannot create the same layout in the meantime)
+        let mut cache = device.layout_cache();
+        if let Some(layout) = cache.get(&key).and_then(|layout| layout.upgrade()) { return Ok(layout); }
+
+        // Otherwise, create a new one and cache it (cleaning up any layouts that have since been dropped)
+        let layout: Shared<Self> = Self::new(device.clone(), layouts, push_constants)?;
+        cache.retain(|_, layout| layout.strong_count() > 0);
+        cache.insert(key, Shared::downgrade(&layout));
+        Ok(layout)
+    }
+
+
+
     /// Returns the parent device of this layout
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the internal VkPipelineLayout struct.
     #[inline]
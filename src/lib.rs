@@ -28,6 +28,8 @@ pub mod render_pass;
 pub mod pipeline;
 pub mod pools;
 pub mod image;
+pub mod sampler;
+pub mod render_target;
 pub mod framebuffer;
 pub mod sync;
 
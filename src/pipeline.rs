@@ -24,13 +24,51 @@ use ash::vk;
 use crate::{debug, warn};
 pub use crate::errors::PipelineError as Error;
 use crate::log_destroy;
-use crate::auxillary::enums::{BlendFactor, BlendOp, CompareOp, DynamicState, LogicOp, StencilOp, VertexTopology};
-use crate::auxillary::flags::{ColourComponentFlags, ShaderStage};
-use crate::auxillary::structs::{AttachmentBlendState, ColourBlendState, DepthTestingState, MultisampleState, RasterizerState,  StencilOpState, VertexAssemblyState, VertexInputState, ViewportState};
+use crate::auxillary::enums::{BlendFactor, BlendOp, CompareOp, DeviceExtension, DynamicState, LogicOp, StencilOp, VertexTopology};
+use crate::auxillary::flags::{ColourComponentFlags, SampleCount, ShaderStage};
+use crate::auxillary::structs::{AttachmentBlendState, ColourBlendState, DepthTestingState, MultisampleState, PipelineCreationFeedback, PipelineFeedback, RasterizerState,  StencilOpState, TessellationState, VertexAssemblyState, VertexInputState, ViewportState};
 use crate::device::Device;
-use crate::shader::{Error as ShaderError, Shader};
+use crate::shader::{Error as ShaderError, Shader, Specialization};
 use crate::layout::PipelineLayout;
 use crate::render_pass::RenderPass;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auxillary::structs::Rect2D;
+
+    /// Tests that the viewport state may be left out if both the viewport and the scissor are dynamic
+    #[test]
+    fn test_dynamic_viewport_info() {
+        // Both dynamic, so no static state is needed
+        let (info, mem) = viewport_info(None, &[ DynamicState::Viewport, DynamicState::Scissor ]).unwrap();
+        assert!(mem.is_none());
+        assert_eq!((info.viewport_count, info.scissor_count), (1, 1));
+        assert!(info.p_viewports.is_null() && info.p_scissors.is_null());
+
+        // Only one of them dynamic still needs a static state
+        assert!(viewport_info(None, &[ DynamicState::Viewport ]).is_none());
+        assert!(viewport_info(None, &[ DynamicState::Scissor, DynamicState::LineWidth ]).is_none());
+        assert!(viewport_info(None, &[]).is_none());
+
+        // A static state is always used if given
+        let state: ViewportState = ViewportState {
+            viewport : Rect2D::new(0.0, 0.0, 800.0, 600.0),
+            scissor  : Rect2D::new(0, 0, 800, 600),
+            depth    : 0.0..1.0,
+        };
+        let (info, mem) = viewport_info(Some(&state), &[ DynamicState::Viewport, DynamicState::Scissor ]).unwrap();
+        let (viewport, scissor) = mem.unwrap();
+        assert_eq!((info.p_viewports, info.p_scissors), (&*viewport as *const vk::Viewport, &*scissor as *const vk::Rect2D));
+        assert_eq!((viewport.width, scissor.extent.height), (800.0, 600));
+    }
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS ******/
@@ -54,10 +92,11 @@ fn populate_cache_info(data: &[u8]) -> vk::PipelineCacheCreateInfo {
 /// Populates a VkPipelineShaderStageCreateInfo struct with the shader data given.
 /// 
 /// # Arguments
-/// - `entry`: The CStr that defines the name of the entry function in the shader (anything other than 'main' does not work :( ).
+/// - `entry`: The CStr that defines the name of the entry function in the shader.
 /// - `stage`: The VkShaderStage that determines where this shader will be run.
 /// - `module`: The VkShaderModule that contains the shader code.
-fn populate_shader_stage_info(entry: &CStr, stage: vk::ShaderStageFlags, module: vk::ShaderModule) -> vk::PipelineShaderStageCreateInfo {
+/// - `specialization`: The VkSpecializationInfo that sets the shader's specialization constants, if any.
+fn populate_shader_stage_info(entry: &CStr, stage: vk::ShaderStageFlags, module: vk::ShaderModule, specialization: Option<&vk::SpecializationInfo>) -> vk::PipelineShaderStageCreateInfo {
     vk::PipelineShaderStageCreateInfo {
         // Set the default stuff
         s_type : vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
@@ -69,37 +108,106 @@ fn populate_shader_stage_info(entry: &CStr, stage: vk::ShaderStageFlags, module:
         module,
         stage,
 
-        // Set the specialization information for this shader (ignored for now)
-        p_specialization_info : ptr::null(),
+        // Set the specialization information for this shader
+        p_specialization_info : specialization.map(|info| info as *const vk::SpecializationInfo).unwrap_or(ptr::null()),
+    }
+}
+
+/// Populates a VkPipelineDynamicStateCreateInfo struct.
+/// 
+/// # Arguments
+/// - `states`: The list of VkDynamicStates that are set dynamically in the pipeline.
+#[inline]
+fn populate_dynamic_state_info(states: &[vk::DynamicState]) -> vk::PipelineDynamicStateCreateInfo {
+    vk::PipelineDynamicStateCreateInfo {
+        // Do the default stuff
+        s_type : vk::StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+        p_next : ptr::null(),
+        flags  : vk::PipelineDynamicStateCreateFlags::empty(),
+
+        // Set the dynamic states
+        dynamic_state_count : states.len() as u32,
+        p_dynamic_states    : states.as_ptr(),
+    }
+}
+
+/// Populates a VkPipelineViewportStateCreateInfo struct for a pipeline where both the viewport and the scissor are dynamic.
+/// 
+/// The counts are still set (to one), but the actual values are left to `CommandBuffer` calls.
+#[inline]
+fn populate_dynamic_viewport_info() -> vk::PipelineViewportStateCreateInfo {
+    vk::PipelineViewportStateCreateInfo {
+        // Do the default stuff
+        s_type : vk::StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+        p_next : ptr::null(),
+        flags  : vk::PipelineViewportStateCreateFlags::empty(),
+
+        // Set the only viewport, but without value
+        viewport_count : 1,
+        p_viewports    : ptr::null(),
+
+        // Set the only scissor, but without value
+        scissor_count : 1,
+        p_scissors    : ptr::null(),
+    }
+}
+
+/// The memory that a static VkPipelineViewportStateCreateInfo points to.
+type ViewportMem = (Box<vk::Viewport>, Box<vk::Rect2D>);
+
+/// Resolves the VkPipelineViewportStateCreateInfo for a pipeline, which may be omitted if both the viewport and the scissor are dynamic.
+/// 
+/// # Arguments
+/// - `viewport`: The static ViewportState, if any.
+/// - `dynamic`: The DynamicStates of the pipeline.
+/// 
+/// # Returns
+/// The VkPipelineViewportStateCreateInfo together with the memory it points to (if any), or `None` if no static ViewportState is given while the viewport and scissor are not both dynamic.
+fn viewport_info(viewport: Option<&ViewportState>, dynamic: &[DynamicState]) -> Option<(vk::PipelineViewportStateCreateInfo, Option<ViewportMem>)> {
+    match viewport {
+        Some(viewport) => {
+            let (info, mem): (vk::PipelineViewportStateCreateInfo, ViewportMem) = viewport.clone().into();
+            Some((info, Some(mem)))
+        },
+        None => {
+            if !dynamic.contains(&DynamicState::Viewport) || !dynamic.contains(&DynamicState::Scissor) { return None; }
+            Some((populate_dynamic_viewport_info(), None))
+        },
     }
 }
 
 /// Populates the given VkGraphicsPipelineCreateInfo struct with the configuration structs given.
 /// 
 /// # Arguments
+/// - `flags`: The VkPipelineCreateFlags that determine if this pipeline may be a base for and/or is derived from another.
 /// - `base_pipeline`: A base Pipeline to (potentially) speed up building this one.
 /// - `shader_stages`: The list of shader (stages) to enable for this pipeline.
-/// - `vertex_input`: The information about the vertex layout for this pipeline.
-/// - `vertex_assembly`: The information about the vertex list layout for this pipeline.
+/// - `vertex_input`: The information about the vertex layout for this pipeline. Should be `None` (only) for mesh shader pipelines.
+/// - `vertex_assembly`: The information about the vertex list layout for this pipeline. Should be `None` (only) for mesh shader pipelines.
+/// - `tessellation`: The information about how patches are formed for the tessellation stages. Should be `None` if the pipeline has no tessellation shaders.
 /// - `viewport`: The information about the resulting frame for this pipeline.
 /// - `rasterizer`: The information about the rasterization stage of the pipeline.
 /// - `multisampling`: The information about multisampling in the pipeline.
 /// - `depth_testing`: The information about depth testing in the pipeline.
 /// - `colour_blend`: The information about how to write fragments in the pipeline.
+/// - `dynamic`: The information about which parts of the pipeline are dynamic. Should be `None` if none of them are.
 /// - `layout`: The PipelineLayout to base the pipeline on.
 /// - `render_pass`: The RenderPass to base the pipeline on.
 /// - `subpass`: The index of the first subpass in the render pass to run.
 #[inline]
 fn populate_graphics_pipeline_info(
+    flags: vk::PipelineCreateFlags,
     base_pipeline: vk::Pipeline,
     shader_stages: &Vec<vk::PipelineShaderStageCreateInfo>,
-    vertex_input: &vk::PipelineVertexInputStateCreateInfo,
-    vertex_assembly: &vk::PipelineInputAssemblyStateCreateInfo,
+    vertex_input: Option<&vk::PipelineVertexInputStateCreateInfo>,
+    vertex_assembly: Option<&vk::PipelineInputAssemblyStateCreateInfo>,
+    tessellation: Option<&vk::PipelineTessellationStateCreateInfo>,
     viewport: &vk::PipelineViewportStateCreateInfo,
     rasterizer: &vk::PipelineRasterizationStateCreateInfo,
     multisampling: &vk::PipelineMultisampleStateCreateInfo,
     depth_testing: &vk::PipelineDepthStencilStateCreateInfo,
     colour_blend: &vk::PipelineColorBlendStateCreateInfo,
+    dynamic: Option<&vk::PipelineDynamicStateCreateInfo>,
     layout: vk::PipelineLayout,
     render_pass: vk::RenderPass,
     subpass: u32,
@@ -108,22 +216,22 @@ fn populate_graphics_pipeline_info(
         // Do the default stuff
         s_type : vk::StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
         p_next : ptr::null(),
-        flags  : vk::PipelineCreateFlags::empty(),
+        flags,
 
         // Set the shaders
         stage_count : shader_stages.len() as u32,
         p_stages    : shader_stages.as_ptr(),
 
         // Set the fixed-function stuff
-        p_vertex_input_state   : &*vertex_input,
-        p_input_assembly_state : &*vertex_assembly,
-        p_tessellation_state   : ptr::null(),
+        p_vertex_input_state   : vertex_input.map(|info| info as *const _).unwrap_or(ptr::null()),
+        p_input_assembly_state : vertex_assembly.map(|info| info as *const _).unwrap_or(ptr::null()),
+        p_tessellation_state   : tessellation.map(|info| info as *const _).unwrap_or(ptr::null()),
         p_viewport_state       : &*viewport,
         p_rasterization_state  : &*rasterizer,
         p_multisample_state    : &*multisampling,
         p_depth_stencil_state  : &*depth_testing,
         p_color_blend_state    : &*colour_blend,
-        p_dynamic_state        : ptr::null(),
+        p_dynamic_state        : dynamic.map(|info| info as *const _).unwrap_or(ptr::null()),
 
         // Set the layout and the render pass
         layout,
@@ -144,7 +252,7 @@ fn populate_graphics_pipeline_info(
 /// May speed up pipeline construction by caching the results and re-using that when possible.
 pub struct PipelineCache {
     /// The parent Device of this PipelineCache.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The path where the cache has to be written to when destroyed.
     path   : PathBuf,
     /// The underlying VkPipelineCache struct.
@@ -163,7 +271,7 @@ impl PipelineCache {
     /// 
     /// # Errors
     /// This function errors if the Vulkan backend could not create the new cache, or the given file existed but could not be read.
-    pub fn new<P: AsRef<Path>>(device: Rc<Device>, path: P) -> Result<Rc<Self>, Error> {
+    pub fn new<P: AsRef<Path>>(device: Shared<Device>, path: P) -> Result<Rc<Self>, Error> {
         // Convert Path-likes to Path
         let path: &Path = path.as_ref();
 
@@ -208,7 +316,7 @@ impl PipelineCache {
 
     /// Returns the parent Device.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the underlying VkPipelineCache struct.
     #[inline]
@@ -265,6 +373,12 @@ pub struct PipelineBuilder {
     cache         : Option<Rc<PipelineCache>>,
     /// An optional base pipeline to start construction from.
     base_pipeline : Option<Rc<Pipeline>>,
+    /// An optional debug name to give the built pipeline(s).
+    name          : Option<String>,
+    /// Whether to collect creation feedback for the built pipeline(s).
+    feedback      : bool,
+    /// The index of the subpass in the RenderPass in which the built pipeline(s) will be used.
+    subpass       : u32,
     
     // Default stuff
     /// Describes how we treat the input vertices.
@@ -279,14 +393,18 @@ pub struct PipelineBuilder {
     dynamic         : Vec<DynamicState>,
 
     // Non-default stuff
-    /// Defines the different shaders used in this pipeline
-    shaders       : Vec<(ShaderStage, Rc<Shader>)>,
+    /// Defines the different shaders used in this pipeline, together with the name of their entry point
+    shaders         : Vec<(ShaderStage, Rc<Shader>, CString)>,
+    /// Defines the values of the specialization constants of the shaders in some stages.
+    specializations : Vec<(ShaderStage, Specialization)>,
     /// Describes how the input vertices look like.
-    vertex_input  : Option<VertexInputState>,
+    vertex_input    : Option<VertexInputState>,
+    /// Describes how patches are formed, if the pipeline has tessellation shaders.
+    tessellation    : Option<TessellationState>,
     /// Describes the output images dimensions, cutoff and depth.
-    viewport      : Option<ViewportState>,
+    viewport        : Option<ViewportState>,
     /// Describes the rasterization stage
-    rasterization : Option<RasterizerState>,
+    rasterization   : Option<RasterizerState>,
 }
 
 impl PipelineBuilder {
@@ -303,12 +421,15 @@ impl PipelineBuilder {
 
             cache         : None,
             base_pipeline : None,
+            name          : None,
+            feedback      : false,
+            subpass       : 0,
 
             vertex_assembly : VertexAssemblyState {
                 topology          : VertexTopology::TriangleList,
                 restart_primitive : false,
             },
-            multisampling : MultisampleState {},
+            multisampling : MultisampleState::default(),
             depth_testing : DepthTestingState {
                 enable_depth   : false,
                 enable_write   : false,
@@ -362,10 +483,12 @@ impl PipelineBuilder {
             },
             dynamic : vec![],
 
-            shaders       : Vec::with_capacity(2),
-            vertex_input  : None,
-            viewport      : None,
-            rasterization : None,
+            shaders         : Vec::with_capacity(2),
+            specializations : vec![],
+            vertex_input    : None,
+            tessellation    : None,
+            viewport        : None,
+            rasterization   : None,
         }
     }
 
@@ -425,6 +548,8 @@ impl PipelineBuilder {
 
     /// Uses the given pipeline as a base for constructing the new one.
     /// 
+    /// The new pipeline is then created as a derivative of the given one (`VK_PIPELINE_CREATE_DERIVATIVE_BIT`), which may speed up its creation. Note that all pipelines built by a PipelineBuilder allow derivatives.
+    /// 
     /// # Arguments
     /// - `pipeline`: The Pipeline to base this new one off.
     /// 
@@ -459,14 +584,139 @@ impl PipelineBuilder {
     /// 
     /// # Errors
     /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
-    pub fn shader(mut self, stage: ShaderStage, shader: Rc<Shader>) -> Self {
+    pub fn shader(self, stage: ShaderStage, shader: Rc<Shader>) -> Self {
+        self.shader_entry(stage, shader, "main")
+    }
+
+    /// Adds a Geometry shader to the pipeline.
+    /// 
+    /// Note that this requires the `geometryShader` feature to be enabled on the Device.
+    /// 
+    /// # Arguments
+    /// - `shader`: The Shader to run in the geometry stage.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    #[inline]
+    pub fn geometry_shader(self, shader: Rc<Shader>) -> Self {
+        self.shader(ShaderStage::GEOMETRY, shader)
+    }
+
+    /// Adds a pair of tessellation shaders to the pipeline, together with the TessellationState that describes the patches they receive.
+    /// 
+    /// Note that this requires the `tessellationShader` feature to be enabled on the Device, and that the pipeline must use `VertexTopology::PatchList` (see `PipelineBuilder::vertex_assembly()`).
+    /// 
+    /// # Arguments
+    /// - `control`: The Shader to run in the tessellation control stage.
+    /// - `evaluation`: The Shader to run in the tessellation evaluation stage.
+    /// - `state`: The TessellationState that defines the number of control points per patch.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    #[inline]
+    pub fn tessellation_shaders(self, control: Rc<Shader>, evaluation: Rc<Shader>, state: TessellationState) -> Self {
+        self.shader(ShaderStage::TESSELLATION_CONTROL, control)
+            .shader(ShaderStage::TESSELLATION_EVALUATION, evaluation)
+            .tessellation_state(state)
+    }
+
+    /// Adds a certain Shader to the pipeline, using the given entry point instead of 'main'.
+    /// 
+    /// This allows a single Shader module that contains entry points for multiple stages (e.g., a vertex and a fragment entry point) to be added once per stage.
+    /// 
+    /// # Arguments
+    /// - `stage`: The ShaderStage where the Shader will be ran.
+    /// - `shader`: The Shader to add to the Pipeline.
+    /// - `entry_point`: The name of the function in the Shader to run for this stage.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call. In particular, it passes an error if the entry point is not a valid C-string or if the Shader is known not to have it (see `Shader::entry_points()`).
+    pub fn shader_entry<S: AsRef<str>>(mut self, stage: ShaderStage, shader: Rc<Shader>, entry_point: S) -> Self {
         if self.error.is_some() { return self; }
+        let entry_point: &str = entry_point.as_ref();
+
+        // Make sure the entry point exists, if we know the Shader's entry points
+        if !shader.entry_points().is_empty() && !shader.entry_points().iter().any(|(s, name)| *s == stage && name == entry_point) {
+            self.error = Some(Error::UnknownEntryPoint{ stage, name: entry_point.into() });
+            return self;
+        }
+        let c_entry_point: CString = match CString::new(entry_point) {
+            Ok(entry_point) => entry_point,
+            Err(_)          => {
+                self.error = Some(Error::IllegalEntryPointName{ name: entry_point.into() });
+                return self;
+            }
+        };
 
         // Add the shader internally
-        self.shaders.push((stage, shader));
+        self.shaders.push((stage, shader, c_entry_point));
 
         // Done, return ourselves again
-        debug!("Defined {} Shader", stage);
+        debug!("Defined {} Shader (entry point '{}')", stage, entry_point);
+        self
+    }
+
+    /// Adds multiple (stage, entry point) combinations to the pipeline at once, which may share the same Shader module.
+    /// 
+    /// # Generic types
+    /// - `S`: The str-like type of the entry point names.
+    /// 
+    /// # Arguments
+    /// - `stages`: A list of (Shader, ShaderStage, entry point) tuples to add (see `PipelineBuilder::shader_entry()`).
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn shader_stages<S: AsRef<str>>(mut self, stages: Vec<(Rc<Shader>, ShaderStage, S)>) -> Self {
+        for (shader, stage, entry_point) in stages {
+            self = self.shader_entry(stage, shader, entry_point);
+        }
+        self
+    }
+
+    /// Adds a certain Shader to the pipeline, using the ShaderStage(s) inferred from its SPIR-V code.
+    /// 
+    /// Every entry point in the Shader is added for its own stage, so a module with (e.g.) both a vertex and a fragment entry point is added for both stages. If the stage cannot be inferred (see `Shader::entry_points()`), use `PipelineBuilder::shader()` to give it explicitly instead.
+    /// 
+    /// # Arguments
+    /// - `shader`: The Shader to add to the Pipeline.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call. In particular, it passes an error if the Shader's stage could not be inferred or if it has multiple entry points for the same stage.
+    pub fn auto_shader(mut self, shader: Rc<Shader>) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Try to get the stage(s) of the shader
+        let entry_points: Vec<(ShaderStage, String)> = shader.entry_points().to_vec();
+        if entry_points.is_empty() {
+            self.error = Some(Error::UnknownShaderStage);
+            return self;
+        }
+        for (i, (stage, _)) in entry_points.iter().enumerate() {
+            if entry_points[..i].iter().any(|(s, _)| s == stage) {
+                self.error = Some(Error::AmbiguousEntryPoint{ stage: *stage });
+                return self;
+            }
+        }
+
+        // Add the shader for every stage
+        for (stage, entry_point) in entry_points {
+            self = self.shader_entry(stage, shader.clone(), entry_point);
+        }
         self
     }
 
@@ -498,16 +748,37 @@ impl PipelineBuilder {
         };
 
         // Add the shader internally
-        self.shaders.push((stage, shader));
+        self.shader(stage, shader)
+    }
+
+    /// Sets the specialization constants of the Shader in the given stage.
+    /// 
+    /// Calling this again for the same stage replaces the previous values.
+    /// 
+    /// # Arguments
+    /// - `stage`: The ShaderStage whose Shader to specialize.
+    /// - `specialization`: The values of the specialization constants, as built (and checked against the Shader) by `SpecializationConstants`.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn specialization(mut self, stage: ShaderStage, specialization: Specialization) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Replace any existing values for this stage
+        self.specializations.retain(|(s, _)| *s != stage);
+        self.specializations.push((stage, specialization));
 
         // Done, return ourselves again
-        debug!("Defined {} Shader", stage);
+        debug!("Defined specialization constants for the {} Shader", stage);
         self
     }
 
     /// Define a VertexInputState for this Pipeline.
     /// 
-    /// This is one of the non-default functions that must always be called to define the input.
+    /// This is one of the non-default functions that must always be called to define the input, except for pipelines that use a mesh shader (which ignore it).
     /// 
     /// # Arguments
     /// - `info`: The new VertexInputState struct that describes how the input vertices look like.
@@ -553,9 +824,32 @@ impl PipelineBuilder {
         self
     }
 
+    /// Define a TessellationState for this Pipeline.
+    /// 
+    /// This must be called if the pipeline has tessellation shaders (which `PipelineBuilder::tessellation_shaders()` does for you), and is ignored otherwise.
+    /// 
+    /// # Arguments
+    /// - `info`: The new TessellationState struct that describes how patches are formed.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn tessellation_state(mut self, info: TessellationState) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Set the state
+        self.tessellation = Some(info);
+
+        // Done, return us again
+        debug!("Defined tessellation state");
+        self
+    }
+
     /// Defines how the viewport looks like, i.e., the size of the output frame.
     /// 
-    /// This is one of the non-default functions that must always be called to define the input.
+    /// This is one of the non-default functions that must always be called to define the input, unless both `DynamicState::Viewport` and `DynamicState::Scissor` are given to `PipelineBuilder::dynamic_state()`.
     /// 
     /// # Arguments
     /// - `info`: The new Viewport struct that describes how the output frame looks like.
@@ -604,17 +898,21 @@ impl PipelineBuilder {
     /// By default, no multisampling is used.
     /// 
     /// # Arguments
-    /// - `info`: The new Multisampling struct that describes the config.
+    /// - `info`: The new Multisampling struct that describes the config. Its sample count must match that of the colour and depth attachments of the RenderPass given at build time.
     /// 
     /// # Returns
     /// Because this function is consuming, returns the same instance of self as passed to it.
     /// 
     /// # Errors
     /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
-    pub fn multisampling(self, _info: MultisampleState) -> Self {
+    pub fn multisampling(mut self, info: MultisampleState) -> Self {
         if self.error.is_some() { return self; }
 
-        warn!("Called useless PipelineBuilder::multisampling() function");
+        // Set the state
+        self.multisampling = info;
+
+        // Done, return us again
+        debug!("Defined non-default multisample state");
         self
     }
 
@@ -666,36 +964,133 @@ impl PipelineBuilder {
 
     /// Defines the parts of the Pipeline that will be dynamic.
     /// 
-    /// By default, no dynamic state is defined.
+    /// By default, no dynamic state is defined. Note that the dynamic parts must be set on the CommandBuffer before drawing with the resulting Pipeline.
+    /// 
+    /// If both `DynamicState::Viewport` and `DynamicState::Scissor` are given, calling `PipelineBuilder::viewport()` becomes optional. In that case, set them with `CommandBuffer::set_viewport()` and `CommandBuffer::set_scissor()` before drawing, e.g., so the Pipeline needn't be rebuilt when a window is resized.
     /// 
     /// # Arguments
-    /// - `info`: A list of Pipeline parts (as DynamicStates) to make dynamic.
+    /// - `states`: A list of Pipeline parts (as DynamicStates) to make dynamic.
     /// 
     /// # Returns
     /// Because this function is consuming, returns the same instance of self as passed to it.
     /// 
     /// # Errors
     /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
-    pub fn dynamic_state(mut self, states: Vec<DynamicState>) -> Self {
+    pub fn dynamic_state(mut self, states: &[DynamicState]) -> Self {
         if self.error.is_some() { return self; }
 
         // Set the state
-        self.dynamic = states;
+        self.dynamic = states.to_vec();
 
         // Done, return us again
         debug!("Defined non-default dynamic state");
         self
     }
 
+    /// Gives the built Pipeline(s) a debug name, which shows up in validation messages and debuggers such as RenderDoc.
+    /// 
+    /// This is a no-op if debugging is not enabled on the Instance (see `Device::set_object_name()`).
+    /// 
+    /// # Arguments
+    /// - `name`: The name to give the Pipeline.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Set the name
+        self.name = Some(name.into());
+
+        // Done, return us again
+        self
+    }
+
+
+
+    /// Sets the subpass of the RenderPass (given at build time) in which the built pipeline(s) will be used.
+    /// 
+    /// By default, the pipeline is used in the first subpass (index 0).
+    /// 
+    /// # Arguments
+    /// - `subpass`: The index of the subpass. Its colour and depth attachments must exist and match the sample count of the pipeline.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn subpass(mut self, subpass: u32) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Set the index
+        self.subpass = subpass;
+
+        // Done, return us again
+        debug!("Set pipeline subpass to {}", subpass);
+        self
+    }
+
 
 
+    /// Collects creation feedback (i.e., how long creating the pipeline and its stages took, and whether they hit the PipelineCache) for the built pipeline(s), which may be retrieved using `Pipeline::creation_feedback()`.
+    /// 
+    /// Note that this requires `DeviceExtension::PipelineCreationFeedback` to be enabled on the Device.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `PipelineBuilder::build()` call.
+    pub fn creation_feedback(mut self) -> Self {
+        if self.error.is_some() { return self; }
+
+        // Set the flag
+        self.feedback = true;
+
+        // Done, return us again
+        debug!("Enabled pipeline creation feedback");
+        self
+    }
+
+    /// Returns a copy of the configuration in this builder, without any collected error or base pipeline.
+    fn clone_state(&self) -> Self {
+        Self {
+            error : None,
+
+            cache         : self.cache.clone(),
+            base_pipeline : None,
+            name          : self.name.clone(),
+            feedback      : self.feedback,
+            subpass       : self.subpass,
+
+            vertex_assembly : self.vertex_assembly.clone(),
+            multisampling   : self.multisampling.clone(),
+            depth_testing   : self.depth_testing.clone(),
+            colour_blending : self.colour_blending.clone(),
+            dynamic         : self.dynamic.clone(),
+
+            shaders         : self.shaders.clone(),
+            specializations : self.specializations.clone(),
+            vertex_input    : self.vertex_input.clone(),
+            tessellation    : self.tessellation.clone(),
+            viewport        : self.viewport.clone(),
+            rasterization   : self.rasterization.clone(),
+        }
+    }
+
     /// Builds the Pipeline as a Graphics pipeline, requiring at least the following functions:
     /// - `PipelineBuidler::shader()` or `PipelineBuilder::try_shader()`, for a Vertex shader.
     /// - `PipelineBuidler::shader()` or `PipelineBuilder::try_shader()`, for a Fragment shader.
     /// - `PipelineBuilder::vertex_input()`.
-    /// - `PipelineBuilder::viewport()`.
+    /// - `PipelineBuilder::viewport()`, unless both the viewport and the scissor are given as dynamic state.
     /// - `PipelineBuilder::rasterization()`.
     /// 
+    /// Alternatively, a Mesh shader (and optionally a Task shader) may be given instead of the Vertex shader. In that case, the pipeline is built without vertex input and assembly states (so `PipelineBuilder::vertex_input()` is not needed), but the Device must have `VK_EXT_mesh_shader` enabled and the pipeline may not use any of the other pre-rasterization stages.
+    /// 
     /// After the build is complete, you can use this builder to generate more pipelines. Those subsequent pipelines will use this pipeline as their base (unless `PipelineBuilder::set_pipeline()` is called to override it).
     /// 
     /// # Arguments
@@ -707,20 +1102,81 @@ impl PipelineBuilder {
     /// A new Pipeline on success.
     /// 
     /// # Errors
-    /// This function returns an error if the backend Vulkan driver errors while creating the pipeline, or if an error occurred during any of the other functions.
-    pub fn build(&mut self, device: Rc<Device>, layout: Rc<PipelineLayout>, render_pass: Rc<RenderPass>) -> Result<Rc<Pipeline>, Error> {
-        let Self { ref base_pipeline, ref shaders, ref vertex_input, ref vertex_assembly, ref viewport, ref rasterization, ref multisampling, ref depth_testing, ref colour_blending, .. } = self;
+    /// This function returns an error if the backend Vulkan driver errors while creating the pipeline, if the pipeline's subpass does not exist in the RenderPass or its sample count does not match that of the subpass' attachments, if mesh shaders are used incorrectly or without `VK_EXT_mesh_shader`, if primitive restart is enabled for a topology other than a strip or fan, if alpha-to-one is used without the `alphaToOne` feature, if dual-source blending is used without the `dualSrcBlend` feature or for more attachments than the Device supports, if geometry or tessellation shaders are used without their feature or tessellation shaders are used without a valid TessellationState and patch list topology, if creation feedback is requested without `VK_EXT_pipeline_creation_feedback`, or if an error occurred during any of the other functions.
+    pub fn build(&mut self, device: Shared<Device>, layout: Shared<PipelineLayout>, render_pass: Rc<RenderPass>) -> Result<Rc<Pipeline>, Error> {
+        // If any errors, then return those
+        if let Some(err) = self.error.take() { return Err(err); }
+        let Self { ref base_pipeline, ref shaders, ref specializations, ref vertex_input, ref vertex_assembly, ref tessellation, ref viewport, ref rasterization, ref multisampling, ref depth_testing, ref colour_blending, ref dynamic, subpass, .. } = *self;
+
+        // Make sure the subpass exists and that the sample count matches that of the attachments used in it
+        let subpass_samples: Vec<SampleCount> = match render_pass.subpass_samples(subpass) {
+            Ok(samples) => samples,
+            Err(err)    => { return Err(Error::SubpassError{ err }); }
+        };
+        if let Some(samples) = subpass_samples.into_iter().find(|samples| *samples != multisampling.samples) {
+            return Err(Error::SampleCountMismatch{ pipeline: multisampling.samples, attachment: samples });
+        }
+
+        // Check if this is a mesh shader pipeline and, if so, if it is a valid one
+        let mesh: bool = shaders.iter().any(|(stage, _, _)| *stage == ShaderStage::TASK || *stage == ShaderStage::MESH);
+        if mesh {
+            if !device.enabled_extensions().contains(&DeviceExtension::MeshShader) { return Err(Error::MeshShaderNotEnabled); }
+            if !shaders.iter().any(|(stage, _, _)| *stage == ShaderStage::MESH) { return Err(Error::MeshShaderMissing); }
+            if let Some((stage, _, _)) = shaders.iter().find(|(stage, _, _)| *stage == ShaderStage::VERTEX || *stage == ShaderStage::TESSELLATION_CONTROL || *stage == ShaderStage::TESSELLATION_EVALUATION || *stage == ShaderStage::GEOMETRY) {
+                return Err(Error::MeshShaderIncompatibleStage{ stage: *stage });
+            }
+        }
+
+        // Geometry and tessellation shaders must be enabled, and the latter need both stages and a patch list with a valid size
+        if shaders.iter().any(|(stage, _, _)| *stage == ShaderStage::GEOMETRY) && !device.enabled_features().geometry_shader { return Err(Error::GeometryShaderNotEnabled); }
+        let has_control: bool    = shaders.iter().any(|(stage, _, _)| *stage == ShaderStage::TESSELLATION_CONTROL);
+        let has_evaluation: bool = shaders.iter().any(|(stage, _, _)| *stage == ShaderStage::TESSELLATION_EVALUATION);
+        let tessellated: bool    = has_control || has_evaluation;
+        if tessellated {
+            if !device.enabled_features().tessellation_shader { return Err(Error::TessellationShaderNotEnabled); }
+            if !has_control { return Err(Error::TessellationShaderMissing{ stage: ShaderStage::TESSELLATION_CONTROL }); }
+            if !has_evaluation { return Err(Error::TessellationShaderMissing{ stage: ShaderStage::TESSELLATION_EVALUATION }); }
+            if !matches!(vertex_assembly.topology, VertexTopology::PatchList) { return Err(Error::TessellationTopologyMismatch{ topology: vertex_assembly.topology }); }
+            let got: u32 = tessellation.as_ref().map(|state| state.patch_control_points).unwrap_or(0);
+            let max: u32 = device.get_physical_device_props().limits.max_tessellation_patch_size;
+            if got == 0 || got > max { return Err(Error::IllegalPatchControlPoints{ got, max }); }
+        }
+
+        // Creation feedback needs its extension
+        if self.feedback && !device.enabled_extensions().contains(&DeviceExtension::PipelineCreationFeedback) { return Err(Error::CreationFeedbackNotEnabled); }
+
+        // Primitive restart only makes sense for connected primitives
+        if !mesh && !vertex_assembly.is_valid() { return Err(Error::PrimitiveRestartUnsupported{ topology: vertex_assembly.topology }); }
+
+        // Alpha-to-one must be enabled
+        if multisampling.alpha_to_one && !device.enabled_features().alpha_to_one { return Err(Error::AlphaToOneNotEnabled); }
+
+        // Dual-source blending must be enabled and supported for the number of attachments that use it
+        let dual_source: usize = colour_blending.dual_source_count();
+        if dual_source > 0 {
+            if !device.enabled_features().dual_src_blend { return Err(Error::DualSrcBlendNotEnabled); }
+            let max: u32 = device.get_physical_device_props().limits.max_fragment_dual_src_attachments;
+            if dual_source > max as usize { return Err(Error::TooManyDualSrcAttachments{ got: dual_source, max }); }
+        }
 
         // First, cast the stages and shaders to VkShaderStageFlags and VkShaderModules
-        let entry_point = CString::new("main").unwrap();
-        let vk_shader_stages: Vec<vk::PipelineShaderStageCreateInfo> = shaders.iter().map(|(stage, shader)| populate_shader_stage_info(&entry_point, stage.into(), shader.vk())).collect();
+        let vk_specializations: Vec<Option<vk::SpecializationInfo>> = shaders.iter().map(|(stage, _, _)| specializations.iter().find(|(s, _)| s == stage).map(|(_, specialization)| specialization.vk())).collect();
+        let vk_shader_stages: Vec<vk::PipelineShaderStageCreateInfo> = shaders.iter().zip(vk_specializations.iter()).map(|((stage, shader, entry_point), specialization)| populate_shader_stage_info(entry_point, stage.into(), shader.vk(), specialization.as_ref())).collect();
+
+        // Next, cast the vertex input & assemply info (which mesh shader pipelines do without)
+        let (vk_vertex_input, _vk_vertex_input_mem) = if !mesh {
+            let (info, mem): (vk::PipelineVertexInputStateCreateInfo, (Vec<vk::VertexInputAttributeDescription>, Vec<vk::VertexInputBindingDescription>)) = vertex_input.as_ref().expect("Called PipelineBuilder::build() without calling PipelineBuilder::vertex_input()").clone().into();
+            (Some(info), Some(mem))
+        } else {
+            (None, None)
+        };
+        let vk_vertex_assembly: Option<vk::PipelineInputAssemblyStateCreateInfo> = if !mesh { Some(vertex_assembly.clone().into()) } else { None };
 
-        // Next, cast the vertex input & assemply info
-        let (vk_vertex_input, _vk_vertex_input_mem): (vk::PipelineVertexInputStateCreateInfo, (Vec<vk::VertexInputAttributeDescription>, Vec<vk::VertexInputBindingDescription>)) = vertex_input.as_ref().expect("Called PipelineBuilder::build() without calling PipelineBuilder::vertex_input()").clone().into();
-        let vk_vertex_assembly: vk::PipelineInputAssemblyStateCreateInfo = vertex_assembly.clone().into();
+        // The tessellation state is only given if there are tessellation shaders
+        let vk_tessellation: Option<vk::PipelineTessellationStateCreateInfo> = if tessellated { tessellation.clone().map(|state| state.into()) } else { None };
 
-        // Then, cast the Viewport
-        let (vk_viewport, _vk_viewport_mem): (vk::PipelineViewportStateCreateInfo, (Box<vk::Viewport>, Box<vk::Rect2D>)) = viewport.as_ref().expect("Called PipelineBuilder::build() without calling PipelineBuilder::viewport()").clone().into();
+        // Then, cast the Viewport (which may be left undefined if it's completely dynamic)
+        let (vk_viewport, _vk_viewport_mem): (vk::PipelineViewportStateCreateInfo, Option<ViewportMem>) = viewport_info(viewport.as_ref(), dynamic).expect("Called PipelineBuilder::build() without calling PipelineBuilder::viewport() (and the viewport and scissor are not both dynamic)");
 
         // Cast the rasterizer & multisampling states
         let vk_rasterizer: vk::PipelineRasterizationStateCreateInfo = rasterization.as_ref().expect("Called PipelineBuilder::build() without calling PipelineBuilder::rasterization()").clone().into();
@@ -730,22 +1186,49 @@ impl PipelineBuilder {
         let vk_depth_testing: vk::PipelineDepthStencilStateCreateInfo = depth_testing.clone().into();
         let (vk_colour_blend, _vk_colour_blend_mem): (vk::PipelineColorBlendStateCreateInfo, Vec<vk::PipelineColorBlendAttachmentState>) = colour_blending.clone().into();
 
+        // Cast the dynamic states (if any)
+        let vk_dynamic_states: Vec<vk::DynamicState> = dynamic.iter().map(|state| (*state).into()).collect();
+        let vk_dynamic: Option<vk::PipelineDynamicStateCreateInfo> = if !vk_dynamic_states.is_empty() { Some(populate_dynamic_state_info(&vk_dynamic_states)) } else { None };
+
+        // Every pipeline may serve as a base; and is a derivative if we have a base
+        let flags: vk::PipelineCreateFlags = if base_pipeline.is_some() {
+            vk::PipelineCreateFlags::ALLOW_DERIVATIVES | vk::PipelineCreateFlags::DERIVATIVE
+        } else {
+            vk::PipelineCreateFlags::ALLOW_DERIVATIVES
+        };
+
         // Now populate the struct
-        let pipeline_info = populate_graphics_pipeline_info(
+        let mut pipeline_info = populate_graphics_pipeline_info(
+            flags,
             base_pipeline.as_ref().map(|pipeline| pipeline.vk()).unwrap_or(vk::Pipeline::null()),
             &vk_shader_stages,
-            &vk_vertex_input,
-            &vk_vertex_assembly,
+            vk_vertex_input.as_ref(),
+            vk_vertex_assembly.as_ref(),
+            vk_tessellation.as_ref(),
             &vk_viewport,
             &vk_rasterizer,
             &vk_multisampling,
             &vk_depth_testing,
             &vk_colour_blend,
+            vk_dynamic.as_ref(),
             layout.vk(),
             render_pass.vk(),
-            0
+            subpass,
         );
 
+        // Chain the feedback struct, if requested
+        let mut vk_feedback: vk::PipelineCreationFeedback = Default::default();
+        let mut vk_stage_feedback: Vec<vk::PipelineCreationFeedback> = vec![ Default::default(); vk_shader_stages.len() ];
+        let feedback_info = vk::PipelineCreationFeedbackCreateInfo {
+            s_type : vk::StructureType::PIPELINE_CREATION_FEEDBACK_CREATE_INFO,
+            p_next : ptr::null(),
+
+            p_pipeline_creation_feedback           : &mut vk_feedback,
+            pipeline_stage_creation_feedback_count : vk_stage_feedback.len() as u32,
+            p_pipeline_stage_creation_feedbacks    : vk_stage_feedback.as_mut_ptr(),
+        };
+        if self.feedback { pipeline_info.p_next = &feedback_info as *const vk::PipelineCreationFeedbackCreateInfo as *const c_void; }
+
         // With that, create the pipeline...
         let pipeline = unsafe {
             match device.create_graphics_pipelines(self.cache.as_ref().map(|cache| cache.vk()).unwrap_or(vk::PipelineCache::null()), &[pipeline_info], None) {
@@ -756,12 +1239,25 @@ impl PipelineBuilder {
                 Err((_, err)) => { return Err(Error::PipelineCreateError{ err }); }
             }
         };
+        device.try_set_object_name(pipeline, self.name.as_deref());
+
+        // Collect the feedback, if any
+        let feedback: Option<PipelineFeedback> = if self.feedback {
+            Some(PipelineFeedback {
+                pipeline : PipelineCreationFeedback::from_vk(vk_feedback),
+                stages   : self.shaders.iter().zip(vk_stage_feedback).map(|((stage, _, _), feedback)| (*stage, PipelineCreationFeedback::from_vk(feedback))).collect(),
+            })
+        } else {
+            None
+        };
 
         // Wrap it in a Pipeline struct, set it as the base for subsequent calls and return it
         let pipeline = Rc::new(Pipeline {
             device,
             layout,
             render_pass,
+            state : self.clone_state(),
+            feedback,
 
             pipeline,
         });
@@ -776,29 +1272,57 @@ impl PipelineBuilder {
 /// Wraps around a Vulkan Pipeline, which describes the process of rendering some vertices to an image.
 pub struct Pipeline {
     /// The parent device of this pipeline.
-    device      : Rc<Device>,
+    device      : Shared<Device>,
     /// The layout for this Pipeline.
-    layout      : Rc<PipelineLayout>,
+    layout      : Shared<PipelineLayout>,
     /// The render pass for this Pipeline.
     render_pass : Rc<RenderPass>,
+    /// The configuration this Pipeline was built with, so that we may derive variants of it.
+    state       : PipelineBuilder,
+    /// The creation feedback of this Pipeline, if it was requested.
+    feedback    : Option<PipelineFeedback>,
 
     /// The VkPipeline that we wrap around.
     pipeline : vk::Pipeline,
 }
 
 impl Pipeline {
+    /// Creates a variant of this Pipeline as a pipeline derivative (`VK_PIPELINE_CREATE_DERIVATIVE_BIT`), which is typically faster than building it from scratch.
+    /// 
+    /// The new Pipeline starts from the same configuration (and Device, PipelineLayout and RenderPass) as this one, which may then be changed by the given closure.
+    /// 
+    /// # Arguments
+    /// - `modify`: A closure that receives a PipelineBuilder with this Pipeline's configuration and returns it with the states that differ for the variant (e.g., `|builder| builder.colour_blending(...)`).
+    /// 
+    /// # Returns
+    /// A new Pipeline that is derived from this one.
+    /// 
+    /// # Errors
+    /// This function errors if building the new Pipeline fails (see `PipelineBuilder::build()`).
+    pub fn derive(self: &Rc<Self>, modify: impl FnOnce(PipelineBuilder) -> PipelineBuilder) -> Result<Rc<Self>, Error> {
+        // Start from our own state, let the closure change it and make sure we are the base
+        let mut builder: PipelineBuilder = modify(self.state.clone_state()).set_pipeline(self.clone());
+        builder.build(self.device.clone(), self.layout.clone(), self.render_pass.clone())
+    }
+
+
+
     /// Returns the parent device of this pipeline.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the layout of this pipeline.
     #[inline]
-    pub fn layout(&self) -> &Rc<PipelineLayout> { &self.layout }
+    pub fn layout(&self) -> &Shared<PipelineLayout> { &self.layout }
 
     /// Returns the render pass of this pipeline.
     #[inline]
     pub fn render_pass(&self) -> &Rc<RenderPass> { &self.render_pass }
 
+    /// Returns the creation feedback of this pipeline, if it was requested with `PipelineBuilder::creation_feedback()`.
+    #[inline]
+    pub fn creation_feedback(&self) -> Option<&PipelineFeedback> { self.feedback.as_ref() }
+
 
 
     /// Returns the VkPipeline behind this pipeline.
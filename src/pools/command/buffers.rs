@@ -12,7 +12,9 @@
 //!   Contains the buffer definitions for this type of Pool.
 // 
 
-use std::cell::{RefCell, RefMut};
+use std::cell::{Cell, RefCell, RefMut};
+use std::ffi::c_void;
+use std::ops::Range;
 use std::ptr;
 use std::rc::Rc;
 
@@ -20,15 +22,92 @@ use ash::vk;
 
 pub use crate::pools::errors::CommandPoolError as Error;
 use crate::log_destroy;
-use crate::auxillary::enums::{BindPoint, CommandBufferLevel};
-use crate::auxillary::flags::{CommandBufferFlags, CommandBufferUsageFlags};
-use crate::auxillary::structs::Rect2D;
+#[cfg(debug_assertions)]
+use crate::warn;
+use crate::auxillary::enums::{AttachmentLoadOp, AttachmentStoreOp, BindPoint, CommandBufferLevel, ImageFormat, ImageLayout, IndexType, SubpassContents};
+use crate::auxillary::flags::{CommandBufferFlags, CommandBufferUsageFlags, ImageAspectFlags, PipelineStage, SampleCount, ShaderStageFlags};
+use crate::auxillary::structs::{ImageCopy, Rect2D};
 use crate::device::Device;
+use crate::descriptors::DescriptorSet;
+use crate::layout::PipelineLayout;
 use crate::pipeline::Pipeline;
 use crate::render_pass::RenderPass;
 use crate::framebuffer::Framebuffer;
-use crate::pools::memory::{Buffer, IndexBuffer, VertexBuffer};
+use crate::image::{Image, View};
+use crate::pools::memory::{Buffer, BufferSlice, IndexBuffer, VertexBuffer};
 use crate::pools::command::Pool as CommandPool;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_depth_stencil_image() {
+        // A combined depth/stencil image copies both of its aspects in one region
+        let format: ImageFormat = ImageFormat::D24UNormS8UInt;
+        let copy: vk::ImageCopy = populate_image_copy(format.default_aspect().into(), vk::Extent3D{ width: 800, height: 600, depth: 1 });
+        assert_eq!(copy.src_subresource.aspect_mask, vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL);
+        assert_eq!(copy.dst_subresource.aspect_mask, vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL);
+        assert_eq!((copy.extent.width, copy.extent.height, copy.extent.depth), (800, 600, 1));
+
+        // Transitions (and clears) of such an image cover both aspects as well
+        let barrier: vk::ImageMemoryBarrier = populate_image_barrier(vk::Image::null(), vk::ImageLayout::UNDEFINED, vk::ImageLayout::TRANSFER_DST_OPTIMAL, format.default_aspect().into(), vk::AccessFlags::empty(), vk::AccessFlags::TRANSFER_WRITE);
+        assert_eq!(barrier.subresource_range.aspect_mask, vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL);
+
+        // Other formats only get the aspects they have
+        assert_eq!(vk::ImageAspectFlags::from(ImageFormat::D32SFloat.default_aspect()), vk::ImageAspectFlags::DEPTH);
+        assert_eq!(vk::ImageAspectFlags::from(ImageFormat::S8UInt.default_aspect()), vk::ImageAspectFlags::STENCIL);
+        assert_eq!(vk::ImageAspectFlags::from(ImageFormat::B8G8R8A8SRgb.default_aspect()), vk::ImageAspectFlags::COLOR);
+        assert_eq!(ImageAspectFlags::from(vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL), ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL);
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Returns the access mask and pipeline stage(s) that touch an image in the given layout.
+/// 
+/// # Arguments
+/// - `layout`: The ImageLayout to get the masks for.
+/// - `src`: Whether the layout is the one we transition from (true) or the one we transition to (false).
+/// 
+/// # Returns
+/// A tuple of the VkAccessFlags and the VkPipelineStageFlags that should be waited on (if `src`) or that should wait (if not `src`).
+fn layout_masks(layout: ImageLayout, src: bool) -> (vk::AccessFlags, vk::PipelineStageFlags) {
+    use ImageLayout::*;
+    match layout {
+        Undefined      => (vk::AccessFlags::empty(), vk::PipelineStageFlags::TOP_OF_PIPE),
+        Preinitialized => (vk::AccessFlags::HOST_WRITE, vk::PipelineStageFlags::HOST),
+        General        => (vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE, vk::PipelineStageFlags::ALL_COMMANDS),
+
+        ColourAttachment     => (vk::AccessFlags::COLOR_ATTACHMENT_READ | vk::AccessFlags::COLOR_ATTACHMENT_WRITE, vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT),
+        DepthStencil         => (vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE, vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS),
+        DepthStencilReadOnly => (vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ | vk::AccessFlags::SHADER_READ, vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::FRAGMENT_SHADER),
+        ShaderReadOnly       => (vk::AccessFlags::SHADER_READ, vk::PipelineStageFlags::VERTEX_SHADER | vk::PipelineStageFlags::FRAGMENT_SHADER | vk::PipelineStageFlags::COMPUTE_SHADER),
+        // The presentation engine synchronises through semaphores, so there is nothing to wait on or make available
+        Present              => (vk::AccessFlags::empty(), if src { vk::PipelineStageFlags::TOP_OF_PIPE } else { vk::PipelineStageFlags::BOTTOM_OF_PIPE }),
+        // A shared presentable image is accessed by both the application and the presentation engine, so treat it like the general layout
+        SharedPresent        => (vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE, vk::PipelineStageFlags::ALL_COMMANDS),
+
+        DepthReadOnlyStencilAttachment | DepthAttachmentStencilReadOnly => (vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE | vk::AccessFlags::SHADER_READ, vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS | vk::PipelineStageFlags::FRAGMENT_SHADER),
+        DepthAttachment | StencilAttachment                             => (vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE, vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS),
+
+        TransferSrc => (vk::AccessFlags::TRANSFER_READ, vk::PipelineStageFlags::TRANSFER),
+        TransferDst => (vk::AccessFlags::TRANSFER_WRITE, vk::PipelineStageFlags::TRANSFER),
+
+        // We don't know whether these are used for colour or depth/stencil images, so we conservatively cover both
+        AttachmentOptimal => (vk::AccessFlags::COLOR_ATTACHMENT_READ | vk::AccessFlags::COLOR_ATTACHMENT_WRITE | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE, vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS),
+        ReadOnlyOptimal   => (vk::AccessFlags::SHADER_READ | vk::AccessFlags::INPUT_ATTACHMENT_READ | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ, vk::PipelineStageFlags::VERTEX_SHADER | vk::PipelineStageFlags::FRAGMENT_SHADER | vk::PipelineStageFlags::COMPUTE_SHADER | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS),
+    }
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -52,6 +131,31 @@ fn populate_begin_info(flags: vk::CommandBufferUsageFlags, inheritance_info: *co
     }
 }
 
+/// Populates the inheritance info for beginning a secondary command buffer.
+/// 
+/// # Arguments
+/// - `render_pass`: The VkRenderPass that the secondary command buffer will be executed in. May be a null handle if the buffer is not executed within a render pass.
+/// - `subpass`: The index of the subpass within the `render_pass` that the buffer will be executed in.
+/// - `framebuffer`: The VkFramebuffer that the buffer will render to. May be a null handle if it is not known.
+#[inline]
+fn populate_inheritance_info(render_pass: vk::RenderPass, subpass: u32, framebuffer: vk::Framebuffer) -> vk::CommandBufferInheritanceInfo {
+    vk::CommandBufferInheritanceInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+        p_next : ptr::null(),
+
+        // Set the render pass & framebuffer to inherit
+        render_pass,
+        subpass,
+        framebuffer,
+
+        // We do not (yet) support inheriting queries
+        occlusion_query_enable : vk::FALSE,
+        query_flags            : vk::QueryControlFlags::empty(),
+        pipeline_statistics    : vk::QueryPipelineStatisticFlags::empty(),
+    }
+}
+
 /// Populates a VkRenderPassBeginInfo struct.
 /// 
 /// # Arguments
@@ -79,15 +183,218 @@ fn populate_render_pass_begin_info(render_pass: vk::RenderPass, framebuffer: vk:
     }
 }
 
+/// Populates a VkRenderingAttachmentInfo struct that describes a single attachment of a dynamic rendering scope.
+/// 
+/// # Arguments
+/// - `view`: The VkImageView to render to.
+/// - `layout`: The VkImageLayout that the view will be in during rendering.
+/// - `load_op`: The VkAttachmentLoadOp that determines what happens with the attachment when the scope begins.
+/// - `store_op`: The VkAttachmentStoreOp that determines what happens with the attachment when the scope ends.
+/// - `clear_value`: The VkClearValue to clear the attachment with if `load_op` is `VK_ATTACHMENT_LOAD_OP_CLEAR`.
+#[inline]
+fn populate_rendering_attachment_info(view: vk::ImageView, layout: vk::ImageLayout, load_op: vk::AttachmentLoadOp, store_op: vk::AttachmentStoreOp, clear_value: vk::ClearValue) -> vk::RenderingAttachmentInfo {
+    vk::RenderingAttachmentInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::RENDERING_ATTACHMENT_INFO,
+        p_next : ptr::null(),
+
+        // Set the view to render to
+        image_view   : view,
+        image_layout : layout,
+
+        // We do not (yet) support resolving
+        resolve_mode         : vk::ResolveModeFlags::NONE,
+        resolve_image_view   : vk::ImageView::null(),
+        resolve_image_layout : vk::ImageLayout::UNDEFINED,
+
+        // Set what to do with the attachment
+        load_op,
+        store_op,
+        clear_value,
+    }
+}
+
+/// Populates a VkRenderingInfo struct that describes a dynamic rendering scope.
+/// 
+/// # Arguments
+/// - `flags`: The VkRenderingFlags that, among other things, determine if the contents are recorded in secondary command buffers.
+/// - `render_area`: A VkRect2D detailling the area of the attachments to render to.
+/// - `colour_attachments`: The VkRenderingAttachmentInfo structs that describe the colour attachments.
+/// - `depth_attachment`: The VkRenderingAttachmentInfo struct that describes the depth attachment, or NULL if there is none.
+#[inline]
+fn populate_rendering_info(flags: vk::RenderingFlags, render_area: vk::Rect2D, colour_attachments: &[vk::RenderingAttachmentInfo], depth_attachment: *const vk::RenderingAttachmentInfo) -> vk::RenderingInfo {
+    vk::RenderingInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::RENDERING_INFO,
+        p_next : ptr::null(),
+        flags,
+
+        // Set the area to render to
+        render_area,
+        layer_count : 1,
+        view_mask   : 0,
+
+        // Set the attachments
+        color_attachment_count : colour_attachments.len() as u32,
+        p_color_attachments    : colour_attachments.as_ptr(),
+        p_depth_attachment     : depth_attachment,
+        p_stencil_attachment   : ptr::null(),
+    }
+}
+
+/// Populates a VkCommandBufferInheritanceRenderingInfo struct that describes the dynamic rendering scope a secondary command buffer will be executed in.
+/// 
+/// # Arguments
+/// - `colour_formats`: The VkFormats of the colour attachments in the scope.
+/// - `depth_format`: The VkFormat of the depth attachment in the scope, or `VK_FORMAT_UNDEFINED` if there is none.
+/// - `samples`: The number of samples used by the attachments.
+#[inline]
+fn populate_inheritance_rendering_info(colour_formats: &[vk::Format], depth_format: vk::Format, samples: vk::SampleCountFlags) -> vk::CommandBufferInheritanceRenderingInfo {
+    vk::CommandBufferInheritanceRenderingInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::COMMAND_BUFFER_INHERITANCE_RENDERING_INFO,
+        p_next : ptr::null(),
+        flags  : vk::RenderingFlags::empty(),
+
+        // Set the formats of the attachments
+        view_mask                  : 0,
+        color_attachment_count     : colour_formats.len() as u32,
+        p_color_attachment_formats : colour_formats.as_ptr(),
+        depth_attachment_format    : depth_format,
+        stencil_attachment_format  : vk::Format::UNDEFINED,
+
+        // Set the sample count
+        rasterization_samples : samples,
+    }
+}
+
+
+
+
+
+/// Populates a VkImageMemoryBarrier struct that transitions the entire given image from one layout to another.
+/// 
+/// # Arguments
+/// - `image`: The VkImage to transition.
+/// - `old_layout`: The VkImageLayout the image is currently in.
+/// - `new_layout`: The VkImageLayout to transition the image to.
+/// - `aspect`: The VkImageAspectFlags of the image that are transitioned.
+/// - `src_access_mask`: The VkAccessFlags of the operations that have to be made available before the transition.
+/// - `dst_access_mask`: The VkAccessFlags of the operations that have to wait for the transition.
+#[inline]
+fn populate_image_barrier(image: vk::Image, old_layout: vk::ImageLayout, new_layout: vk::ImageLayout, aspect: vk::ImageAspectFlags, src_access_mask: vk::AccessFlags, dst_access_mask: vk::AccessFlags) -> vk::ImageMemoryBarrier {
+    vk::ImageMemoryBarrier {
+        // Do the standard stuff
+        s_type : vk::StructureType::IMAGE_MEMORY_BARRIER,
+        p_next : ptr::null(),
+
+        // Set the access masks
+        src_access_mask,
+        dst_access_mask,
+
+        // Set the layouts
+        old_layout,
+        new_layout,
+
+        // We do not transfer queue ownership
+        src_queue_family_index : vk::QUEUE_FAMILY_IGNORED,
+        dst_queue_family_index : vk::QUEUE_FAMILY_IGNORED,
+
+        // Set the image and the (whole) area of it
+        image,
+        subresource_range : populate_full_range(aspect),
+    }
+}
+
+
+
+
+
+/// Populates a VkImageCopy struct that copies the entire first mip level of one image to another.
+/// 
+/// # Arguments
+/// - `aspect`: The VkImageAspectFlags of both images that are copied. For combined depth/stencil images, this may (and should) contain both aspects.
+/// - `extent`: The VkExtent3D that describes the size of the area to copy.
+#[inline]
+fn populate_image_copy(aspect: vk::ImageAspectFlags, extent: vk::Extent3D) -> vk::ImageCopy {
+    // Both images use the same subresource
+    let subresource = vk::ImageSubresourceLayers {
+        aspect_mask      : aspect,
+        mip_level        : 0,
+        base_array_layer : 0,
+        layer_count      : 1,
+    };
+
+    vk::ImageCopy {
+        // Set the source area
+        src_subresource : subresource,
+        src_offset      : vk::Offset3D{ x: 0, y: 0, z: 0 },
+
+        // Set the destination area
+        dst_subresource : subresource,
+        dst_offset      : vk::Offset3D{ x: 0, y: 0, z: 0 },
+
+        // Set the size of the copy
+        extent,
+    }
+}
+
+/// Populates a VkImageSubresourceRange struct that covers an entire image.
+/// 
+/// # Arguments
+/// - `aspect`: The VkImageAspectFlags of the image to cover.
+#[inline]
+fn populate_full_range(aspect: vk::ImageAspectFlags) -> vk::ImageSubresourceRange {
+    vk::ImageSubresourceRange {
+        aspect_mask      : aspect,
+        base_mip_level   : 0,
+        level_count      : vk::REMAINING_MIP_LEVELS,
+        base_array_layer : 0,
+        layer_count      : vk::REMAINING_ARRAY_LAYERS,
+    }
+}
+
 
 
 
 
 /***** LIBRARY *****/
+/// Describes a single attachment to render to in a dynamic rendering scope (see `CommandBuffer::begin_rendering()`).
+#[derive(Clone)]
+pub struct RenderingAttachment<'a> {
+    /// The View of the image to render to.
+    pub view        : &'a Rc<View>,
+    /// The layout that the image will be in while rendering.
+    pub layout      : ImageLayout,
+    /// Determines what happens with the attachment when rendering begins.
+    pub load_op     : AttachmentLoadOp,
+    /// Determines what happens with the attachment when rendering ends.
+    pub store_op    : AttachmentStoreOp,
+    /// The value to clear the attachment with if `load_op` is `AttachmentLoadOp::Clear`. For depth attachments, only the first element is used.
+    pub clear_value : [f32; 4],
+}
+
+impl<'a> RenderingAttachment<'a> {
+    /// Converts this attachment to its Vulkan counterpart.
+    /// 
+    /// # Arguments
+    /// - `depth`: Whether this attachment is a depth attachment (which determines how the clear value is interpreted).
+    fn vk(&self, depth: bool) -> vk::RenderingAttachmentInfo {
+        let clear_value: vk::ClearValue = if depth {
+            vk::ClearValue { depth_stencil: vk::ClearDepthStencilValue { depth: self.clear_value[0], stencil: 0 } }
+        } else {
+            vk::ClearValue { color: vk::ClearColorValue { float32: self.clear_value } }
+        };
+        populate_rendering_attachment_info(self.view.vk(), self.layout.into(), self.load_op.into(), self.store_op.into(), clear_value)
+    }
+}
+
+
+
 /// The CommandBuffer is used to record various GPU commands in.
 pub struct CommandBuffer {
     /// The parent CommandPool where this buffer was allocated from.
-    device  : Rc<Device>,
+    device  : Shared<Device>,
     /// The parent CommandPool where this buffer was allocated from.
     pool    : Rc<RefCell<CommandPool>>,
 
@@ -95,6 +402,11 @@ pub struct CommandBuffer {
     vk_pool : vk::CommandPool,
     /// The VkCommandBuffer around which we wrap.
     buffer  : vk::CommandBuffer,
+    /// The level of this CommandBuffer (i.e., primary or secondary).
+    level   : CommandBufferLevel,
+
+    /// The BindPoint of the most recently bound Pipeline, which descriptor set bindings are checked against (in debug builds).
+    bound_point : Cell<Option<BindPoint>>,
 }
 
 impl CommandBuffer {
@@ -113,7 +425,7 @@ impl CommandBuffer {
     /// 
     /// # Errors
     /// This function errors if the given CommandPool could not allocate a new Buffer of this type.
-    pub fn new(device: Rc<Device>, pool: Rc<RefCell<CommandPool>>, index: u32, flags: CommandBufferFlags) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<CommandPool>>, index: u32, flags: CommandBufferFlags) -> Result<Rc<Self>, Error> {
         // Allocate a new vk::CommandBuffer
         let (vk_pool, buffer): (vk::CommandPool, vk::CommandBuffer) = {
             // Get a lock on the pool
@@ -130,6 +442,9 @@ impl CommandBuffer {
 
             vk_pool,
             buffer,
+            level : CommandBufferLevel::Primary,
+
+            bound_point : Cell::new(None),
         }))
     }
 
@@ -148,7 +463,7 @@ impl CommandBuffer {
     /// 
     /// # Errors
     /// This function errors if the given CommandPool could not allocate a new Buffer of this type.
-    pub fn secondary(device: Rc<Device>, pool: Rc<RefCell<CommandPool>>, index: u32, flags: CommandBufferFlags) -> Result<Rc<Self>, Error> {
+    pub fn secondary(device: Shared<Device>, pool: Rc<RefCell<CommandPool>>, index: u32, flags: CommandBufferFlags) -> Result<Rc<Self>, Error> {
         // Allocate a new vk::CommandBuffer
         let (vk_pool, buffer): (vk::CommandPool, vk::CommandBuffer) = {
             // Get a lock on the pool
@@ -165,6 +480,9 @@ impl CommandBuffer {
 
             vk_pool,
             buffer,
+            level : CommandBufferLevel::Secondary,
+
+            bound_point : Cell::new(None),
         }))
     }
 
@@ -183,7 +501,7 @@ impl CommandBuffer {
     /// 
     /// # Errors
     /// This function errors if the given CommandPool could not allocate a new Buffer of this type.
-    pub fn multiple(device: Rc<Device>, pool: Rc<RefCell<CommandPool>>, count: usize, index: u32, flags: CommandBufferFlags, level: CommandBufferLevel) -> Result<Vec<Rc<Self>>, Error> {
+    pub fn multiple(device: Shared<Device>, pool: Rc<RefCell<CommandPool>>, count: usize, index: u32, flags: CommandBufferFlags, level: CommandBufferLevel) -> Result<Vec<Rc<Self>>, Error> {
         // Allocate N new vk::CommandBuffers
         let buffers: Vec<(vk::CommandPool, vk::CommandBuffer)> = {
             // Get a lock on the pool
@@ -200,6 +518,9 @@ impl CommandBuffer {
 
             vk_pool : p,
             buffer  : b,
+            level,
+
+            bound_point : Cell::new(None),
         })).collect())
     }
 
@@ -207,12 +528,18 @@ impl CommandBuffer {
 
     /// Prepares the CommandBuffer for recording.
     /// 
+    /// This function is meant for primary CommandBuffers. To begin a secondary CommandBuffer, use `CommandBuffer::begin_secondary()` instead, as those require inheritance info.
+    /// 
     /// # Arguments
     /// - `flags`: The CommandBufferUsageFlags that define some optional begin states.
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend could not begin the command buffer.
+    /// This function errors if this is a secondary CommandBuffer, if `flags` contains `CommandBufferUsageFlags::RENDER_PASS_ONLY` or if the underlying Vulkan backend could not begin the command buffer.
     pub fn begin(&self, flags: CommandBufferUsageFlags) -> Result<(), Error> {
+        // Make sure we are in a valid state to begin without inheritance info
+        if flags.check(CommandBufferUsageFlags::RENDER_PASS_ONLY) && matches!(self.level, CommandBufferLevel::Primary) { return Err(Error::RenderPassOnlyPrimary); }
+        if matches!(self.level, CommandBufferLevel::Secondary) { return Err(Error::MissingInheritanceInfo); }
+
         // Populate the begin info
         let begin_info = populate_begin_info(flags.into(), ptr::null());
 
@@ -222,6 +549,95 @@ impl CommandBuffer {
                 return Err(Error::CommandBufferBeginError{ err });
             }
         }
+        // Nothing is bound in a freshly begun buffer
+        self.bound_point.set(None);
+
+        // Success
+        Ok(())
+    }
+
+    /// Prepares a secondary CommandBuffer for recording.
+    /// 
+    /// If this is called on a primary CommandBuffer, the inheritance info is ignored and this function behaves like `CommandBuffer::begin()`.
+    /// 
+    /// # Arguments
+    /// - `flags`: The CommandBufferUsageFlags that define some optional begin states.
+    /// - `render_pass`: The RenderPass (and the index of the subpass within it) in which this buffer will be executed. Required if `flags` contains `CommandBufferUsageFlags::RENDER_PASS_ONLY`.
+    /// - `framebuffer`: The Framebuffer that this buffer will render to, if known.
+    /// 
+    /// # Errors
+    /// This function errors if `flags` contains `CommandBufferUsageFlags::RENDER_PASS_ONLY` while this is a primary CommandBuffer or while no `render_pass` is given, or if the underlying Vulkan backend could not begin the command buffer.
+    pub fn begin_secondary(&self, flags: CommandBufferUsageFlags, render_pass: Option<(&Rc<RenderPass>, u32)>, framebuffer: Option<&Rc<Framebuffer>>) -> Result<(), Error> {
+        // Make sure the flags make sense for this buffer
+        if matches!(self.level, CommandBufferLevel::Primary) {
+            if flags.check(CommandBufferUsageFlags::RENDER_PASS_ONLY) { return Err(Error::RenderPassOnlyPrimary); }
+            return self.begin(flags);
+        }
+        if flags.check(CommandBufferUsageFlags::RENDER_PASS_ONLY) && render_pass.is_none() { return Err(Error::MissingInheritanceInfo); }
+
+        // Populate the inheritance info
+        let (vk_render_pass, subpass): (vk::RenderPass, u32) = match render_pass {
+            Some((render_pass, subpass)) => (render_pass.vk(), subpass),
+            None                         => (vk::RenderPass::null(), 0),
+        };
+        let vk_framebuffer: vk::Framebuffer = framebuffer.map(|f| f.vk()).unwrap_or_else(vk::Framebuffer::null);
+        let inheritance_info = populate_inheritance_info(vk_render_pass, subpass, vk_framebuffer);
+
+        // Populate the begin info
+        let begin_info = populate_begin_info(flags.into(), &inheritance_info);
+
+        // Begin the buffer
+        unsafe {
+            if let Err(err) = self.device.begin_command_buffer(self.buffer, &begin_info) {
+                return Err(Error::CommandBufferBeginError{ err });
+            }
+        }
+        // Nothing is bound in a freshly begun buffer
+        self.bound_point.set(None);
+
+        // Success
+        Ok(())
+    }
+
+    /// Prepares a secondary CommandBuffer for recording commands that are executed within a dynamic rendering scope (see `CommandBuffer::begin_rendering()`).
+    /// 
+    /// If this is called on a primary CommandBuffer, the inheritance info is ignored and this function behaves like `CommandBuffer::begin()`.
+    /// 
+    /// # Arguments
+    /// - `flags`: The CommandBufferUsageFlags that define some optional begin states. Should contain `CommandBufferUsageFlags::RENDER_PASS_ONLY` if the buffer records rendering commands.
+    /// - `colour_formats`: The formats of the colour attachments of the rendering scope, in order.
+    /// - `depth_format`: The format of the depth attachment of the rendering scope, if any.
+    /// - `samples`: The number of samples used by the attachments of the rendering scope.
+    /// 
+    /// # Errors
+    /// This function errors if `flags` contains `CommandBufferUsageFlags::RENDER_PASS_ONLY` while this is a primary CommandBuffer, or if the underlying Vulkan backend could not begin the command buffer.
+    pub fn begin_secondary_rendering(&self, flags: CommandBufferUsageFlags, colour_formats: &[ImageFormat], depth_format: Option<ImageFormat>, samples: SampleCount) -> Result<(), Error> {
+        // Make sure the flags make sense for this buffer
+        if matches!(self.level, CommandBufferLevel::Primary) {
+            if flags.check(CommandBufferUsageFlags::RENDER_PASS_ONLY) { return Err(Error::RenderPassOnlyPrimary); }
+            return self.begin(flags);
+        }
+
+        // Populate the rendering inheritance info
+        let vk_colour_formats: Vec<vk::Format> = colour_formats.iter().map(|format| (*format).into()).collect();
+        let vk_depth_format: vk::Format = depth_format.map(|format| format.into()).unwrap_or(vk::Format::UNDEFINED);
+        let rendering_info = populate_inheritance_rendering_info(&vk_colour_formats, vk_depth_format, samples.into());
+
+        // Chain it to the normal inheritance info (which does not refer to any render pass)
+        let mut inheritance_info = populate_inheritance_info(vk::RenderPass::null(), 0, vk::Framebuffer::null());
+        inheritance_info.p_next = &rendering_info as *const vk::CommandBufferInheritanceRenderingInfo as *const c_void;
+
+        // Populate the begin info
+        let begin_info = populate_begin_info(flags.into(), &inheritance_info);
+
+        // Begin the buffer
+        unsafe {
+            if let Err(err) = self.device.begin_command_buffer(self.buffer, &begin_info) {
+                return Err(Error::CommandBufferBeginError{ err });
+            }
+        }
+        // Nothing is bound in a freshly begun buffer
+        self.bound_point.set(None);
 
         // Success
         Ok(())
@@ -233,11 +649,15 @@ impl CommandBuffer {
     /// - `render_pass`: The RenderPass to begin.
     /// - `framebuffer`: The Framebuffer to render to in this pass.
     /// - `render_area`: A Rect2D detailling the area of the framebuffer to render to.
-    /// - `clear_values`: A list of 4D colour vectors that indicate the colour to reset the framebuffer for when loading it (if set so in the render pass).
+    /// - `clear_values`: A list of 4D colour vectors that indicate the colour to reset the framebuffer for when loading it (if set so in the render pass). These are indexed by attachment, so there should be one for every attachment up to the last one that is cleared (see `RenderPass::clear_value_count()`). For depth attachments, only the first element is used.
+    /// - `contents`: Whether the commands of the first subpass are recorded inline or in secondary CommandBuffers (executed with `CommandBuffer::execute_commands()`).
     /// 
     /// # Errors
-    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
-    pub fn begin_render_pass(&self, render_pass: &Rc<RenderPass>, framebuffer: &Rc<Framebuffer>, render_area: Rect2D<i32, u32>, clear_values: &[[f32; 4]]) {
+    /// This function errors if fewer clear values are given than the RenderPass needs. Otherwise, it may pass errors on to `CommandBuffer::end()`.
+    pub fn begin_render_pass(&self, render_pass: &Rc<RenderPass>, framebuffer: &Rc<Framebuffer>, render_area: Rect2D<i32, u32>, clear_values: &[[f32; 4]], contents: SubpassContents) -> Result<(), Error> {
+        // Make sure there is a clear value for every attachment that needs one
+        if clear_values.len() < render_pass.clear_value_count() { return Err(Error::TooFewClearValues{ got: clear_values.len(), expected: render_pass.clear_value_count() }); }
+
         // Cast the clear values
         let vk_clear_values: Vec<vk::ClearValue> = clear_values.iter().map(|value| {
             vk::ClearValue {
@@ -252,14 +672,48 @@ impl CommandBuffer {
 
         // Begin!
         unsafe {
-            self.device.cmd_begin_render_pass(self.buffer, &begin_info, vk::SubpassContents::INLINE);
+            self.device.cmd_begin_render_pass(self.buffer, &begin_info, contents.into());
+        }
+        Ok(())
+    }
+
+    /// Records the beginning of a dynamic rendering scope, i.e., rendering without a RenderPass or Framebuffer.
+    /// 
+    /// # Arguments
+    /// - `render_area`: A Rect2D detailling the area of the attachments to render to.
+    /// - `colour_attachments`: The colour attachments to render to.
+    /// - `depth_attachment`: The depth attachment to render to, if any.
+    /// - `contents`: Whether the commands in the scope are recorded inline or in secondary CommandBuffers (executed with `CommandBuffer::execute_commands()`). In the latter case, the secondary buffers should be begun with `CommandBuffer::begin_secondary_rendering()`.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    /// 
+    /// # Panics
+    /// This function panics if the Device does not have `VK_KHR_dynamic_rendering` enabled.
+    pub fn begin_rendering(&self, render_area: Rect2D<i32, u32>, colour_attachments: &[RenderingAttachment], depth_attachment: Option<&RenderingAttachment>, contents: SubpassContents) {
+        let loader = self.device.dynamic_rendering().expect("Called CommandBuffer::begin_rendering() on a Device without VK_KHR_dynamic_rendering enabled");
+
+        // Cast the attachments
+        let vk_colour_attachments: Vec<vk::RenderingAttachmentInfo> = colour_attachments.iter().map(|attachment| attachment.vk(false)).collect();
+        let vk_depth_attachment: Option<vk::RenderingAttachmentInfo> = depth_attachment.map(|attachment| attachment.vk(true));
+
+        // Prepare the rendering info
+        let flags: vk::RenderingFlags = match contents {
+            SubpassContents::Inline                  => vk::RenderingFlags::empty(),
+            SubpassContents::SecondaryCommandBuffers => vk::RenderingFlags::CONTENTS_SECONDARY_COMMAND_BUFFERS,
+        };
+        let rendering_info = populate_rendering_info(flags, render_area.into(), &vk_colour_attachments, vk_depth_attachment.as_ref().map(|attachment| attachment as *const vk::RenderingAttachmentInfo).unwrap_or(ptr::null()));
+
+        // Begin!
+        unsafe {
+            loader.cmd_begin_rendering(self.buffer, &rendering_info);
         }
     }
 
-    /// Binds the given pipeline to this RenderPass.
+    /// Binds the given pipeline to this CommandBuffer.
     /// 
     /// # Arguments
-    /// - `bind_point`: The BindPoint where to bind the pipeline.
+    /// - `bind_point`: The BindPoint where to bind the pipeline (i.e., `BindPoint::Graphics` or `BindPoint::Compute`). Should match the kind of the given pipeline.
     /// - `pipeline`: The Pipeline to bind.
     /// 
     /// # Errors
@@ -269,6 +723,87 @@ impl CommandBuffer {
         unsafe {
             self.device.cmd_bind_pipeline(self.buffer, bind_point.into(), pipeline.vk());
         }
+        self.bound_point.set(Some(bind_point));
+    }
+
+    /// Binds the given DescriptorSets for the next draw or dispatch calls.
+    /// 
+    /// In debug builds, this warns if the given BindPoint does not match that of the most recently bound Pipeline, since the sets would then not reach its shaders.
+    /// 
+    /// # Arguments
+    /// - `bind_point`: The BindPoint where to bind the sets (i.e., `BindPoint::Graphics` or `BindPoint::Compute`). Should match the kind of pipeline that uses them.
+    /// - `layout`: The PipelineLayout that describes how the sets are laid out.
+    /// - `first_set`: The index of the first set to bind in the layout. The others are bound to consecutive indices.
+    /// - `sets`: The DescriptorSets to bind.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    pub fn bind_descriptor_sets(&self, bind_point: BindPoint, layout: &Shared<PipelineLayout>, first_set: u32, sets: &[&DescriptorSet]) {
+        // Check if the sets end up where the user expects
+        #[cfg(debug_assertions)]
+        if let Some(bound_point) = self.bound_point.get() {
+            if bound_point != bind_point { warn!("Binding descriptor sets to the {} bind point, but the most recently bound pipeline uses the {} bind point", bind_point, bound_point); }
+        }
+
+        // Bind them
+        let vk_sets: Vec<vk::DescriptorSet> = sets.iter().map(|set| set.vk()).collect();
+        unsafe {
+            self.device.cmd_bind_descriptor_sets(self.buffer, bind_point.into(), layout.vk(), first_set, &vk_sets, &[]);
+        }
+    }
+
+    /// Updates (part of) the push constants for the next draw or dispatch calls.
+    /// 
+    /// # Arguments
+    /// - `layout`: The PipelineLayout that declares the push constant range(s) that are updated.
+    /// - `stage`: The shader stage(s) that use the updated push constants. Must match the stages of the ranges in the layout that overlap with the update.
+    /// - `offset`: The offset (in bytes) of the update in the push constant space.
+    /// - `data`: The new values of the push constants. Its size must be a multiple of four.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    #[inline]
+    pub fn push_constants(&self, layout: &Shared<PipelineLayout>, stage: ShaderStageFlags, offset: u32, data: &[u8]) {
+        unsafe {
+            self.device.cmd_push_constants(self.buffer, layout.vk(), stage.into(), offset, data);
+        }
+    }
+
+    /// Sets the viewport for the next draw calls, for Pipelines that have `DynamicState::Viewport`.
+    /// 
+    /// # Arguments
+    /// - `viewport`: The rectangle (in pixels) that defines the viewport's dimensions.
+    /// - `depth`: The depth range of the viewport.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    #[inline]
+    pub fn set_viewport(&self, viewport: Rect2D<f32>, depth: Range<f32>) {
+        let vk_viewport: vk::Viewport = vk::Viewport {
+            x         : viewport.x(),
+            y         : viewport.y(),
+            width     : viewport.w(),
+            height    : viewport.h(),
+            min_depth : depth.start,
+            max_depth : depth.end,
+        };
+        unsafe {
+            self.device.cmd_set_viewport(self.buffer, 0, &[ vk_viewport ]);
+        }
+    }
+
+    /// Sets the scissor for the next draw calls, for Pipelines that have `DynamicState::Scissor`.
+    /// 
+    /// # Arguments
+    /// - `scissor`: The rectangle (in pixels) outside of which fragments are discarded.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    #[inline]
+    pub fn set_scissor(&self, scissor: Rect2D<i32, u32>) {
+        unsafe {
+            self.device.cmd_set_scissor(self.buffer, 0, &[ scissor.into() ]);
+        }
     }
 
     /// Binds a single vertex buffer for the next `CommandBuffer::draw()`-call.
@@ -276,21 +811,53 @@ impl CommandBuffer {
     /// # Arguments
     /// - `index`: The binding index for the given buffer.
     /// - `vertex_buffer`: The VertexBuffers to bind.
+    /// - `offset`: The offset (in bytes) within the buffer where the vertices start. Note that this is relative to the start of the buffer, not to the start of the memory block it is allocated in.
+    /// 
+    /// # Errors
+    /// This function errors if the offset is outside of the buffer. Other errors may be passed on to `CommandBuffer::end()`.
     #[inline]
-    pub fn bind_vertex_buffer(&self, index: usize, vertex_buffer: &Rc<VertexBuffer>) {
+    pub fn bind_vertex_buffer(&self, index: usize, vertex_buffer: &Rc<VertexBuffer>, offset: usize) -> Result<(), Error> {
         // Call the function
-        self.bind_vertex_buffers(index, &[ vertex_buffer ])
+        self.bind_vertex_buffers(index, &[ (vertex_buffer, offset) ])
     }
 
     /// Binds a given list of vertex buffers for the next `CommandBuffer::draw()`-call.
     /// 
     /// # Arguments
     /// - `index`: The first binding index for the given buffers.
-    /// - `vertex_buffesr`: The list of VertexBuffers to bind.
-    pub fn bind_vertex_buffers(&self, index: usize, vertex_buffers: &[&Rc<VertexBuffer>]) {
+    /// - `vertex_buffers`: The list of VertexBuffers to bind, each paired with the offset (in bytes) within that buffer where its vertices start.
+    /// 
+    /// # Errors
+    /// This function errors if any of the offsets is outside of its buffer. Other errors may be passed on to `CommandBuffer::end()`.
+    pub fn bind_vertex_buffers(&self, index: usize, vertex_buffers: &[(&Rc<VertexBuffer>, usize)]) -> Result<(), Error> {
+        // Make sure the offsets are within range
+        for (buffer, offset) in vertex_buffers {
+            if *offset >= buffer.capacity() { return Err(Error::BufferOffsetOutOfBounds{ offset: *offset, capacity: buffer.capacity() }); }
+        }
+
         // Extract the required properties into two arrays
-        let buffers: Vec<vk::Buffer>     = vertex_buffers.iter().map(|b| b.vk()).collect();
-        let offsets: Vec<vk::DeviceSize> = vertex_buffers.iter().map(|b| b.vk_offset()).collect();
+        let buffers: Vec<vk::Buffer>     = vertex_buffers.iter().map(|(b, _)| b.vk()).collect();
+        let offsets: Vec<vk::DeviceSize> = vertex_buffers.iter().map(|(_, o)| *o as vk::DeviceSize).collect();
+
+        // Call the function
+        unsafe {
+            self.device.cmd_bind_vertex_buffers(self.buffer, index as u32, &buffers, &offsets);
+        }
+        Ok(())
+    }
+
+    /// Binds a given list of BufferSlices as vertex buffers for the next `CommandBuffer::draw()`-call.
+    /// 
+    /// This allows drawing meshes that are packed together in a single (Vertex)Buffer.
+    /// 
+    /// # Arguments
+    /// - `index`: The first binding index for the given slices.
+    /// - `slices`: The list of BufferSlices to bind. The vertices of each are assumed to start at the start of the slice.
+    #[inline]
+    pub fn bind_vertex_slices(&self, index: usize, slices: &[BufferSlice]) {
+        // Extract the required properties into two arrays (the slices are already bounds-checked)
+        let buffers: Vec<vk::Buffer>     = slices.iter().map(|s| s.vk()).collect();
+        let offsets: Vec<vk::DeviceSize> = slices.iter().map(|s| s.offset() as vk::DeviceSize).collect();
 
         // Call the function
         unsafe {
@@ -302,15 +869,27 @@ impl CommandBuffer {
     /// 
     /// # Arguments
     /// - `index_buffer`: The IndexBuffers to bind.
-    #[inline]
-    pub fn bind_index_buffer(&self, index_buffer: &Rc<IndexBuffer>) {
+    /// - `offset`: The offset (in bytes) within the buffer where the indices start. Note that this is relative to the start of the buffer, not to the start of the memory block it is allocated in.
+    /// 
+    /// # Errors
+    /// This function errors if the offset is outside of the buffer or is not a multiple of the size of the buffer's IndexType. Other errors may be passed on to `CommandBuffer::end()`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn bind_index_buffer(&self, index_buffer: &Rc<IndexBuffer>, offset: usize) -> Result<(), Error> {
+        // Make sure the offset is within range and aligned to the index type
+        let index_type: IndexType = index_buffer.index_type();
+        if offset >= index_buffer.capacity() { return Err(Error::BufferOffsetOutOfBounds{ offset, capacity: index_buffer.capacity() }); }
+        if offset % index_type.vk_size() != 0 { return Err(Error::IndexOffsetMisaligned{ offset, index_type }); }
+
         // Call the function
         unsafe {
-            self.device.cmd_bind_index_buffer(self.buffer, index_buffer.vk(), index_buffer.vk_offset(), index_buffer.index_type().into());
+            self.device.cmd_bind_index_buffer(self.buffer, index_buffer.vk(), offset as vk::DeviceSize, index_type.into());
         }
+        Ok(())
     }
 
-    /// Records a draw call.
+    /// Records a (non-indexed) draw call.
+    /// 
+    /// Use `CommandBuffer::draw_indexed()` instead to draw using the bound IndexBuffer.
     /// 
     /// # Arguments
     /// - `n_vertices`: The number of vertices to draw.
@@ -345,6 +924,186 @@ impl CommandBuffer {
         }
     }
 
+    /// Records a draw call that launches the bound Task shader (or, if none, the bound Mesh shader) in the given number of workgroups.
+    /// 
+    /// # Arguments
+    /// - `x`: The number of workgroups to launch in the X dimension.
+    /// - `y`: The number of workgroups to launch in the Y dimension.
+    /// - `z`: The number of workgroups to launch in the Z dimension.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    /// 
+    /// # Panics
+    /// This function panics if the Device does not have `VK_EXT_mesh_shader` enabled.
+    #[inline]
+    pub fn draw_mesh_tasks(&self, x: u32, y: u32, z: u32) {
+        let loader = self.device.mesh_shader().expect("Called CommandBuffer::draw_mesh_tasks() on a Device without VK_EXT_mesh_shader enabled");
+        unsafe {
+            loader.cmd_draw_mesh_tasks(self.buffer, x, y, z);
+        }
+    }
+
+    /// Records a single pipeline barrier that transitions all of the given images to new layouts.
+    /// 
+    /// The access masks and pipeline stages are derived per image from its old and new layout, after which the stages of all images are combined in one barrier. Every image is transitioned as a whole, i.e., all of the aspects of its format (see `ImageFormat::default_aspect()`).
+    /// 
+    /// # Arguments
+    /// - `transitions`: A list of (Image, old layout, new layout) tuples that describe the transitions to perform.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    /// 
+    /// # Panics
+    /// This function panics if any of the layouts is `ImageLayout::AttachmentOptimal` or `ImageLayout::ReadOnlyOptimal` while the Device does not have the `synchronization2` DeviceFeature enabled.
+    pub fn transition_images(&self, transitions: &[(&Image, ImageLayout, ImageLayout)]) {
+        // Don't bother recording empty barriers
+        if transitions.is_empty() { return; }
+        if !self.device.enabled_features().synchronization2 && transitions.iter().any(|(_, old_layout, new_layout)| old_layout.requires_synchronization2() || new_layout.requires_synchronization2()) {
+            panic!("Called CommandBuffer::transition_images() with a synchronization2 layout on a Device without the synchronization2 feature enabled");
+        }
+
+        // Build the barriers, collecting the stages as we go
+        let mut src_stages: vk::PipelineStageFlags = vk::PipelineStageFlags::empty();
+        let mut dst_stages: vk::PipelineStageFlags = vk::PipelineStageFlags::empty();
+        let barriers: Vec<vk::ImageMemoryBarrier> = transitions.iter().map(|(image, old_layout, new_layout)| {
+            let (src_access, src_stage): (vk::AccessFlags, vk::PipelineStageFlags) = layout_masks(*old_layout, true);
+            let (dst_access, dst_stage): (vk::AccessFlags, vk::PipelineStageFlags) = layout_masks(*new_layout, false);
+            src_stages |= src_stage;
+            dst_stages |= dst_stage;
+            populate_image_barrier(image.vk(), (*old_layout).into(), (*new_layout).into(), image.aspect().into(), src_access, dst_access)
+        }).collect();
+
+        // Record them all in one go
+        unsafe {
+            self.device.cmd_pipeline_barrier(self.buffer, src_stages, dst_stages, vk::DependencyFlags::empty(), &[], &[], &barriers);
+        }
+    }
+
+    /// Records a copy of regions of one Image to another, without any scaling or format conversion (e.g., to copy a resolved image to a readback image).
+    /// 
+    /// If no regions are given, the entire first mip level is copied, including all aspects of the images (see `ImageFormat::default_aspect()`). The copied area is then the intersection of both extents.
+    /// 
+    /// # Arguments
+    /// - `src`: The Image to copy from.
+    /// - `src_layout`: The ImageLayout `src` is in (should be `ImageLayout::TransferSrc` or `ImageLayout::General`).
+    /// - `dst`: The Image to copy to.
+    /// - `dst_layout`: The ImageLayout `dst` is in (should be `ImageLayout::TransferDst` or `ImageLayout::General`).
+    /// - `regions`: The ImageCopy regions that describe which parts of the images to copy.
+    /// 
+    /// # Errors
+    /// This function errors if the formats of the images are not copy-compatible (see `ImageFormat::is_copy_compatible()`). Other errors may be passed on to `CommandBuffer::end()`.
+    pub fn copy_image(&self, src: &Image, src_layout: ImageLayout, dst: &Image, dst_layout: ImageLayout, regions: &[ImageCopy]) -> Result<(), Error> {
+        // Make sure the formats match
+        if !src.format().is_copy_compatible(dst.format()) { return Err(Error::IncompatibleCopyFormats{ src: src.format(), dst: dst.format() }); }
+
+        // Cast the regions, defaulting to the entire image
+        let vk_regions: Vec<vk::ImageCopy> = if regions.is_empty() {
+            let extent: vk::Extent3D = vk::Extent3D {
+                width  : std::cmp::min(src.extent().w, dst.extent().w),
+                height : std::cmp::min(src.extent().h, dst.extent().h),
+                depth  : 1,
+            };
+            vec![ populate_image_copy(src.aspect().into(), extent) ]
+        } else {
+            regions.iter().cloned().map(|region| region.into()).collect()
+        };
+
+        // Record the copy
+        unsafe {
+            self.device.cmd_copy_image(self.buffer, src.vk(), src_layout.into(), dst.vk(), dst_layout.into(), &vk_regions);
+        }
+        Ok(())
+    }
+
+    /// Records the clear of an entire Image to the given value.
+    /// 
+    /// Depending on its format, the image is cleared as a colour image or as a depth/stencil image. In the latter case, all of its aspects are cleared (see `ImageFormat::default_aspect()`).
+    /// 
+    /// # Arguments
+    /// - `image`: The Image to clear.
+    /// - `layout`: The ImageLayout `image` is in (should be `ImageLayout::TransferDst` or `ImageLayout::General`).
+    /// - `value`: The value to clear the image with. For depth/stencil images, the first element is used as the depth value and the second element as the stencil value.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    pub fn clear_image(&self, image: &Image, layout: ImageLayout, value: [f32; 4]) {
+        let aspect: ImageAspectFlags = image.aspect();
+        let range: vk::ImageSubresourceRange = populate_full_range(aspect.into());
+        unsafe {
+            if aspect.check(ImageAspectFlags::DEPTH) || aspect.check(ImageAspectFlags::STENCIL) {
+                self.device.cmd_clear_depth_stencil_image(self.buffer, image.vk(), layout.into(), &vk::ClearDepthStencilValue { depth: value[0], stencil: value[1] as u32 }, &[ range ]);
+            } else {
+                self.device.cmd_clear_color_image(self.buffer, image.vk(), layout.into(), &vk::ClearColorValue { float32: value }, &[ range ]);
+            }
+        }
+    }
+
+    /// Records the fill of (a range of) a Buffer with a repeated 4-byte value.
+    /// 
+    /// # Arguments
+    /// - `buffer`: The Buffer to fill. Must have been created with `BufferUsageFlags::TRANSFER_DST`.
+    /// - `offset`: The offset (in bytes, relative to the start of the Buffer) of the range to fill. Must be a multiple of 4.
+    /// - `size`: The size (in bytes) of the range to fill, or `None` to fill the rest of the Buffer (i.e., `VK_WHOLE_SIZE`, rounded down to a multiple of 4). Must be a multiple of 4 otherwise.
+    /// - `data`: The 4-byte value to fill the range with.
+    /// 
+    /// # Errors
+    /// This function errors if the range is empty, out of bounds or not aligned to 4 bytes. Other errors may be passed on to `CommandBuffer::end()`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn fill_buffer(&self, buffer: &dyn Buffer, offset: u64, size: Option<u64>, data: u32) -> Result<(), Error> {
+        // Resolve the range and make sure it is aligned
+        let info: vk::DescriptorBufferInfo = match buffer.vk_info(offset, size) {
+            Ok(info) => info,
+            Err(err) => { return Err(Error::FillRangeError{ err: Box::new(err) }); }
+        };
+        if offset % 4 != 0 || size.map(|size| size % 4 != 0).unwrap_or(false) { return Err(Error::FillRangeMisaligned{ offset, size }); }
+
+        // Record the fill
+        unsafe {
+            self.device.cmd_fill_buffer(self.buffer, info.buffer, info.offset, info.range, data);
+        }
+        Ok(())
+    }
+
+    /// Records a pipeline barrier that only introduces an execution dependency, i.e., without any memory barriers.
+    /// 
+    /// This is enough if the commands before and after the barrier do not touch the same memory (e.g., two compute dispatches that write to disjoint regions), but must still be ordered.
+    /// 
+    /// # Arguments
+    /// - `src_stage`: The PipelineStage of the previous commands that must complete before the barrier.
+    /// - `dst_stage`: The PipelineStage of the next commands that must wait for the barrier.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    /// 
+    /// # Panics
+    /// This function panics if either stage is `PipelineStage::NONE` while the Device does not have the `synchronization2` DeviceFeature enabled.
+    #[inline]
+    pub fn execution_barrier(&self, src_stage: PipelineStage, dst_stage: PipelineStage) {
+        if !self.device.enabled_features().synchronization2 && (src_stage == PipelineStage::NONE || dst_stage == PipelineStage::NONE) {
+            panic!("Called CommandBuffer::execution_barrier() with PipelineStage::NONE on a Device without the synchronization2 feature enabled");
+        }
+        unsafe {
+            self.device.cmd_pipeline_barrier(self.buffer, src_stage.into(), dst_stage.into(), vk::DependencyFlags::empty(), &[], &[], &[]);
+        }
+    }
+
+    /// Records the execution of the given secondary CommandBuffers.
+    /// 
+    /// When called within a RenderPass or dynamic rendering scope, it must have been begun with `SubpassContents::SecondaryCommandBuffers`.
+    /// 
+    /// # Arguments
+    /// - `buffers`: The secondary CommandBuffers to execute, in order.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    pub fn execute_commands(&self, buffers: &[&Rc<CommandBuffer>]) {
+        let vk_buffers: Vec<vk::CommandBuffer> = buffers.iter().map(|buffer| buffer.buffer).collect();
+        unsafe {
+            self.device.cmd_execute_commands(self.buffer, &vk_buffers);
+        }
+    }
+
     /// Records the end of a RenderPass.
     /// 
     /// # Errors
@@ -356,6 +1115,21 @@ impl CommandBuffer {
         }
     }
 
+    /// Records the end of a dynamic rendering scope.
+    /// 
+    /// # Errors
+    /// This function does not error directly, but may pass errors on to `CommandBuffer::end()`.
+    /// 
+    /// # Panics
+    /// This function panics if the Device does not have `VK_KHR_dynamic_rendering` enabled.
+    #[inline]
+    pub fn end_rendering(&self) {
+        let loader = self.device.dynamic_rendering().expect("Called CommandBuffer::end_rendering() on a Device without VK_KHR_dynamic_rendering enabled");
+        unsafe {
+            loader.cmd_end_rendering(self.buffer);
+        }
+    }
+
     /// Ends recording in the CommandBuffer.
     /// 
     /// # Errors
@@ -373,12 +1147,16 @@ impl CommandBuffer {
 
     /// Returns the parent Device where this buffer lives.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the parent Pool where this buffer lives.
     #[inline]
     pub fn pool(&self) -> &Rc<RefCell<CommandPool>> { &self.pool }
 
+    /// Returns the level of this buffer (i.e., primary or secondary).
+    #[inline]
+    pub fn level(&self) -> CommandBufferLevel { self.level }
+
     /// Returns the internal buffer.
     #[inline]
     pub fn vk(&self) -> vk::CommandBuffer { self.buffer }
@@ -391,3 +1169,4 @@ impl Drop for CommandBuffer {
         unsafe { self.device.free_command_buffers(self.vk_pool, &[self.buffer]); }
     }
 }
+
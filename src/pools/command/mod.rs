@@ -19,5 +19,5 @@ pub mod pool;
 
 
 // Bring some stuff into the module scope
-pub use buffers::CommandBuffer as Buffer;
+pub use buffers::{CommandBuffer as Buffer, RenderingAttachment};
 pub use pool::{Error, CommandPool as Pool};
@@ -12,8 +12,9 @@
 //!   Contains the pool implemenation for this type of pool.
 // 
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::ptr;
 use std::rc::Rc;
 
@@ -25,6 +26,7 @@ use crate::auxillary::enums::CommandBufferLevel;
 use crate::auxillary::flags::CommandBufferFlags;
 use crate::auxillary::structs::QueueFamilyInfo;
 use crate::device::Device;
+use crate::spec::Shared;
 
 
 /***** POPULATES *****/
@@ -76,11 +78,17 @@ fn populate_buffer_info(pool: vk::CommandPool, count: u32, level: vk::CommandBuf
 
 /***** LIBRARY *****/
 /// The CommandPool defines a Pool for command buffers.
+/// 
+/// # Thread safety
+/// Vulkan command pools are externally synchronized, i.e., a pool (and the buffers allocated from it) may only be used by one thread at a time. The CommandPool is therefore never `Sync`. By default, it also refers to its Device with an `Rc`, so the compiler confines it (and the Device) to the thread that created it. To record on multiple threads, enable the `arc` feature and create one CommandPool per thread from the shared Device (see `Device::create_command_pool_for_thread()`).
 pub struct CommandPool {
     /// The Device where the CommandPool lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The VkCommandPools around which we wrap. There is one per queue family.
     pools  : HashMap<u32, HashMap<CommandBufferFlags, vk::CommandPool>>,
+
+    /// Makes the CommandPool `!Sync`, since the VkCommandPools are externally synchronized.
+    _not_sync : PhantomData<Cell<()>>,
 }
 
 impl CommandPool {
@@ -94,7 +102,7 @@ impl CommandPool {
     /// 
     /// # Errors
     /// This function errors if the underlying Vulkan backend could not allocate the pool for some reason.
-    pub fn new(device: Rc<Device>) -> Result<Rc<RefCell<Self>>, Error> {
+    pub fn new(device: Shared<Device>) -> Result<Rc<RefCell<Self>>, Error> {
         // Get the family info
         let family_info: &QueueFamilyInfo = device.families();
 
@@ -108,6 +116,8 @@ impl CommandPool {
         Ok(Rc::new(RefCell::new(Self {
             device,
             pools,
+
+            _not_sync : PhantomData,
         })))
     }
 
@@ -268,7 +278,7 @@ impl CommandPool {
 
     /// Returns the parent device.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 }
 
 impl Drop for CommandPool {
@@ -15,6 +15,7 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
 
+use crate::auxillary::enums::{ImageFormat, IndexType};
 use crate::auxillary::flags::{DeviceMemoryType, DeviceMemoryTypeFlags, MemoryPropertyFlags};
 
 
@@ -30,6 +31,8 @@ pub enum MemoryPoolError {
     OutOfMemoryError{ req_size: usize },
     /// The given memory pointer was not one matching a block to free.
     UnknownPointer{ ptr: usize },
+    /// The pool does not support freeing individual blocks. This is the case for the LinearPool, which can only release all of its memory at once with `MemoryPool::reset()`.
+    FreeUnsupported{ pool: &'static str },
 
     /// Could not allocate a CommandBuffer for some purpose.
     CommandBufferError{ what: &'static str, err: CommandPoolError },
@@ -50,6 +53,22 @@ pub enum MemoryPoolError {
     BufferMapError{ err: ash::vk::Result },
     /// Failed to flush a buffer's mapped memory area.
     BufferFlushError{ err: ash::vk::Result },
+    /// The range of a BufferSlice is empty or does not fit in its parent Buffer.
+    BufferSliceOutOfBounds{ start: usize, end: usize, capacity: usize },
+
+    /// The size of a mapped memory area is not a multiple of the size of the type to view it as.
+    MappedSizeMismatch{ size: usize, type_name: &'static str, type_size: usize },
+    /// The pointer to a mapped memory area is not aligned to the alignment of the type to view it as.
+    MappedAlignmentMismatch{ ptr: usize, type_name: &'static str, type_align: usize },
+    /// The range to flush, invalidate or write does not fit in the mapped memory area.
+    MappedRangeOutOfBounds{ start: usize, end: usize, capacity: usize },
+    /// Failed to invalidate a buffer's mapped memory area.
+    BufferInvalidateError{ err: ash::vk::Result },
+
+    /// Failed to create the Fence that guards a recycled StagingBuffer.
+    StagingFenceCreateError{ err: crate::sync::Error },
+    /// Failed to poll the Fence that guards a recycled StagingBuffer.
+    StagingFencePollError{ err: crate::sync::Error },
 }
 
 impl Display for MemoryPoolError {
@@ -61,6 +80,7 @@ impl Display for MemoryPoolError {
             MemoryAllocateError{ name, size, mem_type, err }    => write!(f, "Device '{}' could not allocate {} bytes on memory type {}: {}", name, size, u32::from(*mem_type), err),
             OutOfMemoryError{ req_size }                        => write!(f, "Could not allocate new block of {} bytes", req_size),
             UnknownPointer{ ptr }                               => write!(f, "Pointer '{:#X}' does not point to an allocated block", ptr),
+            FreeUnsupported{ pool }                             => write!(f, "A {} cannot free individual blocks; reset it instead", pool),
 
             CommandBufferError{ what, err }            => write!(f, "Could not create a {} command buffer: {}", what, err),
             CommandBufferRecordBeginError{ what, err } => write!(f, "Could not start recording a {} command buffer: {}", what, err),
@@ -72,6 +92,15 @@ impl Display for MemoryPoolError {
             BufferBindError{ err }   => write!(f, "Could not bind Buffer to memory: {}", err),
             BufferMapError{ err }    => write!(f, "Could not map Buffer memory to host memory: {}", err),
             BufferFlushError{ err }  => write!(f, "Could not flush Buffer mapped memory area: {}", err),
+            BufferSliceOutOfBounds{ start, end, capacity } => write!(f, "Range {}..{} is empty or out of bounds for a Buffer of {} bytes", start, end, capacity),
+
+            MappedSizeMismatch{ size, type_name, type_size }      => write!(f, "Mapped memory area of {} bytes is not a multiple of the size of {} ({} bytes)", size, type_name, type_size),
+            MappedAlignmentMismatch{ ptr, type_name, type_align } => write!(f, "Mapped memory area at {:#X} is not aligned to the alignment of {} ({} bytes)", ptr, type_name, type_align),
+            MappedRangeOutOfBounds{ start, end, capacity }        => write!(f, "Range {}..{} is out of bounds for a mapped memory area of {} bytes", start, end, capacity),
+            BufferInvalidateError{ err }                          => write!(f, "Could not invalidate Buffer mapped memory area: {}", err),
+
+            StagingFenceCreateError{ err } => write!(f, "Could not create Fence for StagingBuffer: {}", err),
+            StagingFencePollError{ err }   => write!(f, "Could not poll Fence of StagingBuffer: {}", err),
         }
     }
 }
@@ -92,10 +121,27 @@ pub enum CommandPoolError {
     /// Could not reset the command pool(s).
     CommandPoolResetError{ err: ash::vk::Result },
 
+    /// The RENDER_PASS_ONLY usage flag was given for a primary command buffer.
+    RenderPassOnlyPrimary,
+    /// A secondary command buffer was begun without the inheritance info it needs (or RENDER_PASS_ONLY was given without a render pass).
+    MissingInheritanceInfo,
     /// Could not begin a command buffer.
     CommandBufferBeginError{ err: ash::vk::Result },
     /// Could not end a command buffer (because something else went wrong).
     CommandBufferRecordError{ err: ash::vk::Result },
+
+    /// The offset at which a buffer is bound lies outside of that buffer.
+    BufferOffsetOutOfBounds{ offset: usize, capacity: usize },
+    /// The offset at which an index buffer is bound is not a multiple of the size of its index type.
+    IndexOffsetMisaligned{ offset: usize, index_type: IndexType },
+    /// A RenderPass was begun with fewer clear values than it has attachments up to (and including) the last one that is cleared.
+    TooFewClearValues{ got: usize, expected: usize },
+    /// An Image was copied to an Image of a format that it is not copy-compatible with.
+    IncompatibleCopyFormats{ src: ImageFormat, dst: ImageFormat },
+    /// The range of a Buffer to fill is invalid (e.g., empty or out of bounds).
+    FillRangeError{ err: Box<MemoryPoolError> },
+    /// The offset or size of a Buffer range to fill is not a multiple of 4.
+    FillRangeMisaligned{ offset: u64, size: Option<u64> },
 }
 
 impl Display for CommandPoolError {
@@ -109,8 +155,17 @@ impl Display for CommandPoolError {
 
             CommandPoolResetError{ err } => write!(f, "Could not reset CommandPool: {}", err),
 
+            RenderPassOnlyPrimary           => write!(f, "Cannot begin a primary CommandBuffer with the RENDER_PASS_ONLY usage flag (only valid for secondary CommandBuffers)"),
+            MissingInheritanceInfo          => write!(f, "Cannot begin a secondary CommandBuffer without inheritance info (and a RenderPass if RENDER_PASS_ONLY is given)"),
             CommandBufferBeginError{ err }  => write!(f, "Could not begin CommandBuffer: {}", err),
             CommandBufferRecordError{ err } => write!(f, "Failed to record CommandBuffer: {}", err),
+
+            BufferOffsetOutOfBounds{ offset, capacity } => write!(f, "Cannot bind Buffer at offset {} (Buffer is only {} bytes)", offset, capacity),
+            IndexOffsetMisaligned{ offset, index_type } => write!(f, "Cannot bind IndexBuffer at offset {} (not a multiple of the size of index type {:?}, {} bytes)", offset, index_type, index_type.vk_size()),
+            TooFewClearValues{ got, expected }          => write!(f, "Cannot begin RenderPass with {} clear values, as it needs {} (clear values are indexed by attachment, not by clear operation)", got, expected),
+            IncompatibleCopyFormats{ src, dst }         => write!(f, "Cannot copy an Image of format {} to an Image of format {}, since the formats are not copy-compatible", src, dst),
+            FillRangeError{ err }                       => write!(f, "Cannot fill Buffer range: {}", err),
+            FillRangeMisaligned{ offset, size }         => write!(f, "Cannot fill Buffer range at offset {} with size {} (both must be a multiple of 4)", offset, match size { Some(size) => size.to_string(), None => "VK_WHOLE_SIZE".into() }),
         }
     }
 }
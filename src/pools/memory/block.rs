@@ -14,15 +14,16 @@
 // 
 
 use std::ptr;
-use std::rc::Rc;
 use std::slice;
 
 use ash::vk;
 
 pub use crate::pools::errors::MemoryPoolError as Error;
+use crate::log_destroy;
 use crate::auxillary::flags::{DeviceMemoryType, MemoryPropertyFlags};
 use crate::auxillary::structs::MemoryRequirements;
 use crate::device::Device;
+use crate::spec::Shared;
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -52,7 +53,7 @@ fn populate_alloc_info(size: vk::DeviceSize, types: u32) -> vk::MemoryAllocateIn
 /// Defines a single, continious block of memory that lives on a single type of memory on the Device.
 pub struct MemoryBlock {
     /// The Device where the block lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
 
     /// The VkDeviceMemory that is actually represented by this block.
     mem       : vk::DeviceMemory,
@@ -77,21 +78,21 @@ impl MemoryBlock {
     /// 
     /// # Errors
     /// This function may error if we could not find a suitable memory type or there was no memory left.
-    pub fn allocate(device: Rc<Device>, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<Self, Error> {
+    pub fn allocate(device: Shared<Device>, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<Self, Error> {
         // Attempt to find a suitable memory type for the given requirements & properties
         let mut found_candidate = false;
         let device_props : vk::PhysicalDeviceMemoryProperties = unsafe { device.instance().get_physical_device_memory_properties(device.physical_device()) };
         let device_types : &[vk::MemoryType] = unsafe { slice::from_raw_parts(device_props.memory_types.as_ptr(), device_props.memory_type_count as usize) };
-        for i in 0..device_types.len() {
-            // Check if this type is in the required ones
-            if !reqs.types.check(i.into()) { continue; }
+        for i in reqs.types.iter_indices() {
+            // Stop once we're past the types the device has
+            if i as usize >= device_types.len() { break; }
             // Check if this type satisfies the properties
-            let mem_props = MemoryPropertyFlags::from(device_types[i].property_flags);
+            let mem_props = MemoryPropertyFlags::from(device_types[i as usize].property_flags);
             if !mem_props.check(props) { continue; }
             found_candidate = true;
 
             // Call the other factory method for this device type
-            match Self::allocate_on_type(device.clone(), DeviceMemoryType::from(i as u32), reqs.size) {
+            match Self::allocate_on_type(device.clone(), DeviceMemoryType::from(i), reqs.size) {
                 // If it's an out-of-memory error, then we try the next type
                 Err(Error::OutOfMemoryError{ .. }) => { continue; }
 
@@ -119,7 +120,7 @@ impl MemoryBlock {
     /// 
     /// # Errors
     /// This function may error if there was no memory left.
-    pub fn allocate_on_type(device: Rc<Device>, mem_type: DeviceMemoryType, size: usize) -> Result<Self, Error> {
+    pub fn allocate_on_type(device: Shared<Device>, mem_type: DeviceMemoryType, size: usize) -> Result<Self, Error> {
         // First: query the supported properties of this block (again)
         let device_props : vk::PhysicalDeviceMemoryProperties = unsafe { device.instance().get_physical_device_memory_properties(device.physical_device()) };
 
@@ -179,6 +180,7 @@ impl Drop for MemoryBlock {
     #[inline]
     fn drop(&mut self) {
         // Deallocate the device memory
+        log_destroy!(self, MemoryBlock);
         unsafe { self.device.free_memory(self.mem, None); }
     }
 }
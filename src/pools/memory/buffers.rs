@@ -19,11 +19,12 @@ use std::rc::Rc;
 use ash::vk;
 
 pub use crate::pools::errors::MemoryPoolError as Error;
-use crate::{log_destroy, vec_as_ptr};
+use crate::{log_destroy, vec_as_ptr, warn};
 use crate::auxillary::enums::{IndexType, SharingMode};
 use crate::auxillary::flags::{BufferUsageFlags, MemoryPropertyFlags};
 use crate::auxillary::structs::MemoryRequirements;
 use crate::device::Device;
+use crate::spec::Shared;
 
 use super::spec::{Buffer, GpuPtr, HostBuffer, LocalBuffer, MemoryPool, TransferBuffer, Vertex};
 
@@ -63,7 +64,7 @@ fn populate_buffer_info(usage_flags: vk::BufferUsageFlags, sharing_mode: vk::Sha
 
 /***** HELPER FUNCTIONS *****/
 /// Creates & allocates a new vk::Buffer object.
-fn create_buffer(device: &Rc<Device>, pool: &Rc<RefCell<dyn MemoryPool>>, usage_flags: BufferUsageFlags, sharing_mode: &SharingMode, mem_props: MemoryPropertyFlags, capacity: usize) -> Result<(vk::Buffer, vk::DeviceMemory, GpuPtr, MemoryRequirements), Error> {
+fn create_buffer(device: &Shared<Device>, pool: &Rc<RefCell<dyn MemoryPool>>, usage_flags: BufferUsageFlags, sharing_mode: &SharingMode, mem_props: MemoryPropertyFlags, capacity: usize) -> Result<(vk::Buffer, vk::DeviceMemory, GpuPtr, MemoryRequirements), Error> {
     // Split the sharing mode
     let (vk_sharing_mode, vk_queue_family_indices) = sharing_mode.clone().into();
 
@@ -113,7 +114,7 @@ fn create_buffer(device: &Rc<Device>, pool: &Rc<RefCell<dyn MemoryPool>>, usage_
 /// The StagingBuffer is used to transfer memory to other Buffers.
 pub struct StagingBuffer {
     /// The Device where the Buffer lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The MemoryPool where the Buffer lives.
     pool   : Rc<RefCell<dyn MemoryPool>>,
 
@@ -150,7 +151,7 @@ impl StagingBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize) -> Result<Rc<Self>, Error> {
         Self::new_with_sharing_mode(device, pool, capacity, SharingMode::Exclusive)
     }
 
@@ -164,7 +165,7 @@ impl StagingBuffer {
     /// 
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
-    pub fn new_with_sharing_mode(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_with_sharing_mode(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Create a buffer in the helper function
         let (buffer, memory, ptr, mem_req): (vk::Buffer, vk::DeviceMemory, GpuPtr, MemoryRequirements) = create_buffer(
             &device, &pool,
@@ -230,7 +231,7 @@ impl StagingBuffer {
 impl Buffer for StagingBuffer {
     /// Returns the Device where the Buffer lives.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
     
     /// Returns the MemoryPool where the Buffer's memory is allocated.
     #[inline]
@@ -285,7 +286,10 @@ impl Drop for StagingBuffer {
         // Destroy the buffer
         unsafe { self.device.destroy_buffer(self.buffer, None); }
         // Lock the pool to free the memory
-        self.pool.borrow_mut().free(self.ptr);
+        match self.pool.borrow_mut().free(self.ptr) {
+            Ok(_) | Err(Error::FreeUnsupported{ .. }) => {},
+            Err(_err)                                 => { warn!("Failed to free buffer memory: {}", _err); },
+        }
     }
 }
 
@@ -294,7 +298,7 @@ impl Drop for StagingBuffer {
 /// The VertexBuffer is used to transfer vertices to the GPU.
 pub struct VertexBuffer {
     /// The Device where the Buffer lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The MemoryPool where the Buffer lives.
     pool   : Rc<RefCell<dyn MemoryPool>>,
 
@@ -334,7 +338,7 @@ impl VertexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new<V: Vertex>(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_vertices: usize) -> Result<Rc<Self>, Error> {
+    pub fn new<V: Vertex>(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_vertices: usize) -> Result<Rc<Self>, Error> {
         Self::new_with_sharing_mode::<V>(device, pool, n_vertices, SharingMode::Exclusive)
     }
 
@@ -351,7 +355,7 @@ impl VertexBuffer {
     /// 
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
-    pub fn new_with_sharing_mode<V: Vertex>(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_vertices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_with_sharing_mode<V: Vertex>(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_vertices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Compute the total capacity
         let capacity: usize = n_vertices * V::vk_size();
 
@@ -383,7 +387,7 @@ impl VertexBuffer {
 impl Buffer for VertexBuffer {
     /// Returns the Device where the Buffer lives.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
     
     /// Returns the MemoryPool where the Buffer's memory is allocated.
     #[inline]
@@ -438,7 +442,10 @@ impl Drop for VertexBuffer {
         // Destroy the buffer
         unsafe { self.device.destroy_buffer(self.buffer, None); }
         // Lock the pool to free the memory
-        self.pool.borrow_mut().free(self.ptr);
+        match self.pool.borrow_mut().free(self.ptr) {
+            Ok(_) | Err(Error::FreeUnsupported{ .. }) => {},
+            Err(_err)                                 => { warn!("Failed to free buffer memory: {}", _err); },
+        }
     }
 }
 
@@ -447,7 +454,7 @@ impl Drop for VertexBuffer {
 /// The IndexBuffer is used to transfer vertex indices to the GPU.
 pub struct IndexBuffer {
     /// The Device where the Buffer lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The MemoryPool where the Buffer lives.
     pool   : Rc<RefCell<dyn MemoryPool>>,
 
@@ -487,7 +494,7 @@ impl IndexBuffer {
     /// # Returns
     /// A new IndexBuffer, complete with allocated memory and already wrapped in an Rc-pointer.
     #[inline]
-    pub fn new(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, index_type: IndexType) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, index_type: IndexType) -> Result<Rc<Self>, Error> {
         // Relay to `new_with_sharing_mode` with the default SharingMode
         Self::new_with_sharing_mode(device, pool, n_indices, index_type, SharingMode::Exclusive)
     }
@@ -503,7 +510,7 @@ impl IndexBuffer {
     /// 
     /// # Returns
     /// A new IndexBuffer, complete with allocated memory and already wrapped in an Rc-pointer.
-    pub fn new_with_sharing_mode(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, index_type: IndexType, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_with_sharing_mode(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, index_type: IndexType, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Compute the total capacity
         let capacity: usize = n_indices * index_type.vk_size();
 
@@ -542,7 +549,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u8(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
+    pub fn new_u8(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 8-bit type flag
         Self::new(device, pool, n_indices, IndexType::UInt8)
     }
@@ -558,7 +565,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u8_with_sharing_mode<I: Sized>(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_u8_with_sharing_mode<I: Sized>(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 8-bit type flag
         Self::new_with_sharing_mode(device, pool, n_indices, IndexType::UInt8, sharing_mode)
     }
@@ -573,7 +580,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u16(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
+    pub fn new_u16(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 16-bit type flag
         Self::new(device, pool, n_indices, IndexType::UInt16)
     }
@@ -589,7 +596,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u16_with_sharing_mode<I: Sized>(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_u16_with_sharing_mode<I: Sized>(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 16-bit type flag
         Self::new_with_sharing_mode(device, pool, n_indices, IndexType::UInt16, sharing_mode)
     }
@@ -604,7 +611,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u32(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
+    pub fn new_u32(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 32-bit type flag
         Self::new(device, pool, n_indices, IndexType::UInt32)
     }
@@ -620,7 +627,7 @@ impl IndexBuffer {
     /// # Errors
     /// This function may error if the buffer creation in the Vulkan backend failed.
     #[inline]
-    pub fn new_u32_with_sharing_mode<I: Sized>(device: Rc<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+    pub fn new_u32_with_sharing_mode<I: Sized>(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, n_indices: usize, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
         // Relay it to the normal constructor but with the 32-bit type flag
         Self::new_with_sharing_mode(device, pool, n_indices, IndexType::UInt32, sharing_mode)
     }
@@ -638,7 +645,7 @@ impl IndexBuffer {
 impl Buffer for IndexBuffer {
     /// Returns the Device where the Buffer lives.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
     
     /// Returns the MemoryPool where the Buffer's memory is allocated.
     #[inline]
@@ -693,6 +700,160 @@ impl Drop for IndexBuffer {
         // Destroy the buffer
         unsafe { self.device.destroy_buffer(self.buffer, None); }
         // Lock the pool to free the memory
-        self.pool.borrow_mut().free(self.ptr);
+        match self.pool.borrow_mut().free(self.ptr) {
+            Ok(_) | Err(Error::FreeUnsupported{ .. }) => {},
+            Err(_err)                                 => { warn!("Failed to free buffer memory: {}", _err); },
+        }
+    }
+}
+
+
+
+/// The DeviceBuffer is a general-purpose, device-local Buffer with custom usage flags (e.g., for uniform or storage buffers).
+pub struct DeviceBuffer {
+    /// The Device where the Buffer lives.
+    device : Shared<Device>,
+    /// The MemoryPool where the Buffer lives.
+    pool   : Rc<RefCell<dyn MemoryPool>>,
+
+    /// The VkBuffer object we wrap.
+    buffer  : vk::Buffer,
+    /// The bound memory area for this buffer.
+    memory  : vk::DeviceMemory,
+    /// The offset in that memory area for this buffer.
+    ptr     : GpuPtr,
+
+    /// The size (in bytes) of this Buffer.
+    capacity     : usize,
+    /// The usage flags of this Buffer.
+    usage_flags  : BufferUsageFlags,
+    /// The sharing mode that determines which queue families have access to this Buffer.
+    sharing_mode : SharingMode,
+    /// The memory requirements of this Buffer.
+    mem_req      : MemoryRequirements,
+}
+
+impl DeviceBuffer {
+    /// The memory property flags for the DeviceBuffer
+    const MEM_PROPS: MemoryPropertyFlags = MemoryPropertyFlags::DEVICE_LOCAL;
+
+
+
+    /// Constructor for the DeviceBuffer.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Buffer-part of the Buffer (i.e., the non-content part) will live.
+    /// - `pool`: The MemoryPool where the Buffer-part of the Buffer (i.e., the content part) will live.
+    /// - `capacity`: The size (in bytes) of the buffer. Note that the actual capacity may be slightly higher due to alignment and such.
+    /// - `usage_flags`: The BufferUsageFlags that determine how we may use the Buffer. `BufferUsageFlags::TRANSFER_DST` is always added, so the Buffer can be filled.
+    /// 
+    /// # Errors
+    /// This function may error if the buffer creation in the Vulkan backend failed.
+    #[inline]
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize, usage_flags: BufferUsageFlags) -> Result<Rc<Self>, Error> {
+        Self::new_with_sharing_mode(device, pool, capacity, usage_flags, SharingMode::Exclusive)
+    }
+
+    /// Constructor for the DeviceBuffer that also accepts a custom sharing mode.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Buffer-part of the Buffer (i.e., the non-content part) will live.
+    /// - `pool`: The MemoryPool where the Buffer-part of the Buffer (i.e., the content part) will live.
+    /// - `capacity`: The size (in bytes) of the buffer. Note that the actual capacity may be slightly higher due to alignment and such.
+    /// - `usage_flags`: The BufferUsageFlags that determine how we may use the Buffer. `BufferUsageFlags::TRANSFER_DST` is always added, so the Buffer can be filled.
+    /// - `sharing_mode`: The mode of sharing the Buffer across queues.
+    /// 
+    /// # Errors
+    /// This function may error if the buffer creation in the Vulkan backend failed.
+    pub fn new_with_sharing_mode(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, capacity: usize, usage_flags: BufferUsageFlags, sharing_mode: SharingMode) -> Result<Rc<Self>, Error> {
+        // Create a buffer in the helper function
+        let usage_flags: BufferUsageFlags = usage_flags | BufferUsageFlags::TRANSFER_DST;
+        let (buffer, memory, ptr, mem_req): (vk::Buffer, vk::DeviceMemory, GpuPtr, MemoryRequirements) = create_buffer(
+            &device, &pool,
+            usage_flags,
+            &sharing_mode,
+            Self::MEM_PROPS,
+            capacity,
+        )?;
+
+        // Wrap it in ourselves as well as all other properties; done
+        Ok(Rc::new(Self {
+            device,
+            pool,
+
+            buffer,
+            memory,
+            ptr,
+
+            capacity,
+            usage_flags,
+            sharing_mode,
+            mem_req,
+        }))
+    }
+}
+
+impl Buffer for DeviceBuffer {
+    /// Returns the Device where the Buffer lives.
+    #[inline]
+    fn device(&self) -> &Shared<Device> { &self.device }
+    
+    /// Returns the MemoryPool where the Buffer's memory is allocated.
+    #[inline]
+    fn pool(&self) -> &Rc<RefCell<dyn MemoryPool>> { &self.pool }
+
+
+
+    /// Returns the Vulkan vk::Buffer which we wrap.
+    #[inline]
+    fn vk(&self) -> vk::Buffer { self.buffer }
+
+    /// Returns the Vulkan vk::DeviceMemory which we also wrap.
+    #[inline]
+    fn vk_mem(&self) -> vk::DeviceMemory { self.memory }
+
+    /// Returns the offset of this Buffer in the DeviceMemory.
+    #[inline]
+    fn vk_offset(&self) -> vk::DeviceSize { self.ptr.into() }
+
+
+
+    /// Returns the usage flags for this Buffer.
+    #[inline]
+    fn usage(&self) -> BufferUsageFlags { self.usage_flags }
+
+    /// Returns the usage flags for this Buffer.
+    #[inline]
+    fn sharing_mode(&self) -> &SharingMode { &self.sharing_mode }
+
+    /// Returns the memory requirements for this Buffer.
+    #[inline]
+    fn requirements(&self) -> &MemoryRequirements { &self.mem_req }
+
+    /// Returns the memory properties of the memory underlying this Buffer.
+    #[inline]
+    fn properties(&self) -> MemoryPropertyFlags { Self::MEM_PROPS }
+
+    /// Returns the actually allocated size of the buffer.
+    #[inline]
+    fn capacity(&self) -> usize { self.capacity }
+}
+
+impl LocalBuffer for DeviceBuffer {}
+
+impl TransferBuffer for DeviceBuffer {}
+
+impl Drop for DeviceBuffer {
+    #[inline]
+    fn drop(&mut self) {
+        log_destroy!(self, DeviceBuffer);
+
+        // Destroy the buffer
+        unsafe { self.device.destroy_buffer(self.buffer, None); }
+        // Lock the pool to free the memory
+        match self.pool.borrow_mut().free(self.ptr) {
+            Ok(_) | Err(Error::FreeUnsupported{ .. }) => {},
+            Err(_err)                                 => { warn!("Failed to free buffer memory: {}", _err); },
+        }
     }
 }
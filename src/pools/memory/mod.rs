@@ -17,6 +17,7 @@ pub mod spec;
 pub mod block;
 pub mod pools;
 pub mod buffers;
+pub mod staging;
 
 // Define a prelude to import
 pub mod prelude {
@@ -24,6 +25,7 @@ pub mod prelude {
 }
 
 // Bring some stuff into the module scope
-pub use buffers::{IndexBuffer, StagingBuffer, VertexBuffer};
-pub use spec::{Buffer, HostBuffer, LocalBuffer, MappedMemory, MemoryPool, TransferBuffer};
-pub use pools::{Error, BlockPool, LinearPool, MetaPool};
+pub use buffers::{DeviceBuffer, IndexBuffer, StagingBuffer, VertexBuffer};
+pub use spec::{Buffer, BufferSlice, HostBuffer, LocalBuffer, MappedMemory, MemoryPool, TransferBuffer};
+pub use pools::{Error, BlockPool, DensePool, LinearPool, MemoryTypeStats, MetaPool, PoolStats};
+pub use staging::StagingRing;
@@ -18,23 +18,28 @@ use std::slice;
 
 use ash::vk;
 
-use crate::warn;
+use crate::{log_destroy, warn};
 pub use crate::pools::errors::MemoryPoolError as Error;
+use crate::auxillary::enums::FitStrategy;
 use crate::auxillary::flags::{DeviceMemoryType, MemoryPropertyFlags};
 use crate::auxillary::structs::MemoryRequirements;
 use crate::device::Device;
 use crate::pools::memory::block::MemoryBlock;
 use crate::pools::memory::spec::{GpuPtr, MemoryPool};
+use crate::spec::Shared;
 
 
 /***** UNIT TESTS *****/
 #[cfg(test)]
 mod tests {
     use std::cell::RefMut;
-    use semver::Version;
     use crate::auxillary::flags::DeviceMemoryTypeFlags;
     use crate::auxillary::structs::DeviceFeatures;
     use crate::instance::Instance;
+    use crate::device::DeviceSelector;
+    use crate::pools::memory::buffers::StagingBuffer;
+    use crate::pools::memory::spec::Buffer;
+    use crate::spec::ApiVersion;
     use super::*;
 
     /// The instance extensions to use for the tests
@@ -44,19 +49,26 @@ mod tests {
     /// The device extensions to use for the tests
     const DEVICE_EXTENSIONS: &[&'static str]   = &[];
     /// The device layers to use for the tests
-    const DEVICE_LAYERS: &[&'static str]       = &[];
-    /// The device layers to use for the tests
     const DEVICE_FEATURES: DeviceFeatures      = DeviceFeatures::cdefault();
 
+    /// Returns the version of this crate as an ApiVersion.
+    fn crate_version() -> ApiVersion {
+        ApiVersion::new(
+            env!("CARGO_PKG_VERSION_MAJOR").parse().expect("Could not parse CARGO major version"),
+            env!("CARGO_PKG_VERSION_MINOR").parse().expect("Could not parse CARGO minor version"),
+            env!("CARGO_PKG_VERSION_PATCH").parse().expect("Could not parse CARGO patch version"),
+        )
+    }
+
     /// Tests the linearpool's allocation algorithm
     #[test]
     fn test_linear_pool() {
         // Initialize an instance and a device
         let instance = Instance::new(
             format!("{}_test_linear_pool", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             format!("{}_test_linear_pool_engine", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             INSTANCE_EXTENSIONS,
             INSTANCE_LAYERS,
         ).expect("Failed to initialize Instance");
@@ -65,11 +77,10 @@ mod tests {
             Device::auto_select(
                 instance.clone(),
                 &DEVICE_EXTENSIONS,
-                &DEVICE_LAYERS,
                 &DEVICE_FEATURES,
+                &DeviceSelector::default(),
             ).expect("Could not find a suitable GPU for tests"),
             &DEVICE_EXTENSIONS,
-            &DEVICE_LAYERS,
             &DEVICE_FEATURES,
         ).expect("Failed to initialize Device");
 
@@ -77,27 +88,27 @@ mod tests {
         let pool = LinearPool::new(device.clone(), 512);
         let mut mpool: RefMut<LinearPool> = pool.borrow_mut();
         // Allocate four non-aligned blocks of 128 bytes
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 128));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 256));
 
         // Create another to check it overflow correctly
         let pool = LinearPool::new(device.clone(), 512);
         let mut mpool: RefMut<LinearPool> = pool.borrow_mut();
         // Allocate a block that's always too large
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: 1024, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 1024, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
         // Next, allocate some blocks and then check out-of-bounds
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
@@ -107,25 +118,32 @@ mod tests {
         let pool = LinearPool::new(device.clone(), 512);
         let mut mpool: RefMut<LinearPool> = pool.borrow_mut();
         // Allocate the first block with  weird size
-        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         // Allocate one that needs to be aligned
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 136));
         // One with even bigger alignment
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 272));
         // This one should fail _because_ of its alignment
-        match mpool.allocate(&MemoryRequirements{ align: 32, size: 112, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 32, size: 112, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 400));
 
+        // Individual blocks cannot be freed
+        match mpool.free(pointer) {
+            Ok(_)                             => { panic!("LinearPool successfully freed a single block"); },
+            Err(Error::FreeUnsupported{ .. }) => {},
+            Err(err)                          => { panic!("Memory free failed: {}", err); },
+        }
+
         // If we now reset this pool, we should then be able to allocate new blocks
         mpool.reset();
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
     }
 
@@ -135,9 +153,9 @@ mod tests {
         // Initialize an instance and a device
         let instance = Instance::new(
             format!("{}_test_block_pool", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             format!("{}_test_block_pool_engine", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             INSTANCE_EXTENSIONS,
             INSTANCE_LAYERS,
         ).expect("Failed to initialize Instance");
@@ -146,92 +164,279 @@ mod tests {
             Device::auto_select(
                 instance.clone(),
                 &DEVICE_EXTENSIONS,
-                &DEVICE_LAYERS,
                 &DEVICE_FEATURES,
+                &DeviceSelector::default(),
             ).expect("Could not find a suitable GPU for tests"),
             &DEVICE_EXTENSIONS,
-            &DEVICE_LAYERS,
             &DEVICE_FEATURES,
         ).expect("Failed to initialize Device");
 
         // Create a BlockPool on said device
-        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"));
+        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"), FitStrategy::First);
         let mut mpool: RefMut<BlockPool> = pool.borrow_mut();
         // Allocate four non-aligned blocks of 128 bytes
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 128));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 256));
 
         // Create another to check it overflow correctly
-        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"));
+        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"), FitStrategy::First);
         let mut mpool: RefMut<BlockPool> = pool.borrow_mut();
         // Allocate a block that's always too large
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: 1024, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 1024, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
         // Next, allocate some blocks and then check out-of-bounds
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
 
         // A block to check alignment
-        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"));
+        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"), FitStrategy::First);
         let mut mpool: RefMut<BlockPool> = pool.borrow_mut();
         // Allocate the first block with  weird size
-        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         // Allocate one that needs to be aligned
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 136));
         // One with even bigger alignment
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 272));
         // This one should fail _because_ of its alignment
-        match mpool.allocate(&MemoryRequirements{ align: 32, size: 112, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 32, size: 112, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 400));
 
         // If we now reset this pool, we should then be able to allocate new blocks
         mpool.reset();
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
 
         // Finally we do a pool to check if it properly frees
-        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"));
+        let pool = BlockPool::new(device.clone(), MemoryBlock::allocate(device.clone(), &MemoryRequirements{ align: 1, size: 512, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Could not allocate block pool memory block"), FitStrategy::First);
         let mut mpool: RefMut<BlockPool> = pool.borrow_mut();
         // Allocate three blocks of 128 bytes
-        let (_, _       ) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
-        let (_, pointer2) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
-        let (_, pointer3) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, _       ) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, pointer2) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer3) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
         // Free the second
-        mpool.free(pointer2);
+        mpool.free(pointer2).expect("Failed to free block");
         // Where we expect the new pointer to be allocated we don't know, but we should be able to allocate at least two
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fifth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fifth block");
         // Free the third now
-        mpool.free(pointer3);
+        mpool.free(pointer3).expect("Failed to free block");
         // This one fails bc not enough space
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
         }
         // This _two_ succeed again
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 37, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 4, size: 60, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 37, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 4, size: 60, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+
+        // Freeing a pointer twice is caught
+        match mpool.free(pointer3) {
+            Ok(_)                            => { panic!("Pool successfully freed a block that was already freed"); },
+            Err(Error::UnknownPointer{ .. }) => {},
+            Err(err)                         => { panic!("Memory free failed: {}", err); },
+        }
+    }
+
+    /// Tests the different FitStrategies on a fragmented list of free blocks
+    #[test]
+    fn test_fit_strategy() {
+        // A fragmented pool with free blocks of 128, 64 and 256 bytes
+        let free: Vec<(GpuPtr, usize)> = vec![ (GpuPtr::new(0, 0, 0), 128), (GpuPtr::new(0, 0, 256), 64), (GpuPtr::new(0, 0, 512), 256) ];
+        let reqs = MemoryRequirements{ align: 1, size: 64, types: DeviceMemoryTypeFlags::all(), linear: true };
+
+        // Each strategy picks a different block for a 64-byte allocation
+        assert_eq!(select_free_block(&free, &reqs, FitStrategy::First), Some((0, GpuPtr::new(0, 0, 0), 64)));
+        assert_eq!(select_free_block(&free, &reqs, FitStrategy::Best), Some((1, GpuPtr::new(0, 0, 256), 64)));
+        assert_eq!(select_free_block(&free, &reqs, FitStrategy::Worst), Some((2, GpuPtr::new(0, 0, 512), 64)));
+
+        // Alignment padding counts towards whether a block fits
+        let free: Vec<(GpuPtr, usize)> = vec![ (GpuPtr::new(0, 0, 200), 72), (GpuPtr::new(0, 0, 512), 128) ];
+        let reqs = MemoryRequirements{ align: 32, size: 64, types: DeviceMemoryTypeFlags::all(), linear: true };
+        assert_eq!(select_free_block(&free, &reqs, FitStrategy::Best), Some((1, GpuPtr::new(0, 0, 512), 64)));
+        let reqs = MemoryRequirements{ align: 8, size: 64, types: DeviceMemoryTypeFlags::all(), linear: true };
+        assert_eq!(select_free_block(&free, &reqs, FitStrategy::Best), Some((0, GpuPtr::new(0, 0, 200), 64)));
+
+        // Best-fit keeps the larger block intact for a later, larger allocation, whereas first-fit splits it up
+        let free: Vec<(GpuPtr, usize)> = vec![ (GpuPtr::new(0, 0, 0), 128), (GpuPtr::new(0, 0, 256), 64) ];
+        let small = MemoryRequirements{ align: 1, size: 64, types: DeviceMemoryTypeFlags::all(), linear: true };
+        let large = MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true };
+        for (strategy, fits) in [ (FitStrategy::First, false), (FitStrategy::Best, true) ] {
+            let mut free: Vec<(GpuPtr, usize)> = free.clone();
+            let (index, _, size) = select_free_block(&free, &small, strategy).expect("Failed to select block for small allocation");
+            free[index].0 += size;
+            free[index].1 -= size;
+            assert_eq!(select_free_block(&free, &large, strategy).is_some(), fits);
+        }
+
+        // Nothing is selected if no block is large enough
+        assert_eq!(select_free_block(&free, &MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all(), linear: true }, FitStrategy::Worst), None);
+    }
+
+    /// Tests the densepool's allocation algorithm
+    #[test]
+    fn test_dense_pool() {
+        // Initialize an instance and a device
+        let instance = Instance::new(
+            format!("{}_test_dense_pool", file!()),
+            crate_version(),
+            format!("{}_test_dense_pool_engine", file!()),
+            crate_version(),
+            INSTANCE_EXTENSIONS,
+            INSTANCE_LAYERS,
+        ).expect("Failed to initialize Instance");
+        let device = Device::new(
+            instance.clone(),
+            Device::auto_select(
+                instance.clone(),
+                DEVICE_EXTENSIONS,
+                &DEVICE_FEATURES,
+                &DeviceSelector::default(),
+            ).expect("Could not find a suitable GPU for tests"),
+            DEVICE_EXTENSIONS,
+            &DEVICE_FEATURES,
+        ).expect("Failed to initialize Device");
+
+        // Create a DensePool on said device
+        let pool = DensePool::new(device.clone(), 512);
+        let mut mpool: RefMut<DensePool> = pool.borrow_mut();
+        // Allocate three non-aligned blocks of 128 bytes
+        let (_, first) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        assert_eq!(first, GpuPtr::new(0, 0, 0));
+        let (_, second) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        assert_eq!(second, GpuPtr::new(0, 0, 128));
+        let (_, third) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        assert_eq!(third, GpuPtr::new(0, 0, 256));
+        assert_eq!(mpool.size(), 384);
+
+        // Free the middle block; a block of the same size should re-use the hole instead of the space at the end
+        mpool.free(second).expect("Failed to free block");
+        assert_eq!(mpool.size(), 256);
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to re-allocate second block");
+        assert_eq!(pointer, GpuPtr::new(0, 0, 128));
+
+        // Freeing the first two blocks merges them, such that a block of twice the size fits in their place
+        mpool.free(first).expect("Failed to free block");
+        mpool.free(pointer).expect("Failed to free block");
+        assert_eq!(mpool.free_areas(), 2);
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate merged block");
+        assert_eq!(pointer, GpuPtr::new(0, 0, 0));
+
+        // The remaining 128 bytes at the end can't hold a larger block
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: 129, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
+            Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
+            Err(Error::OutOfMemoryError{ .. }) => {},
+            Err(err)                           => { panic!("Memory allocation failed: {}", err); },
+        }
+
+        // Alignment padding is returned together with the block, so freeing everything leaves a single free area
+        mpool.free(pointer).expect("Failed to free block");
+        mpool.free(third).expect("Failed to free block");
+        let (_, first) = mpool.allocate(&MemoryRequirements{ align: 1, size: 3, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, second) = mpool.allocate(&MemoryRequirements{ align: 16, size: 16, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate aligned block");
+        assert_eq!(second, GpuPtr::new(0, 0, 16));
+        mpool.free(second).expect("Failed to free block");
+        mpool.free(first).expect("Failed to free block");
+        assert_eq!((mpool.size(), mpool.free_areas()), (0, 1));
+    }
+
+    /// Tests that freed blocks are merged with their neighbours
+    #[test]
+    fn test_insert_free_block() {
+        // Blocks that are not adjacent are inserted in order
+        let mut free: Vec<(GpuPtr, usize)> = vec![ (GpuPtr::from(0usize), 16), (GpuPtr::from(64usize), 16) ];
+        insert_free_block(&mut free, (GpuPtr::from(32usize), 8));
+        assert_eq!(free, vec![ (GpuPtr::from(0usize), 16), (GpuPtr::from(32usize), 8), (GpuPtr::from(64usize), 16) ]);
+
+        // Blocks are merged with the previous, the next or both
+        insert_free_block(&mut free, (GpuPtr::from(16usize), 8));
+        assert_eq!(free, vec![ (GpuPtr::from(0usize), 24), (GpuPtr::from(32usize), 8), (GpuPtr::from(64usize), 16) ]);
+        insert_free_block(&mut free, (GpuPtr::from(48usize), 16));
+        assert_eq!(free, vec![ (GpuPtr::from(0usize), 24), (GpuPtr::from(32usize), 8), (GpuPtr::from(48usize), 32) ]);
+        insert_free_block(&mut free, (GpuPtr::from(40usize), 8));
+        assert_eq!(free, vec![ (GpuPtr::from(0usize), 24), (GpuPtr::from(32usize), 48) ]);
+        insert_free_block(&mut free, (GpuPtr::from(24usize), 8));
+        assert_eq!(free, vec![ (GpuPtr::from(0usize), 80) ]);
+
+        // Blocks are inserted in empty lists too
+        let mut free: Vec<(GpuPtr, usize)> = vec![];
+        insert_free_block(&mut free, (GpuPtr::from(8usize), 8));
+        assert_eq!(free, vec![ (GpuPtr::from(8usize), 8) ]);
+    }
+
+    /// Tests that non-linear resources are padded to whole bufferImageGranularity pages
+    #[test]
+    fn test_pad_granularity() {
+        // Linear resources are left alone
+        let reqs = pad_granularity(&MemoryRequirements{ align: 4, size: 100, types: DeviceMemoryTypeFlags::all(), linear: true }, 1024);
+        assert_eq!((reqs.align, reqs.size), (4, 100));
+
+        // Non-linear ones start and end on a page boundary
+        let reqs = pad_granularity(&MemoryRequirements{ align: 4, size: 100, types: DeviceMemoryTypeFlags::all(), linear: false }, 1024);
+        assert_eq!((reqs.align, reqs.size), (1024, 1024));
+        let reqs = pad_granularity(&MemoryRequirements{ align: 4096, size: 1025, types: DeviceMemoryTypeFlags::all(), linear: false }, 1024);
+        assert_eq!((reqs.align, reqs.size), (4096, 2048));
+
+        // Nothing changes if the device has no granularity to speak of
+        let reqs = pad_granularity(&MemoryRequirements{ align: 4, size: 100, types: DeviceMemoryTypeFlags::all(), linear: false }, 1);
+        assert_eq!((reqs.align, reqs.size), (4, 100));
+    }
+
+    /// Tests that a pool keeps buffers and optimal images on separate bufferImageGranularity pages
+    #[test]
+    fn test_buffer_image_granularity() {
+        // Initialize an instance and a device
+        let instance = Instance::new(
+            format!("{}_test_buffer_image_granularity", file!()),
+            crate_version(),
+            format!("{}_test_buffer_image_granularity_engine", file!()),
+            crate_version(),
+            INSTANCE_EXTENSIONS,
+            INSTANCE_LAYERS,
+        ).expect("Failed to initialize Instance");
+        let device = Device::new(
+            instance.clone(),
+            Device::auto_select(
+                instance.clone(),
+                DEVICE_EXTENSIONS,
+                &DEVICE_FEATURES,
+                &DeviceSelector::default(),
+            ).expect("Could not find a suitable GPU for tests"),
+            DEVICE_EXTENSIONS,
+            &DEVICE_FEATURES,
+        ).expect("Failed to initialize Device");
+        let granularity: u64 = device.get_physical_device_props().limits.buffer_image_granularity;
+
+        // Create a LinearPool on said device that is large enough for a few pages
+        let pool = LinearPool::new(device.clone(), 4 * granularity as usize);
+        let mut mpool: RefMut<LinearPool> = pool.borrow_mut();
+        // Allocate a buffer, then an optimal image, then a buffer again
+        let (_, buffer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 100, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first buffer");
+        assert_eq!(buffer, GpuPtr::new(0, 0, 0));
+        let (_, image) = mpool.allocate(&MemoryRequirements{ align: 1, size: 100, types: DeviceMemoryTypeFlags::all(), linear: false }, MemoryPropertyFlags::empty()).expect("Failed to allocate image");
+        assert_eq!(image, GpuPtr::new(0, 0, 100).align(granularity));
+        let (_, buffer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 100, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second buffer");
+        assert_eq!(buffer, (image + 100).align(granularity));
     }
 
     /// Tests the metapool's allocation algorithm
@@ -240,9 +445,9 @@ mod tests {
         // Initialize an instance and a device
         let instance = Instance::new(
             format!("{}_test_block_pool", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             format!("{}_test_block_pool_engine", file!()),
-            Version::parse(env!("CARGO_PKG_VERSION")).expect("Could not parse CARGO version"),
+            crate_version(),
             INSTANCE_EXTENSIONS,
             INSTANCE_LAYERS,
         ).expect("Failed to initialize Instance");
@@ -251,11 +456,10 @@ mod tests {
             Device::auto_select(
                 instance.clone(),
                 &DEVICE_EXTENSIONS,
-                &DEVICE_LAYERS,
                 &DEVICE_FEATURES,
+                &DeviceSelector::default(),
             ).expect("Could not find a suitable GPU for tests"),
             &DEVICE_EXTENSIONS,
-            &DEVICE_LAYERS,
             &DEVICE_FEATURES,
         ).expect("Failed to initialize Device");
 
@@ -263,18 +467,18 @@ mod tests {
         let pool = MetaPool::new(device.clone(), 2048);
         let mut mpool: RefMut<MetaPool> = pool.borrow_mut();
         // Allocate four non-aligned blocks of 128 bytes
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 128));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 256, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 256));
 
         // Create another to check it overflow correctly
         let pool = MetaPool::new(device.clone(), 2048);
         let mut mpool: RefMut<MetaPool> = pool.borrow_mut();
         // Allocate a block that's always too large
-        match mpool.allocate(&MemoryRequirements{ align: 1, size: usize::MAX, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()) {
+        match mpool.allocate(&MemoryRequirements{ align: 1, size: usize::MAX, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()) {
             Ok(_)                              => { panic!("Pool successfully allocated block that should throw out-of-memory"); },
             Err(Error::OutOfMemoryError{ .. }) => {},
             Err(err)                           => { panic!("Memory allocation failed: {}", err); },
@@ -284,46 +488,96 @@ mod tests {
         let pool = MetaPool::new(device.clone(), 2048);
         let mut mpool: RefMut<MetaPool> = pool.borrow_mut();
         // Allocate the first block with  weird size
-        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, _)       = mpool.allocate(&MemoryRequirements{ align: 1, size: 133, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
         // Allocate one that needs to be aligned
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 4, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 136));
         // One with even bigger alignment
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 16, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 272));
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 112, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 400));
 
         // If we now reset this pool, we should then be able to allocate new blocks
         mpool.reset();
-        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
         assert_eq!(pointer, GpuPtr::new(0, 0, 0));
 
         // Finally we do a pool to check if it properly frees
         let pool = MetaPool::new(device.clone(), 2048);
         let mut mpool: RefMut<MetaPool> = pool.borrow_mut();
         // Allocate three blocks of 128 bytes
-        let (_, _       ) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
-        let (_, pointer2) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
-        let (_, pointer3) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, _       ) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate first block");
+        let (_, pointer2) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate second block");
+        let (_, pointer3) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
         // Free the second
-        mpool.free(pointer2);
+        mpool.free(pointer2).expect("Failed to free block");
         // Where we expect the new pointer to be allocated we don't know, but we should be able to allocate at least two
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fifth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fifth block");
         // Free the third now
-        mpool.free(pointer3);
+        mpool.free(pointer3).expect("Failed to free block");
         // This _two_ succeed again
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 37, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 4, size: 60, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 37, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 4, size: 60, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate fourth block");
 
         // It can also allocate multiple blocks of memory
         // NOTE: Might want to remove this, especially the last one
         let pool = MetaPool::new(device.clone(), 2048);
         let mut mpool: RefMut<MetaPool> = pool.borrow_mut();
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::HOST_COHERENT).expect("Failed to allocate first block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all() }, MemoryPropertyFlags::DEVICE_LOCAL).expect("Failed to allocate second block");
-        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::from(2 as u32) }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::HOST_COHERENT).expect("Failed to allocate first block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::all(), linear: true }, MemoryPropertyFlags::DEVICE_LOCAL).expect("Failed to allocate second block");
+        let (_, _) = mpool.allocate(&MemoryRequirements{ align: 1, size: 128, types: DeviceMemoryTypeFlags::from(2 as u32), linear: true }, MemoryPropertyFlags::empty()).expect("Failed to allocate third block");
+    }
+
+    /// Tests that the metapool's statistics follow allocations and frees
+    #[test]
+    fn test_meta_pool_stats() {
+        // Initialize an instance and a device
+        let instance = Instance::new(
+            format!("{}_test_meta_pool_stats", file!()),
+            crate_version(),
+            format!("{}_test_meta_pool_stats_engine", file!()),
+            crate_version(),
+            INSTANCE_EXTENSIONS,
+            INSTANCE_LAYERS,
+        ).expect("Failed to initialize Instance");
+        let device = Device::new(
+            instance.clone(),
+            Device::auto_select(
+                instance.clone(),
+                &DEVICE_EXTENSIONS,
+                &DEVICE_FEATURES,
+                &DeviceSelector::default(),
+            ).expect("Could not find a suitable GPU for tests"),
+            &DEVICE_EXTENSIONS,
+            &DEVICE_FEATURES,
+        ).expect("Failed to initialize Device");
+
+        // An empty pool has nothing reserved
+        let pool = MetaPool::new(device.clone(), 2048);
+        assert_eq!(pool.borrow().stats(), PoolStats{ reserved: 0, allocated: 0, blocks: 0, types: vec![] });
+
+        // Allocate two buffers
+        let first  = StagingBuffer::new(device.clone(), pool.clone(), 128).expect("Failed to allocate first buffer");
+        let second = StagingBuffer::new(device.clone(), pool.clone(), 256).expect("Failed to allocate second buffer");
+        let stats: PoolStats = pool.borrow().stats();
+        // Buffers with the same usage have the same alignment, so the second buffer is placed right after the first one, padded to that alignment
+        let align: usize = first.requirements().align as usize;
+        assert_eq!(stats.allocated, first.requirements().size.div_ceil(align) * align + second.requirements().size);
+        assert_eq!(stats.blocks, 2);
+        assert!(stats.reserved >= stats.allocated);
+        assert_eq!(stats.allocated, stats.types.iter().map(|mem_type| mem_type.allocated).sum::<usize>());
+        assert_eq!(pool.borrow().size(), stats.allocated);
+
+        // Freeing one buffer is reflected as well
+        let second_size: usize = second.requirements().size;
+        drop(second);
+        let stats: PoolStats = pool.borrow().stats();
+        assert_eq!(stats.allocated, first.requirements().size);
+        assert_eq!(stats.blocks, 1);
+        // The memory itself stays reserved for later allocations
+        assert!(stats.reserved >= stats.allocated + second_size);
     }
 }
 
@@ -352,6 +606,92 @@ impl<T, E> DiscreetUnwrap<T, E> for Result<T, E> {
 
 
 
+/***** HELPER FUNCTIONS *****/
+/// Pads the given memory requirements such that a non-linear resource occupies whole `bufferImageGranularity` pages.
+/// 
+/// Vulkan requires that linear and non-linear resources never share such a page. By giving every non-linear resource its own pages, we never have to look at what its neighbours are.
+/// 
+/// # Arguments
+/// - `reqs`: The memory requirements of the new allocation.
+/// - `granularity`: The `bufferImageGranularity` of the device. Must be a power of two.
+/// 
+/// # Returns
+/// The padded memory requirements, which are the same as `reqs` for linear resources.
+fn pad_granularity(reqs: &MemoryRequirements, granularity: u64) -> MemoryRequirements {
+    if reqs.linear || granularity <= 1 { return reqs.clone(); }
+    MemoryRequirements {
+        size   : ((reqs.size as u64 + granularity - 1) & !(granularity - 1)) as usize,
+        align  : std::cmp::max(reqs.align, granularity),
+        types  : reqs.types,
+        linear : false,
+    }
+}
+
+/// Selects the free block to serve a new allocation from.
+/// 
+/// # Arguments
+/// - `free`: The list of free blocks to choose from, as `(offset, size)` pairs.
+/// - `reqs`: The memory requirements of the new allocation.
+/// - `strategy`: The FitStrategy that decides which of the blocks that are large enough is picked.
+/// 
+/// # Returns
+/// A tuple with the index of the chosen free block on `.0`, the aligned pointer of the new allocation on `.1` and the number of bytes taken from the free block (including alignment padding) on `.2`, or `None` if no block is large enough.
+fn select_free_block(free: &[(GpuPtr, usize)], reqs: &MemoryRequirements, strategy: FitStrategy) -> Option<(usize, GpuPtr, usize)> {
+    let mut chosen: Option<(usize, GpuPtr, usize)> = None;
+    for (i, (block_ptr, block_size)) in free.iter().enumerate() {
+        // Compute the aligned pointer for this block, and take that into account with the aligned size
+        let align_ptr: GpuPtr = block_ptr.align(reqs.align);
+        let new_size: usize   = (align_ptr.ptr() - block_ptr.ptr()) as usize + reqs.size;
+        // If not enough size, try the next one
+        if new_size > *block_size { continue; }
+
+        // Decide whether this block is better than what we found so far
+        let better: bool = match (strategy, chosen) {
+            (_, None)                           => true,
+            (FitStrategy::First, Some(_))       => false,
+            (FitStrategy::Best, Some((j, ..)))  => *block_size < free[j].1,
+            (FitStrategy::Worst, Some((j, ..))) => *block_size > free[j].1,
+        };
+        if better { chosen = Some((i, align_ptr, new_size)); }
+
+        // Quit for speedz
+        if strategy == FitStrategy::First { break; }
+    }
+    chosen
+}
+
+/// Returns a block to a list of free blocks, merging it with any free blocks that are directly adjacent to it.
+/// 
+/// # Arguments
+/// - `free`: The list of free blocks, as `(offset, size)` pairs. Must be sorted by offset, which it will still be afterwards.
+/// - `block`: The `(offset, size)` of the block to return. Must not overlap with any of the free blocks.
+fn insert_free_block(free: &mut Vec<(GpuPtr, usize)>, block: (GpuPtr, usize)) {
+    let (mut ptr, mut size): (GpuPtr, usize) = block;
+
+    // Find where the block goes in the (sorted) list
+    let mut index: usize = free.partition_point(|(free_ptr, _)| free_ptr.ptr() < ptr.ptr());
+
+    // Merge with the next block if it starts where this one ends
+    if index < free.len() && free[index].0.ptr() == ptr.ptr() + size as u64 {
+        size += free[index].1;
+        free.remove(index);
+    }
+    // Merge with the previous block if it ends where this one starts
+    if index > 0 && free[index - 1].0.ptr() + free[index - 1].1 as u64 == ptr.ptr() {
+        index -= 1;
+        ptr   = free[index].0;
+        size += free[index].1;
+        free.remove(index);
+    }
+
+    // Insert the (merged) block
+    free.insert(index, (ptr, size));
+}
+
+
+
+
+
 /***** HELPER STRUCTS *****/
 /// Groups the BlockPools belonging to one type.
 struct MemoryType {
@@ -371,7 +711,7 @@ struct MemoryType {
 /// A LinearPool uses a very fast memory allocation algorithm, but wastes space because freed blocks cannot be re-used until the pool is reset. Additionally, this type of pool only supports one type of memory.
 pub struct LinearPool {
     /// The Device where the LinearPool lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The single memory block used in the linear pool.
     block  : Option<MemoryBlock>,
 
@@ -392,7 +732,7 @@ impl LinearPool {
     /// # Returns
     /// A new LinearPool instance, already wrapped in an Rc and a RefCell.
     #[inline]
-    pub fn new(device: Rc<Device>, capacity: usize) -> Rc<RefCell<Self>> {
+    pub fn new(device: Shared<Device>, capacity: usize) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Self {
             device,
             block : None,
@@ -439,11 +779,14 @@ impl MemoryPool for LinearPool {
     /// # Errors
     /// This function errors if the MemoryPool failed to allocate new memory.
     fn allocate(&mut self, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<(vk::DeviceMemory, GpuPtr), Error> {
+        // Keep non-linear resources on their own granularity pages
+        let reqs: MemoryRequirements = pad_granularity(reqs, self.device.get_physical_device_props().limits.buffer_image_granularity);
+
         // Check whether we have a block of memory already
         let memory: vk::DeviceMemory = match self.block.as_ref() {
             Some(block) => {
                 // Make sure the requirements & properties are satisfied
-                if !reqs.types.check(block.mem_type().into()) { panic!("LinearPool is allocated for device memory type {}, but new allocation only supports {}", block.mem_type(), reqs.types); }
+                if !reqs.types.iter_indices().any(|i| i == u32::from(block.mem_type())) { panic!("LinearPool is allocated for device memory type {}, but new allocation only supports {}", block.mem_type(), reqs.types); }
                 if !block.mem_props().check(props) { panic!("LinearPool is allocated for device memory type {} which supports the properties {}, but new allocation requires {}", block.mem_type(), block.mem_props(), props); }
                 block.vk()
             },
@@ -468,18 +811,18 @@ impl MemoryPool for LinearPool {
         Ok((memory, pointer))
     }
 
-    /// Frees an allocated bit of memory.
+    /// Would free an allocated bit of memory, but the LinearPool does not support this.
     /// 
-    /// Note that not all types of pools may actually do anything with this. A LinearPool, for example, might deallocate but will never re-use that memory until reset anyway.
+    /// Its memory can only be released all at once with `MemoryPool::reset()`.
     /// 
     /// # Arguments
     /// - `pointer`: The pointer to the block that was allocated.
     /// 
-    /// # Panics
-    /// This function may panic if the given pointer was never allocated with this pool.
+    /// # Errors
+    /// This function always errors with `Error::FreeUnsupported`.
     #[inline]
-    fn free(&mut self, _pointer: GpuPtr) {
-        warn!("Calling `LinearPool::free()` has no effect");
+    fn free(&mut self, _pointer: GpuPtr) -> Result<(), Error> {
+        Err(Error::FreeUnsupported{ pool: "LinearPool" })
     }
 
     /// Resets the memory pool back to its initial, empty state.
@@ -490,7 +833,7 @@ impl MemoryPool for LinearPool {
 
     /// Returns the device of the pool.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the used space in the pool.
     #[inline]
@@ -501,16 +844,28 @@ impl MemoryPool for LinearPool {
     fn capacity(&self) -> usize { self.capacity }
 }
 
+impl Drop for LinearPool {
+    fn drop(&mut self) {
+        log_destroy!(self, LinearPool);
+
+        // Since the LinearPool does not track individual allocations, we can only warn if anything has been allocated since the last reset
+        if self.block.is_some() && usize::from(self.pointer) > 0 { warn!("LinearPool is dropped while {} bytes have not been reset; any Buffers still using them now refer to freed memory", usize::from(self.pointer)); }
+
+        // The MemoryBlock frees the device memory when dropped
+        self.block = None;
+    }
+}
+
 
 
 /// A BlockPool uses a more complicated and slow allocation algorithm, but saves space because it does reuse freed blocks. This specific type of pool only supports one type of memory.
 pub struct BlockPool {
     /// The Device where the BlockPool lives.
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The single memory block used in this pool.
     block  : MemoryBlock,
 
-    /// The list of free blocks in the BlockPool.
+    /// The list of free blocks in the BlockPool, sorted by offset.
     /// 
     /// Elements are of the shape:
     /// - `.0`: The offset of the block compared to the MemoryBlock.
@@ -519,11 +874,15 @@ pub struct BlockPool {
     /// The list of used blocks in the BlockPool.
     /// 
     /// Elements are of the shape:
-    /// - `.0`: The offset of the block compared to the MemoryBlock.
-    /// - `.1`: The size of the block (in bytes).
-    used : Vec<(GpuPtr, usize)>,
-    /// The used space in the BlockPool.
+    /// - `.0`: The (aligned) pointer that was handed out for the block.
+    /// - `.1`: The offset where the block starts, which is before `.0` if it needed alignment padding.
+    /// - `.2`: The size of the block (in bytes), including the alignment padding.
+    used : Vec<(GpuPtr, GpuPtr, usize)>,
+    /// The used space in the BlockPool, including alignment padding.
     size : usize,
+
+    /// The strategy with which we pick a free block for new allocations.
+    strategy : FitStrategy,
 }
 
 impl BlockPool {
@@ -531,10 +890,11 @@ impl BlockPool {
     /// 
     /// # Arguments
     /// - `block`: The already allocated MemoryBlock. If you have yet to allocate one, check `MemoryBlock::allocate()`.
+    /// - `strategy`: The FitStrategy that determines which free block is used for new allocations. `FitStrategy::default()` (best-fit) keeps fragmentation lowest.
     /// 
     /// # Returns
     /// A new BlockPool instance, already wrapped in an Rc and a RefCell.
-    pub fn new(device: Rc<Device>, block: MemoryBlock) -> Rc<RefCell<Self>> {
+    pub fn new(device: Shared<Device>, block: MemoryBlock, strategy: FitStrategy) -> Rc<RefCell<Self>> {
         // Get the new capacity
         let capacity = block.mem_size();
 
@@ -546,8 +906,16 @@ impl BlockPool {
             free : vec![ (GpuPtr::default(), capacity) ],
             used : Vec::with_capacity(1),
             size : 0,
+
+            strategy,
         }))
     }
+
+
+
+    /// Returns the FitStrategy with which this BlockPool picks free blocks.
+    #[inline]
+    pub fn strategy(&self) -> FitStrategy { self.strategy }
 }
 
 impl MemoryPool for BlockPool {
@@ -563,84 +931,61 @@ impl MemoryPool for BlockPool {
     /// # Errors
     /// This function errors if the MemoryPool failed to allocate new memory.
     fn allocate(&mut self, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<(vk::DeviceMemory, GpuPtr), Error> {
+        // Keep non-linear resources on their own granularity pages
+        let reqs: MemoryRequirements = pad_granularity(reqs, self.device.get_physical_device_props().limits.buffer_image_granularity);
+
         // Make sure the requirements & properties are satisfied
-        if !reqs.types.check(self.block.mem_type().into()) { panic!("BlockPool is allocated for device memory type {}, but new allocation only supports {}", self.block.mem_type(), reqs.types); }
+        if !reqs.types.iter_indices().any(|i| i == u32::from(self.block.mem_type())) { panic!("BlockPool is allocated for device memory type {}, but new allocation only supports {}", self.block.mem_type(), reqs.types); }
         if !self.block.mem_props().check(props) { panic!("BlockPool is allocated for device memory type {} which supports the properties {}, but new allocation requires {}", self.block.mem_type(), self.block.mem_props(), props); }
 
         // Optimization: we can stop early if there is no more space
         if reqs.size > self.block.mem_size() { return Err(Error::OutOfMemoryError{ req_size: reqs.size }); }
 
         // Now, search for a free block with enough size
-        let mut new_used: (GpuPtr, usize) = (GpuPtr::null(), reqs.size);
-        let mut remove: Option<usize> = None;
-        for (i, (block_ptr, block_size)) in self.free.iter_mut().enumerate() {
-            // Compute the aligned pointer for this block
-            let align_ptr: GpuPtr = block_ptr.align(reqs.align);
-
-            // Take that into account with the aligned size
-            let new_size: usize = (align_ptr.ptr() - block_ptr.ptr()) as usize + reqs.size;
-            if new_size <= *block_size {
-                // Set the pointer of the new used block
-                new_used.0 = align_ptr;
-
-                // Split the block in a used block and shrink the free block.
-                *block_ptr  += new_size;
-                *block_size -= new_size;
-                // Mark for removal if that leaves us with an empty block
-                if *block_size == 0 { remove = Some(i); }
-
-                // Quit for speedz
-                break;
-            }
+        let (index, pointer, new_size): (usize, GpuPtr, usize) = match select_free_block(&self.free, &reqs, self.strategy) {
+            Some(res) => res,
+            None      => { return Err(Error::OutOfMemoryError{ req_size: reqs.size }); }
+        };
 
-            // If not enough size, try the next one
-        }
-        if new_used.0.is_null() { return Err(Error::OutOfMemoryError{ req_size: reqs.size }); }
-        // If needed, remove the free block
-        if let Some(index) = remove { self.free.swap_remove(index); }
+        // Split the block (including its alignment padding) in a used block and shrink the free block, removing it if that leaves us with an empty block
+        let (block_ptr, block_size) = &mut self.free[index];
+        let start: GpuPtr = *block_ptr;
+        *block_ptr  += new_size;
+        *block_size -= new_size;
+        if *block_size == 0 { self.free.remove(index); }
 
         // Insert the new used block
         if self.used.len() == self.used.capacity() { self.used.reserve(self.used.capacity()); }
-        self.used.push(new_used);
+        self.used.push((pointer, start, new_size));
 
         // Update the size and we're done
-        self.size += new_used.1;
-        println!("Blocks after allocate:\n - Used: {:?}\n - Free: {:?}", self.used, self.free);
-        Ok((self.block.vk(), new_used.0))
+        self.size += new_size;
+        Ok((self.block.vk(), pointer))
     }
 
     /// Frees an allocated bit of memory.
     /// 
-    /// Note that not all types of pools may actually do anything with this. A LinearPool, for example, might deallocate but will never re-use that memory until reset anyway.
+    /// The freed block is merged with any adjacent free blocks, so it may be re-used for larger allocations later.
     /// 
     /// # Arguments
     /// - `pointer`: The pointer to the block that was allocated.
     /// 
-    /// # Panics
-    /// This function may panic if the given pointer was never allocated with this pool.
-    #[inline]
-    fn free(&mut self, pointer: GpuPtr) {
+    /// # Errors
+    /// This function errors if the given pointer was never allocated with this pool.
+    fn free(&mut self, pointer: GpuPtr) -> Result<(), Error> {
         // Search the used blocks for a matching allocation
-        let mut new_free: (GpuPtr, usize) = (GpuPtr::null(), 0);
-        let mut remove: usize = 0;
-        for (i, (block_ptr, block_size)) in self.used.iter_mut().enumerate() {
-            if *block_ptr == pointer {
-                // Mark this one for removal, update the new free
-                new_free = (*block_ptr, *block_size);
-                remove   = i;
-                break;
-            }
-        }
-        if new_free.0.is_null() { panic!("Given pointer '{:?}' was not allocated with this pool", pointer); }
-        self.used.swap_remove(remove);
+        let index: usize = match self.used.iter().position(|(used_ptr, _, _)| *used_ptr == pointer) {
+            Some(index) => index,
+            None        => { return Err(Error::UnknownPointer{ ptr: pointer.ptr() as usize }); }
+        };
+        let (_, start, size): (GpuPtr, GpuPtr, usize) = self.used.swap_remove(index);
 
-        // Add the new free to the list
-        if self.free.len() == self.free.capacity() { self.free.reserve(self.free.capacity()); }
-        self.free.push(new_free);
+        // Return the block to the free list
+        insert_free_block(&mut self.free, (start, size));
 
         // Update the size, done
-        self.size -= new_free.1;
-        println!("Blocks after free:\n - Used: {:?}\n - Free: {:?}", self.used, self.free);
+        self.size -= size;
+        Ok(())
     }
 
     /// Resets the memory pool back to its initial, empty state.
@@ -659,7 +1004,7 @@ impl MemoryPool for BlockPool {
 
     /// Returns the device of the pool.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the used space in the pool.
     #[inline]
@@ -670,20 +1015,230 @@ impl MemoryPool for BlockPool {
     fn capacity(&self) -> usize { self.block.mem_size() }
 }
 
+impl Drop for BlockPool {
+    fn drop(&mut self) {
+        log_destroy!(self, BlockPool);
+
+        // Warn if there are still allocations alive
+        if !self.used.is_empty() { warn!("BlockPool is dropped while {} allocation(s) ({} bytes) are still in use; any Buffers still using them now refer to freed memory", self.used.len(), self.size); }
+
+        // The MemoryBlock frees the device memory when dropped
+    }
+}
+
+
+
+/// A DensePool is the space-efficient pool behind `MemoryAllocatorKind::Dense`. It re-uses freed memory (best-fit) and merges adjacent freed areas, at the cost of slower allocations than a LinearPool. Like the LinearPool, this type of pool only supports one type of memory.
+pub struct DensePool {
+    /// The Device where the DensePool lives.
+    device : Shared<Device>,
+    /// The single memory block used in the pool.
+    block  : Option<MemoryBlock>,
+
+    /// The list of free areas in the DensePool, sorted by offset.
+    /// 
+    /// Elements are of the shape:
+    /// - `.0`: The offset of the area compared to the MemoryBlock.
+    /// - `.1`: The size of the area (in bytes).
+    free : Vec<(GpuPtr, usize)>,
+    /// The list of allocated areas in the DensePool.
+    /// 
+    /// Elements are of the shape:
+    /// - `.0`: The (aligned) pointer that was handed out for the area.
+    /// - `.1`: The offset where the area starts, which is before `.0` if it needed alignment padding.
+    /// - `.2`: The size of the area (in bytes), including the alignment padding.
+    used : Vec<(GpuPtr, GpuPtr, usize)>,
+    /// The used space in the DensePool, including alignment padding.
+    size     : usize,
+    /// The size (in bytes) of the DensePool.
+    capacity : usize,
+}
+
+impl DensePool {
+    /// Constructor for the DensePool.
+    /// 
+    /// Note that memory will be allocated lazily.
+    /// 
+    /// # Arguments
+    /// - `capacity`: The size (in bytes) of the pool.
+    /// 
+    /// # Returns
+    /// A new DensePool instance, already wrapped in an Rc and a RefCell.
+    #[inline]
+    pub fn new(device: Shared<Device>, capacity: usize) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            device,
+            block : None,
+
+            free : vec![ (GpuPtr::default(), capacity) ],
+            used : vec![],
+            size : 0,
+            capacity,
+        }))
+    }
+
+
+
+    /// Returns the number of separate free areas in the DensePool, which is a measure of its fragmentation.
+    #[inline]
+    pub fn free_areas(&self) -> usize { self.free.len() }
+}
+
+impl MemoryPool for DensePool {
+    /// Returns a newly allocated area of (at least) the requested size.
+    /// 
+    /// # Arguments
+    /// - `reqs`: The memory requirements of the new memory block.
+    /// - `props`: Any desired memory properties for this memory block.
+    /// 
+    /// # Returns
+    /// A tuple with the VkDeviceMemory where the new block of memory is allocated on `.0`, and the index in this memory block on `.1`.
+    /// 
+    /// # Errors
+    /// This function errors if the MemoryPool failed to allocate new memory.
+    fn allocate(&mut self, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<(vk::DeviceMemory, GpuPtr), Error> {
+        // Keep non-linear resources on their own granularity pages
+        let reqs: MemoryRequirements = pad_granularity(reqs, self.device.get_physical_device_props().limits.buffer_image_granularity);
+
+        // Optimization: we can stop early if there is no more space
+        if reqs.size > self.capacity { return Err(Error::OutOfMemoryError{ req_size: reqs.size }); }
+
+        // Check whether we have a block of memory already
+        let memory: vk::DeviceMemory = match self.block.as_ref() {
+            Some(block) => {
+                // Make sure the requirements & properties are satisfied
+                if !reqs.types.iter_indices().any(|i| i == u32::from(block.mem_type())) { panic!("DensePool is allocated for device memory type {}, but new allocation only supports {}", block.mem_type(), reqs.types); }
+                if !block.mem_props().check(props) { panic!("DensePool is allocated for device memory type {} which supports the properties {}, but new allocation requires {}", block.mem_type(), block.mem_props(), props); }
+                block.vk()
+            },
+
+            None => {
+                // Allocate a new block of the pool's capacity
+                let block = MemoryBlock::allocate(self.device.clone(), &MemoryRequirements{ size: self.capacity, ..reqs.clone() }, props)?;
+                let memory = block.vk();
+                self.block = Some(block);
+                memory
+            },
+        };
+
+        // Search for the smallest free area with enough size
+        let (index, pointer, new_size): (usize, GpuPtr, usize) = match select_free_block(&self.free, &reqs, FitStrategy::Best) {
+            Some(res) => res,
+            None      => { return Err(Error::OutOfMemoryError{ req_size: reqs.size }); }
+        };
+
+        // Take the area (including its alignment padding) from the front of the free area, removing it if that leaves it empty
+        let (area_ptr, area_size) = &mut self.free[index];
+        let start: GpuPtr = *area_ptr;
+        *area_ptr  += new_size;
+        *area_size -= new_size;
+        if *area_size == 0 { self.free.remove(index); }
+
+        // Mark it as used, and we're done
+        self.used.push((pointer, start, new_size));
+        self.size += new_size;
+        Ok((memory, pointer))
+    }
+
+    /// Frees an allocated bit of memory.
+    /// 
+    /// The freed area is merged with any adjacent free areas, so it may be re-used for larger allocations later.
+    /// 
+    /// # Arguments
+    /// - `pointer`: The pointer to the block that was allocated.
+    /// 
+    /// # Errors
+    /// This function errors if the given pointer was never allocated with this pool.
+    fn free(&mut self, pointer: GpuPtr) -> Result<(), Error> {
+        // Search the used areas for a matching allocation
+        let index: usize = match self.used.iter().position(|(used_ptr, _, _)| *used_ptr == pointer) {
+            Some(index) => index,
+            None        => { return Err(Error::UnknownPointer{ ptr: pointer.ptr() as usize }); }
+        };
+        let (_, start, size): (GpuPtr, GpuPtr, usize) = self.used.swap_remove(index);
+
+        // Return the area to the free list
+        insert_free_block(&mut self.free, (start, size));
+        self.size -= size;
+        Ok(())
+    }
+
+    /// Resets the memory pool back to its initial, empty state.
+    #[inline]
+    fn reset(&mut self) {
+        self.used.clear();
+        self.free.clear();
+        self.free.push((GpuPtr::default(), self.capacity));
+        self.size = 0;
+    }
+
+
+
+    /// Returns the device of the pool.
+    #[inline]
+    fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns the used space in the pool.
+    #[inline]
+    fn size(&self) -> usize { self.size }
+
+    /// Returns the total space in the pool.
+    #[inline]
+    fn capacity(&self) -> usize { self.capacity }
+}
+
+impl Drop for DensePool {
+    fn drop(&mut self) {
+        log_destroy!(self, DensePool);
+
+        // Warn if there are still allocations alive
+        if !self.used.is_empty() { warn!("DensePool is dropped while {} allocation(s) ({} bytes) are still in use; any Buffers still using them now refer to freed memory", self.used.len(), self.size); }
+
+        // The MemoryBlock frees the device memory when dropped
+        self.block = None;
+    }
+}
+
+
+
+/// Describes the memory in use by a single memory type of a MetaPool.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoryTypeStats {
+    /// The memory type described.
+    pub memory_type : DeviceMemoryType,
+    /// The number of bytes reserved on the device for this type (i.e., the total size of its memory blocks).
+    pub reserved    : usize,
+    /// The number of bytes currently allocated from those blocks, including alignment padding.
+    pub allocated   : usize,
+    /// The number of allocations currently alive in this type.
+    pub blocks      : usize,
+}
+
+/// Describes the memory in use by a MetaPool, as returned by `MetaPool::stats()`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolStats {
+    /// The number of bytes reserved on the device (i.e., the total size of all memory blocks).
+    pub reserved  : usize,
+    /// The number of bytes currently allocated from those blocks, including alignment padding.
+    pub allocated : usize,
+    /// The number of allocations currently alive.
+    pub blocks    : usize,
+    /// The same statistics, broken down per memory type. Only types that have reserved memory are listed.
+    pub types     : Vec<MemoryTypeStats>,
+}
+
 
 
 /// A MetaPool is a dynamic collection of BlockPools such that it allows allocating for any device memory type.
 pub struct MetaPool {
     /// The device where all nested pools live.
-    device: Rc<Device>,
+    device: Shared<Device>,
 
     /// The preferred size of a new pool. Note that pools may actually be smaller or larger, but this is the default size.
     pref_size  : usize,
     /// A collection of memory types supported by this GPU.
     types      : Vec<MemoryType>,
 
-    /// The total used size in the MetaPool.
-    size     : usize,
     /// The total capacity in the MetaPool (estimation).
     capacity : usize,
 }
@@ -699,7 +1254,7 @@ impl MetaPool {
     /// 
     /// # Returns
     /// A new MetaPool instance, wrapped in a reference-counting pointer.
-    pub fn new(device: Rc<Device>, pref_size: usize) -> Rc<RefCell<Self>> {
+    pub fn new(device: Shared<Device>, pref_size: usize) -> Rc<RefCell<Self>> {
         // Get all available types from the device
         let device_props: vk::PhysicalDeviceMemoryProperties = unsafe { device.instance().get_physical_device_memory_properties(device.physical_device()) };
         let device_heaps: &[vk::MemoryHeap] = unsafe { slice::from_raw_parts(device_props.memory_heaps.as_ptr(), device_props.memory_heap_count as usize) };
@@ -724,10 +1279,39 @@ impl MetaPool {
             pref_size,
             types,
 
-            size : 0,
             capacity,
         }))
     }
+
+
+
+    /// Returns statistics about the memory currently reserved and allocated by this MetaPool.
+    /// 
+    /// These are computed from the nested pools, so they always reflect the latest `allocate()`s and `free()`s.
+    /// 
+    /// # Returns
+    /// A new PoolStats describing the pool in total and per memory type.
+    pub fn stats(&self) -> PoolStats {
+        let mut stats: PoolStats = PoolStats{ reserved: 0, allocated: 0, blocks: 0, types: vec![] };
+        for mem_type in &self.types {
+            if mem_type.pools.is_empty() { continue; }
+
+            // Collect the stats of the type's pools
+            let type_stats: MemoryTypeStats = MemoryTypeStats {
+                memory_type : mem_type.index,
+                reserved    : mem_type.pools.iter().map(|pool| pool.capacity()).sum(),
+                allocated   : mem_type.pools.iter().map(|pool| pool.size()).sum(),
+                blocks      : mem_type.pools.iter().map(|pool| pool.used.len()).sum(),
+            };
+
+            // Add them to the total
+            stats.reserved  += type_stats.reserved;
+            stats.allocated += type_stats.allocated;
+            stats.blocks    += type_stats.blocks;
+            stats.types.push(type_stats);
+        }
+        stats
+    }
 }
 
 impl MemoryPool for MetaPool {
@@ -755,6 +1339,9 @@ impl MemoryPool for MetaPool {
     /// # Errors
     /// This function errors if the MemoryPool failed to allocate new memory.
     fn allocate(&mut self, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<(vk::DeviceMemory, GpuPtr), Error> {
+        // Pad non-linear resources already, such that the padding is taken into account when picking a pool
+        let reqs: &MemoryRequirements = &pad_granularity(reqs, self.device.get_physical_device_props().limits.buffer_image_granularity);
+
         // Preparation: construct a list of types that favours those we have already allocated from
         let mut memory_types: Vec<&mut MemoryType> = Vec::with_capacity(self.types.len());
         let mut unused_types: Vec<&mut MemoryType> = Vec::with_capacity(self.types.len());
@@ -767,8 +1354,8 @@ impl MemoryPool for MetaPool {
         // 1. Iterate over the blocks to find if any existing block suits us
         for mem_type in memory_types {
             // Skip if not in the allowed types or not supporting the correct properties
-            if !reqs.types.check(mem_type.index.into()) { continue; }
-            if !mem_type.props.check(props)      { continue; }
+            if !reqs.types.iter_indices().any(|i| i == u32::from(mem_type.index)) { continue; }
+            if !mem_type.props.check(props)                                      { continue; }
 
             // Now try to find a pool with enough space
             for (i, pool) in &mut mem_type.pools.iter_mut().enumerate() {
@@ -796,7 +1383,7 @@ impl MemoryPool for MetaPool {
                 };
 
                 // Allocate new memory on this block (which we assume succeeds)
-                let mut new_pool: BlockPool = Rc::try_unwrap(BlockPool::new(self.device.clone(), new_block)).dunwrap().into_inner();
+                let mut new_pool: BlockPool = Rc::try_unwrap(BlockPool::new(self.device.clone(), new_block, FitStrategy::default())).dunwrap().into_inner();
                 let (memory, mut pointer): (vk::DeviceMemory, GpuPtr) = new_pool.allocate(reqs, props)?;
 
                 // Set the pointer indices
@@ -816,22 +1403,19 @@ impl MemoryPool for MetaPool {
         Err(Error::OutOfMemoryError{ req_size: reqs.size })
     }
 
-    /// Frees an allocated bit of memory.
-    /// 
-    /// Note that not all types of pools may actually do anything with this. A LinearPool, for example, might deallocate but will never re-use that memory until reset anyway.
+    /// Frees an allocated bit of memory by handing it back to the BlockPool it was allocated in.
     /// 
     /// # Arguments
     /// - `pointer`: The pointer to the block that was allocated.
     /// 
-    /// # Panics
-    /// This function may panic if the given pointer was never allocated with this pool.
-    fn free(&mut self, pointer: GpuPtr) {
+    /// # Errors
+    /// This function errors if the given pointer was never allocated with this pool.
+    fn free(&mut self, pointer: GpuPtr) -> Result<(), Error> {
         let type_idx: usize = pointer.type_idx() as usize;
         let pool_idx: usize = pointer.pool_idx() as usize;
 
         // Do some sanity checking on the type & pool index
-        if type_idx >= self.types.len()                 { panic!("The given pointer {:?} was not allocated in this MetaPool: no type '{}'", pointer, type_idx); }
-        if pool_idx >= self.types[type_idx].pools.len() { panic!("The given pointer {:?} was not allocated in this MetaPool: no pool '{}' in type {}", pointer, pool_idx, type_idx); }
+        if type_idx >= self.types.len() || pool_idx >= self.types[type_idx].pools.len() { return Err(Error::UnknownPointer{ ptr: pointer.as_raw() as usize }); }
 
         // We can instantly go to the correct memory type / pool
         self.types[type_idx].pools[pool_idx].free(pointer.agnostic())
@@ -852,12 +1436,23 @@ impl MemoryPool for MetaPool {
 
     /// Returns the device of the pool.
     #[inline]
-    fn device(&self) -> &Rc<Device> { &self.device }
+    fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the used space in the pool.
-    fn size(&self) -> usize { self.size }
+    fn size(&self) -> usize { self.types.iter().flat_map(|mem_type| mem_type.pools.iter()).map(|pool| pool.size()).sum() }
 
     /// Returns the total space in the pool.
     #[inline]
     fn capacity(&self) -> usize { self.capacity }
 }
+
+impl Drop for MetaPool {
+    fn drop(&mut self) {
+        log_destroy!(self, MetaPool);
+
+        // The nested BlockPools will warn for any allocations still in use and free their memory once they are dropped
+        for mem_type in &mut self.types {
+            mem_type.pools.clear();
+        }
+    }
+}
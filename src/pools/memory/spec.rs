@@ -15,7 +15,7 @@
 use std::cell::RefCell;
 use std::ffi::c_void;
 use std::fmt::{Debug, Formatter, Result as FResult};
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Range};
 use std::ptr;
 use std::rc::Rc;
 use std::slice;
@@ -29,6 +29,7 @@ use crate::auxillary::flags::{BufferUsageFlags, CommandBufferFlags, CommandBuffe
 use crate::auxillary::structs::{MemoryRequirements, VertexAttribute};
 use crate::device::Device;
 use crate::pools::command::{Buffer as CommandBuffer, Pool as CommandPool};
+use crate::spec::Shared;
 
 
 /***** UNIT TESTS *****/
@@ -159,6 +160,132 @@ mod tests {
         assert_eq!(ptr6, GpuPtr::new(0, 5, 0x42));
         assert_eq!(ptr7, GpuPtr::new(5, 5, 0x84));
     }
+
+    /// Tests GpuPtr's ordering and hashing
+    #[test]
+    fn test_ordering() {
+        use std::collections::{BTreeMap, HashSet};
+
+        // Pointers are ordered by type, then pool, then pointer
+        assert!(GpuPtr::new(0, 0, 0x42) < GpuPtr::new(0, 0, 0x84));
+        assert!(GpuPtr::new(0, 5, 0x42) > GpuPtr::new(0, 0, 0xFFFFFFFFFFFF));
+        assert!(GpuPtr::new(5, 0, 0x42) > GpuPtr::new(0, 2047, 0xFFFFFFFFFFFF));
+        assert!(GpuPtr::new(5, 5, 0x42) < GpuPtr::new(5, 6, 0));
+
+        // They may be used as keys
+        let mut tree: BTreeMap<GpuPtr, usize> = BTreeMap::new();
+        tree.insert(GpuPtr::new(1, 0, 0x10), 2);
+        tree.insert(GpuPtr::new(0, 1, 0x20), 1);
+        tree.insert(GpuPtr::new(0, 0, 0x30), 0);
+        assert_eq!(tree.values().cloned().collect::<Vec<usize>>(), vec![ 0, 1, 2 ]);
+        let set: HashSet<GpuPtr> = [ GpuPtr::new(5, 5, 0x42), GpuPtr::new(5, 5, 0x42), GpuPtr::new(5, 5, 0x84) ].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    /// Tests the bounds- and alignment checks for MappedMemory's typed slices
+    #[test]
+    fn test_slice_len() {
+        // Sizes that are a multiple of the type's size
+        assert_eq!(slice_len::<u32>(0x1000 as *const c_void, 0).unwrap(), 0);
+        assert_eq!(slice_len::<u32>(0x1000 as *const c_void, 16).unwrap(), 4);
+        assert_eq!(slice_len::<[f32; 3]>(0x1000 as *const c_void, 24).unwrap(), 2);
+        assert_eq!(slice_len::<u8>(0x1001 as *const c_void, 3).unwrap(), 3);
+
+        // Sizes that aren't
+        assert!(matches!(slice_len::<u32>(0x1000 as *const c_void, 6), Err(Error::MappedSizeMismatch{ size: 6, type_size: 4, .. })));
+        assert!(matches!(slice_len::<[f32; 3]>(0x1000 as *const c_void, 16), Err(Error::MappedSizeMismatch{ size: 16, type_size: 12, .. })));
+
+        // Misaligned pointers
+        assert!(matches!(slice_len::<u32>(0x1002 as *const c_void, 16), Err(Error::MappedAlignmentMismatch{ ptr: 0x1002, type_align: 4, .. })));
+        assert!(matches!(slice_len::<u64>(0x1004 as *const c_void, 16), Err(Error::MappedAlignmentMismatch{ ptr: 0x1004, type_align: 8, .. })));
+    }
+
+    /// Tests the rounding of flushed and invalidated ranges to the non-coherent atom size
+    #[test]
+    fn test_atom_range() {
+        // Ranges are rounded outwards to multiples of the atom size
+        assert_eq!(atom_range(&(0..64), 0, 64, 256), (0, 64));
+        assert_eq!(atom_range(&(1..2), 0, 64, 256), (0, 64));
+        assert_eq!(atom_range(&(60..70), 0, 64, 256), (0, 128));
+        assert_eq!(atom_range(&(64..256), 0, 64, 256), (64, 192));
+
+        // The padding before the mapped area is taken into account
+        assert_eq!(atom_range(&(0..16), 16, 64, 256), (0, 64));
+        assert_eq!(atom_range(&(40..48), 16, 64, 256), (0, 64));
+        assert_eq!(atom_range(&(48..64), 16, 64, 256), (64, 64));
+
+        // The range never exceeds the mapped area
+        assert_eq!(atom_range(&(200..250), 0, 64, 250), (192, 58));
+    }
+
+    /// Tests the bounds checks for writes to MappedMemory
+    #[test]
+    fn test_write_range() {
+        // Writes that fit
+        assert_eq!(write_range(0, 256, 256).unwrap(), 0..256);
+        assert_eq!(write_range(64, 16, 256).unwrap(), 64..80);
+        assert_eq!(write_range(256, 0, 256).unwrap(), 256..256);
+
+        // Writes that overflow the region
+        assert!(matches!(write_range(0, 257, 256), Err(Error::MappedRangeOutOfBounds{ start: 0, end: 257, capacity: 256 })));
+        assert!(matches!(write_range(250, 12, 256), Err(Error::MappedRangeOutOfBounds{ start: 250, end: 262, capacity: 256 })));
+        assert!(matches!(write_range(usize::MAX, 4, 256), Err(Error::MappedRangeOutOfBounds{ start: usize::MAX, end: usize::MAX, capacity: 256 })));
+    }
+
+    /// Tests the resolving of optional ranges in mapped memory
+    #[test]
+    fn test_mapped_range() {
+        // Explicit sizes, including empty ones
+        assert_eq!(mapped_range(0, Some(256), 256).unwrap(), 0..256);
+        assert_eq!(mapped_range(64, Some(16), 256).unwrap(), 64..80);
+        assert_eq!(mapped_range(256, Some(0), 256).unwrap(), 256..256);
+
+        // Missing sizes become the rest of the memory
+        assert_eq!(mapped_range(0, None, 256).unwrap(), 0..256);
+        assert_eq!(mapped_range(128, None, 256).unwrap(), 128..256);
+
+        // Out-of-bounds ranges are rejected
+        assert!(matches!(mapped_range(0, Some(257), 256), Err(Error::MappedRangeOutOfBounds{ start: 0, end: 257, capacity: 256 })));
+        assert!(matches!(mapped_range(257, None, 256), Err(Error::MappedRangeOutOfBounds{ start: 257, end: 256, capacity: 256 })));
+    }
+
+    /// Tests the resolving of optional buffer ranges to `VK_WHOLE_SIZE`
+    #[test]
+    fn test_buffer_range() {
+        // Explicit sizes are passed as-is
+        assert_eq!(buffer_range(0, Some(256), 256).unwrap(), (0, 256));
+        assert_eq!(buffer_range(64, Some(16), 256).unwrap(), (64, 16));
+
+        // Missing sizes become the rest of the buffer
+        assert_eq!(buffer_range(0, None, 256).unwrap(), (0, vk::WHOLE_SIZE));
+        assert_eq!(buffer_range(128, None, 256).unwrap(), (128, vk::WHOLE_SIZE));
+
+        // Empty or out-of-bounds ranges are rejected
+        assert!(matches!(buffer_range(0, Some(257), 256), Err(Error::BufferSliceOutOfBounds{ start: 0, end: 257, capacity: 256 })));
+        assert!(matches!(buffer_range(64, Some(0), 256), Err(Error::BufferSliceOutOfBounds{ .. })));
+        assert!(matches!(buffer_range(256, None, 256), Err(Error::BufferSliceOutOfBounds{ start: 256, end: 256, capacity: 256 })));
+    }
+
+    /// Tests the bounds checks for BufferSlices
+    #[test]
+    fn test_check_slice_range() {
+        // Ranges within the buffer
+        assert!(check_slice_range(&(0..256), 256).is_ok());
+        assert!(check_slice_range(&(0..1), 256).is_ok());
+        assert!(check_slice_range(&(128..256), 256).is_ok());
+        assert!(check_slice_range(&(255..256), 256).is_ok());
+
+        // Ranges that go past the end
+        assert!(matches!(check_slice_range(&(0..257), 256), Err(Error::BufferSliceOutOfBounds{ start: 0, end: 257, capacity: 256 })));
+        assert!(matches!(check_slice_range(&(256..512), 256), Err(Error::BufferSliceOutOfBounds{ start: 256, end: 512, capacity: 256 })));
+
+        // Empty or inverted ranges
+        assert!(matches!(check_slice_range(&(0..0), 256), Err(Error::BufferSliceOutOfBounds{ .. })));
+        assert!(matches!(check_slice_range(&(128..128), 256), Err(Error::BufferSliceOutOfBounds{ .. })));
+        #[allow(clippy::reversed_empty_ranges)]
+        let inverted: Range<usize> = 128..64;
+        assert!(matches!(check_slice_range(&inverted, 256), Err(Error::BufferSliceOutOfBounds{ start: 128, end: 64, .. })));
+    }
 }
 
 
@@ -251,6 +378,125 @@ fn populate_mapped_memory_range(memory: vk::DeviceMemory, offset: vk::DeviceSize
 
 
 
+/***** HELPER FUNCTIONS *****/
+/// Computes the number of elements of the given type that fit in a mapped memory area.
+/// 
+/// # Generic arguments
+/// - `T`: The type of the elements to view the memory as.
+/// 
+/// # Arguments
+/// - `ptr`: The host pointer to the start of the mapped memory area.
+/// - `size`: The size (in bytes) of the mapped memory area.
+/// 
+/// # Returns
+/// The number of `T`s in the memory area.
+/// 
+/// # Errors
+/// This function errors if the size is not a multiple of the size of `T`, or the pointer is not aligned to the alignment of `T`.
+/// 
+/// # Panics
+/// This function panics if `T` is a zero-sized type.
+#[allow(clippy::manual_is_multiple_of)]
+fn slice_len<T>(ptr: *const c_void, size: usize) -> Result<usize, Error> {
+    let type_size: usize  = std::mem::size_of::<T>();
+    let type_align: usize = std::mem::align_of::<T>();
+    if type_size == 0 { panic!("Cannot view mapped memory as a slice of zero-sized type {}", std::any::type_name::<T>()); }
+
+    // Check the size and the alignment
+    if size % type_size != 0 { return Err(Error::MappedSizeMismatch{ size, type_name: std::any::type_name::<T>(), type_size }); }
+    if (ptr as usize) % type_align != 0 { return Err(Error::MappedAlignmentMismatch{ ptr: ptr as usize, type_name: std::any::type_name::<T>(), type_align }); }
+
+    // Done
+    Ok(size / type_size)
+}
+
+/// Rounds the given range in a mapped memory area to multiples of the `nonCoherentAtomSize` limit, as required by flushes and invalidates.
+/// 
+/// # Arguments
+/// - `range`: The range (in bytes, relative to the start of the MappedMemory) to round.
+/// - `pad`: The number of bytes between the (atom-aligned) start of the mapped area and the start of the MappedMemory.
+/// - `atom`: The `nonCoherentAtomSize` limit of the device.
+/// - `mapped_size`: The size of the mapped area, which may not be exceeded.
+/// 
+/// # Returns
+/// A tuple with the offset (relative to the start of the mapped area) and the size of the rounded range.
+fn atom_range(range: &Range<usize>, pad: vk::DeviceSize, atom: vk::DeviceSize, mapped_size: vk::DeviceSize) -> (vk::DeviceSize, vk::DeviceSize) {
+    let start: vk::DeviceSize = (pad + range.start as vk::DeviceSize) / atom * atom;
+    let end: vk::DeviceSize   = vk::DeviceSize::min((pad + range.end as vk::DeviceSize).div_ceil(atom) * atom, mapped_size);
+    (start, end - start)
+}
+
+/// Computes the range of bytes written by a write to a mapped memory area, checking that it fits.
+/// 
+/// # Arguments
+/// - `offset`: The offset (in bytes) of the write.
+/// - `size`: The number of bytes to write.
+/// - `capacity`: The size (in bytes) of the mapped memory area.
+/// 
+/// # Returns
+/// The range of bytes that is written.
+/// 
+/// # Errors
+/// This function errors if the write does not fit within the capacity.
+fn write_range(offset: usize, size: usize, capacity: usize) -> Result<Range<usize>, Error> {
+    let end: usize = offset.saturating_add(size);
+    if end > capacity { return Err(Error::MappedRangeOutOfBounds{ start: offset, end, capacity }); }
+    Ok(offset..end)
+}
+
+/// Resolves an offset and an optional size in a mapped memory area to a range of bytes, where a missing size means the rest of the area.
+/// 
+/// # Arguments
+/// - `offset`: The offset (in bytes) where the range starts.
+/// - `size`: The size (in bytes) of the range, or `None` for the rest of the area.
+/// - `capacity`: The size (in bytes) of the mapped memory area.
+/// 
+/// # Returns
+/// The range of bytes, which may be empty.
+/// 
+/// # Errors
+/// This function errors if the range does not fit within the capacity.
+fn mapped_range(offset: u64, size: Option<u64>, capacity: usize) -> Result<Range<usize>, Error> {
+    let end: u64 = size.map(|size| offset.saturating_add(size)).unwrap_or(capacity as u64);
+    if offset > end || end > capacity as u64 { return Err(Error::MappedRangeOutOfBounds{ start: offset as usize, end: end as usize, capacity }); }
+    Ok(offset as usize..end as usize)
+}
+
+/// Resolves an offset and an optional size in a Buffer to the offset and range as Vulkan expects them, where a missing size means the rest of the Buffer.
+/// 
+/// # Arguments
+/// - `offset`: The offset (in bytes) where the range starts.
+/// - `size`: The size (in bytes) of the range, or `None` for the rest of the Buffer.
+/// - `capacity`: The capacity (in bytes) of the Buffer.
+/// 
+/// # Returns
+/// A tuple with the offset and the size, where `None` is mapped to `vk::WHOLE_SIZE`.
+/// 
+/// # Errors
+/// This function errors if the range is empty or does not fit within the capacity.
+fn buffer_range(offset: u64, size: Option<u64>, capacity: usize) -> Result<(vk::DeviceSize, vk::DeviceSize), Error> {
+    let end: u64 = size.map(|size| offset.saturating_add(size)).unwrap_or(capacity as u64);
+    if offset >= end || end > capacity as u64 { return Err(Error::BufferSliceOutOfBounds{ start: offset as usize, end: end as usize, capacity }); }
+    Ok((offset, size.unwrap_or(vk::WHOLE_SIZE)))
+}
+
+/// Checks if the given range is a valid range for a BufferSlice.
+/// 
+/// # Arguments
+/// - `range`: The range (in bytes) of the slice.
+/// - `capacity`: The capacity (in bytes) of the parent buffer.
+/// 
+/// # Errors
+/// This function errors if the range is empty or does not fit within the capacity.
+fn check_slice_range(range: &Range<usize>, capacity: usize) -> Result<(), Error> {
+    if range.start >= range.end || range.end > capacity { return Err(Error::BufferSliceOutOfBounds{ start: range.start, end: range.end, capacity }); }
+    Ok(())
+}
+
+
+
+
+
 /***** LIBRARY *****/
 /// The type of pointers used across the pools.
 /// 
@@ -258,7 +504,9 @@ fn populate_mapped_memory_range(memory: vk::DeviceMemory, offset: vk::DeviceSize
 /// - The first number determines the memory type used (in the case of a non-meta pool, always 0's)
 /// - The second number determines the block pool used within that type (in the case of a non-meta pool, always 0's)
 /// - The third number determines the pointer within that pool.
-#[derive(Copy, Clone, Eq, Ord, PartialEq, PartialOrd)]
+/// 
+/// Because of this layout, GpuPtrs are ordered by memory type first, then by pool and only then by the pointer within that pool. They may thus be used as keys in `BTreeMap`s and `HashMap`s to track allocations.
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct GpuPtr(u64);
 
 impl GpuPtr {
@@ -545,39 +793,130 @@ pub trait Vertex: Sized {
 /// Represents a piece of mapped memory. When it goes out-of-scope, the memory is automatically unmapped.
 pub struct MappedMemory {
     /// The device to which the mapped memory belongs.
-    device : Rc<Device>,
+    device : Shared<Device>,
 
     /// The device memory which we represent.
     dmem : vk::DeviceMemory,
-    /// The offset in said memory.
+    /// The offset in said memory where the mapped area starts. Is aligned to the `nonCoherentAtomSize` limit of the device.
     doff : vk::DeviceSize,
-    /// The raw host memory which we represent.
+    /// The raw host memory which we represent (i.e., the start of the mapped area plus `pad`).
     hmem : *mut c_void,
 
     /// The actual size of the mapped memory area, which is aligned to the `nonCoherentAtomSize` limit of the device.
     mapped_size : vk::DeviceSize,
+    /// The number of bytes between the start of the mapped memory area and the start of the buffer.
+    pad         : vk::DeviceSize,
+    /// The `nonCoherentAtomSize` limit of the device.
+    atom_size   : vk::DeviceSize,
+    /// Whether the memory is host coherent, in which case flushes and invalidates are not needed.
+    coherent    : bool,
     /// The number of bytes that are mapped. Equals the size of the range in the device memory.
     capacity    : usize,
 }
 
 impl MappedMemory {
-    /// Flushes the mapper memory range.
+    /// Flushes the given range of the mapped memory, making host writes to it visible to the device.
+    /// 
+    /// The range is rounded outwards to the `nonCoherentAtomSize` limit of the device. If the memory is host coherent, this is a no-op.
+    /// 
+    /// # Arguments
+    /// - `offset`: The offset (in bytes, relative to the start of the mapped memory) of the range to flush.
+    /// - `size`: The size (in bytes) of the range to flush, or `None` to flush the rest of the mapped memory (i.e., `VK_WHOLE_SIZE`).
     /// 
     /// # Errors
-    /// This function may error if the underlying Vulkan backend threw errors.
-    #[inline]
-    pub fn flush(&self) -> Result<(), Error> {
+    /// This function errors if the range is out of bounds, or if the underlying Vulkan backend threw errors.
+    pub fn flush(&self, offset: u64, size: Option<u64>) -> Result<(), Error> {
+        // Check the range and skip if there's nothing to do
+        let range: Range<usize> = mapped_range(offset, size, self.capacity)?;
+        if self.coherent || range.is_empty() { return Ok(()); }
+
         // Call the flush function
+        let (offset, size): (vk::DeviceSize, vk::DeviceSize) = atom_range(&range, self.pad, self.atom_size, self.mapped_size);
         match unsafe{ self.device.flush_mapped_memory_ranges(&[
-            populate_mapped_memory_range(self.dmem, self.doff, self.mapped_size),
+            populate_mapped_memory_range(self.dmem, self.doff + offset, size),
         ]) } {
             Ok(_)    => Ok(()),
             Err(err) => Err(Error::BufferFlushError{ err }),
         }
     }
 
+    /// Invalidates the given range of the mapped memory, making device writes to it visible to the host.
+    /// 
+    /// The range is rounded outwards to the `nonCoherentAtomSize` limit of the device. If the memory is host coherent, this is a no-op.
+    /// 
+    /// # Arguments
+    /// - `offset`: The offset (in bytes, relative to the start of the mapped memory) of the range to invalidate.
+    /// - `size`: The size (in bytes) of the range to invalidate, or `None` to invalidate the rest of the mapped memory (i.e., `VK_WHOLE_SIZE`).
+    /// 
+    /// # Errors
+    /// This function errors if the range is out of bounds, or if the underlying Vulkan backend threw errors.
+    pub fn invalidate(&self, offset: u64, size: Option<u64>) -> Result<(), Error> {
+        // Check the range and skip if there's nothing to do
+        let range: Range<usize> = mapped_range(offset, size, self.capacity)?;
+        if self.coherent || range.is_empty() { return Ok(()); }
+
+        // Call the invalidate function
+        let (offset, size): (vk::DeviceSize, vk::DeviceSize) = atom_range(&range, self.pad, self.atom_size, self.mapped_size);
+        match unsafe{ self.device.invalidate_mapped_memory_ranges(&[
+            populate_mapped_memory_range(self.dmem, self.doff + offset, size),
+        ]) } {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(Error::BufferInvalidateError{ err }),
+        }
+    }
+
 
 
+    /// Copies the given slice into the mapped memory at the given offset.
+    /// 
+    /// Note that this does not flush the written range; call `MappedMemory::flush()` afterwards if the memory is not host coherent.
+    /// 
+    /// # Generic arguments
+    /// - `T`: The type of the elements to write. Must be `Copy`, since the memory is shared with the device.
+    /// 
+    /// # Arguments
+    /// - `offset`: The offset (in bytes, relative to the start of the mapped memory) to write to. Does not have to be aligned to `T`.
+    /// - `data`: The slice of `T`s to write.
+    /// 
+    /// # Errors
+    /// This function errors if the data does not fit in the mapped memory at the given offset.
+    pub fn write_slice<T: Copy>(&mut self, offset: usize, data: &[T]) -> Result<(), Error> {
+        // Check whether the data fits
+        let range: Range<usize> = write_range(offset, std::mem::size_of_val(data), self.capacity)?;
+
+        // Copy it byte-wise, so the offset needn't be aligned
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr() as *const u8, (self.hmem as *mut u8).add(range.start), range.len()); }
+        Ok(())
+    }
+
+    /// Copies the given value into the mapped memory at the given offset.
+    /// 
+    /// Note that this does not flush the written range; call `MappedMemory::flush()` afterwards if the memory is not host coherent.
+    /// 
+    /// # Generic arguments
+    /// - `T`: The type of the value to write. Must be `Copy`, since the memory is shared with the device.
+    /// 
+    /// # Arguments
+    /// - `offset`: The offset (in bytes, relative to the start of the mapped memory) to write to. Does not have to be aligned to `T`.
+    /// - `value`: The value to write.
+    /// 
+    /// # Errors
+    /// This function errors if the value does not fit in the mapped memory at the given offset.
+    #[inline]
+    pub fn write_value<T: Copy>(&mut self, offset: usize, value: &T) -> Result<(), Error> {
+        self.write_slice(offset, slice::from_ref(value))
+    }
+
+
+
+    /// Returns the number of bytes that are mapped.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.capacity }
+
+    /// Returns whether the mapped memory is host coherent, i.e., whether `MappedMemory::flush()` and `MappedMemory::invalidate()` are no-ops.
+    #[inline]
+    pub fn is_coherent(&self) -> bool { self.coherent }
+
     /// Returns the raw, internal pointer.
     #[inline]
     pub fn as_raw(&self) -> *const c_void { self.hmem as *const c_void }
@@ -586,36 +925,54 @@ impl MappedMemory {
     #[inline]
     pub fn as_raw_mut(&mut self) -> *mut c_void { self.hmem }
     
-    /// Returns the host memory as a slice of the given type.
+    /// Returns the entire host memory as a slice of the given type.
     /// 
-    /// # Arguments
-    /// - `size`: The expected size of the slice.
+    /// # Generic arguments
+    /// - `T`: The type of the elements in the slice. Must be `Copy`, since the memory is shared with the device.
+    /// 
+    /// # Returns
+    /// A slice of `T`s that spans the entire mapped memory area.
+    /// 
+    /// # Errors
+    /// This function errors if the size of the mapped memory is not a multiple of the size of `T`, or the memory is not properly aligned for `T`.
     /// 
     /// # Panics
-    /// This function will panic if the given size of the slice is too large.
+    /// This function panics if `T` is a zero-sized type.
+    /// 
+    /// # Safety
+    /// The mapped bytes are reinterpreted as `T`s as-is, so the caller must guarantee that every bit pattern is a valid `T` (e.g., plain integers, floats or `#[repr(C)]` structs thereof; but not `bool`, `char`, enums or references).
     #[inline]
-    pub fn as_slice<T: Sized>(&self, size: usize) -> &[T] {
-        // Sanity check that the size is large enough
-        if size * std::mem::size_of::<T>() > self.capacity { panic!("Mapped memory range of {} bytes cannot accomodate slice of {} {} ({} bytes each, {} bytes total)", self.capacity, size, std::any::type_name::<T>(), std::mem::size_of::<T>(), size * std::mem::size_of::<T>()); }
+    pub unsafe fn as_slice<T: Copy>(&self) -> Result<&[T], Error> {
+        // Check whether the memory fits the type
+        let len: usize = slice_len::<T>(self.hmem, self.capacity)?;
 
         // Cast to a slice
-        unsafe { slice::from_raw_parts(self.hmem as *const T, size) }
+        Ok(slice::from_raw_parts(self.hmem as *const T, len))
     }
 
-    /// Returns the host memory as a slice of the given type but muteable.
+    /// Returns the entire host memory as a slice of the given type but muteable.
     /// 
-    /// # Arguments
-    /// - `size`: The expected size of the slice.
+    /// # Generic arguments
+    /// - `T`: The type of the elements in the slice. Must be `Copy`, since the memory is shared with the device.
+    /// 
+    /// # Returns
+    /// A muteable slice of `T`s that spans the entire mapped memory area.
+    /// 
+    /// # Errors
+    /// This function errors if the size of the mapped memory is not a multiple of the size of `T`, or the memory is not properly aligned for `T`.
     /// 
     /// # Panics
-    /// This function will panic if the given size of the slice is too large.
+    /// This function panics if `T` is a zero-sized type.
+    /// 
+    /// # Safety
+    /// The mapped bytes are reinterpreted as `T`s as-is, so the caller must guarantee that every bit pattern is a valid `T` (e.g., plain integers, floats or `#[repr(C)]` structs thereof; but not `bool`, `char`, enums or references).
     #[inline]
-    pub fn as_slice_mut<T: Sized>(&self, size: usize) -> &mut [T] {
-        // Sanity check that the size is large enough
-        if size * std::mem::size_of::<T>() > self.capacity { panic!("Mapped memory range of {} bytes cannot accomodate slice of {} {} ({} bytes each, {} bytes total)", self.capacity, size, std::any::type_name::<T>(), std::mem::size_of::<T>(), size * std::mem::size_of::<T>()); }
+    pub unsafe fn as_slice_mut<T: Copy>(&mut self) -> Result<&mut [T], Error> {
+        // Check whether the memory fits the type
+        let len: usize = slice_len::<T>(self.hmem, self.capacity)?;
 
         // Cast to a slice
-        unsafe { slice::from_raw_parts_mut(self.hmem as *mut T, size) }
+        Ok(slice::from_raw_parts_mut(self.hmem as *mut T, len))
     }
 }
 
@@ -645,16 +1002,14 @@ pub trait MemoryPool {
     /// This function errors if the MemoryPool failed to allocate new memory.
     fn allocate(&mut self, reqs: &MemoryRequirements, props: MemoryPropertyFlags) -> Result<(vk::DeviceMemory, GpuPtr), Error>;
 
-    /// Frees an allocated bit of memory.
-    /// 
-    /// Note that not all types of pools may actually do anything with this. A LinearPool, for example, might deallocate but will never re-use that memory until reset anyway.
+    /// Frees an allocated bit of memory, such that the pool may re-use it for new allocations.
     /// 
     /// # Arguments
     /// - `pointer`: The pointer to the block that was allocated.
     /// 
-    /// # Panics
-    /// This function may panic if the given pointer was never allocated with this pool.
-    fn free(&mut self, pointer: GpuPtr);
+    /// # Errors
+    /// This function errors if the given pointer was never allocated with this pool, or if the pool does not support freeing individual blocks (e.g., a LinearPool, which can only be reset as a whole).
+    fn free(&mut self, pointer: GpuPtr) -> Result<(), Error>;
 
     /// Resets the memory pool back to its initial, empty state.
     fn reset(&mut self);
@@ -662,7 +1017,7 @@ pub trait MemoryPool {
 
 
     /// Returns the device of the pool.
-    fn device(&self) -> &Rc<Device>;
+    fn device(&self) -> &Shared<Device>;
 
     /// Returns the used space in the pool.
     fn size(&self) -> usize;
@@ -678,7 +1033,7 @@ pub trait MemoryPool {
 /// The Buffer trait, which unifies the interface to various types of Buffers.
 pub trait Buffer {
     /// Returns the Device where the Buffer lives.
-    fn device(&self) -> &Rc<Device>;
+    fn device(&self) -> &Shared<Device>;
     
     /// Returns the MemoryPool where the Buffer's memory is allocated.
     fn pool(&self) -> &Rc<RefCell<dyn MemoryPool>>;
@@ -710,6 +1065,166 @@ pub trait Buffer {
 
     /// Returns the actually allocated size of the buffer.
     fn capacity(&self) -> usize;
+
+    /// Returns the offset and size (in bytes) that cover the whole Buffer, e.g., to flush all of its MappedMemory or to write all of it to a descriptor.
+    /// 
+    /// This is always `(0, None)`, where the `None` maps to `VK_WHOLE_SIZE`.
+    #[inline]
+    fn whole_range(&self) -> (u64, Option<u64>) { (0, None) }
+
+    /// Returns a VkDescriptorBufferInfo that describes the given range of this Buffer, e.g., to bind it to a uniform or storage buffer descriptor.
+    /// 
+    /// # Arguments
+    /// - `offset`: The offset (in bytes, relative to the start of the Buffer) where the range starts.
+    /// - `size`: The size (in bytes) of the range, or `None` to use the rest of the Buffer (i.e., `VK_WHOLE_SIZE`).
+    /// 
+    /// # Returns
+    /// A new VkDescriptorBufferInfo for the range.
+    /// 
+    /// # Errors
+    /// This function errors if the range is empty or does not fit in this Buffer.
+    #[inline]
+    fn vk_info(&self, offset: u64, size: Option<u64>) -> Result<vk::DescriptorBufferInfo, Error> {
+        let (offset, range): (vk::DeviceSize, vk::DeviceSize) = buffer_range(offset, size, self.capacity())?;
+        Ok(vk::DescriptorBufferInfo {
+            buffer : self.vk(),
+            offset,
+            range,
+        })
+    }
+
+
+
+    /// Gives this Buffer a debug name, which shows up in validation messages and debuggers such as RenderDoc.
+    /// 
+    /// This is a no-op if debugging is not enabled on the Instance (see `Device::set_object_name()`).
+    /// 
+    /// # Arguments
+    /// - `name`: The name to give the Buffer.
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend failed to set the name.
+    #[inline]
+    fn set_name(&self, name: &str) -> Result<(), crate::device::Error> { self.device().set_object_name(self.vk(), name) }
+
+    /// Returns a view on a part of this Buffer, e.g., to pack multiple meshes in a single VertexBuffer.
+    /// 
+    /// # Arguments
+    /// - `range`: The range (in bytes, relative to the start of the Buffer) to view.
+    /// 
+    /// # Returns
+    /// A new BufferSlice that references this Buffer.
+    /// 
+    /// # Errors
+    /// This function errors if the range is empty or does not fit in this Buffer.
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> Result<BufferSlice<'_>, Error> where Self: Sized { BufferSlice::new(self, range) }
+}
+
+
+
+/// A view on a range of a Buffer.
+/// 
+/// This allows sub-allocating multiple (small) resources in a single Buffer, such that they can share a single VkBuffer.
+#[derive(Clone, Copy)]
+pub struct BufferSlice<'a> {
+    /// The Buffer that is being viewed.
+    buffer : &'a dyn Buffer,
+    /// The offset (in bytes) of the slice within the Buffer.
+    offset : usize,
+    /// The size (in bytes) of the slice.
+    size   : usize,
+}
+
+impl<'a> BufferSlice<'a> {
+    /// Constructor for the BufferSlice.
+    /// 
+    /// Prefer `Buffer::slice()` unless the Buffer is only available as a trait object.
+    /// 
+    /// # Arguments
+    /// - `buffer`: The Buffer to view.
+    /// - `range`: The range (in bytes, relative to the start of the Buffer) to view.
+    /// 
+    /// # Returns
+    /// A new BufferSlice that references the given Buffer.
+    /// 
+    /// # Errors
+    /// This function errors if the range is empty or does not fit in the given Buffer.
+    pub fn new(buffer: &'a dyn Buffer, range: Range<usize>) -> Result<Self, Error> {
+        check_slice_range(&range, buffer.capacity())?;
+        Ok(Self {
+            buffer,
+            offset : range.start,
+            size   : range.end - range.start,
+        })
+    }
+
+    /// Returns a view on a part of this slice.
+    /// 
+    /// # Arguments
+    /// - `range`: The range (in bytes, relative to the start of this slice) to view.
+    /// 
+    /// # Returns
+    /// A new BufferSlice that references the same Buffer.
+    /// 
+    /// # Errors
+    /// This function errors if the range is empty or does not fit in this slice.
+    pub fn slice(&self, range: Range<usize>) -> Result<Self, Error> {
+        check_slice_range(&range, self.size)?;
+        Ok(Self {
+            buffer : self.buffer,
+            offset : self.offset + range.start,
+            size   : range.end - range.start,
+        })
+    }
+
+
+
+    /// Returns the Buffer that is being viewed.
+    #[inline]
+    pub fn buffer(&self) -> &'a dyn Buffer { self.buffer }
+
+    /// Returns the offset (in bytes) of the slice within its Buffer.
+    #[inline]
+    pub fn offset(&self) -> usize { self.offset }
+
+    /// Returns the size (in bytes) of the slice.
+    #[inline]
+    pub fn size(&self) -> usize { self.size }
+
+    /// Returns the range (in bytes) of the slice within its Buffer.
+    #[inline]
+    pub fn range(&self) -> Range<usize> { self.offset..self.offset + self.size }
+
+
+
+    /// Returns the Vulkan vk::Buffer of the parent Buffer.
+    #[inline]
+    pub fn vk(&self) -> vk::Buffer { self.buffer.vk() }
+
+    /// Returns the offset of this slice in the DeviceMemory of the parent Buffer.
+    #[inline]
+    pub fn vk_offset(&self) -> vk::DeviceSize { self.buffer.vk_offset() + self.offset as vk::DeviceSize }
+
+    /// Returns a VkDescriptorBufferInfo that describes the range of this slice, e.g., to bind it to a uniform or storage buffer descriptor.
+    #[inline]
+    pub fn vk_info(&self) -> vk::DescriptorBufferInfo {
+        vk::DescriptorBufferInfo {
+            buffer : self.buffer.vk(),
+            offset : self.offset as vk::DeviceSize,
+            range  : self.size as vk::DeviceSize,
+        }
+    }
+}
+
+impl<'a> Debug for BufferSlice<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_struct("BufferSlice")
+            .field("buffer", &self.buffer.vk())
+            .field("offset", &self.offset)
+            .field("size", &self.size)
+            .finish()
+    }
 }
 
 
@@ -773,7 +1288,7 @@ pub trait TransferBuffer: Buffer {
         if let Err(err) = cmd.end() { return Err(Error::CommandBufferRecordEndError{ what: "transfer", err }); };
 
         // Submit the command buffer and wait until it is completed
-        if let Err(err) = self.device().queues().memory.submit(&cmd, &[], &[], None) { return Err(Error::SubmitError{ what: "transfer", err }); }
+        if let Err(err) = self.device().queues().memory.submit(&cmd, &[], &[], &[], &[], None) { return Err(Error::SubmitError{ what: "transfer", err }); }
         if let Err(err) = self.device().queues().memory.drain() { return Err(Error::DrainError{ err }); }
 
         // Done
@@ -838,27 +1353,45 @@ pub trait HostBuffer: Buffer {
     /// This function may error if we failed to map the Buffer memory.
     fn map(&self) -> Result<MappedMemory, Error> {
         // Get the coherent atom size of the device
-        let coherent_size: vk::DeviceSize = unsafe {
-            self.device().instance().get_physical_device_properties(self.device().physical_device())
-        }.limits.non_coherent_atom_size;
-
-        // Simply call the map function
-        let mapped_size: vk::DeviceSize = GpuPtr::from(self.capacity()).align(coherent_size).into();
-        println!("Mapped size: {}/{:#X} -> {}/{:#X} (coherent size: {}/{:#X})", self.capacity(), self.capacity(), mapped_size, mapped_size, coherent_size, coherent_size);
-        match unsafe{ self.device().map_memory(self.vk_mem(), self.vk_offset(), mapped_size, vk::MemoryMapFlags::empty()) } {
+        let atom_size: vk::DeviceSize = self.device().get_physical_device_props().limits.non_coherent_atom_size;
+
+        // Map an area that starts and ends on the atom size, such that any range in it may be flushed
+        let pad: vk::DeviceSize         = self.vk_offset() % atom_size;
+        let offset: vk::DeviceSize      = self.vk_offset() - pad;
+        let mapped_size: vk::DeviceSize = GpuPtr::from(pad + self.capacity() as vk::DeviceSize).align(atom_size).into();
+        match unsafe{ self.device().map_memory(self.vk_mem(), offset, mapped_size, vk::MemoryMapFlags::empty()) } {
             Ok(ptr) => Ok(MappedMemory {
                 device : self.device().clone(),
 
                 dmem : self.vk_mem(),
-                doff : self.vk_offset(),
-                hmem : ptr,
+                doff : offset,
+                hmem : unsafe { (ptr as *mut u8).add(pad as usize) as *mut c_void },
 
                 mapped_size,
+                pad,
+                atom_size,
+                coherent : self.properties().check(MemoryPropertyFlags::HOST_COHERENT),
                 capacity : self.capacity(),
             }),
             Err(err) => Err(Error::BufferMapError{ err }),
         }
     }
+
+    /// Uploads the given slice to the start of the Buffer by mapping it, writing the data, flushing it and unmapping it again.
+    /// 
+    /// # Generic arguments
+    /// - `T`: The type of the elements to upload (e.g., a vertex type).
+    /// 
+    /// # Arguments
+    /// - `data`: The slice of `T`s to upload.
+    /// 
+    /// # Errors
+    /// This function errors if the data does not fit in the Buffer, or if we failed to map or flush the Buffer memory.
+    fn upload<T: Copy>(&self, data: &[T]) -> Result<(), Error> where Self: Sized {
+        let mut mapped: MappedMemory = self.map()?;
+        mapped.write_slice(0, data)?;
+        mapped.flush(0, Some(std::mem::size_of_val(data) as u64))
+    }
 }
 
 
@@ -0,0 +1,119 @@
+//  STAGING.rs
+//    by Lut99
+// 
+//  Created:
+//    17 Oct 2026, 10:12:41
+//  Last edited:
+//    17 Oct 2026, 10:12:41
+//  Auto updated?
+//    Yes
+// 
+//  Description:
+//!   Implements a pool of StagingBuffers that are recycled once the GPU
+//!   is done with them.
+// 
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub use crate::pools::errors::MemoryPoolError as Error;
+use crate::device::Device;
+use crate::sync::Fence;
+use crate::spec::Shared;
+
+use super::buffers::StagingBuffer;
+use super::spec::{Buffer, MemoryPool};
+
+
+/***** LIBRARY *****/
+/// The StagingRing owns a set of StagingBuffers, each paired with a Fence, and recycles them once the GPU is done with them.
+/// 
+/// A buffer is considered free again once nobody but the StagingRing holds a reference to it and its Fence is signalled. Thus, the intended use is to `acquire()` a buffer, record a transfer from it, submit that transfer with the returned Fence and then drop the buffer.
+pub struct StagingRing {
+    /// The Device where the StagingBuffers live.
+    device : Shared<Device>,
+    /// The MemoryPool where the StagingBuffers are allocated.
+    pool   : Rc<RefCell<dyn MemoryPool>>,
+
+    /// The minimum size (in bytes) of newly allocated StagingBuffers.
+    min_capacity : usize,
+    /// The StagingBuffers in this ring, each paired with the Fence that signals the GPU is done with it.
+    buffers      : Vec<(Rc<StagingBuffer>, Rc<Fence>)>,
+}
+
+impl StagingRing {
+    /// Constructor for the StagingRing.
+    /// 
+    /// Note that buffers will be allocated lazily.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the StagingBuffers will live.
+    /// - `pool`: The MemoryPool where the StagingBuffers will be allocated.
+    /// - `min_capacity`: The minimum size (in bytes) of each new StagingBuffer. Larger buffers are allocated if a larger size is requested.
+    /// 
+    /// # Returns
+    /// A new StagingRing instance, already wrapped in an Rc and a RefCell.
+    #[inline]
+    pub fn new(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, min_capacity: usize) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            device,
+            pool,
+
+            min_capacity,
+            buffers : Vec::with_capacity(4),
+        }))
+    }
+
+
+
+    /// Returns a StagingBuffer of at least the given size that is not in use by the CPU or GPU.
+    /// 
+    /// If no such buffer exists, a new one is allocated (together with an already signalled Fence).
+    /// 
+    /// # Arguments
+    /// - `size`: The minimum size (in bytes) of the StagingBuffer.
+    /// 
+    /// # Returns
+    /// A tuple with the StagingBuffer and the Fence to submit its transfer with. The buffer is returned to the ring automatically once it is dropped and the Fence is signalled.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to poll any of the existing Fences, or failed to allocate a new StagingBuffer or Fence.
+    pub fn acquire(&mut self, size: usize) -> Result<(Rc<StagingBuffer>, Rc<Fence>), Error> {
+        // Search for a buffer that is large enough and not in use anymore
+        for (buffer, fence) in &self.buffers {
+            if buffer.capacity() < size || Rc::strong_count(buffer) > 1 { continue; }
+            match fence.poll() {
+                Ok(true)  => { return Ok((buffer.clone(), fence.clone())); },
+                Ok(false) => { continue; },
+                Err(err)  => { return Err(Error::StagingFencePollError{ err }); }
+            }
+        }
+
+        // Otherwise, allocate a new one
+        let buffer: Rc<StagingBuffer> = StagingBuffer::new(self.device.clone(), self.pool.clone(), size.max(self.min_capacity))?;
+        let fence: Rc<Fence> = match Fence::new(self.device.clone(), true) {
+            Ok(fence) => fence,
+            Err(err)  => { return Err(Error::StagingFenceCreateError{ err }); }
+        };
+        self.buffers.push((buffer.clone(), fence.clone()));
+        Ok((buffer, fence))
+    }
+
+
+
+    /// Returns the Device where the StagingBuffers live.
+    #[inline]
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns the MemoryPool where the StagingBuffers are allocated.
+    #[inline]
+    pub fn pool(&self) -> &Rc<RefCell<dyn MemoryPool>> { &self.pool }
+
+    /// Returns the number of StagingBuffers (both free and in use) in this ring.
+    #[inline]
+    pub fn len(&self) -> usize { self.buffers.len() }
+
+    /// Returns whether this ring has not allocated any StagingBuffers yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.buffers.is_empty() }
+}
@@ -12,8 +12,10 @@
 //!   Defines the Queue object, which wraps around a device queue.
 // 
 
+use std::collections::HashMap;
 use std::ptr;
 use std::rc::Rc;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 use ash::vk;
 
@@ -23,6 +25,30 @@ use crate::auxillary::flags::PipelineStage;
 use crate::auxillary::structs::QueueFamilyInfo;
 use crate::pools::command::Buffer as CommandBuffer;
 use crate::sync::{Fence, Semaphore};
+use crate::spec::Shared;
+
+
+/***** HELPER STRUCTS *****/
+/// Collects the Vulkan handles and values of a single SubmitInfo, which must outlive the VkSubmitInfo that references them.
+struct SubmitHandles {
+    /// The CommandBuffers to submit.
+    command_buffers : Vec<vk::CommandBuffer>,
+    /// The (binary and timeline) Semaphores to wait for.
+    wait_semaphores : Vec<vk::Semaphore>,
+    /// The stage at which each of the wait Semaphores is waited for.
+    wait_stages     : Vec<vk::PipelineStageFlags>,
+    /// The (binary and timeline) Semaphores to signal.
+    done_semaphores : Vec<vk::Semaphore>,
+    /// The values to wait for, one per wait Semaphore (the values of binary Semaphores are ignored).
+    wait_values     : Vec<u64>,
+    /// The values to signal, one per done Semaphore (the values of binary Semaphores are ignored).
+    signal_values   : Vec<u64>,
+    /// Whether any timeline Semaphores are involved (and thus whether we need a VkTimelineSemaphoreSubmitInfo).
+    timeline        : bool,
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -33,7 +59,8 @@ use crate::sync::{Fence, Semaphore};
 /// - `wait_semaphores`: The Semaphores to wait for before rendering.
 /// - `wait_stage_mask`: A list of PipelineStages where each semaphore waiting should occur.
 /// - `done_semaphores`: The Semaphores to signal when done with rendering.
-fn populate_submit_info(command_buffers: &[vk::CommandBuffer], wait_semaphores: &[vk::Semaphore], wait_stages: &[vk::PipelineStageFlags], done_semaphores: &[vk::Semaphore]) -> vk::SubmitInfo {
+/// - `timeline_info`: If given, the VkTimelineSemaphoreSubmitInfo to chain in the `p_next` (which provides the values for any timeline Semaphores). Must be alive as long as the returned struct is.
+fn populate_submit_info(command_buffers: &[vk::CommandBuffer], wait_semaphores: &[vk::Semaphore], wait_stages: &[vk::PipelineStageFlags], done_semaphores: &[vk::Semaphore], timeline_info: Option<&vk::TimelineSemaphoreSubmitInfo>) -> vk::SubmitInfo {
     // Do a few sanity checks
     if wait_semaphores.len() != wait_stages.len() { panic!("The length of the Semaphores (wait_semaphores) and associated waiting stages (wait_stages) should be the same"); }
 
@@ -41,7 +68,7 @@ fn populate_submit_info(command_buffers: &[vk::CommandBuffer], wait_semaphores:
     vk::SubmitInfo {
         // Do the standard stuff
         s_type : vk::StructureType::SUBMIT_INFO,
-        p_next : ptr::null(),
+        p_next : timeline_info.map(|info| info as *const vk::TimelineSemaphoreSubmitInfo as *const std::ffi::c_void).unwrap_or(ptr::null()),
 
         // Set the command buffers to submit
         command_buffer_count : command_buffers.len() as u32,
@@ -58,6 +85,28 @@ fn populate_submit_info(command_buffers: &[vk::CommandBuffer], wait_semaphores:
     }
 }
 
+/// Populates a VkTimelineSemaphoreSubmitInfo struct.
+/// 
+/// # Arguments
+/// - `wait_values`: The values to wait for, one per wait semaphore in the VkSubmitInfo (the values of binary semaphores are ignored).
+/// - `signal_values`: The values to signal, one per signal semaphore in the VkSubmitInfo (the values of binary semaphores are ignored).
+#[inline]
+fn populate_timeline_submit_info(wait_values: &[u64], signal_values: &[u64]) -> vk::TimelineSemaphoreSubmitInfo {
+    vk::TimelineSemaphoreSubmitInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO,
+        p_next : ptr::null(),
+
+        // Set the values to wait for
+        wait_semaphore_value_count : wait_values.len() as u32,
+        p_wait_semaphore_values    : wait_values.as_ptr(),
+
+        // Set the values to signal
+        signal_semaphore_value_count : signal_values.len() as u32,
+        p_signal_semaphore_values    : signal_values.as_ptr(),
+    }
+}
+
 
 
 
@@ -80,17 +129,39 @@ impl Queues {
     /// 
     /// Requests the three queues from the queue families in the given QueueFamilyInfo on the given vk::Device.
     #[inline]
-    pub(crate) fn new(device: &Rc<ash::Device>, family_info: &QueueFamilyInfo) -> Self {
+    pub(crate) fn new(device: &Shared<ash::Device>, family_info: &QueueFamilyInfo) -> Self {
+        // Queues from the same family are the same VkQueue, so they should also share the lock that guards it
+        let mut locks: HashMap<u32, Shared<Mutex<()>>> = HashMap::with_capacity(4);
+        let mut get_queue = |family: u32| -> Queue {
+            Queue {
+                device : device.clone(),
+                queue  : unsafe { device.get_device_queue(family, 0) },
+                lock   : locks.entry(family).or_insert_with(|| Shared::new(Mutex::new(()))).clone(),
+            }
+        };
+
         Self {
-            graphics : unsafe { Queue{ device: device.clone(), queue: device.get_device_queue(family_info.graphics, 0) } },
-            memory   : unsafe { Queue{ device: device.clone(), queue: device.get_device_queue(family_info.memory, 0) } },
-            present  : unsafe { Queue{ device: device.clone(), queue: device.get_device_queue(family_info.present, 0) } },
-            compute  : unsafe { Queue{ device: device.clone(), queue: device.get_device_queue(family_info.compute, 0) } },
+            graphics : get_queue(family_info.graphics),
+            memory   : get_queue(family_info.memory),
+            present  : get_queue(family_info.present),
+            compute  : get_queue(family_info.compute),
         }
     }
 
 
 
+    /// Locks all (distinct) VkQueues for exclusive use, e.g., to wait until the entire device is idle.
+    /// 
+    /// # Returns
+    /// A guard per distinct VkQueue that keeps it locked until it is dropped.
+    pub fn lock_all(&self) -> Vec<MutexGuard<'_, ()>> {
+        let mut locks: Vec<&Shared<Mutex<()>>> = Vec::with_capacity(4);
+        for queue in [ &self.graphics, &self.memory, &self.present, &self.compute ] {
+            if !locks.iter().any(|lock| Shared::ptr_eq(lock, &queue.lock)) { locks.push(&queue.lock); }
+        }
+        locks.into_iter().map(|lock| lock.lock().unwrap_or_else(PoisonError::into_inner)).collect()
+    }
+
     /// Returns the queue that is of the given QueueKind.
     #[inline]
     pub fn get_queue(&self, kind: QueueKind) -> &Queue {
@@ -105,12 +176,62 @@ impl Queues {
 
 
 
+/// Groups the CommandBuffers and Semaphores of a single batch of work in a `Queue::submit_batches()` call.
+#[derive(Clone, Copy)]
+pub struct SubmitInfo<'a> {
+    /// The CommandBuffers to submit in this batch.
+    pub command_buffers  : &'a [&'a Rc<CommandBuffer>],
+    /// Zero or more (binary) Semaphores to wait for before the batch can start rendering.
+    pub wait_semaphores  : &'a [&'a Rc<Semaphore>],
+    /// Zero or more (binary) Semaphores to signal when the batch is done rendering.
+    pub done_semaphores  : &'a [&'a Rc<Semaphore>],
+    /// Zero or more timeline Semaphores to wait for before the batch can start executing, each paired with the value to wait for.
+    pub timeline_waits   : &'a [(&'a Rc<Semaphore>, u64)],
+    /// Zero or more timeline Semaphores to signal when the batch is done executing, each paired with the value to set them to.
+    pub timeline_signals : &'a [(&'a Rc<Semaphore>, u64)],
+}
+
+impl<'a> SubmitInfo<'a> {
+    /// Checks that the Semaphores in this batch are of the proper type.
+    /// 
+    /// # Errors
+    /// This function errors if a timeline Semaphore is given as binary or vice versa.
+    fn validate(&self) -> Result<(), Error> {
+        for (i, sem) in self.wait_semaphores.iter().chain(self.done_semaphores.iter()).enumerate() {
+            if sem.is_timeline() { return Err(Error::UnexpectedTimelineSemaphore{ index: i }); }
+        }
+        for (i, (sem, _)) in self.timeline_waits.iter().chain(self.timeline_signals.iter()).enumerate() {
+            if !sem.is_timeline() { return Err(Error::ExpectedTimelineSemaphore{ index: i }); }
+        }
+        Ok(())
+    }
+
+    /// Casts this batch to the Vulkan handles and values that a VkSubmitInfo references.
+    fn vk(&self) -> SubmitHandles {
+        SubmitHandles {
+            command_buffers : self.command_buffers.iter().map(|cmd| cmd.vk()).collect(),
+            // Binary waits wait before writing colour attachments, timeline waits wait for the entire submission
+            wait_semaphores : self.wait_semaphores.iter().map(|sem| sem.vk()).chain(self.timeline_waits.iter().map(|(sem, _)| sem.vk())).collect(),
+            wait_stages     : (0..self.wait_semaphores.len()).map(|_| PipelineStage::COLOUR_ATTACHMENT_OUTPUT.into()).chain((0..self.timeline_waits.len()).map(|_| PipelineStage::ALL_COMMANDS.into())).collect(),
+            done_semaphores : self.done_semaphores.iter().map(|sem| sem.vk()).chain(self.timeline_signals.iter().map(|(sem, _)| sem.vk())).collect(),
+            // Binary semaphores get a dummy value that is ignored
+            wait_values     : self.wait_semaphores.iter().map(|_| 0).chain(self.timeline_waits.iter().map(|(_, value)| *value)).collect(),
+            signal_values   : self.done_semaphores.iter().map(|_| 0).chain(self.timeline_signals.iter().map(|(_, value)| *value)).collect(),
+            timeline        : !self.timeline_waits.is_empty() || !self.timeline_signals.is_empty(),
+        }
+    }
+}
+
+
+
 /// The Queue struct wraps around a Device Queue to submit easily.
 pub struct Queue {
     /// The parent Device.
-    pub(crate) device : Rc<ash::Device>,
+    pub(crate) device : Shared<ash::Device>,
     /// The Queue object to wrap.
     pub(crate) queue  : vk::Queue,
+    /// Serializes access to the VkQueue, which Vulkan requires to be externally synchronized. Shared with any other Queue that wraps the same VkQueue.
+    lock              : Shared<Mutex<()>>,
 }
 
 impl Queue {
@@ -118,26 +239,53 @@ impl Queue {
     /// 
     /// # Arguments
     /// - `command_buffer`: The CommandBuffer to submit to.
-    /// - `wait_semaphores`: One or more Semaphores to wait for before we can start rendering.
-    /// - `done_semaphores`: One or more Semaphores to signal when we're done rendering.
+    /// - `wait_semaphores`: One or more (binary) Semaphores to wait for before we can start rendering.
+    /// - `done_semaphores`: One or more (binary) Semaphores to signal when we're done rendering.
+    /// - `timeline_waits`: Zero or more timeline Semaphores to wait for before we can start executing, each paired with the value to wait for.
+    /// - `timeline_signals`: Zero or more timeline Semaphores to signal when we're done executing, each paired with the value to set them to.
     /// - `done_fence`: Fence to signal when rendering is done.
     /// 
     /// # Errors
-    /// This function errors if we fail to submit the queue.
-    pub fn submit(&self, command_buffer: &Rc<CommandBuffer>, wait_semaphores: &[&Rc<Semaphore>], done_semaphores: &[&Rc<Semaphore>], done_fence: Option<&Rc<Fence>>) -> Result<(), Error> {
-        // Cast the semaphores and generate a list of wait stages
-        let vk_wait_semaphores: Vec<vk::Semaphore>      = wait_semaphores.iter().map(|sem| sem.vk()).collect();
-        let vk_wait_stages: Vec<vk::PipelineStageFlags> = (0..wait_semaphores.len()).map(|_| PipelineStage::COLOUR_ATTACHMENT_OUTPUT.into()).collect();
-        let vk_done_semaphores: Vec<vk::Semaphore>      = done_semaphores.iter().map(|sem| sem.vk()).collect();
+    /// This function errors if we fail to submit the queue, or if any of the given Semaphores is of the wrong type (i.e., a timeline Semaphore given as binary or vice versa).
+    #[inline]
+    pub fn submit(&self, command_buffer: &Rc<CommandBuffer>, wait_semaphores: &[&Rc<Semaphore>], done_semaphores: &[&Rc<Semaphore>], timeline_waits: &[(&Rc<Semaphore>, u64)], timeline_signals: &[(&Rc<Semaphore>, u64)], done_fence: Option<&Rc<Fence>>) -> Result<(), Error> {
+        self.submit_batches(&[ SubmitInfo {
+            command_buffers : &[ command_buffer ],
+            wait_semaphores,
+            done_semaphores,
+            timeline_waits,
+            timeline_signals,
+        } ], done_fence)
+    }
+
+    /// Submits multiple independent batches of command buffers to this queue in a single call, which has less driver overhead than submitting them one by one.
+    /// 
+    /// # Arguments
+    /// - `batches`: The SubmitInfos that describe each batch, which are submitted in order.
+    /// - `done_fence`: Fence to signal when all batches are done.
+    /// 
+    /// # Errors
+    /// This function errors if we fail to submit the queue, or if any of the given Semaphores is of the wrong type (i.e., a timeline Semaphore given as binary or vice versa). In the latter case, the index in the error is relative to the offending batch.
+    pub fn submit_batches(&self, batches: &[SubmitInfo], done_fence: Option<&Rc<Fence>>) -> Result<(), Error> {
+        // Make sure the semaphores are of the proper type
+        for batch in batches { batch.validate()?; }
 
-        // Prepare the SubmitInfo
-        let vk_command_buffers: [vk::CommandBuffer; 1] = [command_buffer.vk()];
-        let submit_info = populate_submit_info(&vk_command_buffers, &vk_wait_semaphores, &vk_wait_stages, &vk_done_semaphores);
+        // Cast the batches to their Vulkan handles, and prepare the timeline values of those that need it
+        let handles: Vec<SubmitHandles> = batches.iter().map(|batch| batch.vk()).collect();
+        let timeline_infos: Vec<Option<vk::TimelineSemaphoreSubmitInfo>> = handles.iter().map(|handles| {
+            if handles.timeline { Some(populate_timeline_submit_info(&handles.wait_values, &handles.signal_values)) } else { None }
+        }).collect();
+
+        // Prepare the SubmitInfos
+        let submit_infos: Vec<vk::SubmitInfo> = handles.iter().zip(timeline_infos.iter()).map(|(handles, timeline_info)| {
+            populate_submit_info(&handles.command_buffers, &handles.wait_semaphores, &handles.wait_stages, &handles.done_semaphores, timeline_info.as_ref())
+        }).collect();
 
         // Submit!
         if let Some(done_fence) = done_fence { if let Err(err) = done_fence.reset() { return Err(Error::FenceResetError{ err }); } }
+        let _guard = self.lock();
         unsafe {
-            match self.device.queue_submit(self.queue, &[submit_info], done_fence.map(|f| f.vk()).unwrap_or(vk::Fence::null())) {
+            match self.device.queue_submit(self.queue, &submit_infos, done_fence.map(|f| f.vk()).unwrap_or(vk::Fence::null())) {
                 Ok(_)    => Ok(()),
                 Err(err) => Err(Error::SubmitError{ err }),
             }
@@ -147,6 +295,7 @@ impl Queue {
     /// Wait until the queue is idle.
     #[inline]
     pub fn drain(&self) -> Result<(), Error> {
+        let _guard = self.lock();
         match unsafe { self.device.queue_wait_idle(self.queue) } {
             Ok(_)    => Ok(()),
             Err(err) => Err(Error::IdleError{ err }),
@@ -155,6 +304,15 @@ impl Queue {
 
 
 
+    /// Locks the internal VkQueue for exclusive use, e.g., to present to it.
+    /// 
+    /// `Queue::submit()`, `Queue::submit_batches()` and `Queue::drain()` already do this themselves. The lock is not re-entrant, so do not call them while holding it.
+    /// 
+    /// # Returns
+    /// A guard that keeps the VkQueue locked until it is dropped.
+    #[inline]
+    pub fn lock(&self) -> MutexGuard<'_, ()> { self.lock.lock().unwrap_or_else(PoisonError::into_inner) }
+
     /// Returns the internal VkQueue object.
     #[inline]
     pub fn vk(&self) -> vk::Queue { self.queue }
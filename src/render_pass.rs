@@ -17,11 +17,103 @@ use std::rc::Rc;
 
 use ash::vk;
 
-use crate::debug;
+use crate::{debug, warn};
+use crate::auxillary::enums::{AttachmentLoadOp, AttachmentStoreOp, ImageFormat};
 pub use crate::errors::RenderPassError as Error;
 use crate::log_destroy;
-use crate::auxillary::structs::{AttachmentDescription, SubpassDependency, SubpassDescription};
+use crate::auxillary::flags::{PipelineStage, SampleCount};
+use crate::auxillary::structs::{AttachmentDescription, SubpassDependency, SubpassDescription, SubpassDescriptionMem};
 use crate::device::Device;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use crate::auxillary::enums::{BindPoint, ImageLayout};
+    use crate::auxillary::flags::ImageAspectFlags;
+    use crate::auxillary::structs::AttachmentRef;
+    use super::*;
+
+    /// Tests whether RenderPasses only require `vkCreateRenderPass2` when using features that need it
+    #[test]
+    fn test_requires_render_pass2() {
+        let subpass = |input_aspect: Option<ImageAspectFlags>| SubpassDescription {
+            bind_point : BindPoint::Graphics,
+
+            input_attaches    : vec![ AttachmentRef{ index: 1, layout: ImageLayout::DepthStencilReadOnly, aspect: input_aspect } ],
+            colour_attaches   : vec![ AttachmentRef{ index: 0, layout: ImageLayout::ColourAttachment, aspect: None } ],
+            resolve_attaches  : vec![],
+            preserve_attaches : vec![],
+
+            depth_stencil : None,
+        };
+
+        // Plain references can use the legacy function, but aspect-specific ones cannot
+        assert!(!requires_render_pass2(&[]));
+        assert!(!requires_render_pass2(&[ subpass(None), subpass(None) ]));
+        assert!(requires_render_pass2(&[ subpass(None), subpass(Some(ImageAspectFlags::DEPTH)) ]));
+    }
+
+    /// Tests collecting the sample counts of a subpass' attachments, including out-of-range indices
+    #[test]
+    fn test_collect_subpass_samples() {
+        let samples = [ SampleCount::FOUR, SampleCount::ONE, SampleCount::FOUR ];
+        let subpasses = vec![ vec![ 0, 2 ], vec![ 1 ], vec![ 0, 3 ] ];
+
+        assert_eq!(collect_subpass_samples(&samples, &subpasses, 0).unwrap(), vec![ SampleCount::FOUR, SampleCount::FOUR ]);
+        assert_eq!(collect_subpass_samples(&samples, &subpasses, 1).unwrap(), vec![ SampleCount::ONE ]);
+        assert!(matches!(collect_subpass_samples(&samples, &subpasses, 2), Err(Error::UnknownAttachment{ subpass: 2, attachment: 3, count: 3 })));
+        assert!(matches!(collect_subpass_samples(&samples, &subpasses, 3), Err(Error::UnknownSubpass{ subpass: 3, count: 3 })));
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Decides whether a RenderPass with the given subpasses must be created with `VK_KHR_create_renderpass2`.
+/// 
+/// This is the case if any of the features that only `vkCreateRenderPass2` supports is used, i.e., if any of the attachment references is restricted to specific aspects.
+/// 
+/// # Arguments
+/// - `subpasses`: The subpasses of the RenderPass.
+/// 
+/// # Returns
+/// Whether `vkCreateRenderPass2` is required; otherwise, the legacy `vkCreateRenderPass` suffices.
+fn requires_render_pass2(subpasses: &[SubpassDescription]) -> bool {
+    subpasses.iter().any(|subpass| {
+        subpass.input_attaches.iter().chain(subpass.colour_attaches.iter()).chain(subpass.resolve_attaches.iter()).chain(subpass.depth_stencil.iter())
+            .any(|attach| attach.aspect.is_some())
+    })
+}
+
+/// Collects the sample counts of the colour and depth/stencil attachments used in the given subpass.
+/// 
+/// # Arguments
+/// - `attachment_samples`: The SampleCount of every attachment in the RenderPass.
+/// - `subpass_attachments`: The indices of the colour and depth/stencil attachments used in every subpass of the RenderPass.
+/// - `subpass`: The index of the subpass to collect the sample counts of.
+/// 
+/// # Returns
+/// A list with the SampleCount of every colour attachment and the depth/stencil attachment (if any), in that order.
+/// 
+/// # Errors
+/// This function errors if the subpass does not exist or if it references an attachment that does not exist.
+fn collect_subpass_samples(attachment_samples: &[SampleCount], subpass_attachments: &[Vec<u32>], subpass: u32) -> Result<Vec<SampleCount>, Error> {
+    let indices: &[u32] = match subpass_attachments.get(subpass as usize) {
+        Some(indices) => indices,
+        None          => { return Err(Error::UnknownSubpass{ subpass, count: subpass_attachments.len() }); }
+    };
+    indices.iter().map(|index| match attachment_samples.get(*index as usize) {
+        Some(samples) => Ok(*samples),
+        None          => Err(Error::UnknownAttachment{ subpass, attachment: *index, count: attachment_samples.len() }),
+    }).collect()
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -53,6 +145,38 @@ fn populate_render_pass_info(attachments: &Vec<vk::AttachmentDescription>, subpa
     }
 }
 
+/// Populates the given VkRenderPassCreateInfo2 struct (as used by `VK_KHR_create_renderpass2`).
+/// 
+/// # Arguments
+/// - `attachments`: The list of attachment descriptions for this RenderPass.
+/// - `subpasses`: The list of subpasses for this RenderPass.
+/// - `dependencies`: The list subpass dependencies for this RenderPass.
+#[inline]
+fn populate_render_pass_info2(attachments: &[vk::AttachmentDescription2], subpasses: &[vk::SubpassDescription2], dependencies: &[vk::SubpassDependency2]) -> vk::RenderPassCreateInfo2 {
+    vk::RenderPassCreateInfo2 {
+        // Do the default stuff
+        s_type : vk::StructureType::RENDER_PASS_CREATE_INFO_2,
+        p_next : ptr::null(),
+        flags  : vk::RenderPassCreateFlags::empty(),
+
+        // Set the attachments
+        attachment_count : attachments.len() as u32,
+        p_attachments    : attachments.as_ptr(),
+
+        // Set the subpasses
+        subpass_count : subpasses.len() as u32,
+        p_subpasses   : subpasses.as_ptr(),
+
+        // Set the attachments
+        dependency_count : dependencies.len() as u32,
+        p_dependencies   : dependencies.as_ptr(),
+
+        // We do not use multiview
+        correlated_view_mask_count : 0,
+        p_correlated_view_masks    : ptr::null(),
+    }
+}
+
 
 
 
@@ -178,44 +302,170 @@ impl RenderPassBuilder {
     /// 
     /// # Errors
     /// Whenever the creation of the new VkRenderPass failed, or when an error occurred during any of the other functions during the build process.
-    pub fn build(self, device: Rc<Device>) -> Result<Rc<RenderPass>, Error> {
+    pub fn build(self, device: Shared<Device>) -> Result<Rc<RenderPass>, Error> {
         // If any errors, then return those
         if let Some(err) = self.error { return Err(err); }
 
-        // Cast the attachments to their Vulkan counterparts
-        debug!("Casting attachments...");
-        let attachments: Vec<vk::AttachmentDescription> = self.attachments.iter().map(|attach| attach.into()).collect();
-
-        // Cast the subpasses (with associated memory) to Vulkan counterparts
-        debug!("Casting subpasses...");
-        let mut subpasses: Vec<vk::SubpassDescription> = Vec::with_capacity(self.subpasses.len());
-        let mut _subpasses_mem: Vec<(Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<u32>, Option<Box<vk::AttachmentReference>>)> = Vec::with_capacity(self.subpasses.len());
-        for subpass in self.subpasses {
-            // Convert to Vulkan
-            let result: (vk::SubpassDescription, (Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<vk::AttachmentReference>, Vec<u32>, Option<Box<vk::AttachmentReference>>)) = subpass.into();
-            debug!("Depth stencil after into(): {:?}", if let Some(p) = result.1.4.as_ref() { &**p as *const vk::AttachmentReference } else { ptr::null() });
-
-            // Store in the arrays
-            subpasses.push(result.0);
-            _subpasses_mem.push(result.1);
-            debug!("Depth stencil after push(): {:?}", if let Some(p) = _subpasses_mem.last().unwrap().4.as_ref() { &**p as *const vk::AttachmentReference } else { ptr::null() });
+        // Use VK_KHR_create_renderpass2 only if any of the features that need it is used
+        let render_pass2: bool = requires_render_pass2(&self.subpasses);
+        RenderPass::create(device, self.attachments, self.subpasses, self.dependencies, render_pass2)
+    }
+}
+
+
+
+/// Defines a render pass, i.e., a single run through a/the pipeline.
+pub struct RenderPass {
+    /// The device where the RenderPass will live.
+    device : Shared<Device>,
+
+    /// The Vulkan RenderPass which we wrap.
+    render_pass : vk::RenderPass,
+
+    /// The format of each attachment in the RenderPass.
+    attachment_formats  : Vec<ImageFormat>,
+    /// The number of samples of each attachment in the RenderPass.
+    attachment_samples  : Vec<SampleCount>,
+    /// The indices of the colour and depth/stencil attachments used by each subpass.
+    subpass_attachments : Vec<Vec<u32>>,
+    /// The number of clear values needed to begin this RenderPass.
+    clear_value_count   : usize,
+}
+
+impl RenderPass {
+    /// Constructor for the RenderPass that always uses `VK_KHR_create_renderpass2` (i.e., `vkCreateRenderPass2`) to create it.
+    /// 
+    /// `RenderPassBuilder::build()` already does this automatically if any of the features that require it are used, so this is only needed to force it.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where to create the RenderPass on. Must have `DeviceExtension::CreateRenderPass2` enabled.
+    /// - `attachments`: The attachments of the RenderPass.
+    /// - `subpasses`: The subpasses of the RenderPass, which reference the attachments by index.
+    /// - `dependencies`: The dependencies between the subpasses, which reference the subpasses by index.
+    /// 
+    /// # Returns
+    /// A new RenderPass on success.
+    /// 
+    /// # Errors
+    /// This function errors if `VK_KHR_create_renderpass2` is not enabled on the Device or if the creation of the new VkRenderPass failed.
+    #[inline]
+    pub fn new2(device: Shared<Device>, attachments: Vec<AttachmentDescription>, subpasses: Vec<SubpassDescription>, dependencies: Vec<SubpassDependency>) -> Result<Rc<Self>, Error> {
+        Self::create(device, attachments, subpasses, dependencies, true)
+    }
+
+    /// Creates a new RenderPass from the given attachments, subpasses and dependencies.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where to create the RenderPass on.
+    /// - `attachments`: The attachments of the RenderPass.
+    /// - `subpasses`: The subpasses of the RenderPass.
+    /// - `dependencies`: The dependencies between the subpasses.
+    /// - `render_pass2`: Whether to create the RenderPass with `vkCreateRenderPass2` instead of `vkCreateRenderPass`.
+    /// 
+    /// # Returns
+    /// A new RenderPass on success.
+    /// 
+    /// # Errors
+    /// This function errors if `render_pass2` is true but `VK_KHR_create_renderpass2` is not enabled on the Device, or if the creation of the new VkRenderPass failed.
+    fn create(device: Shared<Device>, attachments: Vec<AttachmentDescription>, subpasses: Vec<SubpassDescription>, dependencies: Vec<SubpassDependency>, render_pass2: bool) -> Result<Rc<Self>, Error> {
+        // Warn for stencil operations that will be ignored
+        #[allow(unused_variables)]
+        for (i, attach) in attachments.iter().enumerate() {
+            if !attach.format.is_stencil() && (!matches!(attach.on_stencil_load, AttachmentLoadOp::DontCare | AttachmentLoadOp::None) || !matches!(attach.on_stencil_store, AttachmentStoreOp::DontCare | AttachmentStoreOp::None)) {
+                warn!("Attachment {} has non-default stencil load/store operations, but its format {} has no stencil aspect (operations will be ignored)", i, attach.format);
+            }
         }
 
-        // Cast the dependencies
-        debug!("Casting dependencies...");
-        let dependencies: Vec<vk::SubpassDependency> = self.dependencies.iter().map(|dep| dep.into()).collect();
-
-        // Now populate the create info for the render pass with this
-        debug!("Populating render pass info...");
-        let render_pass_info = populate_render_pass_info(&attachments, &subpasses, &dependencies);
-        debug!("Depth stencil according to render_pass: {:?}", unsafe { std::slice::from_raw_parts(render_pass_info.p_subpasses, render_pass_info.subpass_count as usize) }[0].p_depth_stencil_attachment);
-
-        // Create the new RenderPass...
-        let render_pass = unsafe {
-            debug!("Creating VkRenderPass...");
-            match device.create_render_pass(&render_pass_info, None) {
-                Ok(render_pass) => render_pass,
-                Err(err)        => { return Err(Error::RenderPassCreateError{ err }); }
+        // Without synchronization2, a dependency must wait on and block at least one stage
+        if !device.enabled_features().synchronization2 {
+            if let Some(i) = dependencies.iter().position(|dep| dep.from_stage == PipelineStage::NONE || dep.to_stage == PipelineStage::NONE) { return Err(Error::NoneStageNotEnabled{ dependency: i }); }
+        }
+
+        // Collect the formats and sample counts of the attachments, as well as which of them are rendered to in every subpass
+        let attachment_formats: Vec<ImageFormat> = attachments.iter().map(|attach| attach.format).collect();
+        let attachment_samples: Vec<SampleCount> = attachments.iter().map(|attach| attach.samples).collect();
+        let clear_value_count: usize = attachments.iter().rposition(|attach| {
+            matches!(attach.on_load, AttachmentLoadOp::Clear) || (attach.format.is_stencil() && matches!(attach.on_stencil_load, AttachmentLoadOp::Clear))
+        }).map(|index| index + 1).unwrap_or(0);
+        let subpass_attachments: Vec<Vec<u32>> = subpasses.iter().map(|subpass| {
+            subpass.colour_attaches.iter().chain(subpass.depth_stencil.iter())
+                .map(|attach| attach.index)
+                .filter(|index| *index != vk::ATTACHMENT_UNUSED)
+                .collect()
+        }).collect();
+
+        // Create it with VK_KHR_create_renderpass2 if asked, or with the legacy function otherwise
+        let render_pass: vk::RenderPass = if render_pass2 {
+            let loader = match device.create_render_pass2() {
+                Some(loader) => loader,
+                None         => { return Err(Error::CreateRenderPass2NotEnabled); }
+            };
+
+            // Cast the attachments, subpasses and dependencies to their Vulkan counterparts
+            debug!("Casting attachments, subpasses and dependencies for VK_KHR_create_renderpass2...");
+            let vk_attachments: Vec<vk::AttachmentDescription2> = attachments.iter().map(|attach| attach.into()).collect();
+            let mut vk_subpasses: Vec<vk::SubpassDescription2> = Vec::with_capacity(subpasses.len());
+            let mut _subpasses_mem: Vec<SubpassDescriptionMem<vk::AttachmentReference2>> = Vec::with_capacity(subpasses.len());
+            for subpass in subpasses {
+                let mut result: (vk::SubpassDescription2, SubpassDescriptionMem<vk::AttachmentReference2>) = subpass.into();
+
+                // Input attachments must always specify an aspect, so default those without one to all aspects of their format (this does not move the vector's memory)
+                for input in result.1.0.iter_mut() {
+                    if input.aspect_mask.is_empty() && input.attachment != vk::ATTACHMENT_UNUSED {
+                        if let Some(attach) = attachments.get(input.attachment as usize) { input.aspect_mask = attach.format.default_aspect().into(); }
+                    }
+                }
+
+                vk_subpasses.push(result.0);
+                _subpasses_mem.push(result.1);
+            }
+            let vk_dependencies: Vec<vk::SubpassDependency2> = dependencies.iter().map(|dep| dep.into()).collect();
+
+            // Create the new RenderPass
+            let render_pass_info = populate_render_pass_info2(&vk_attachments, &vk_subpasses, &vk_dependencies);
+            unsafe {
+                debug!("Creating VkRenderPass (using VK_KHR_create_renderpass2)...");
+                match loader.create_render_pass2(&render_pass_info, None) {
+                    Ok(render_pass) => render_pass,
+                    Err(err)        => { return Err(Error::RenderPassCreateError{ err }); }
+                }
+            }
+        } else {
+            // Cast the attachments to their Vulkan counterparts
+            debug!("Casting attachments...");
+            let vk_attachments: Vec<vk::AttachmentDescription> = attachments.iter().map(|attach| attach.into()).collect();
+
+            // Cast the subpasses (with associated memory) to Vulkan counterparts
+            debug!("Casting subpasses...");
+            let mut vk_subpasses: Vec<vk::SubpassDescription> = Vec::with_capacity(subpasses.len());
+            let mut _subpasses_mem: Vec<SubpassDescriptionMem<vk::AttachmentReference>> = Vec::with_capacity(subpasses.len());
+            for subpass in subpasses {
+                // Convert to Vulkan
+                let result: (vk::SubpassDescription, SubpassDescriptionMem<vk::AttachmentReference>) = subpass.into();
+                debug!("Depth stencil after into(): {:?}", if let Some(p) = result.1.4.as_ref() { &**p as *const vk::AttachmentReference } else { ptr::null() });
+
+                // Store in the arrays
+                vk_subpasses.push(result.0);
+                _subpasses_mem.push(result.1);
+                debug!("Depth stencil after push(): {:?}", if let Some(p) = _subpasses_mem.last().unwrap().4.as_ref() { &**p as *const vk::AttachmentReference } else { ptr::null() });
+            }
+
+            // Cast the dependencies
+            debug!("Casting dependencies...");
+            let vk_dependencies: Vec<vk::SubpassDependency> = dependencies.iter().map(|dep| dep.into()).collect();
+
+            // Now populate the create info for the render pass with this
+            debug!("Populating render pass info...");
+            let render_pass_info = populate_render_pass_info(&vk_attachments, &vk_subpasses, &vk_dependencies);
+            debug!("Depth stencil according to render_pass: {:?}", unsafe { std::slice::from_raw_parts(render_pass_info.p_subpasses, render_pass_info.subpass_count as usize) }[0].p_depth_stencil_attachment);
+
+            // Create the new RenderPass...
+            unsafe {
+                debug!("Creating VkRenderPass...");
+                match device.create_render_pass(&render_pass_info, None) {
+                    Ok(render_pass) => render_pass,
+                    Err(err)        => { return Err(Error::RenderPassCreateError{ err }); }
+                }
             }
         };
 
@@ -224,25 +474,55 @@ impl RenderPassBuilder {
         Ok(Rc::new(RenderPass {
             device,
             render_pass,
+
+            attachment_formats,
+            attachment_samples,
+            subpass_attachments,
+            clear_value_count,
         }))
     }
-}
 
 
 
-/// Defines a render pass, i.e., a single run through a/the pipeline.
-pub struct RenderPass {
-    /// The device where the RenderPass will live.
-    device : Rc<Device>,
+    /// Returns the sample counts of the colour and depth/stencil attachments used in the given subpass.
+    /// 
+    /// # Arguments
+    /// - `subpass`: The index of the subpass to return the sample counts of.
+    /// 
+    /// # Returns
+    /// A list with the SampleCount of every colour attachment and the depth/stencil attachment (if any), in that order.
+    /// 
+    /// # Errors
+    /// This function errors if the subpass does not exist or if it references an attachment that does not exist.
+    #[inline]
+    pub fn subpass_samples(&self, subpass: u32) -> Result<Vec<SampleCount>, Error> {
+        collect_subpass_samples(&self.attachment_samples, &self.subpass_attachments, subpass)
+    }
+
+
+
+    /// Returns the format of each attachment in the RenderPass.
+    #[inline]
+    pub fn attachment_formats(&self) -> &[ImageFormat] { &self.attachment_formats }
+
+    /// Returns the number of samples of each attachment in the RenderPass.
+    #[inline]
+    pub fn attachment_samples(&self) -> &[SampleCount] { &self.attachment_samples }
+
+    /// Returns the number of attachments in the RenderPass.
+    #[inline]
+    pub fn attachment_count(&self) -> usize { self.attachment_samples.len() }
+
+    /// Returns the number of clear values needed to begin this RenderPass.
+    /// 
+    /// Because clear values are indexed by attachment, this is the index of the last attachment that is cleared when loaded, plus one (or zero if no attachment is cleared).
+    #[inline]
+    pub fn clear_value_count(&self) -> usize { self.clear_value_count }
 
-    /// The Vulkan RenderPass which we wrap.
-    render_pass : vk::RenderPass,
-}
 
-impl RenderPass {
     /// Returns the internal device in the RenderPass.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
     
     /// Returns the internal VkRenderPass in the RenderPass.
     #[inline]
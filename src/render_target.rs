@@ -0,0 +1,291 @@
+//  RENDER TARGET.rs
+//    by Lut99
+// 
+//  Created:
+//    17 Oct 2026, 15:12:40
+//  Last edited:
+//    17 Oct 2026, 15:12:40
+//  Auto updated?
+//    Yes
+// 
+//  Description:
+//!   Implements the RenderTarget, which abstracts over rendering to a
+//!   Swapchain or to offscreen images.
+// 
+
+use std::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+pub use crate::errors::RenderTargetError as Error;
+use crate::auxillary::enums::ImageFormat;
+use crate::auxillary::flags::{ImageAspectFlags, ImageUsageFlags, MemoryPropertyFlags};
+use crate::auxillary::structs::Extent2D;
+use crate::device::Device;
+use crate::image::{Image, View, ViewInfo};
+use crate::pools::memory::spec::MemoryPool;
+use crate::swapchain::Swapchain;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests whether only colour formats are accepted for offscreen RenderTargets
+    #[test]
+    fn test_check_colour_format() {
+        assert!(check_colour_format(ImageFormat::B8G8R8A8SRgb).is_ok());
+        assert!(check_colour_format(ImageFormat::R32G32B32A32SFloat).is_ok());
+        assert!(matches!(check_colour_format(ImageFormat::D32SFloat), Err(Error::NonColourFormat{ format: ImageFormat::D32SFloat })));
+        assert!(matches!(check_colour_format(ImageFormat::S8UInt), Err(Error::NonColourFormat{ format: ImageFormat::S8UInt })));
+        assert!(matches!(check_colour_format(ImageFormat::D24UNormS8UInt), Err(Error::NonColourFormat{ format: ImageFormat::D24UNormS8UInt })));
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether the given format may be used for the (colour) Images of a RenderTarget.
+/// 
+/// # Arguments
+/// - `format`: The ImageFormat to check.
+/// 
+/// # Errors
+/// This function errors if the format has a depth and/or stencil aspect.
+#[inline]
+fn check_colour_format(format: ImageFormat) -> Result<(), Error> {
+    if format.is_depth() || format.is_stencil() { return Err(Error::NonColourFormat{ format }); }
+    Ok(())
+}
+
+/// Creates a colour View for every given Image.
+/// 
+/// # Arguments
+/// - `device`: The Device where the Images live.
+/// - `images`: The Images to create the Views for.
+/// 
+/// # Returns
+/// A new View per Image, in the same order.
+/// 
+/// # Errors
+/// This function errors if we failed to create any of the Views.
+fn create_views(device: &Shared<Device>, images: &[Rc<Image>]) -> Result<Vec<Rc<View>>, Error> {
+    let mut views: Vec<Rc<View>> = Vec::with_capacity(images.len());
+    for image in images {
+        match View::new(device.clone(), image.clone(), ViewInfo {
+            format : image.format(),
+            aspect : ImageAspectFlags::COLOUR,
+            ..Default::default()
+        }) {
+            Ok(view) => { views.push(view); },
+            Err(err) => { return Err(Error::ViewError{ err }); }
+        }
+    }
+    Ok(views)
+}
+
+/// Allocates the given number of offscreen colour Images.
+/// 
+/// # Arguments
+/// - `device`: The Device where the Images will live.
+/// - `pool`: The MemoryPool where the Images' memory will be allocated.
+/// - `format`: The ImageFormat of the new Images.
+/// - `extent`: The size (in pixels) of the new Images.
+/// - `usage_flags`: Any additional ImageUsageFlags (next to `ImageUsageFlags::COLOUR_ATTACHMENT`) of the new Images.
+/// - `count`: The number of Images to allocate.
+/// 
+/// # Returns
+/// The new Images.
+/// 
+/// # Errors
+/// This function errors if we failed to create any of the Images.
+fn create_images(device: &Shared<Device>, pool: &Rc<RefCell<dyn MemoryPool>>, format: ImageFormat, extent: &Extent2D<u32>, usage_flags: ImageUsageFlags, count: usize) -> Result<Vec<Rc<Image>>, Error> {
+    let mut images: Vec<Rc<Image>> = Vec::with_capacity(count);
+    for _ in 0..count {
+        match Image::new(device.clone(), pool.clone(), format, extent.clone(), ImageUsageFlags::COLOUR_ATTACHMENT | usage_flags, MemoryPropertyFlags::DEVICE_LOCAL, None) {
+            Ok(image) => { images.push(image); },
+            Err(err)  => { return Err(Error::ImageError{ err }); }
+        }
+    }
+    Ok(images)
+}
+
+
+
+
+
+/***** AUXILLARY STRUCTS *****/
+/// Defines what backs a RenderTarget.
+pub enum RenderTargetKind {
+    /// The RenderTarget renders to the images of a Swapchain.
+    Swapchain(Rc<RefCell<Swapchain>>),
+    /// The RenderTarget renders to offscreen Images, allocated in the given pool with the given additional usage flags.
+    Offscreen{ pool: Rc<RefCell<dyn MemoryPool>>, usage_flags: ImageUsageFlags },
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A set of colour attachments to render to, which are either backed by a Swapchain (windowed rendering) or by offscreen Images (headless rendering).
+/// 
+/// Either way, it exposes the same format, extent and Views, so that the code that builds framebuffers and records the render loop is identical for both.
+pub struct RenderTarget {
+    /// The Device where the RenderTarget lives.
+    device : Shared<Device>,
+    /// What backs this RenderTarget.
+    kind   : RenderTargetKind,
+
+    /// The Images that we render to.
+    images : Vec<Rc<Image>>,
+    /// A View per Image.
+    views  : Vec<Rc<View>>,
+    /// The format of the Images.
+    format : ImageFormat,
+    /// The size of the Images.
+    extent : Extent2D<u32>,
+}
+
+impl RenderTarget {
+    /// Constructor for a RenderTarget that renders to the images of the given Swapchain.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Swapchain lives.
+    /// - `swapchain`: The Swapchain to render to.
+    /// 
+    /// # Returns
+    /// A new RenderTarget with a View per image in the Swapchain.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to create the Views.
+    pub fn from_swapchain(device: Shared<Device>, swapchain: Rc<RefCell<Swapchain>>) -> Result<Self, Error> {
+        // Collect the information of the swapchain
+        let (images, format, extent): (Vec<Rc<Image>>, ImageFormat, Extent2D<u32>) = {
+            let swapchain: Ref<Swapchain> = swapchain.borrow();
+            (swapchain.images().clone(), swapchain.format(), swapchain.extent().clone())
+        };
+        let views: Vec<Rc<View>> = create_views(&device, &images)?;
+
+        // Done
+        Ok(Self {
+            device,
+            kind : RenderTargetKind::Swapchain(swapchain),
+
+            images,
+            views,
+            format,
+            extent,
+        })
+    }
+
+    /// Constructor for a RenderTarget that renders to offscreen Images.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Images will live.
+    /// - `pool`: The MemoryPool where the Images' memory will be allocated.
+    /// - `format`: The ImageFormat of the Images. Must be a colour format.
+    /// - `extent`: The size (in pixels) of the Images.
+    /// - `usage_flags`: Any additional ImageUsageFlags of the Images (e.g., `ImageUsageFlags::TRANSFER_SRC` to read them back). They are always usable as colour attachment.
+    /// - `count`: The number of Images to render to (e.g., one per frame-in-flight).
+    /// 
+    /// # Returns
+    /// A new RenderTarget with the given number of Images and a View per Image.
+    /// 
+    /// # Errors
+    /// This function errors if the format is not a colour format (i.e., it has a depth and/or stencil aspect), or if we failed to create the Images or their Views.
+    pub fn offscreen(device: Shared<Device>, pool: Rc<RefCell<dyn MemoryPool>>, format: ImageFormat, extent: Extent2D<u32>, usage_flags: ImageUsageFlags, count: usize) -> Result<Self, Error> {
+        // Only colour attachments are supported
+        check_colour_format(format)?;
+
+        // Create the images and views
+        let images: Vec<Rc<Image>> = create_images(&device, &pool, format, &extent, usage_flags, count)?;
+        let views: Vec<Rc<View>>   = create_views(&device, &images)?;
+
+        // Done
+        Ok(Self {
+            device,
+            kind : RenderTargetKind::Offscreen{ pool, usage_flags },
+
+            images,
+            views,
+            format,
+            extent,
+        })
+    }
+
+
+
+    /// Rebuilds the RenderTarget with a new size.
+    /// 
+    /// For a Swapchain-backed RenderTarget, this rebuilds the Swapchain; for an offscreen one, this re-allocates the Images. Either way, the Views are re-created, so any Framebuffers referring to them should be re-created as well.
+    /// 
+    /// # Arguments
+    /// - `new_width`: The new width (in pixels) of the Images.
+    /// - `new_height`: The new height (in pixels) of the Images.
+    /// 
+    /// # Errors
+    /// This function errors if we failed to rebuild the Swapchain, to create the new Images or to create their Views.
+    pub fn rebuild(&mut self, new_width: u32, new_height: u32) -> Result<(), Error> {
+        // Get the new images
+        let (images, format, extent): (Vec<Rc<Image>>, ImageFormat, Extent2D<u32>) = match &self.kind {
+            RenderTargetKind::Swapchain(swapchain) => {
+                if let Err(err) = swapchain.borrow_mut().rebuild(new_width, new_height) { return Err(Error::SwapchainError{ err }); }
+                let swapchain: Ref<Swapchain> = swapchain.borrow();
+                (swapchain.images().clone(), swapchain.format(), swapchain.extent().clone())
+            },
+
+            RenderTargetKind::Offscreen{ pool, usage_flags } => {
+                let extent: Extent2D<u32> = Extent2D::new(new_width, new_height);
+                (create_images(&self.device, pool, self.format, &extent, *usage_flags, self.images.len())?, self.format, extent)
+            },
+        };
+        let views: Vec<Rc<View>> = create_views(&self.device, &images)?;
+
+        // Replace everything with the new ones
+        self.images = images;
+        self.views  = views;
+        self.format = format;
+        self.extent = extent;
+
+        // Done
+        Ok(())
+    }
+
+
+
+    /// Returns the device on which this RenderTarget lives.
+    #[inline]
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns what backs this RenderTarget.
+    #[inline]
+    pub fn kind(&self) -> &RenderTargetKind { &self.kind }
+
+    /// Returns the Swapchain backing this RenderTarget, if any.
+    #[inline]
+    pub fn swapchain(&self) -> Option<&Rc<RefCell<Swapchain>>> { if let RenderTargetKind::Swapchain(swapchain) = &self.kind { Some(swapchain) } else { None } }
+
+    /// Returns whether this RenderTarget renders to offscreen Images.
+    #[inline]
+    pub fn is_offscreen(&self) -> bool { matches!(self.kind, RenderTargetKind::Offscreen{ .. }) }
+
+    /// Returns the Images that we render to.
+    #[inline]
+    pub fn images(&self) -> &[Rc<Image>] { &self.images }
+
+    /// Returns a View per Image that we render to, e.g., to create Framebuffers with.
+    #[inline]
+    pub fn views(&self) -> &[Rc<View>] { &self.views }
+
+    /// Returns the format of the Images.
+    #[inline]
+    pub fn format(&self) -> ImageFormat { self.format }
+
+    /// Returns the size of the Images.
+    #[inline]
+    pub fn extent(&self) -> &Extent2D<u32> { &self.extent }
+}
@@ -0,0 +1,212 @@
+//  SAMPLER.rs
+//    by Lut99
+// 
+//  Created:
+//    17 Oct 2026, 14:02:11
+//  Last edited:
+//    17 Oct 2026, 14:02:11
+//  Auto updated?
+//    Yes
+// 
+//  Description:
+//!   Contains code related to samplers, which define how shaders read
+//!   from images.
+// 
+
+use std::ptr;
+use std::rc::Rc;
+
+use ash::vk;
+
+pub use crate::errors::SamplerError as Error;
+use crate::log_destroy;
+use crate::auxillary::enums::{CompareOp, Filter, SamplerAddressMode, SamplerMipmapMode};
+use crate::device::Device;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that the optional parts of the CreateInfo toggle their Vulkan counterparts
+    #[test]
+    fn test_sampler_info() {
+        // The default does not filter anisotropically nor compare
+        let info: vk::SamplerCreateInfo = populate_sampler_info(&CreateInfo::default());
+        assert_eq!((info.anisotropy_enable, info.compare_enable), (vk::FALSE, vk::FALSE));
+        assert_eq!((info.mag_filter, info.mipmap_mode, info.address_mode_w), (vk::Filter::LINEAR, vk::SamplerMipmapMode::LINEAR, vk::SamplerAddressMode::REPEAT));
+
+        // A shadow map sampler
+        let info: vk::SamplerCreateInfo = populate_sampler_info(&CreateInfo {
+            mag_filter   : Filter::Nearest,
+            address_mode : (SamplerAddressMode::ClampToEdge, SamplerAddressMode::ClampToEdge, SamplerAddressMode::ClampToBorder),
+            anisotropy   : Some(16.0),
+            compare_op   : Some(CompareOp::LessEq),
+            ..Default::default()
+        });
+        assert_eq!((info.mag_filter, info.min_filter), (vk::Filter::NEAREST, vk::Filter::LINEAR));
+        assert_eq!((info.address_mode_u, info.address_mode_w), (vk::SamplerAddressMode::CLAMP_TO_EDGE, vk::SamplerAddressMode::CLAMP_TO_BORDER));
+        assert_eq!((info.anisotropy_enable, info.max_anisotropy), (vk::TRUE, 16.0));
+        assert_eq!((info.compare_enable, info.compare_op), (vk::TRUE, vk::CompareOp::LESS_OR_EQUAL));
+    }
+}
+
+
+
+
+
+/***** POPULATE FUNCTIONS *****/
+/// Populates a new VkSamplerCreateInfo struct.
+/// 
+/// # Arguments
+/// - `info`: The CreateInfo that describes the new Sampler.
+#[inline]
+fn populate_sampler_info(info: &CreateInfo) -> vk::SamplerCreateInfo {
+    vk::SamplerCreateInfo {
+        // Do the default stuff
+        s_type : vk::StructureType::SAMPLER_CREATE_INFO,
+        p_next : ptr::null(),
+        flags  : vk::SamplerCreateFlags::empty(),
+
+        // Set the filters
+        mag_filter  : info.mag_filter.into(),
+        min_filter  : info.min_filter.into(),
+        mipmap_mode : info.mipmap_mode.into(),
+
+        // Set what to do outside of the image
+        address_mode_u : info.address_mode.0.into(),
+        address_mode_v : info.address_mode.1.into(),
+        address_mode_w : info.address_mode.2.into(),
+        border_color   : vk::BorderColor::FLOAT_TRANSPARENT_BLACK,
+
+        // Set the anisotropic filtering
+        anisotropy_enable : info.anisotropy.is_some() as vk::Bool32,
+        max_anisotropy    : info.anisotropy.unwrap_or(1.0),
+
+        // Set the comparison (for, e.g., shadow maps)
+        compare_enable : info.compare_op.is_some() as vk::Bool32,
+        compare_op     : info.compare_op.map(|op| op.into()).unwrap_or(vk::CompareOp::ALWAYS),
+
+        // Set the LOD properties
+        mip_lod_bias : info.lod_bias,
+        min_lod      : info.min_lod,
+        max_lod      : info.max_lod,
+
+        // We always use normalized coordinates
+        unnormalized_coordinates : vk::FALSE,
+    }
+}
+
+
+
+
+
+/***** AUXILLARY STRUCTS *****/
+/// CreateInfo for the Sampler.
+#[derive(Clone, Debug)]
+pub struct CreateInfo {
+    /// The Filter to use when the image is magnified.
+    pub mag_filter  : Filter,
+    /// The Filter to use when the image is minified.
+    pub min_filter  : Filter,
+    /// Defines how to choose between mip levels.
+    pub mipmap_mode : SamplerMipmapMode,
+
+    /// The SamplerAddressMode for coordinates outside of the image, for the U, V and W axis, respectively.
+    pub address_mode : (SamplerAddressMode, SamplerAddressMode, SamplerAddressMode),
+
+    /// If given, enables anisotropic filtering with the given maximum anisotropy. Requires the `sampler_anisotropy` DeviceFeature.
+    pub anisotropy : Option<f32>,
+    /// If given, compares the texels to a reference value with the given operation before filtering them (e.g., for shadow maps).
+    pub compare_op : Option<CompareOp>,
+
+    /// The bias to add to the computed mip level.
+    pub lod_bias : f32,
+    /// The minimum mip level that may be sampled.
+    pub min_lod  : f32,
+    /// The maximum mip level that may be sampled. Use `vk::LOD_CLAMP_NONE` to not clamp it at all.
+    pub max_lod  : f32,
+}
+
+impl Default for CreateInfo {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            mag_filter  : Filter::Linear,
+            min_filter  : Filter::Linear,
+            mipmap_mode : SamplerMipmapMode::Linear,
+
+            address_mode : (SamplerAddressMode::Repeat, SamplerAddressMode::Repeat, SamplerAddressMode::Repeat),
+
+            anisotropy : None,
+            compare_op : None,
+
+            lod_bias : 0.0,
+            min_lod  : 0.0,
+            max_lod  : vk::LOD_CLAMP_NONE,
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// The Sampler class, which defines how shaders read from (sampled) images.
+pub struct Sampler {
+    /// The Device where the Sampler lives.
+    device  : Shared<Device>,
+
+    /// The VkSampler we wrap around.
+    sampler : vk::Sampler,
+}
+
+impl Sampler {
+    /// Constructor for the Sampler.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the Sampler will live.
+    /// - `create_info`: The CreateInfo that describes how the new Sampler samples images.
+    /// 
+    /// # Returns
+    /// A new Sampler instance, already wrapped in an Rc.
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend could not create the sampler.
+    pub fn new(device: Shared<Device>, create_info: CreateInfo) -> Result<Rc<Self>, Error> {
+        // Create the sampler
+        let sampler_info = populate_sampler_info(&create_info);
+        let sampler = unsafe {
+            match device.create_sampler(&sampler_info, None) {
+                Ok(sampler) => sampler,
+                Err(err)    => { return Err(Error::SamplerCreateError{ err }); }
+            }
+        };
+
+        // Wrap it and done
+        Ok(Rc::new(Self {
+            device,
+            sampler,
+        }))
+    }
+
+
+
+    /// Returns the Device where this Sampler lives.
+    #[inline]
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns the internal VkSampler.
+    #[inline]
+    pub fn vk(&self) -> vk::Sampler { self.sampler }
+}
+
+impl Drop for Sampler {
+    fn drop(&mut self) {
+        log_destroy!(self, Sampler);
+        unsafe { self.device.destroy_sampler(self.sampler, None); }
+    }
+}
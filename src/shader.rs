@@ -13,6 +13,8 @@
 //!   ShaderModule
 // 
 
+use std::collections::HashMap;
+use std::ffi::c_void;
 use std::fs::{self, File};
 use std::io::Read;
 use std::path::Path;
@@ -22,19 +24,565 @@ use std::rc::Rc;
 use ash::vk;
 use rust_embed::EmbeddedFile;
 
+use crate::warn;
 pub use crate::errors::ShaderError as Error;
 use crate::log_destroy;
+use crate::auxillary::flags::{ShaderStage, ShaderStageFlags};
+use crate::auxillary::structs::PushConstantRange;
 use crate::device::Device;
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a minimal SPIR-V module (header + the given instructions) as bytes.
+    fn module(instrs: &[&[u32]]) -> Vec<u8> {
+        let mut words: Vec<u32> = vec![ SPIRV_MAGIC, 0x00010000, 0, 16, 0 ];
+        for instr in instrs { words.extend_from_slice(instr); }
+        words.into_iter().flat_map(|w| w.to_le_bytes()).collect()
+    }
+
+    /// Tests whether the entry points are correctly parsed from a module
+    #[test]
+    fn test_parse_entry_points() {
+        // OpEntryPoint <model> %1 "main"; OpEntryPoint <model> %2 "fragMain"
+        let main: &[u32]      = &[ (5 << 16) | 15, 0, 1, u32::from_le_bytes(*b"main"), 0 ];
+        let frag_main: &[u32] = &[ (6 << 16) | 15, 4, 2, u32::from_le_bytes(*b"frag"), u32::from_le_bytes(*b"Main"), 0 ];
+        assert_eq!(parse_entry_points(&module(&[ main, frag_main ])), vec![ (ShaderStage::VERTEX, "main".to_string()), (ShaderStage::FRAGMENT, "fragMain".to_string()) ]);
+
+        // Unsupported models are skipped, and invalid modules yield nothing
+        let kernel: &[u32] = &[ (5 << 16) | 15, 6, 3, u32::from_le_bytes(*b"main"), 0 ];
+        assert_eq!(parse_entry_points(&module(&[ kernel, frag_main ])), vec![ (ShaderStage::FRAGMENT, "fragMain".to_string()) ]);
+        assert_eq!(parse_entry_points(&[ 0x03, 0x02, 0x23 ]), vec![]);
+    }
+
+    /// Tests whether the stage is correctly parsed from a module's entry point(s)
+    #[test]
+    fn test_parse_stage() {
+        // OpCapability Shader; OpMemoryModel Logical GLSL450; OpEntryPoint <model> %1 "main"
+        let capability: &[u32]   = &[ (2 << 16) | 17, 1 ];
+        let memory_model: &[u32] = &[ (3 << 16) | 14, 0, 1 ];
+        let entry = |model: u32| -> [u32; 5] { [ (5 << 16) | 15, model, 1, u32::from_le_bytes(*b"main"), 0 ] };
+
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(0) ])), Some(ShaderStage::VERTEX));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(1) ])), Some(ShaderStage::TESSELLATION_CONTROL));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(2) ])), Some(ShaderStage::TESSELLATION_EVALUATION));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(3) ])), Some(ShaderStage::GEOMETRY));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(4) ])), Some(ShaderStage::FRAGMENT));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(5) ])), Some(ShaderStage::COMPUTE));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(5364) ])), Some(ShaderStage::TASK));
+        assert_eq!(parse_stage(&module(&[ capability, memory_model, &entry(5365) ])), Some(ShaderStage::MESH));
+
+        // Multiple entry points of the same stage are fine, but different stages are ambiguous
+        assert_eq!(parse_stage(&module(&[ capability, &entry(4), &entry(4) ])), Some(ShaderStage::FRAGMENT));
+        assert_eq!(parse_stage(&module(&[ capability, &entry(0), &entry(4) ])), None);
+
+        // Unsupported models, missing entry points and invalid modules yield nothing
+        assert_eq!(parse_stage(&module(&[ capability, &entry(6) ])), None);
+        assert_eq!(parse_stage(&module(&[ capability, memory_model ])), None);
+        assert_eq!(parse_stage(&module(&[ &[ SPIRV_OP_ENTRY_POINT ] ])), None);
+        assert_eq!(parse_stage(&[ 0x03, 0x02, 0x23 ]), None);
+        assert_eq!(parse_stage(&[ 0; 20 ]), None);
+    }
+
+    /// Tests whether push constant ranges are correctly reflected from a module's push constant block(s)
+    #[test]
+    fn test_parse_push_constants() {
+        let entry = |model: u32| -> [u32; 5] { [ (5 << 16) | 15, model, 1, u32::from_le_bytes(*b"main"), 0 ] };
+        // %2 = OpTypeFloat 32; %3 = OpTypeVector %2 4; %4 = OpTypeMatrix %3 4; %5 = OpTypeInt 32 0; %6 = OpConstant %5 2; %9 = OpTypeArray %2 %6
+        let types: &[&[u32]] = &[ &[ (3 << 16) | 22, 2, 32 ], &[ (4 << 16) | 23, 3, 2, 4 ], &[ (4 << 16) | 24, 4, 3, 4 ], &[ (4 << 16) | 21, 5, 32, 0 ], &[ (4 << 16) | 43, 5, 6, 2 ], &[ (4 << 16) | 28, 9, 2, 6 ] ];
+        // %11 = OpTypePointer PushConstant %10; %12 = OpVariable %11 PushConstant
+        let variable: &[&[u32]] = &[ &[ (4 << 16) | 32, 11, 9, 10 ], &[ (4 << 16) | 59, 11, 12, 9 ] ];
+
+        // A block { mat4 (offset 0, matrix stride 16); vec4 (offset 64); float[2] (offset 80, array stride 4) } used by a vertex and fragment entry point
+        let decorations: &[&[u32]] = &[ &[ (5 << 16) | 72, 10, 0, 35, 0 ], &[ (5 << 16) | 72, 10, 0, 7, 16 ], &[ (5 << 16) | 72, 10, 1, 35, 64 ], &[ (5 << 16) | 72, 10, 2, 35, 80 ], &[ (4 << 16) | 71, 9, 6, 4 ] ];
+        let block: &[u32] = &[ (5 << 16) | 30, 10, 4, 3, 9 ];
+        let code = module(&[ &[ &entry(0)[..], &entry(4)[..] ][..], decorations, types, &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![ PushConstantRange{ stage: ShaderStageFlags::VERTEX | ShaderStageFlags::FRAGMENT, offset: 0, size: 88 } ]);
+
+        // A block that starts at an offset { vec3 (offset 16) }, which is rounded up to a multiple of four
+        let vec3: &[u32]           = &[ (4 << 16) | 23, 3, 2, 3 ];
+        let decorations: &[&[u32]] = &[ &[ (5 << 16) | 72, 10, 0, 35, 16 ] ];
+        let block: &[u32]          = &[ (3 << 16) | 30, 10, 3 ];
+        let code = module(&[ &[ &entry(4)[..] ][..], decorations, &[ types[0], vec3 ], &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![ PushConstantRange{ stage: ShaderStageFlags::FRAGMENT, offset: 16, size: 12 } ]);
+
+        // Modules without push constants, with blocks without a layout or that are invalid yield nothing
+        assert_eq!(parse_push_constants(&module(&[ &entry(0) ])), vec![]);
+        let code = module(&[ &[ &entry(4)[..] ][..], &[ types[0], vec3 ], &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![]);
+        assert_eq!(parse_push_constants(&[ 0x03, 0x02, 0x23 ]), vec![]);
+
+        // Blocks whose size overflows, or which (indirectly) contain themselves, yield nothing too
+        let huge: &[u32]           = &[ (4 << 16) | 43, 5, 6, u32::MAX ];
+        let decorations: &[&[u32]] = &[ &[ (5 << 16) | 72, 10, 0, 35, 0 ] ];
+        let block: &[u32]          = &[ (3 << 16) | 30, 10, 9 ];
+        let code = module(&[ &[ &entry(4)[..] ][..], decorations, &[ types[0], types[3], huge, types[5] ], &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![]);
+        let decorations: &[&[u32]] = &[ &[ (5 << 16) | 72, 10, 0, 35, u32::MAX ] ];
+        let block: &[u32]          = &[ (3 << 16) | 30, 10, 2 ];
+        let code = module(&[ &[ &entry(4)[..] ][..], decorations, &[ types[0] ], &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![]);
+        let decorations: &[&[u32]] = &[ &[ (5 << 16) | 72, 10, 0, 35, 0 ] ];
+        let block: &[u32]          = &[ (3 << 16) | 30, 10, 10 ];
+        let code = module(&[ &[ &entry(4)[..] ][..], decorations, &[ block ], variable ].concat());
+        assert_eq!(parse_push_constants(&code), vec![]);
+    }
+
+    /// Tests whether specialization constants are correctly reflected from a module
+    #[test]
+    fn test_parse_spec_constants() {
+        // %1 = OpTypeInt 32 0; %2 = OpTypeFloat 64; %3 = OpTypeBool
+        let types: &[&[u32]] = &[ &[ (4 << 16) | 21, 1, 32, 0 ], &[ (3 << 16) | 22, 2, 64 ], &[ (2 << 16) | 20, 3 ] ];
+        // %4 = OpSpecConstant %1 7; %5 = OpSpecConstant %2 0.0; %6 = OpSpecConstantTrue %3; %7 = OpSpecConstant %1 1 (without SpecId)
+        let constants: &[&[u32]] = &[ &[ (4 << 16) | 50, 1, 4, 7 ], &[ (5 << 16) | 50, 2, 5, 0, 0 ], &[ (3 << 16) | 48, 3, 6 ], &[ (4 << 16) | 50, 1, 7, 1 ] ];
+        // OpDecorate %4 SpecId 2; OpDecorate %5 SpecId 0; OpDecorate %6 SpecId 1
+        let decorations: &[&[u32]] = &[ &[ (4 << 16) | 71, 4, 1, 2 ], &[ (4 << 16) | 71, 5, 1, 0 ], &[ (4 << 16) | 71, 6, 1, 1 ] ];
+        let code = module(&[ decorations, types, constants ].concat());
+        assert_eq!(parse_spec_constants(&code), vec![ (0, 8), (1, 4), (2, 4) ]);
+
+        // Modules without specialization constants and invalid modules yield nothing
+        assert_eq!(parse_spec_constants(&module(&[ types[0], &[ (4 << 16) | 43, 1, 4, 7 ] ])), vec![]);
+        assert_eq!(parse_spec_constants(&[ 0x03, 0x02, 0x23 ]), vec![]);
+    }
+
+    /// Tests whether SpecializationConstants checks the values against the declared constants
+    #[test]
+    fn test_specialization_constants() {
+        let new = || SpecializationConstants{ error: None, declared: vec![ (0, 8), (1, 4) ], entries: vec![], data: vec![] };
+
+        // Values of the right size are packed back-to-back, and unknown constants are passed along
+        let specialization = new().constant(0, 1.0f64.to_ne_bytes()).constant(1, vk::TRUE.to_ne_bytes()).constant(5, [ 0u8; 2 ]).build().unwrap();
+        assert_eq!(specialization.entries().iter().map(|entry| (entry.constant_id, entry.offset, entry.size)).collect::<Vec<_>>(), vec![ (0, 0, 8), (1, 8, 4), (5, 12, 2) ]);
+        assert_eq!(specialization.data().len(), 14);
+        let info = specialization.vk();
+        assert_eq!((info.map_entry_count, info.data_size), (3, 14));
+
+        // Values of the wrong size and duplicate values are rejected
+        assert!(matches!(new().constant(0, 1.0f32.to_ne_bytes()).build(), Err(Error::SpecializationSizeMismatch{ id: 0, expected: 8, got: 4 })));
+        assert!(matches!(new().constant(1, 1u32.to_ne_bytes()).constant(1, 2u32.to_ne_bytes()).build(), Err(Error::DuplicateSpecializationConstant{ id: 1 })));
+        assert!(new().build().unwrap().vk().p_data.is_null());
+    }
+}
+
+
+
+
+
+/***** CONSTANTS *****/
+/// The magic number that starts every SPIR-V module.
+const SPIRV_MAGIC: u32 = 0x07230203;
+
+/// The opcode of the OpEntryPoint instruction.
+const SPIRV_OP_ENTRY_POINT: u32 = 15;
+/// The opcode of the OpTypeInt instruction.
+const SPIRV_OP_TYPE_INT: u32 = 21;
+/// The opcode of the OpTypeFloat instruction.
+const SPIRV_OP_TYPE_FLOAT: u32 = 22;
+/// The opcode of the OpTypeVector instruction.
+const SPIRV_OP_TYPE_VECTOR: u32 = 23;
+/// The opcode of the OpTypeMatrix instruction.
+const SPIRV_OP_TYPE_MATRIX: u32 = 24;
+/// The opcode of the OpTypeArray instruction.
+const SPIRV_OP_TYPE_ARRAY: u32 = 28;
+/// The opcode of the OpTypeStruct instruction.
+const SPIRV_OP_TYPE_STRUCT: u32 = 30;
+/// The opcode of the OpTypePointer instruction.
+const SPIRV_OP_TYPE_POINTER: u32 = 32;
+/// The opcode of the OpConstant instruction.
+const SPIRV_OP_CONSTANT: u32 = 43;
+/// The opcode of the OpSpecConstantTrue instruction.
+const SPIRV_OP_SPEC_CONSTANT_TRUE: u32 = 48;
+/// The opcode of the OpSpecConstantFalse instruction.
+const SPIRV_OP_SPEC_CONSTANT_FALSE: u32 = 49;
+/// The opcode of the OpSpecConstant instruction.
+const SPIRV_OP_SPEC_CONSTANT: u32 = 50;
+/// The opcode of the OpVariable instruction.
+const SPIRV_OP_VARIABLE: u32 = 59;
+/// The opcode of the OpDecorate instruction.
+const SPIRV_OP_DECORATE: u32 = 71;
+/// The opcode of the OpMemberDecorate instruction.
+const SPIRV_OP_MEMBER_DECORATE: u32 = 72;
+
+/// The SpecId decoration.
+const SPIRV_DECORATION_SPEC_ID: u32 = 1;
+/// The ArrayStride decoration.
+const SPIRV_DECORATION_ARRAY_STRIDE: u32 = 6;
+/// The MatrixStride decoration.
+const SPIRV_DECORATION_MATRIX_STRIDE: u32 = 7;
+/// The Offset decoration.
+const SPIRV_DECORATION_OFFSET: u32 = 35;
+
+/// The PushConstant storage class.
+const SPIRV_STORAGE_CLASS_PUSH_CONSTANT: u32 = 9;
+
+/// How deeply types may be nested before we consider a module invalid (e.g., because a struct contains itself).
+const SPIRV_MAX_TYPE_DEPTH: usize = 64;
+
+
+
+
+
+/***** HELPER STRUCTS *****/
+/// The (explicitly laid out) SPIR-V types we need to know about to compute the size of a push constant block.
+#[derive(Clone, Debug)]
+enum SpirvType {
+    /// An integer or floating-point scalar of the given size (in bytes).
+    Scalar(u32),
+    /// A vector with the given component type and number of components.
+    Vector(u32, u32),
+    /// A matrix with the given column type and number of columns.
+    Matrix(u32, u32),
+    /// An array with the given element type and the ID of the constant that holds its length.
+    Array(u32, u32),
+    /// A struct with the given member types.
+    Struct(Vec<u32>),
+}
+
+/// Collects the types, constants and layout decorations of a SPIR-V module, so we can compute the size of its types.
+#[derive(Debug, Default)]
+struct SpirvLayout {
+    /// Maps type IDs to the type they define.
+    types          : HashMap<u32, SpirvType>,
+    /// Maps constant IDs to their (lowest word of their) value.
+    constants      : HashMap<u32, u32>,
+    /// Maps array type IDs to their ArrayStride decoration.
+    array_strides  : HashMap<u32, u32>,
+    /// Maps (struct type ID, member index) pairs to their Offset decoration.
+    offsets        : HashMap<(u32, u32), u32>,
+    /// Maps (struct type ID, member index) pairs to their MatrixStride decoration.
+    matrix_strides : HashMap<(u32, u32), u32>,
+}
+
+impl SpirvLayout {
+    /// Computes the size (in bytes) of the given type in memory.
+    /// 
+    /// # Arguments
+    /// - `id`: The ID of the type to compute the size of.
+    /// - `matrix_stride`: The MatrixStride of the struct member that (indirectly) contains this type, if any.
+    /// - `depth`: How deeply nested the type is in the type we are computing the size of. Used to bail out of (invalid) recursive types.
+    /// 
+    /// # Returns
+    /// The size of the type, or `None` if it is unknown, not explicitly laid out, nested too deeply or too large to fit in a u32.
+    fn size_of(&self, id: u32, matrix_stride: Option<u32>, depth: usize) -> Option<u32> {
+        if depth > SPIRV_MAX_TYPE_DEPTH { return None; }
+        match self.types.get(&id)? {
+            SpirvType::Scalar(size)            => Some(*size),
+            SpirvType::Vector(component, n)    => self.size_of(*component, None, depth + 1)?.checked_mul(*n),
+            SpirvType::Matrix(column, n)       => match matrix_stride {
+                Some(stride) => stride.checked_mul(*n),
+                None         => self.size_of(*column, None, depth + 1)?.checked_mul(*n),
+            },
+            SpirvType::Array(element, length) => {
+                let length: u32 = *self.constants.get(length)?;
+                match self.array_strides.get(&id) {
+                    Some(stride) => stride.checked_mul(length),
+                    None         => self.size_of(*element, matrix_stride, depth + 1)?.checked_mul(length),
+                }
+            },
+            SpirvType::Struct(members) => {
+                // The size of the struct is where its furthest member ends
+                let mut end: u32 = 0;
+                for (i, member) in members.iter().enumerate() {
+                    let offset: u32 = *self.offsets.get(&(id, i as u32))?;
+                    let size: u32   = self.size_of(*member, self.matrix_strides.get(&(id, i as u32)).cloned(), depth + 1)?;
+                    end = end.max(offset.checked_add(size)?);
+                }
+                Some(end)
+            },
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Decodes the given SPIR-V bytecode into words, taking the module's endianness into account.
+/// 
+/// # Arguments
+/// - `code`: The SPIR-V bytecode to decode.
+/// 
+/// # Returns
+/// The words of the module (including its header), or `None` if the bytecode is not a valid SPIR-V module.
+#[allow(clippy::manual_is_multiple_of)]
+fn parse_words(code: &[u8]) -> Option<Vec<u32>> {
+    // Only accept whole words, and make sure there is a complete header
+    if code.len() % 4 != 0 || code.len() < 20 { return None; }
+
+    // Read the magic number to determine the endianness
+    let little: bool = match u32::from_le_bytes([ code[0], code[1], code[2], code[3] ]) {
+        SPIRV_MAGIC                                => true,
+        magic if magic.swap_bytes() == SPIRV_MAGIC => false,
+        _                                          => { return None; }
+    };
+    Some(code.chunks_exact(4).map(|b| {
+        let b: [u8; 4] = [ b[0], b[1], b[2], b[3] ];
+        if little { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) }
+    }).collect())
+}
+
+/// Maps a SPIR-V execution model to the ShaderStage it represents.
+/// 
+/// # Arguments
+/// - `model`: The execution model operand of an OpEntryPoint instruction.
+/// 
+/// # Returns
+/// The matching ShaderStage, or `None` if it is an execution model that we do not support.
+fn parse_execution_model(model: u32) -> Option<ShaderStage> {
+    match model {
+        0    => Some(ShaderStage::VERTEX),
+        1    => Some(ShaderStage::TESSELLATION_CONTROL),
+        2    => Some(ShaderStage::TESSELLATION_EVALUATION),
+        3    => Some(ShaderStage::GEOMETRY),
+        4    => Some(ShaderStage::FRAGMENT),
+        5    => Some(ShaderStage::COMPUTE),
+        5364 => Some(ShaderStage::TASK),
+        5365 => Some(ShaderStage::MESH),
+        _    => None,
+    }
+}
+
+/// Infers the ShaderStage of a SPIR-V module from the execution model of its entry point(s).
+/// 
+/// # Arguments
+/// - `code`: The SPIR-V bytecode to parse.
+/// 
+/// # Returns
+/// The ShaderStage of the module's entry point(s), or `None` if the bytecode is not valid SPIR-V, has no entry points, has entry points for different stages or uses an execution model that we do not support.
+fn parse_stage(code: &[u8]) -> Option<ShaderStage> {
+    let words: Vec<u32> = parse_words(code)?;
+
+    // Iterate over the instructions after the header to find the entry point(s)
+    let mut stage: Option<ShaderStage> = None;
+    let mut i: usize = 5;
+    while i < words.len() {
+        let n_words: usize = (words[i] >> 16) as usize;
+        let opcode: u32    = words[i] & 0xFFFF;
+        if n_words == 0 || i + n_words > words.len() { return None; }
+
+        if opcode == SPIRV_OP_ENTRY_POINT {
+            // The first operand is the execution model
+            if n_words < 2 { return None; }
+            let model: ShaderStage = parse_execution_model(words[i + 1])?;
+
+            // Make sure all entry points agree
+            if stage.is_some() && stage != Some(model) { return None; }
+            stage = Some(model);
+        }
+
+        // Move to the next instruction
+        i += n_words;
+    }
+
+    // Done
+    stage
+}
+
+/// Collects the entry points of a SPIR-V module, together with the stage they are meant for.
+/// 
+/// # Arguments
+/// - `code`: The SPIR-V bytecode to parse.
+/// 
+/// # Returns
+/// A list of (ShaderStage, name) pairs, one per entry point in the module. Entry points with an execution model that we do not support are skipped. If the bytecode is not valid SPIR-V, returns an empty list instead.
+fn parse_entry_points(code: &[u8]) -> Vec<(ShaderStage, String)> {
+    let words: Vec<u32> = match parse_words(code) {
+        Some(words) => words,
+        None        => { return vec![]; }
+    };
+
+    // Iterate over the instructions after the header to find the entry point(s)
+    let mut entry_points: Vec<(ShaderStage, String)> = vec![];
+    let mut i: usize = 5;
+    while i < words.len() {
+        let n_words: usize = (words[i] >> 16) as usize;
+        let opcode: u32    = words[i] & 0xFFFF;
+        if n_words == 0 || i + n_words > words.len() { return vec![]; }
+        let ops: &[u32] = &words[i + 1..i + n_words];
+
+        // The operands are the execution model, the ID of the function and then its (nul-terminated) name
+        if opcode == SPIRV_OP_ENTRY_POINT && ops.len() >= 3 {
+            if let Some(stage) = parse_execution_model(ops[0]) {
+                let bytes: Vec<u8> = ops[2..].iter().flat_map(|w| w.to_le_bytes()).take_while(|b| *b != 0).collect();
+                match String::from_utf8(bytes) {
+                    Ok(name) => { entry_points.push((stage, name)); },
+                    Err(_)   => { return vec![]; }
+                }
+            }
+        }
+
+        // Move to the next instruction
+        i += n_words;
+    }
+
+    // Done
+    entry_points
+}
+
+/// Derives the push constant ranges of a SPIR-V module from its push constant block(s).
+/// 
+/// # Arguments
+/// - `code`: The SPIR-V bytecode to parse.
+/// 
+/// # Returns
+/// A PushConstantRange per push constant block in the module, accessible by the stages of all of the module's entry points. If the bytecode is not valid SPIR-V or a block's layout cannot be determined, returns an empty list instead.
+fn parse_push_constants(code: &[u8]) -> Vec<PushConstantRange> {
+    let words: Vec<u32> = match parse_words(code) {
+        Some(words) => words,
+        None        => { return vec![]; }
+    };
+
+    // Collect the entry point stages, the layout information and the push constant variables
+    let mut stages: ShaderStageFlags           = ShaderStageFlags::empty();
+    let mut layout: SpirvLayout                = SpirvLayout::default();
+    let mut pointers: HashMap<u32, (u32, u32)> = HashMap::new();
+    let mut variables: Vec<u32>                = vec![];
+    let mut i: usize = 5;
+    while i < words.len() {
+        let n_words: usize = (words[i] >> 16) as usize;
+        let opcode: u32    = words[i] & 0xFFFF;
+        if n_words == 0 || i + n_words > words.len() { return vec![]; }
+        let ops: &[u32] = &words[i + 1..i + n_words];
+
+        match opcode {
+            SPIRV_OP_ENTRY_POINT if !ops.is_empty() => {
+                if let Some(stage) = parse_execution_model(ops[0]) { stages |= stage.into(); }
+            },
+
+            SPIRV_OP_TYPE_INT | SPIRV_OP_TYPE_FLOAT if ops.len() >= 2 => { layout.types.insert(ops[0], SpirvType::Scalar(ops[1] / 8)); },
+            SPIRV_OP_TYPE_VECTOR if ops.len() >= 3                    => { layout.types.insert(ops[0], SpirvType::Vector(ops[1], ops[2])); },
+            SPIRV_OP_TYPE_MATRIX if ops.len() >= 3                    => { layout.types.insert(ops[0], SpirvType::Matrix(ops[1], ops[2])); },
+            SPIRV_OP_TYPE_ARRAY if ops.len() >= 3                     => { layout.types.insert(ops[0], SpirvType::Array(ops[1], ops[2])); },
+            SPIRV_OP_TYPE_STRUCT if !ops.is_empty()                   => { layout.types.insert(ops[0], SpirvType::Struct(ops[1..].to_vec())); },
+            SPIRV_OP_TYPE_POINTER if ops.len() >= 3                   => { pointers.insert(ops[0], (ops[1], ops[2])); },
+
+            SPIRV_OP_CONSTANT | SPIRV_OP_SPEC_CONSTANT if ops.len() >= 3 => { layout.constants.insert(ops[1], ops[2]); },
+
+            SPIRV_OP_VARIABLE if ops.len() >= 3 && ops[2] == SPIRV_STORAGE_CLASS_PUSH_CONSTANT => { variables.push(ops[0]); },
+
+            SPIRV_OP_DECORATE if ops.len() >= 3 && ops[1] == SPIRV_DECORATION_ARRAY_STRIDE => { layout.array_strides.insert(ops[0], ops[2]); },
+            SPIRV_OP_MEMBER_DECORATE if ops.len() >= 4 => match ops[2] {
+                SPIRV_DECORATION_OFFSET        => { layout.offsets.insert((ops[0], ops[1]), ops[3]); },
+                SPIRV_DECORATION_MATRIX_STRIDE => { layout.matrix_strides.insert((ops[0], ops[1]), ops[3]); },
+                _                              => {},
+            },
+
+            _ => {},
+        }
+
+        // Move to the next instruction
+        i += n_words;
+    }
+
+    // Compute a range for every push constant block
+    let mut ranges: Vec<PushConstantRange> = Vec::with_capacity(variables.len());
+    for pointer in variables {
+        // Resolve the pointer to the block
+        let block: u32 = match pointers.get(&pointer) {
+            Some((class, block)) if *class == SPIRV_STORAGE_CLASS_PUSH_CONSTANT => *block,
+            _                                                                   => { return vec![]; }
+        };
+        let n_members: usize = match layout.types.get(&block) {
+            Some(SpirvType::Struct(members)) if !members.is_empty() => members.len(),
+            _                                                       => { return vec![]; }
+        };
+
+        // The range starts at the first member and ends where the block ends (rounded up to a multiple of four, as Vulkan requires)
+        let start: u32 = match (0..n_members as u32).map(|m| layout.offsets.get(&(block, m)).cloned()).collect::<Option<Vec<u32>>>() {
+            Some(offsets) => offsets.into_iter().min().unwrap_or(0),
+            None          => { return vec![]; }
+        };
+        let end: u32 = match layout.size_of(block, None, 0) {
+            Some(end) => end,
+            None      => { return vec![]; }
+        };
+        let size: u32 = match (end - start).checked_add(3) {
+            Some(size) => size & !3,
+            None       => { return vec![]; }
+        };
+        ranges.push(PushConstantRange {
+            stage  : stages,
+            offset : start,
+            size,
+        });
+    }
+
+    // Done
+    ranges
+}
+
+/// Collects the specialization constants of a SPIR-V module, together with their size.
+/// 
+/// # Arguments
+/// - `code`: The SPIR-V bytecode to parse.
+/// 
+/// # Returns
+/// A list of (constant ID, size in bytes) pairs, one per scalar specialization constant in the module and ordered by ID. Boolean constants have the size of a VkBool32. If the bytecode is not valid SPIR-V, returns an empty list instead.
+fn parse_spec_constants(code: &[u8]) -> Vec<(u32, u32)> {
+    let words: Vec<u32> = match parse_words(code) {
+        Some(words) => words,
+        None        => { return vec![]; }
+    };
+
+    // Collect the scalar types, the SpecId decorations and the (result ID, size) of the specialization constants
+    let mut scalars: HashMap<u32, u32>  = HashMap::new();
+    let mut spec_ids: HashMap<u32, u32> = HashMap::new();
+    let mut constants: Vec<(u32, u32)>  = vec![];
+    let mut i: usize = 5;
+    while i < words.len() {
+        let n_words: usize = (words[i] >> 16) as usize;
+        let opcode: u32    = words[i] & 0xFFFF;
+        if n_words == 0 || i + n_words > words.len() { return vec![]; }
+        let ops: &[u32] = &words[i + 1..i + n_words];
+
+        match opcode {
+            SPIRV_OP_TYPE_INT | SPIRV_OP_TYPE_FLOAT if ops.len() >= 2 => { scalars.insert(ops[0], ops[1] / 8); },
+
+            SPIRV_OP_SPEC_CONSTANT_TRUE | SPIRV_OP_SPEC_CONSTANT_FALSE if ops.len() >= 2 => { constants.push((ops[1], std::mem::size_of::<vk::Bool32>() as u32)); },
+            SPIRV_OP_SPEC_CONSTANT if ops.len() >= 3 => {
+                if let Some(size) = scalars.get(&ops[0]) { constants.push((ops[1], *size)); }
+            },
+
+            SPIRV_OP_DECORATE if ops.len() >= 3 && ops[1] == SPIRV_DECORATION_SPEC_ID => { spec_ids.insert(ops[0], ops[2]); },
+
+            _ => {},
+        }
+
+        // Move to the next instruction
+        i += n_words;
+    }
+
+    // Only the constants with a SpecId can be specialized
+    let mut result: Vec<(u32, u32)> = constants.into_iter().filter_map(|(id, size)| spec_ids.get(&id).map(|spec_id| (*spec_id, size))).collect();
+    result.sort_unstable();
+    result
+}
+
+
+
 
 
 /***** LIBRARY *****/
 /// The Shader struct, which represents a single piece of Shader code in the render system.
 pub struct Shader {
     /// The parent Device where the Shader is compiled for/allocated
-    device : Rc<Device>,
+    device : Shared<Device>,
 
     /// The Shader module around which we wrap.
     module : vk::ShaderModule,
+    /// The ShaderStage inferred from the SPIR-V entry point(s), if any.
+    stage          : Option<ShaderStage>,
+    /// The entry points reflected from the SPIR-V, together with their stages.
+    entry_points   : Vec<(ShaderStage, String)>,
+    /// The push constant ranges reflected from the SPIR-V push constant block(s).
+    push_constants : Vec<PushConstantRange>,
+    /// The (constant ID, size) pairs of the specialization constants reflected from the SPIR-V.
+    spec_constants : Vec<(u32, u32)>,
 }
 
 impl Shader {
@@ -46,13 +594,14 @@ impl Shader {
     /// # Arguments
     /// - `device`: The Device on which the Shader will live.
     /// - `code`: The Bytecode of the Shader data to compile.
+    /// - `name`: If given, a debug name for the Shader (see `Device::set_object_name()`).
     /// 
     /// # Returns
     /// A new Shader instance on success.
     /// 
     /// # Errors
     /// This function errors if the bytecode is invalid or if the shader module could not be allocated.
-    pub fn from_bytes<B: AsRef<[u8]>>(device: Rc<Device>, code: B) -> Result<Rc<Shader>, Error> {
+    pub fn from_bytes<B: AsRef<[u8]>>(device: Shared<Device>, code: B, name: Option<&str>) -> Result<Rc<Shader>, Error> {
         // Convert the slice-like into a slice
         let code: &[u8] = code.as_ref();
 
@@ -75,12 +624,17 @@ impl Shader {
                 Err(err)   => { return Err(Error::ShaderCreateError{ err }); }
             }
         };
+        device.try_set_object_name(module, name);
 
         // Create a new instance and return that
         Ok(Rc::new(Self {
             device,
             
             module,
+            stage          : parse_stage(code),
+            entry_points   : parse_entry_points(code),
+            push_constants : parse_push_constants(code),
+            spec_constants : parse_spec_constants(code),
         }))
     }
 
@@ -92,13 +646,14 @@ impl Shader {
     /// # Arguments
     /// - `device`: The Device on which the Shader will live.
     /// - `path`: The path to the SPIR-V shader file.
+    /// - `name`: If given, a debug name for the Shader (see `Device::set_object_name()`).
     /// 
     /// # Returns
     /// A new Shader instance on success.
     /// 
     /// # Errors
     /// This function errors if the file could not be read, the bytecode is invalid or if the shader module could not be allocated.
-    pub fn from_path<P: AsRef<Path>>(device: Rc<Device>, path: P) -> Result<Rc<Shader>, Error> {
+    pub fn from_path<P: AsRef<Path>>(device: Shared<Device>, path: P, name: Option<&str>) -> Result<Rc<Shader>, Error> {
         // Convert the Path-like into a Path
         let path: &Path = path.as_ref();
 
@@ -122,7 +677,7 @@ impl Shader {
         }
 
         // With the bytes collected, use from_bytes() to do the actual shader builder
-        Self::from_bytes(device, bytes)
+        Self::from_bytes(device, bytes, name)
     }
 
     /// Constructor for the Shader, which builds it from embedded SPIR-V code.
@@ -130,18 +685,19 @@ impl Shader {
     /// # Arguments
     /// - `device`: The Device on which the Shader will live.
     /// - `data`: The EmbeddedFile struct that contains the data.
+    /// - `name`: If given, a debug name for the Shader (see `Device::set_object_name()`).
     /// 
     /// # Returns
     /// A new Shader instance on success.
     /// 
     /// # Errors
     /// This function errors if the bytecode is invalid or if the shader module could not be created in the Vulkan backend.
-    pub fn from_embedded(device: Rc<Device>, data: EmbeddedFile) -> Result<Rc<Shader>, Error> {
+    pub fn from_embedded(device: Shared<Device>, data: EmbeddedFile, name: Option<&str>) -> Result<Rc<Shader>, Error> {
         // Get the data
         let data: &[u8] = data.data.as_ref();
 
         // Pass it to the other function
-        Self::from_bytes(device, data)
+        Self::from_bytes(device, data, name)
     }
 
     /// Constructor for the Shader, which builds it from embedded SPIR-V code.
@@ -151,13 +707,14 @@ impl Shader {
     /// # Arguments
     /// - `device`: The Device on which the Shader will live.
     /// - `result`: The Option<EmbeddedFile> struct that contains the data.
+    /// - `name`: If given, a debug name for the Shader (see `Device::set_object_name()`).
     /// 
     /// # Returns
     /// A new Shader instance on success.
     /// 
     /// # Errors
     /// This function errors if the given result is a failure, the bytecode is invalid or if the shader module could not be created in the Vulkan backend.
-    pub fn try_embedded(device: Rc<Device>, result: Option<EmbeddedFile>) -> Result<Rc<Shader>, Error> {
+    pub fn try_embedded(device: Shared<Device>, result: Option<EmbeddedFile>, name: Option<&str>) -> Result<Rc<Shader>, Error> {
         // Unpack the data
         let data = match result {
             Some(data) => data,
@@ -165,15 +722,49 @@ impl Shader {
         };
 
         // Pass it to the other function
-        Self::from_embedded(device, data)
+        Self::from_embedded(device, data, name)
     }
 
 
 
     /// Returns the device where the Shader lives.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
     
+    /// Returns the ShaderStage of this Shader, as inferred from the execution model of its SPIR-V entry point(s).
+    /// 
+    /// # Returns
+    /// The ShaderStage, or `None` if the stage could not be inferred (e.g., the module contains entry points for multiple stages). In that case, the stage should be given explicitly.
+    #[inline]
+    pub fn stage(&self) -> Option<ShaderStage> { self.stage }
+
+    /// Returns the entry points in this Shader, as reflected from its SPIR-V.
+    /// 
+    /// A single module may contain entry points for multiple stages (e.g., a vertex and a fragment entry point), in which case it may be added to a pipeline once per entry point (see `PipelineBuilder::shader_entry()`).
+    /// 
+    /// # Returns
+    /// A list of (ShaderStage, name) pairs, which is empty if the SPIR-V could not be parsed.
+    #[inline]
+    pub fn entry_points(&self) -> &[(ShaderStage, String)] { &self.entry_points }
+
+    /// Returns the push constant ranges used by this Shader, as reflected from the push constant block(s) in its SPIR-V.
+    /// 
+    /// Each range covers its block from the first member up to the end of the block, and is accessible by the stages of all of the module's entry points. The ranges of all Shaders in a pipeline may simply be concatenated and passed to `PipelineLayout::new()`.
+    /// 
+    /// # Returns
+    /// A list of PushConstantRanges, which is empty if the Shader has no push constants or if their layout could not be determined.
+    #[inline]
+    pub fn reflect_push_constants(&self) -> Vec<PushConstantRange> { self.push_constants.clone() }
+
+    /// Returns the specialization constants declared by this Shader, as reflected from its SPIR-V.
+    /// 
+    /// Only scalar constants with a `constant_id` (i.e., a SpecId decoration) are listed, since those are the ones that can be set with `SpecializationConstants`.
+    /// 
+    /// # Returns
+    /// A list of (constant ID, size in bytes) pairs ordered by ID, which is empty if the Shader has no specialization constants or if the SPIR-V could not be parsed. Boolean constants have the size of a VkBool32.
+    #[inline]
+    pub fn reflect_spec_constants(&self) -> &[(u32, u32)] { &self.spec_constants }
+
     /// Returns the Vulkan VkShaderModule around which this struct wraps.
     #[inline]
     pub fn vk(&self) -> vk::ShaderModule { self.module }
@@ -185,3 +776,129 @@ impl Drop for Shader {
         unsafe { self.device.destroy_shader_module(self.module, None); }
     }
 }
+
+
+
+/// Builds the values of a Shader's specialization constants, checking them against the constants that the Shader declares.
+/// 
+/// Use `SpecializationConstants::constant()` to set each value and then `SpecializationConstants::build()` to get the Specialization, which may be given to `PipelineBuilder::specialization()`. Any errors that occur mid-build will be propagated until that function.
+pub struct SpecializationConstants {
+    /// Collects errors until build() gets called.
+    error : Option<Error>,
+
+    /// The (constant ID, size) pairs declared by the Shader.
+    declared : Vec<(u32, u32)>,
+    /// The map entries that describe where each constant lives in the data.
+    entries  : Vec<vk::SpecializationMapEntry>,
+    /// The values of all constants, back-to-back.
+    data     : Vec<u8>,
+}
+
+impl SpecializationConstants {
+    /// Constructor for the SpecializationConstants.
+    /// 
+    /// # Arguments
+    /// - `shader`: The Shader whose specialization constants to set (see `Shader::reflect_spec_constants()`).
+    #[inline]
+    pub fn new(shader: &Shader) -> Self {
+        Self {
+            error : None,
+
+            declared : shader.reflect_spec_constants().to_vec(),
+            entries  : vec![],
+            data     : vec![],
+        }
+    }
+
+
+
+    /// Sets the value of the specialization constant with the given ID.
+    /// 
+    /// # Generic types
+    /// - `B`: The byte-slice-like type of the value.
+    /// 
+    /// # Arguments
+    /// - `id`: The `constant_id` of the constant to set.
+    /// - `value`: The value of the constant as raw (native-endian) bytes, e.g., `1.0f32.to_ne_bytes()`. Booleans should be given as a VkBool32.
+    /// 
+    /// # Returns
+    /// Because this function is consuming, returns the same instance of self as passed to it.
+    /// 
+    /// # Errors
+    /// This function doesn't error directly, but may pass any incoming errors to the `SpecializationConstants::build()` call. In particular, it passes an error if the constant was already set or if the size of the value does not match the size of the constant in the Shader. Constants that the Shader does not declare only produce a warning, since Vulkan ignores them.
+    pub fn constant<B: AsRef<[u8]>>(mut self, id: u32, value: B) -> Self {
+        if self.error.is_some() { return self; }
+        let value: &[u8] = value.as_ref();
+
+        // Check the constant against the Shader
+        if self.entries.iter().any(|entry| entry.constant_id == id) {
+            self.error = Some(Error::DuplicateSpecializationConstant{ id });
+            return self;
+        }
+        match self.declared.iter().find(|(declared, _)| *declared == id) {
+            Some((_, size)) => if *size as usize != value.len() {
+                self.error = Some(Error::SpecializationSizeMismatch{ id, expected: *size as usize, got: value.len() });
+                return self;
+            },
+            None => { warn!("Specialization constant {} is not declared by the Shader and will be ignored", id); },
+        }
+
+        // Append it to the data
+        self.entries.push(vk::SpecializationMapEntry {
+            constant_id : id,
+            offset      : self.data.len() as u32,
+            size        : value.len(),
+        });
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    /// Builds the Specialization from the values set so far.
+    /// 
+    /// # Returns
+    /// A new Specialization that may be given to `PipelineBuilder::specialization()`.
+    /// 
+    /// # Errors
+    /// This function errors if any of the values given to `SpecializationConstants::constant()` were invalid.
+    pub fn build(self) -> Result<Specialization, Error> {
+        if let Some(err) = self.error { return Err(err); }
+        Ok(Specialization {
+            entries : self.entries,
+            data    : self.data,
+        })
+    }
+}
+
+
+
+/// The (validated) values of a Shader's specialization constants, as built by `SpecializationConstants`.
+#[derive(Clone, Debug)]
+pub struct Specialization {
+    /// The map entries that describe where each constant lives in the data.
+    entries : Vec<vk::SpecializationMapEntry>,
+    /// The values of all constants, back-to-back.
+    data    : Vec<u8>,
+}
+
+impl Specialization {
+    /// Returns the map entries that describe where each constant lives in the data.
+    #[inline]
+    pub fn entries(&self) -> &[vk::SpecializationMapEntry] { &self.entries }
+
+    /// Returns the values of all constants, back-to-back.
+    #[inline]
+    pub fn data(&self) -> &[u8] { &self.data }
+
+    /// Returns a VkSpecializationInfo that describes this Specialization.
+    /// 
+    /// Note that it refers to the memory of this Specialization, and is thus only valid for as long as it lives.
+    #[inline]
+    pub fn vk(&self) -> vk::SpecializationInfo {
+        vk::SpecializationInfo {
+            map_entry_count : self.entries.len() as u32,
+            p_map_entries   : if !self.entries.is_empty() { self.entries.as_ptr() } else { ptr::null() },
+            data_size       : self.data.len(),
+            p_data          : if !self.data.is_empty() { self.data.as_ptr() as *const c_void } else { ptr::null() },
+        }
+    }
+}
@@ -13,10 +13,31 @@
 // 
 
 use ash::vk;
+#[cfg(feature = "semver")]
 use semver::Version;
 
 
 /***** LIBRARY *****/
+/// The reference-counted pointer through which the long-lived handles (the Instance, the Device and the PipelineLayouts it caches) are shared.
+/// 
+/// This is an `Arc` if the `arc` feature is enabled, such that a Device can be shared between threads (e.g., to record commands on each of them with a thread-local CommandPool). Otherwise, it is a (cheaper) `Rc`.
+#[cfg(feature = "arc")]
+pub type Shared<T> = std::sync::Arc<T>;
+/// The reference-counted pointer through which the long-lived handles (the Instance, the Device and the PipelineLayouts it caches) are shared.
+/// 
+/// This is an `Arc` if the `arc` feature is enabled, such that a Device can be shared between threads (e.g., to record commands on each of them with a thread-local CommandPool). Otherwise, it is a (cheaper) `Rc`.
+#[cfg(not(feature = "arc"))]
+pub type Shared<T> = std::rc::Rc<T>;
+
+/// The weak counterpart of `Shared`.
+#[cfg(feature = "arc")]
+pub type SharedWeak<T> = std::sync::Weak<T>;
+/// The weak counterpart of `Shared`.
+#[cfg(not(feature = "arc"))]
+pub type SharedWeak<T> = std::rc::Weak<T>;
+
+
+
 /// Defines Vulkan-compatible API Version numbers.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ApiVersion {
@@ -76,6 +97,21 @@ impl ApiVersion {
             patch,
         }
     }
+
+    /// Constructor for the ApiVersion that takes the major, minor and patch numbers of a semantic version.
+    /// 
+    /// Any pre-release or build metadata is ignored, since Vulkan version numbers cannot represent it.
+    /// 
+    /// # Arguments
+    /// - `version`: The semver::Version to convert. Note that only the 7, 10 and 12 least significant bits of the major, minor and patch numbers will be used, respectively.
+    /// 
+    /// # Returns
+    /// A new ApiVersion instance.
+    #[cfg(feature = "semver")]
+    #[inline]
+    pub fn from_semver(version: &Version) -> Self {
+        Self::new(version.major as u8, version.minor as u16, version.patch as u16)
+    }
 }
 
 impl From<u32> for ApiVersion {
@@ -103,13 +139,15 @@ impl From<ApiVersion> for u32 {
     }
 }
 
+#[cfg(feature = "semver")]
 impl From<Version> for ApiVersion {
     #[inline]
     fn from(value: Version) -> Self {
-        Self::new(value.major as u8, value.minor as u16, value.patch as u16)
+        Self::from_semver(&value)
     }
 }
 
+#[cfg(feature = "semver")]
 impl From<ApiVersion> for Version {
     #[inline]
     fn from(value: ApiVersion) -> Self {
@@ -13,10 +13,9 @@
 // 
 
 use std::ops::Deref;
+#[cfg(feature = "winit")]
 use std::rc::Rc;
 
-#[allow(unused_imports)]
-use ash::{Entry as VkEntry, Instance as VkInstance};
 use ash::extensions::khr;
 use ash::vk::SurfaceKHR;
 
@@ -25,27 +24,48 @@ use winit::window::Window as WWindow;
 
 pub use crate::errors::SurfaceError as Error;
 use crate::log_destroy;
+#[allow(unused_imports)]
+use crate::auxillary::enums::InstanceExtension;
 use crate::instance::Instance;
+use crate::spec::Shared;
 
 
 /***** HELPER FUNCTIONS *****/
+/// Checks whether the given extension is enabled on the given Instance.
+/// 
+/// For Instances of which we do not know the enabled extensions (i.e., those created with `Instance::from_raw()`), we assume it is.
+/// 
+/// # Arguments
+/// - `instance`: The Instance to check.
+/// - `extension`: The InstanceExtension that should be enabled.
+/// 
+/// # Errors
+/// This function errors if the Instance is known to not have the given extension enabled.
+#[cfg(feature = "winit")]
+#[inline]
+fn check_extension(instance: &Instance, extension: InstanceExtension) -> Result<(), Error> {
+    match instance.has_extension(extension) {
+        Some(false) => Err(Error::ExtensionNotEnabled{ extension }),
+        _           => Ok(()),
+    }
+}
+
 /// Returns a new surface from the given window.
 /// 
 /// There are three overloads for this function, each for the target platform. This overload is for Windows.
 /// 
 /// # Arguments
-/// - `entry`: The ash entry struct that is used to load new pointers from.
-/// - `instance`: The Vulkan instance that is used to create the new Surface in.
+/// - `instance`: The Instance that is used to create the new Surface in (and to load new pointers from).
 /// - `wwindow`: The winit Window to create the Surface from.
 ///
 /// # Returns
 /// A new SurfaceKHR struct.
 /// 
 /// # Errors
-/// This function errors whenever the underlying APIs error.
+/// This function errors whenever the underlying APIs error, or if the Instance does not have the appropriate surface extension enabled.
 #[cfg(feature = "winit")]
 #[cfg(all(windows))]
-unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
+unsafe fn create_surface(instance: &Instance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
     use std::os::raw::c_void;
     use std::ptr;
 
@@ -76,7 +96,8 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 
     // Build the loader for the surface
     debug!("Creating Windows surface...");
-    let loader = khr::Win32Surface::new(entry, instance);
+    check_extension(instance, InstanceExtension::Win32Surface)?;
+    let loader = khr::Win32Surface::new(instance.ash(), instance.vk());
     // Create the new surface
     match loader.create_win32_surface(&surface_info, None) {
         Ok(surface) => Ok(surface),
@@ -89,18 +110,17 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 /// There are three overloads for this function, each for the target platform. This overload is for macOS.
 /// 
 /// # Arguments
-/// - `entry`: The ash entry struct that is used to load new pointers from.
-/// - `instance`: The Vulkan instance that is used to create the new Surface in.
+/// - `instance`: The Instance that is used to create the new Surface in (and to load new pointers from).
 /// - `wwindow`: The winit Window to create the Surface from.
 ///
 /// # Returns
 /// A new SurfaceKHR struct.
 /// 
 /// # Errors
-/// This function errors whenever the underlying APIs error.
+/// This function errors whenever the underlying APIs error, or if the Instance does not have the appropriate surface extension enabled.
 #[cfg(feature = "winit")]
 #[cfg(target_os = "macos")]
-unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
+unsafe fn create_surface(instance: &Instance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
     use std::mem;
     use std::os::raw::c_void;
     use std::ptr;
@@ -144,7 +164,8 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 
     // Create the surface!
     debug!("Creating macOS Cocoa surface...");
-    let loader = MacOSSurface::new(entry, instance);
+    check_extension(instance, InstanceExtension::MacOSSurface)?;
+    let loader = MacOSSurface::new(instance.ash(), instance.vk());
     // Create the new surface
     match loader.create_mac_os_surface(&surface_info, None) {
         Ok(surface) => Ok(surface),
@@ -154,21 +175,20 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 
 /// Returns a new surface from the given window.
 /// 
-/// There are three overloads for this function, each for the target platform. This overload is for linux (X11).
+/// There are three overloads for this function, each for the target platform. This overload is for linux (X11 through either Xlib or XCB, or Wayland).
 /// 
 /// # Arguments
-/// - `entry`: The ash entry struct that is used to load new pointers from.
-/// - `instance`: The Vulkan instance that is used to create the new Surface in.
+/// - `instance`: The Instance that is used to create the new Surface in (and to load new pointers from).
 /// - `wwindow`: The winit Window to create the Surface from.
 ///
 /// # Returns
 /// A new SurfaceKHR struct.
 /// 
 /// # Errors
-/// This function errors whenever the underlying APIs error.
+/// This function errors whenever the underlying APIs error, or if the Instance does not have the appropriate surface extension enabled.
 #[cfg(feature = "winit")]
 #[cfg(all(unix, not(target_os = "android"), not(target_os = "macos")))]
-unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
+unsafe fn create_surface(instance: &Instance, wwindow: &WWindow) -> Result<SurfaceKHR, Error> {
     use std::ptr;
 
     use ash::vk;
@@ -185,29 +205,62 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
         let x11_display = wwindow.xlib_display().expect("We are confirmed on X11, but could not get X11 display; this should never happen!");
         let x11_window  = wwindow.xlib_window().expect("We are confirmed on X11, but could not get X11 window; this should never happen!");
 
-        // Use those to create the create info
-        let surface_info = vk::XlibSurfaceCreateInfoKHR {
-            // Set the standard fields
-            s_type : vk::StructureType::XLIB_SURFACE_CREATE_INFO_KHR,
-            p_next : ptr::null(),
-            flags  : Default::default(),
-
-            // Pass the window & display
-            window : x11_window as vk::Window,
-            dpy    : x11_display as *mut vk::Display,
-        };
-
-        // Create the Surface with that
-        debug!("Creating X11 surface...");
-        let loader = khr::XlibSurface::new(entry, instance);
-        match loader.create_xlib_surface(&surface_info, None) {
-            Ok(surface) => Ok(surface),
-            Err(err)    => { return Err(Error::X11SurfaceKHRCreateError{ err }); }
+        // Prefer Xlib, but fall back to XCB if only that extension is enabled
+        if check_extension(instance, InstanceExtension::XlibSurface).is_ok() {
+            // Use those to create the create info
+            let surface_info = vk::XlibSurfaceCreateInfoKHR {
+                // Set the standard fields
+                s_type : vk::StructureType::XLIB_SURFACE_CREATE_INFO_KHR,
+                p_next : ptr::null(),
+                flags  : Default::default(),
+
+                // Pass the window & display
+                window : x11_window as vk::Window,
+                dpy    : x11_display as *mut vk::Display,
+            };
+
+            // Create the Surface with that
+            debug!("Creating X11 surface (Xlib)...");
+            let loader = khr::XlibSurface::new(instance.ash(), instance.vk());
+            match loader.create_xlib_surface(&surface_info, None) {
+                Ok(surface) => Ok(surface),
+                Err(err)    => Err(Error::X11SurfaceKHRCreateError{ err }),
+            }
+
+        } else if instance.has_extension(InstanceExtension::XcbSurface) == Some(true) {
+            // Get the XCB connection for the display
+            let xcb_connection = wwindow.xcb_connection().expect("We are confirmed on X11, but could not get XCB connection; this should never happen!");
+
+            // Use that and the window to create the create info
+            let surface_info = vk::XcbSurfaceCreateInfoKHR {
+                // Set the standard fields
+                s_type : vk::StructureType::XCB_SURFACE_CREATE_INFO_KHR,
+                p_next : ptr::null(),
+                flags  : Default::default(),
+
+                // Pass the connection & window
+                connection : xcb_connection as *mut vk::xcb_connection_t,
+                window     : x11_window as vk::xcb_window_t,
+            };
+
+            // Create the Surface with that
+            debug!("Creating X11 surface (XCB)...");
+            let loader = khr::XcbSurface::new(instance.ash(), instance.vk());
+            match loader.create_xcb_surface(&surface_info, None) {
+                Ok(surface) => Ok(surface),
+                Err(err)    => Err(Error::X11SurfaceKHRCreateError{ err }),
+            }
+
+        } else {
+            Err(Error::ExtensionNotEnabled{ extension: InstanceExtension::XlibSurface })
         }
 
     } else if wwindow.wayland_display().is_some() {
         // We're on Wayland
 
+        // Make sure the Instance can actually create Wayland surfaces
+        check_extension(instance, InstanceExtension::WaylandSurface)?;
+
         // Get the winit window as Wayland surface & display
         let wayland_display = wwindow.wayland_display().expect("We are confirmed on Wayland, but could not get Wayland display; this should never happen!");
         let wayland_surface = wwindow.wayland_surface().expect("We are confirmed on Wayland, but could not get Wayland surface; this should never happen!");
@@ -226,10 +279,10 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 
         // Create the Surface with that
         debug!("Creating Wayland surface...");
-        let loader = khr::WaylandSurface::new(entry, instance);
+        let loader = khr::WaylandSurface::new(instance.ash(), instance.vk());
         match loader.create_wayland_surface(&surface_info, None) {
             Ok(surface) => Ok(surface),
-            Err(err)    => { return Err(Error::WaylandSurfaceCreateError{ err }); }
+            Err(err)    => Err(Error::WaylandSurfaceCreateError{ err }),
         }
 
     } else {
@@ -246,7 +299,7 @@ unsafe fn create_surface(entry: &VkEntry, instance: &VkInstance, wwindow: &WWind
 /// Implements a Surface, which can be build from a given Window object.
 pub struct Surface {
     /// The Instance that this Surface is build on.
-    instance : Rc<Instance>,
+    instance : Shared<Instance>,
 
     /// The load for the surface which we wrap.
     loader  : khr::Surface,
@@ -259,6 +312,8 @@ impl Surface {
     /// 
     /// This function tries to build a surface from the given winit::Window object.
     /// 
+    /// The platform surface extension used is chosen based on the window; on Linux, X11 windows use `VK_KHR_xlib_surface` (or `VK_KHR_xcb_surface` if only that one is enabled) and Wayland windows use `VK_KHR_wayland_surface`.
+    /// 
     /// # Arguments
     /// - `instance`: The instance where the new Surface will be allocated.
     /// - `wwindow`: The winit Window to build the Surface around.
@@ -267,14 +322,15 @@ impl Surface {
     /// A new Surface object, already wrapped in an Rc.
     /// 
     /// # Errors
-    /// This function errors whenever the backend Vulkan errors.
+    /// This function errors whenever the backend Vulkan errors, or if the given Instance does not have the surface extension enabled that is required for the window.
     #[cfg(feature = "winit")]
-    pub fn new_winit(instance: Rc<Instance>, wwindow: &WWindow) -> Result<Rc<Self>, Error> {
+    pub fn new_winit(instance: Shared<Instance>, wwindow: &WWindow) -> Result<Rc<Self>, Error> {
         use crate::debug;
 
-        // Create the surface KHR
+        // Create the surface KHR, using the surface extension that matches the window's system
         debug!("Initializing surface...");
-        let surface = unsafe { create_surface(instance.ash(), instance.vk(), wwindow) }?;
+        check_extension(&instance, InstanceExtension::Surface)?;
+        let surface = unsafe { create_surface(&instance, wwindow) }?;
 
         // Create the accopmanying loader
         let loader = khr::Surface::new(instance.ash(), instance.vk());
@@ -292,7 +348,7 @@ impl Surface {
 
     /// Returns the instance of the Surface.
     #[inline]
-    pub fn instance(&self) -> &Rc<Instance> { &self.instance }
+    pub fn instance(&self) -> &Shared<Instance> { &self.instance }
 
     /// Returns the internal Surface (loader) object.
     #[inline]
@@ -22,13 +22,80 @@ use ash::extensions::khr;
 
 use crate::{debug, warn};
 pub use crate::errors::SwapchainError as Error;
+use crate::errors::DeviceError;
 use crate::{log_destroy, vec_as_ptr};
-use crate::auxillary::enums::ImageFormat;
-use crate::auxillary::structs::{Extent2D, SwapchainSupport};
+use crate::auxillary::enums::{ColourSpace, DeviceExtension, ImageFormat, PresentMode};
+use crate::auxillary::structs::{Extent2D, Rect2D, SurfaceFormat, SwapchainSupport};
 use crate::device::Device;
 use crate::surface::Surface;
 use crate::image::Image;
 use crate::sync::{Fence, Semaphore};
+use crate::spec::Shared;
+
+
+/***** UNIT TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a SwapchainSupport with the given formats and present modes.
+    fn support(formats: &[(vk::Format, vk::ColorSpaceKHR)], present_modes: &[vk::PresentModeKHR]) -> SwapchainSupport {
+        SwapchainSupport {
+            capabilities  : vk::SurfaceCapabilitiesKHR::default(),
+            formats       : formats.iter().map(|(format, color_space)| vk::SurfaceFormatKHR{ format: *format, color_space: *color_space }).collect(),
+            present_modes : present_modes.to_vec(),
+        }
+    }
+
+    /// Tests choosing a surface format with and without a preference
+    #[test]
+    fn test_choose_format() {
+        let formats = support(&[
+            (vk::Format::R8G8B8A8_UNORM, vk::ColorSpaceKHR::SRGB_NONLINEAR),
+            (vk::Format::B8G8R8A8_SRGB, vk::ColorSpaceKHR::SRGB_NONLINEAR),
+            (vk::Format::A2B10G10R10_UNORM_PACK32, vk::ColorSpaceKHR::HDR10_ST2084_EXT),
+        ], &[]);
+
+        // Without preference, the default format is chosen
+        assert_eq!(choose_format(&formats, None).unwrap(), (vk::Format::B8G8R8A8_SRGB, ColourSpace::SrgbNonlinear));
+        // A supported preference is honoured
+        let hdr = SurfaceFormat::new(ImageFormat::A2B10G10R10UNormPack32, ColourSpace::Hdr10St2084);
+        assert_eq!(choose_format(&formats, Some(hdr)).unwrap(), (vk::Format::A2B10G10R10_UNORM_PACK32, ColourSpace::Hdr10St2084));
+        // An unsupported preference falls back to the first format
+        let unsupported = SurfaceFormat::new(ImageFormat::A2B10G10R10UNormPack32, ColourSpace::SrgbNonlinear);
+        assert_eq!(choose_format(&formats, Some(unsupported)).unwrap(), (vk::Format::R8G8B8A8_UNORM, ColourSpace::SrgbNonlinear));
+
+        // Formats with an unknown colour space are skipped when falling back
+        let unknown = support(&[
+            (vk::Format::R8G8B8A8_UNORM, vk::ColorSpaceKHR::from_raw(0x7FFF_0000)),
+            (vk::Format::B8G8R8A8_UNORM, vk::ColorSpaceKHR::SRGB_NONLINEAR),
+        ], &[]);
+        assert_eq!(choose_format(&unknown, None).unwrap(), (vk::Format::B8G8R8A8_UNORM, ColourSpace::SrgbNonlinear));
+        assert_eq!(choose_format(&unknown, Some(hdr)).unwrap(), (vk::Format::B8G8R8A8_UNORM, ColourSpace::SrgbNonlinear));
+        assert!(matches!(choose_format(&support(&[ (vk::Format::R8G8B8A8_UNORM, vk::ColorSpaceKHR::from_raw(0x7FFF_0000)) ], &[]), None), Err(Error::NoFormatFound)));
+
+        // Without any formats, we cannot choose
+        assert!(matches!(choose_format(&support(&[], &[]), Some(hdr)), Err(Error::NoFormatFound)));
+    }
+
+    /// Tests choosing a present mode with FIFO fallback
+    #[test]
+    fn test_choose_present_mode() {
+        let modes = support(&[], &[ vk::PresentModeKHR::FIFO, vk::PresentModeKHR::IMMEDIATE ]);
+        assert_eq!(choose_present_mode(&modes, PresentMode::Immediate).unwrap(), vk::PresentModeKHR::IMMEDIATE);
+        assert_eq!(choose_present_mode(&modes, PresentMode::Fifo).unwrap(), vk::PresentModeKHR::FIFO);
+        assert_eq!(choose_present_mode(&modes, PresentMode::Mailbox).unwrap(), vk::PresentModeKHR::FIFO);
+    }
+
+    /// Tests that a lost surface is reported as such when querying the Device's support for it
+    #[test]
+    fn test_surface_support_error() {
+        assert!(matches!(surface_support_error(0, "gpu", DeviceError::SurfaceSupportError{ err: vk::Result::ERROR_SURFACE_LOST_KHR }), Error::SurfaceLost));
+        assert!(matches!(surface_support_error(0, "gpu", DeviceError::SurfaceCapabilitiesError{ err: vk::Result::ERROR_SURFACE_LOST_KHR }), Error::SurfaceLost));
+        assert!(matches!(surface_support_error(0, "gpu", DeviceError::SurfaceCapabilitiesError{ err: vk::Result::ERROR_OUT_OF_HOST_MEMORY }), Error::DeviceSurfaceSupportError{ index: 0, err: DeviceError::SurfaceCapabilitiesError{ .. }, .. }));
+        assert!(matches!(surface_support_error(1, "gpu", DeviceError::UnsupportedSurface), Error::DeviceSurfaceSupportError{ index: 1, err: DeviceError::UnsupportedSurface, .. }));
+    }
+}
 
 
 /***** POPULATE FUNCTIONS *****/
@@ -132,28 +199,58 @@ fn populate_present_info(swapchains: &[vk::SwapchainKHR], indices: &[u32], wait_
 
 /***** HELPER FUNCTIONS *****/
 /// Chooses an appropriate swapchain format from the available ones.
-fn choose_format(swapchain_support: &SwapchainSupport) -> Result<(vk::Format, vk::ColorSpaceKHR), Error> {
+/// 
+/// Returns the given one if it is supported, or else falls back to the first available format with a known ColourSpace. If no format is given, prefers B8G8R8A8 in sRGB.
+fn choose_format(swapchain_support: &SwapchainSupport, surface_format: Option<SurfaceFormat>) -> Result<(vk::Format, ColourSpace), Error> {
+    // Use the preferred format if there is any
+    if let Some(surface_format) = surface_format {
+        let vk_surface_format: vk::SurfaceFormatKHR = surface_format.into();
+        if swapchain_support.formats.iter().any(|avail_format| avail_format.format == vk_surface_format.format && avail_format.color_space == vk_surface_format.color_space) {
+            return Ok((vk_surface_format.format, surface_format.colour_space));
+        }
+
+        // Fall back to the first one
+        warn!("Surface format {} is not supported; falling back to first available format", surface_format);
+        return match first_known_format(swapchain_support) {
+            Some(format) => Ok(format),
+            None         => Err(Error::NoFormatFound),
+        };
+    }
+
     // Try to choose B8G8R8A8
     for avail_format in &swapchain_support.formats {
         if avail_format.format == vk::Format::B8G8R8A8_SRGB && avail_format.color_space == vk::ColorSpaceKHR::SRGB_NONLINEAR {
-            return Ok((avail_format.format, avail_format.color_space));
+            return Ok((avail_format.format, ColourSpace::SrgbNonlinear));
         }
     }
 
     // Otherwise, choose the first one or something idc
     warn!("Preferred Format not found; using first one");
-    match swapchain_support.formats.first() {
+    match first_known_format(swapchain_support) {
         Some(format) => {
             debug!("Using unpreferred format: {:?}", format);
-            Ok((format.format, format.color_space))
+            Ok(format)
         },
         None => Err(Error::NoFormatFound),
     }
 }
 
+/// Returns the first available swapchain format of which we know the colour space.
+/// 
+/// Formats with a colour space that is not (yet) a ColourSpace (e.g., from a newer extension) are skipped.
+fn first_known_format(swapchain_support: &SwapchainSupport) -> Option<(vk::Format, ColourSpace)> {
+    swapchain_support.formats.iter().find_map(|format| ColourSpace::try_from(format.color_space).ok().map(|colour_space| (format.format, colour_space)))
+}
+
 /// Chooses an appropriate swapchain prsent mode from the available ones.
-fn choose_present_mode(_swapchain_support: &SwapchainSupport) -> Result<vk::PresentModeKHR, Error> {
+/// 
+/// Returns the given one if it is supported, or else falls back to FIFO (which is always supported).
+fn choose_present_mode(swapchain_support: &SwapchainSupport, present_mode: PresentMode) -> Result<vk::PresentModeKHR, Error> {
+    let vk_present_mode: vk::PresentModeKHR = present_mode.into();
+    if swapchain_support.present_modes.contains(&vk_present_mode) { return Ok(vk_present_mode); }
+
     // The FIFO is always guaranteed to be present, so hit it
+    warn!("Present mode {} is not supported; falling back to {}", present_mode, PresentMode::Fifo);
     Ok(vk::PresentModeKHR::FIFO)
 }
 
@@ -181,11 +278,22 @@ fn choose_extent(swapchain_support: &SwapchainSupport, width: u32, height: u32)
 }
 
 /// Chooses an appropriate image count for the swapchain.
-fn choose_image_count(swapchain_support: &SwapchainSupport, image_count: u32) -> Result<u32, Error> {
+/// 
+/// If no count is given, defaults to one more than the minimum for mailbox (so there is always an image to render to without blocking), or the minimum otherwise.
+fn choose_image_count(swapchain_support: &SwapchainSupport, image_count: Option<u32>, present_mode: vk::PresentModeKHR) -> Result<u32, Error> {
+    // Shared present modes always use exactly one image
+    if present_mode == vk::PresentModeKHR::SHARED_DEMAND_REFRESH || present_mode == vk::PresentModeKHR::SHARED_CONTINUOUS_REFRESH { return Ok(1); }
+
     // Get the supported boundries by the swapchain
     let min = swapchain_support.capabilities.min_image_count;
     let max = swapchain_support.capabilities.max_image_count;
 
+    // Resolve the default count
+    let image_count = match image_count {
+        Some(image_count) => image_count,
+        None              => if present_mode == vk::PresentModeKHR::MAILBOX { min + 1 } else { min },
+    };
+
     // Clamp the image count in between that
     let image_count = if image_count < min { warn!("Increasing image_count to {}", min); min }
     else if max > 0 && image_count > max { warn!("Decreasing image_count to {}", max); max }
@@ -196,11 +304,30 @@ fn choose_image_count(swapchain_support: &SwapchainSupport, image_count: u32) ->
 }
 
 /// Chooses an appropriate sharing mode for the swapchain.
-fn choose_sharing_mode(_device: &Rc<Device>) -> Result<(vk::SharingMode, Vec<u32>), Error> {
+fn choose_sharing_mode(_device: &Shared<Device>) -> Result<(vk::SharingMode, Vec<u32>), Error> {
     // Because we present with the same queue as we render, we only need exclusive
     Ok((vk::SharingMode::EXCLUSIVE, vec![]))
 }
 
+/// Wraps an error from querying a Device's support for a Surface, reporting a lost Surface as such.
+/// 
+/// # Arguments
+/// - `index`: The index of the Device whose support we queried.
+/// - `name`: The name of the Device whose support we queried.
+/// - `err`: The DeviceError that occurred.
+/// 
+/// # Returns
+/// `SwapchainError::SurfaceLost` if any of the queries failed with `VK_ERROR_SURFACE_LOST_KHR`, or else a `SwapchainError::DeviceSurfaceSupportError` wrapping the given error.
+fn surface_support_error(index: usize, name: &str, err: DeviceError) -> Error {
+    match err {
+        DeviceError::SurfaceSupportError{ err: vk::Result::ERROR_SURFACE_LOST_KHR }      |
+        DeviceError::SurfaceCapabilitiesError{ err: vk::Result::ERROR_SURFACE_LOST_KHR } |
+        DeviceError::SurfaceFormatsError{ err: vk::Result::ERROR_SURFACE_LOST_KHR }      |
+        DeviceError::SurfacePresentModesError{ err: vk::Result::ERROR_SURFACE_LOST_KHR } => Error::SurfaceLost,
+        err => Error::DeviceSurfaceSupportError{ index, name: name.to_string(), err },
+    }
+}
+
 /// Chooses the appropriate stuff for the Swapchain, and returns a proper SwapchainCreateInfo.
 /// 
 /// # Arguments
@@ -208,25 +335,28 @@ fn choose_sharing_mode(_device: &Rc<Device>) -> Result<(vk::SharingMode, Vec<u32
 /// - `surface`: The VkSurfaceKHR where this Swapchain will present to.
 /// - `width`: The width (in pixels) of the new Swapchain images.
 /// - `height`: The height (in pixels) of the new Swapchain images.
-/// - `image_count`: The preferred number of images in the Swapchain. May be bound by hardware limits.
+/// - `present_mode`: The preferred PresentMode of the Swapchain. Falls back to FIFO if not supported.
+/// - `surface_format`: The preferred SurfaceFormat of the Swapchain. Falls back to the first available format if not supported, or a default one if omitted.
+/// - `image_count`: The preferred number of images in the Swapchain. May be bound by hardware limits. If omitted, a default based on the present mode is chosen.
 /// 
 /// # Errors
-/// This function errors if any of the `choose_*()` functions do.
-fn choose_swapchain_props(device: &Rc<Device>, surface: &Rc<Surface>, width: u32, height: u32, image_count: u32, old_swapchain: Option<vk::SwapchainKHR>) -> Result<(vk::SwapchainCreateInfoKHR, ImageFormat, Extent2D<u32>, Vec<u32>), Error> {
+/// This function errors if the Surface was lost (`SwapchainError::SurfaceLost`), if the Device does not support it or if any of the `choose_*()` functions do.
+#[allow(clippy::too_many_arguments)]
+fn choose_swapchain_props(device: &Shared<Device>, surface: &Rc<Surface>, width: u32, height: u32, present_mode: PresentMode, surface_format: Option<SurfaceFormat>, image_count: Option<u32>, old_swapchain: Option<vk::SwapchainKHR>) -> Result<(vk::SwapchainCreateInfoKHR, SurfaceFormat, Extent2D<u32>, Vec<u32>), Error> {
     // First, query the Gpu's support for this surface
     let swapchain_support = match device.get_swapchain_support(surface) {
         Ok(support) => support,
-        Err(err)    => { return Err(Error::DeviceSurfaceSupportError{ index: device.index(), name: device.name().to_string(), err }); }
+        Err(err)    => { return Err(surface_support_error(device.index(), device.name(), err)); }
     };
 
     // Next, choose an appropriate swapchain format
-    let (format, colour_space) = choose_format(&swapchain_support)?;
+    let (format, colour_space) = choose_format(&swapchain_support, surface_format)?;
     // Next, choose an appropriate swapchain present mode
-    let present_mode = choose_present_mode(&swapchain_support)?;
+    let present_mode = choose_present_mode(&swapchain_support, present_mode)?;
     // Then, choose the swapchain extent
     let extent = choose_extent(&swapchain_support, width, height)?;
     // Then, choose the image count
-    let image_count = choose_image_count(&swapchain_support, image_count)?;
+    let image_count = choose_image_count(&swapchain_support, image_count, present_mode)?;
     // Finally, choose the charing mode
     let (sharing_mode, queue_families) = choose_sharing_mode(&device)?;
 
@@ -234,7 +364,7 @@ fn choose_swapchain_props(device: &Rc<Device>, surface: &Rc<Surface>, width: u32
     Ok((
         populate_swapchain_info(
             surface.vk(),
-            format, colour_space,
+            format, colour_space.into(),
             present_mode,
             extent,
             image_count,
@@ -242,40 +372,108 @@ fn choose_swapchain_props(device: &Rc<Device>, surface: &Rc<Surface>, width: u32
             swapchain_support.capabilities.current_transform,
             old_swapchain.unwrap_or(vk::SwapchainKHR::null()),
         ),
-        format.into(), extent.into(),
+        SurfaceFormat::new(format.into(), colour_space), extent.into(),
         queue_families
     ))
 }
 
+/// Collects the images of the given swapchain and wraps them in our own Image struct.
+/// 
+/// # Arguments
+/// - `loader`: The swapchain loader with which the swapchain was created.
+/// - `swapchain`: The VkSwapchainKHR to get the images of.
+/// - `format`: The format of the swapchain images.
+/// - `extent`: The size of the swapchain images.
+/// - `usage`: The usage flags with which the swapchain images were created.
+/// 
+/// # Returns
+/// A new Image per swapchain image.
+/// 
+/// # Errors
+/// This function errors if we failed to get the images or to wrap any of them.
+fn wrap_swapchain_images(loader: &khr::Swapchain, swapchain: vk::SwapchainKHR, format: ImageFormat, extent: &Extent2D<u32>, usage: vk::ImageUsageFlags) -> Result<Vec<Rc<Image>>, Error> {
+    // Get the images of the chain
+    let vk_images: Vec<vk::Image> = unsafe {
+        match loader.get_swapchain_images(swapchain) {
+            Ok(images) => images,
+            Err(err)   => { return Err(Error::SwapchainImagesError{ err }); }
+        }
+    };
+
+    // Wrap them in our own struct
+    let mut images: Vec<Rc<Image>> = Vec::with_capacity(vk_images.len());
+    for image in vk_images {
+        match Image::from_vk(image, format, extent.clone(), usage.into()) {
+            Ok(image) => { images.push(image); },
+            Err(err)  => { return Err(Error::ImageError{ err }); }
+        }
+    }
+    Ok(images)
+}
+
+/// Makes sure the given list of per-image Semaphores has exactly one Semaphore per swapchain image.
+/// 
+/// Existing Semaphores are kept where possible, new ones are created as needed and any excess ones are dropped.
+/// 
+/// # Arguments
+/// - `device`: The Device on which to create any new Semaphores.
+/// - `semaphores`: The list of Semaphores to update.
+/// - `count`: The number of images in the swapchain.
+/// 
+/// # Errors
+/// This function errors if we failed to create a new Semaphore.
+fn resize_image_semaphores(device: &Shared<Device>, semaphores: &mut Vec<Rc<Semaphore>>, count: usize) -> Result<(), Error> {
+    semaphores.truncate(count);
+    while semaphores.len() < count {
+        match Semaphore::new(device.clone()) {
+            Ok(semaphore) => { semaphores.push(semaphore); },
+            Err(err)      => { return Err(Error::SemaphoreCreateError{ err }); }
+        }
+    }
+    Ok(())
+}
+
 
 
 
 
 /***** LIBRARY *****/
 /// The Swapchain struct is used to render to and provide the RenderTarget's images.
+/// 
+/// Note that the Swapchain keeps its Surface (and Device) alive, so the user may drop those in any order: the VkSwapchainKHR is always destroyed before the VkSurfaceKHR it presents to.
+/// 
+/// The Swapchain also owns one "render finished" Semaphore per image (see `Swapchain::render_finished()`). Since the presentation engine may still wait on such a Semaphore until the image is acquired again, it must be tied to the image instead of to the frame-in-flight; otherwise, it may be signalled again while still in use by a previous present.
 pub struct Swapchain {
     /// The device where the Swapchain lives.
-    device  : Rc<Device>,
-    /// The surface around which the Swapchain wraps.
+    device  : Shared<Device>,
+    /// The surface around which the Swapchain wraps. Kept alive by us until the VkSwapchainKHR has been destroyed.
     surface : Rc<Surface>,
 
     /// The loader for the swapchain
-    loader    : khr::Swapchain,
+    loader          : khr::Swapchain,
     /// The Swapchain itself
-    swapchain : vk::SwapchainKHR,
+    swapchain       : vk::SwapchainKHR,
     /// The images of the swapchain
-    images    : Vec<Rc<Image>>,
+    images          : Vec<Rc<Image>>,
+    /// The Semaphores that signal rendering to each image is done, one per image
+    render_finished : Vec<Rc<Semaphore>>,
     
     /// The chosen format of the swapchain
-    format : ImageFormat,
+    format       : ImageFormat,
+    /// The chosen colour space of the swapchain
+    colour_space : ColourSpace,
     /// The chosen extent of the swapchain
-    extent : Extent2D<u32>,
+    extent       : Extent2D<u32>,
+    /// The chosen present mode of the swapchain
+    present_mode : PresentMode,
+    /// The chosen (minimum) number of images in the swapchain
+    image_count  : u32,
 }
 
 impl Swapchain {
     /// Constructor for the Swapchain.
     /// 
-    /// Wraps a SwapchainKHR around the given GPU (Device) and surface (SurfaceKHR).
+    /// Wraps a SwapchainKHR around the given GPU (Device) and surface (SurfaceKHR), which presents in FIFO-mode.
     /// 
     /// # Arguments
     /// - `device`: The Device to create the swapchain on.
@@ -286,17 +484,62 @@ impl Swapchain {
     /// 
     /// # Returns
     /// A new Swapchain instance on success, or else an Error explaining what went wrong.
-    pub fn new(device: Rc<Device>, surface: Rc<Surface>, width: u32, height: u32, image_count: u32) -> Result<Rc<RefCell<Self>>, Error> {
+    #[inline]
+    pub fn new(device: Shared<Device>, surface: Rc<Surface>, width: u32, height: u32, image_count: u32) -> Result<Rc<RefCell<Self>>, Error> {
+        Self::new_with_present_mode(device, surface, width, height, PresentMode::Fifo, Some(image_count))
+    }
+
+    /// Constructor for the Swapchain that allows control over the present mode and the number of images.
+    /// 
+    /// Wraps a SwapchainKHR around the given GPU (Device) and surface (SurfaceKHR).
+    /// 
+    /// # Arguments
+    /// - `device`: The Device to create the swapchain on.
+    /// - `surface`: The Surface to create the swapchain around.
+    /// - `width`: The initial width of the swapchain surface. Might be bounded to min/max width supported by this device/surface.
+    /// - `height`: The initial height of the swapchain surface. Might be bounded to min/max height supported by this device/surface.
+    /// - `present_mode`: The PresentMode to use. Falls back to `PresentMode::Fifo` (with a warning) if the device/surface does not support it. The shared present modes require `DeviceExtension::SharedPresentableImage` to be enabled (see `Swapchain::shared_present_image()`).
+    /// - `image_count`: The (minimum) number of images to put in the swapchain. Clamped to the min/max amount supported by this device/surface. If `None`, defaults to one more than the minimum for `PresentMode::Mailbox` (i.e., triple buffering) and the minimum otherwise. Ignored for the shared present modes, which always use a single image.
+    /// 
+    /// # Returns
+    /// A new Swapchain instance on success, or else an Error explaining what went wrong.
+    #[inline]
+    pub fn new_with_present_mode(device: Shared<Device>, surface: Rc<Surface>, width: u32, height: u32, present_mode: PresentMode, image_count: Option<u32>) -> Result<Rc<RefCell<Self>>, Error> {
+        Self::new_with_preferences(device, surface, width, height, present_mode, None, image_count)
+    }
+
+    /// Constructor for the Swapchain that allows control over the present mode, the surface format and the number of images.
+    /// 
+    /// Wraps a SwapchainKHR around the given GPU (Device) and surface (SurfaceKHR). The supported present modes and surface formats can be queried with `Device::get_swapchain_support()`.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device to create the swapchain on.
+    /// - `surface`: The Surface to create the swapchain around.
+    /// - `width`: The initial width of the swapchain surface. Might be bounded to min/max width supported by this device/surface.
+    /// - `height`: The initial height of the swapchain surface. Might be bounded to min/max height supported by this device/surface.
+    /// - `present_mode`: The PresentMode to use (e.g., `PresentMode::Immediate` for an uncapped loop or `PresentMode::Fifo` for vsync). Falls back to `PresentMode::Fifo` (with a warning) if the device/surface does not support it. The shared present modes require `DeviceExtension::SharedPresentableImage` to be enabled (see `Swapchain::shared_present_image()`).
+    /// - `surface_format`: The SurfaceFormat to use. Falls back to the first format supported by the device/surface (with a warning) if it is not supported. If `None`, prefers `ImageFormat::B8G8R8A8SRgb` in `ColourSpace::SrgbNonlinear`.
+    /// - `image_count`: The (minimum) number of images to put in the swapchain. Clamped to the min/max amount supported by this device/surface. If `None`, defaults to one more than the minimum for `PresentMode::Mailbox` (i.e., triple buffering) and the minimum otherwise. Ignored for the shared present modes, which always use a single image.
+    /// 
+    /// # Returns
+    /// A new Swapchain instance on success, or else an Error explaining what went wrong.
+    pub fn new_with_preferences(device: Shared<Device>, surface: Rc<Surface>, width: u32, height: u32, present_mode: PresentMode, surface_format: Option<SurfaceFormat>, image_count: Option<u32>) -> Result<Rc<RefCell<Self>>, Error> {
+        // Shared present modes need their extension
+        if present_mode.is_shared() && !device.enabled_extensions().contains(&DeviceExtension::SharedPresentableImage) { return Err(Error::SharedPresentNotEnabled{ present_mode }); }
+
         // Prepare the swapchain info
         let (swapchain_info, format, extent, _mem) = match choose_swapchain_props(
             &device,
             &surface,
             width, height,
+            present_mode,
+            surface_format,
             image_count,
             None,
         ) {
-            Ok(res)  => res,
-            Err(err) => { return Err(Error::SwapchainDeduceError{ err: Box::new(err) }); }
+            Ok(res)                 => res,
+            Err(Error::SurfaceLost) => { return Err(Error::SurfaceLost); },
+            Err(err)                => { return Err(Error::SwapchainDeduceError{ err: Box::new(err) }); }
         };
 
         // Create the swapchain with it
@@ -304,30 +547,24 @@ impl Swapchain {
         let loader = khr::Swapchain::new(device.instance().vk(), device.ash());
         let swapchain = unsafe {
             match loader.create_swapchain(&swapchain_info, None) {
-                Ok(swapchain) => swapchain,
-                Err(err)      => { return Err(Error::SwapchainCreateError{ err }); }
+                Ok(swapchain)                           => swapchain,
+                Err(vk::Result::ERROR_SURFACE_LOST_KHR) => { return Err(Error::SurfaceLost); },
+                Err(err)                                => { return Err(Error::SwapchainCreateError{ err }); }
             }
         };
 
-        // Get the images of the chain
-        let vk_images: Vec<vk::Image> = unsafe {
-            match loader.get_swapchain_images(swapchain) {
-                Ok(images) => images,
-                Err(err)   => { return Err(Error::SwapchainImagesError{ err }); }
-            }
+        // Wrap its images and create a render finished semaphore for every image, destroying the swapchain again if that fails
+        let images: Vec<Rc<Image>> = match wrap_swapchain_images(&loader, swapchain, format.format, &extent, swapchain_info.image_usage) {
+            Ok(images) => images,
+            Err(err)   => {
+                unsafe { loader.destroy_swapchain(swapchain, None); }
+                return Err(err);
+            },
         };
-
-        // Wrap them in our own struct
-        let mut images: Vec<Rc<Image>> = Vec::with_capacity(vk_images.len());
-        for image in vk_images {
-            // Wrap the image
-            let image = match Image::from_vk(image) {
-                Ok(image) => image,
-                Err(err)  => { return Err(Error::ImageError{ err }); }
-            };
-
-            // Add it to the list
-            images.push(image);
+        let mut render_finished: Vec<Rc<Semaphore>> = Vec::with_capacity(images.len());
+        if let Err(err) = resize_image_semaphores(&device, &mut render_finished, images.len()) {
+            unsafe { loader.destroy_swapchain(swapchain, None); }
+            return Err(err);
         }
 
         // Store everything in a new Swapchain instance and return
@@ -338,27 +575,33 @@ impl Swapchain {
             loader,
             swapchain,
             images,
+            render_finished,
             
-            format : format.into(),
-            extent : extent.into(),
+            format       : format.format,
+            colour_space : format.colour_space,
+            extent,
+            present_mode : swapchain_info.present_mode.into(),
+            image_count  : swapchain_info.min_image_count,
         })))
     }
 
 
 
-    /// Tries to acquire the next image.
+    /// Acquires the next image, reporting whether the Swapchain is suboptimal for its Surface.
+    /// 
+    /// A suboptimal Swapchain can still be rendered to and presented, but should be re-created (see `Swapchain::recreate()`) when convenient.
     /// 
     /// # Arguments
-    /// - `semaphore`: An optional Semaphore to call when done.
-    /// - `fence`: An optional Fence to call when done.
-    /// - `timeout`: An optional timeout for waiting for a new image.
+    /// - `timeout`: An optional timeout (in nanoseconds) for waiting for a new image. If omitted, waits indefinitely.
+    /// - `semaphore`: An optional Semaphore to signal when the image is ready.
+    /// - `fence`: An optional Fence to signal when the image is ready.
     /// 
     /// # Returns
-    /// If the swapchain is still valid, returns the index of the image that is ready. If it's not valid but needs a resize, then 'None' is returned.
+    /// A tuple with the index of the acquired image and whether the Swapchain is suboptimal (`VK_SUBOPTIMAL_KHR`).
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend failed to get the next image (for any other reason than a Swapchain that needs resizing).
-    pub fn next_image(&self, semaphore: Option<&Rc<Semaphore>>, fence: Option<&Rc<Fence>>, timeout: Option<u64>) -> Result<Option<usize>, Error> {
+    /// This function errors with `SwapchainError::OutOfDate` if the Swapchain has to be re-created before an image can be acquired, or with `SwapchainError::SwapchainNextImageError` if the underlying Vulkan backend failed to get the next image for any other reason.
+    pub fn acquire_next_image(&self, timeout: Option<u64>, semaphore: Option<&Rc<Semaphore>>, fence: Option<&Rc<Fence>>) -> Result<(u32, bool), Error> {
         // Resolve the semaphores, fences and timeouts
         let vk_semaphore: vk::Semaphore = match semaphore {
             Some(semaphore) => semaphore.vk(),
@@ -371,27 +614,48 @@ impl Swapchain {
         let vk_timeout: u64 = timeout.unwrap_or(u64::MAX);
 
         // Call the function on the internal loader
-        let index = match unsafe { self.loader.acquire_next_image(self.swapchain, vk_timeout, vk_semaphore, vk_fence) } {
-            Ok((index, not_optimal))                    => { if !not_optimal { index } else { return Ok(None); } },
-            Err(ash::vk::Result::ERROR_OUT_OF_DATE_KHR) => { return Ok(None); }
-            Err(err)                                    => { return Err(Error::SwapchainNextImageError{ err }); }
-        };
+        match unsafe { self.loader.acquire_next_image(self.swapchain, vk_timeout, vk_semaphore, vk_fence) } {
+            Ok((index, suboptimal))                     => Ok((index, suboptimal)),
+            Err(ash::vk::Result::ERROR_OUT_OF_DATE_KHR) => Err(Error::OutOfDate),
+            Err(err)                                    => Err(Error::SwapchainNextImageError{ err }),
+        }
+    }
 
-        // Success; return it
-        Ok(Some(index as usize))
+    /// Tries to acquire the next image.
+    /// 
+    /// Consider using `Swapchain::acquire_next_image()` instead, which distinguishes a suboptimal Swapchain from one that is out-of-date.
+    /// 
+    /// # Arguments
+    /// - `semaphore`: An optional Semaphore to call when done.
+    /// - `fence`: An optional Fence to call when done.
+    /// - `timeout`: An optional timeout for waiting for a new image.
+    /// 
+    /// # Returns
+    /// If the swapchain is still valid, returns the index of the image that is ready. If it's not valid but needs a resize, then 'None' is returned.
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend failed to get the next image (for any other reason than a Swapchain that needs resizing).
+    pub fn next_image(&self, semaphore: Option<&Rc<Semaphore>>, fence: Option<&Rc<Fence>>, timeout: Option<u64>) -> Result<Option<usize>, Error> {
+        match self.acquire_next_image(timeout, semaphore, fence) {
+            Ok((index, false))                    => Ok(Some(index as usize)),
+            Ok((_, true)) | Err(Error::OutOfDate) => Ok(None),
+            Err(err)                              => Err(err),
+        }
     }
 
     /// Presents the image with the given index.
     /// 
+    /// Consider using `Swapchain::present_rendered()` instead, which waits for the image's own render finished Semaphore.
+    /// 
     /// # Arguments
     /// - `index`: The index of the internal image to present.
     /// - `wait_semaphores`: Zero or more Semaphores that we should wait for before we can present the image.
     /// 
     /// # Returns
-    /// Whether the Swapchain needs to be re-created or not.
+    /// Whether the Swapchain is suboptimal (`VK_SUBOPTIMAL_KHR`) and should be re-created when convenient.
     /// 
     /// # Errors
-    /// This function errors if we could not present the Swapchain somehow.
+    /// This function errors with `SwapchainError::OutOfDate` if the Swapchain has to be re-created (see `Swapchain::recreate()`), or if we could not present the Swapchain somehow.
     pub fn present(&self, index: u32, wait_semaphores: &[&Rc<Semaphore>]) -> Result<bool, Error> {
         // Cast the semaphores
         let vk_wait_semaphores: Vec<vk::Semaphore> = wait_semaphores.iter().map(|sem| sem.vk()).collect();
@@ -402,17 +666,117 @@ impl Swapchain {
         let present_info = populate_present_info(&vk_swapchains, &vk_indices, &vk_wait_semaphores);
 
         // Present
+        self.queue_present(index, &present_info)
+    }
+
+    /// Presents the image with the given index, hinting the presentation engine that only the given regions have changed since the last present.
+    /// 
+    /// This may save composition work for mostly static frames. Note that the presentation engine is still free to present the whole image.
+    /// 
+    /// # Arguments
+    /// - `index`: The index of the internal image to present.
+    /// - `wait_semaphores`: Zero or more Semaphores that we should wait for before we can present the image.
+    /// - `regions`: The rectangles (in pixels, relative to the top-left of the image) that have changed. If empty, the whole image is considered changed.
+    /// 
+    /// # Returns
+    /// Whether the Swapchain is suboptimal (`VK_SUBOPTIMAL_KHR`) and should be re-created when convenient.
+    /// 
+    /// # Errors
+    /// This function errors with `SwapchainError::OutOfDate` if the Swapchain has to be re-created, if the Device does not have `DeviceExtension::IncrementalPresent` enabled, or if we could not present the Swapchain somehow.
+    pub fn present_regions(&self, index: u32, wait_semaphores: &[&Rc<Semaphore>], regions: &[Rect2D<i32, u32>]) -> Result<bool, Error> {
+        // Make sure the extension is enabled
+        if !self.device.enabled_extensions().contains(&DeviceExtension::IncrementalPresent) { return Err(Error::IncrementalPresentNotEnabled); }
+
+        // Cast the semaphores and the regions
+        let vk_wait_semaphores: Vec<vk::Semaphore> = wait_semaphores.iter().map(|sem| sem.vk()).collect();
+        let vk_rects: Vec<vk::RectLayerKHR> = regions.iter().map(|region| vk::RectLayerKHR {
+            offset : vk::Offset2D{ x: region.offset.x, y: region.offset.y },
+            extent : vk::Extent2D{ width: region.extent.w, height: region.extent.h },
+            layer  : 0,
+        }).collect();
+
+        // Populate the region structs, one per swapchain
+        let vk_regions: [vk::PresentRegionKHR; 1] = [vk::PresentRegionKHR {
+            rectangle_count : vk_rects.len() as u32,
+            p_rectangles    : vec_as_ptr!(vk_rects),
+        }];
+        let regions_info = vk::PresentRegionsKHR {
+            s_type : vk::StructureType::PRESENT_REGIONS_KHR,
+            p_next : ptr::null(),
+
+            swapchain_count : vk_regions.len() as u32,
+            p_regions       : vk_regions.as_ptr(),
+        };
+
+        // Populate the present info struct and chain the regions to it
+        let vk_swapchains: [vk::SwapchainKHR; 1] = [self.swapchain];
+        let vk_indices: [u32; 1] = [index];
+        let mut present_info = populate_present_info(&vk_swapchains, &vk_indices, &vk_wait_semaphores);
+        present_info.p_next = &regions_info as *const vk::PresentRegionsKHR as *const _;
+
+        // Present
+        self.queue_present(index, &present_info)
+    }
+
+    /// Submits the given VkPresentInfoKHR to the present queue.
+    /// 
+    /// # Arguments
+    /// - `index`: The index of the image to present (used for errors only).
+    /// - `present_info`: The already populated VkPresentInfoKHR.
+    /// 
+    /// # Returns
+    /// Whether the Swapchain is suboptimal (`VK_SUBOPTIMAL_KHR`) and should be re-created when convenient.
+    /// 
+    /// # Errors
+    /// This function errors with `SwapchainError::OutOfDate` if the Swapchain has to be re-created, or with `SwapchainError::SwapchainPresentError` if we could not present the Swapchain for any other reason.
+    fn queue_present(&self, index: u32, present_info: &vk::PresentInfoKHR) -> Result<bool, Error> {
+        let present = &self.device.queues().present;
+        let _guard = present.lock();
         unsafe {
-            match self.loader.queue_present(self.device.queues().present.vk(), &present_info) {
-                Ok(_)                                       => Ok(false),
-                Err(ash::vk::Result::ERROR_OUT_OF_DATE_KHR) => Ok(true),
+            match self.loader.queue_present(present.vk(), present_info) {
+                Ok(suboptimal)                              => Ok(suboptimal),
+                Err(ash::vk::Result::ERROR_OUT_OF_DATE_KHR) => Err(Error::OutOfDate),
                 Err(err)                                    => Err(Error::SwapchainPresentError{ index, err }),
             }
         }
     }
 
+    /// Presents the image with the given index once rendering to it is done, i.e., once its render finished Semaphore (see `Swapchain::render_finished()`) is signalled.
+    /// 
+    /// # Arguments
+    /// - `index`: The index of the internal image to present.
+    /// 
+    /// # Returns
+    /// Whether the Swapchain is suboptimal (`VK_SUBOPTIMAL_KHR`) and should be re-created when convenient.
+    /// 
+    /// # Errors
+    /// This function errors with `SwapchainError::OutOfDate` if the Swapchain has to be re-created (see `Swapchain::recreate()`), or if we could not present the Swapchain somehow.
+    /// 
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the images in this Swapchain.
+    #[inline]
+    pub fn present_rendered(&self, index: u32) -> Result<bool, Error> {
+        self.present(index, &[ self.render_finished(index as usize) ])
+    }
 
 
+
+    /// Recreates the Swapchain with a new extent, e.g., when its window has been resized.
+    /// 
+    /// The new VkSwapchainKHR is created from the old one (which is destroyed afterwards), re-using the same Surface and Device. The extent is clamped to what the Surface currently supports.
+    /// 
+    /// Note that any Views or Framebuffers of the old images have to be re-created by the caller.
+    /// 
+    /// # Arguments
+    /// - `new_extent`: The new extent (in pixels) of the Swapchain images.
+    /// 
+    /// # Errors
+    /// This function errors if the Surface was lost (`SwapchainError::SurfaceLost`), or if the underlying Vulkan backend failed to create a new Swapchain.
+    #[inline]
+    pub fn recreate(&mut self, new_extent: Extent2D<u32>) -> Result<(), Error> {
+        self.rebuild(new_extent.w, new_extent.h)
+    }
+
     /// Rebuilds the Swapchain with a new size.
     /// 
     /// # Arguments
@@ -420,59 +784,62 @@ impl Swapchain {
     /// - `new_height`: The new height (in pixels) of the Swapchain images.
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend failed to create a new Swapchain.
+    /// This function errors if the Surface was lost (`SwapchainError::SurfaceLost`), or if the underlying Vulkan backend failed to create a new Swapchain.
     pub fn rebuild(&mut self, new_width: u32, new_height: u32) -> Result<(), Error> {
         // Prepare the swapchain info
         let (swapchain_info, format, extent, _mem) = match choose_swapchain_props(
             &self.device,
             &self.surface,
             new_width, new_height,
-            self.images.len() as u32,
+            self.present_mode,
+            Some(self.surface_format()),
+            Some(self.image_count),
             Some(self.swapchain),
         ) {
-            Ok(res)  => res,
-            Err(err) => { return Err(Error::SwapchainDeduceError{ err: Box::new(err) }); }
+            Ok(res)                 => res,
+            Err(Error::SurfaceLost) => { return Err(Error::SurfaceLost); },
+            Err(err)                => { return Err(Error::SwapchainDeduceError{ err: Box::new(err) }); }
         };
 
         // Create the swapchain with it
         debug!("Rebuilding swapchain...");
         let swapchain = unsafe {
             match self.loader.create_swapchain(&swapchain_info, None) {
-                Ok(swapchain) => swapchain,
-                Err(err)      => { return Err(Error::SwapchainCreateError{ err }); }
+                Ok(swapchain)                           => swapchain,
+                Err(vk::Result::ERROR_SURFACE_LOST_KHR) => { return Err(Error::SurfaceLost); },
+                Err(err)                                => { return Err(Error::SwapchainCreateError{ err }); }
             }
         };
 
-        // Get the images of the chain
-        let vk_images: Vec<vk::Image> = unsafe {
-            match self.loader.get_swapchain_images(swapchain) {
-                Ok(images) => images,
-                Err(err)   => { return Err(Error::SwapchainImagesError{ err }); }
-            }
+        // Wrap its images, destroying the new swapchain again if that fails
+        let images: Vec<Rc<Image>> = match wrap_swapchain_images(&self.loader, swapchain, format.format, &extent, swapchain_info.image_usage) {
+            Ok(images) => images,
+            Err(err)   => {
+                unsafe { self.loader.destroy_swapchain(swapchain, None); }
+                return Err(err);
+            },
         };
 
-        // Wrap them in our own struct
-        let mut images: Vec<Rc<Image>> = Vec::with_capacity(vk_images.len());
-        for image in vk_images {
-            // Wrap the image
-            let image = match Image::from_vk(image) {
-                Ok(image) => image,
-                Err(err)  => { return Err(Error::ImageError{ err }); }
-            };
-
-            // Add it to the list
-            images.push(image);
+        // Wait until the old swapchain is no longer in use, and make sure we (still) have a render finished semaphore per image
+        if let Err(err) = self.device.drain(None) {
+            unsafe { self.loader.destroy_swapchain(swapchain, None); }
+            return Err(Error::DeviceIdleError{ err });
+        }
+        if let Err(err) = resize_image_semaphores(&self.device, &mut self.render_finished, images.len()) {
+            unsafe { self.loader.destroy_swapchain(swapchain, None); }
+            return Err(err);
         }
 
-        // Destroy the old swapchain now that we reached it
-        if let Err(err) = self.device.drain(None) { return Err(Error::DeviceIdleError{ err }); }
+        // Destroy the old swapchain now that nothing can fail anymore
         unsafe { self.loader.destroy_swapchain(self.swapchain, None); }
 
         // Replace everything with the new ones
-        self.swapchain = swapchain;
-        self.images    = images;
-        self.format    = format.into();
-        self.extent    = extent.into();
+        self.swapchain    = swapchain;
+        self.images       = images;
+        self.format       = format.format;
+        self.colour_space = format.colour_space;
+        self.extent       = extent;
+        self.image_count  = swapchain_info.min_image_count;
 
         // Done
         Ok(())
@@ -482,7 +849,7 @@ impl Swapchain {
 
     /// Returns the device on which this swapchain is built.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the surface around which this swapchain is built.
     #[inline]
@@ -501,6 +868,24 @@ impl Swapchain {
     /// Returns the images for the swapchain.
     #[inline]
     pub fn images(&self) -> &Vec<Rc<Image>> { &self.images }
+
+    /// Returns the single image that is shared with the presentation engine if this Swapchain uses a shared present mode.
+    /// 
+    /// This image only has to be acquired once (using `Swapchain::next_image()`), after which it may be rendered to while it is being displayed. It should then be kept in `ImageLayout::SharedPresent`. With `PresentMode::SharedDemandRefresh`, call `Swapchain::present()` whenever the display should be updated; with `PresentMode::SharedContinuousRefresh`, a single present is enough.
+    /// 
+    /// # Returns
+    /// The shared image, or `None` if the Swapchain does not use a shared present mode (e.g., because it fell back to `PresentMode::Fifo`).
+    #[inline]
+    pub fn shared_present_image(&self) -> Option<&Rc<Image>> {
+        if self.present_mode.is_shared() { self.images.first() } else { None }
+    }
+
+    /// Returns the Semaphore that should be signalled when rendering to the image with the given index is done, and that `Swapchain::present_rendered()` waits for.
+    /// 
+    /// # Panics
+    /// This function panics if `index` is out-of-bounds for the images in this Swapchain.
+    #[inline]
+    pub fn render_finished(&self, index: usize) -> &Rc<Semaphore> { &self.render_finished[index] }
     
 
 
@@ -508,14 +893,34 @@ impl Swapchain {
     #[inline]
     pub fn format(&self) -> ImageFormat { self.format }
 
+    /// Returns the colour space in which the images of this Swapchain are presented.
+    #[inline]
+    pub fn colour_space(&self) -> ColourSpace { self.colour_space }
+
+    /// Returns the chosen SurfaceFormat (i.e., format and colour space) of this Swapchain.
+    #[inline]
+    pub fn surface_format(&self) -> SurfaceFormat { SurfaceFormat::new(self.format, self.colour_space) }
+
     /// Returns the chosen extent for this Swapchain.
     #[inline]
     pub fn extent(&self) -> &Extent2D<u32> { &self.extent }
+
+    /// Returns the chosen present mode for this Swapchain.
+    #[inline]
+    pub fn present_mode(&self) -> PresentMode { self.present_mode }
+
+    /// Returns the chosen (minimum) number of images for this Swapchain, after clamping it to what the device/surface supports.
+    /// 
+    /// Note that the driver may create more images than this; use `Swapchain::images()` to get the images that were actually created.
+    #[inline]
+    pub fn image_count(&self) -> u32 { self.image_count }
 }
 
 impl Drop for Swapchain {
     fn drop(&mut self) {
         log_destroy!(self, Swapchain);
+
+        // Destroy the swapchain first; our references to the Surface and Device are only released after this function returns
         unsafe { self.loader.destroy_swapchain(self.swapchain, None); }
     }
 }
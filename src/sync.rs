@@ -20,20 +20,107 @@ use ash::vk;
 pub use crate::errors::SyncError as Error;
 use crate::log_destroy;
 use crate::device::Device;
+use crate::spec::Shared;
+
+
+/***** HELPER FUNCTIONS *****/
+/// Waits for the given VkFences, treating `VK_TIMEOUT` as a non-error.
+/// 
+/// # Arguments
+/// - `device`: The Device where the Fences live.
+/// - `fences`: The VkFences to wait for.
+/// - `wait_all`: Whether to wait for all Fences (true) or just any of them (false).
+/// - `timeout`: An optional timeout (in nanoseconds). If omitted, waits indefinitely.
+/// 
+/// # Returns
+/// Whether the Fences were signalled (true) or the timeout was reached first (false).
+/// 
+/// # Errors
+/// This function errors if the underlying Vulkan backend does (e.g., because the Device was lost).
+fn wait_for_fences(device: &Device, fences: &[vk::Fence], wait_all: bool, timeout: Option<u64>) -> Result<bool, Error> {
+    unsafe {
+        match device.wait_for_fences(fences, wait_all, timeout.unwrap_or(u64::MAX)) {
+            Ok(_)                         => Ok(true),
+            Err(ash::vk::Result::TIMEOUT) => Ok(false),
+            Err(err)                      => Err(Error::FenceWaitError{ err }),
+        }
+    }
+}
+
+
+
 
 
 /***** POPULATE FUNCTIONS *****/
 /// Creates a new VkSemaphoreCreateInfo struct.
+/// 
+/// # Arguments
+/// - `type_info`: If given, the VkSemaphoreTypeCreateInfo to chain in the `p_next` (to create a timeline Semaphore). Must be alive as long as the returned struct is.
 #[inline]
-fn populate_semaphore_info() -> vk::SemaphoreCreateInfo {
+fn populate_semaphore_info(type_info: Option<&vk::SemaphoreTypeCreateInfo>) -> vk::SemaphoreCreateInfo {
     vk::SemaphoreCreateInfo {
         // Only set the default stuff
         s_type : vk::StructureType::SEMAPHORE_CREATE_INFO,
-        p_next : ptr::null(),
+        p_next : type_info.map(|info| info as *const vk::SemaphoreTypeCreateInfo as *const std::ffi::c_void).unwrap_or(ptr::null()),
         flags  : vk::SemaphoreCreateFlags::empty(),
     }
 }
 
+/// Creates a new VkSemaphoreTypeCreateInfo struct for a timeline Semaphore.
+/// 
+/// # Arguments
+/// - `initial_value`: The value of the Semaphore's counter after creation.
+#[inline]
+fn populate_semaphore_type_info(initial_value: u64) -> vk::SemaphoreTypeCreateInfo {
+    vk::SemaphoreTypeCreateInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::SEMAPHORE_TYPE_CREATE_INFO,
+        p_next : ptr::null(),
+
+        // Define the semaphore as a timeline one
+        semaphore_type : vk::SemaphoreType::TIMELINE,
+        initial_value,
+    }
+}
+
+/// Creates a new VkSemaphoreWaitInfo struct.
+/// 
+/// # Arguments
+/// - `semaphores`: The (timeline) Semaphores to wait for.
+/// - `values`: The value to wait for for each Semaphore. Must be of the same length as `semaphores`.
+#[inline]
+fn populate_semaphore_wait_info(semaphores: &[vk::Semaphore], values: &[u64]) -> vk::SemaphoreWaitInfo {
+    vk::SemaphoreWaitInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::SEMAPHORE_WAIT_INFO,
+        p_next : ptr::null(),
+        flags  : vk::SemaphoreWaitFlags::empty(),
+
+        // Set the semaphores & values to wait for
+        semaphore_count : semaphores.len() as u32,
+        p_semaphores    : semaphores.as_ptr(),
+        p_values        : values.as_ptr(),
+    }
+}
+
+/// Creates a new VkSemaphoreSignalInfo struct.
+/// 
+/// # Arguments
+/// - `semaphore`: The (timeline) Semaphore to signal.
+/// - `value`: The value to set the Semaphore's counter to.
+#[inline]
+fn populate_semaphore_signal_info(semaphore: vk::Semaphore, value: u64) -> vk::SemaphoreSignalInfo {
+    vk::SemaphoreSignalInfo {
+        // Do the standard stuff
+        s_type : vk::StructureType::SEMAPHORE_SIGNAL_INFO,
+        p_next : ptr::null(),
+
+        // Set the semaphore & value
+        semaphore,
+        value,
+    }
+}
+
 /// Creates a new VkFenceCreateInfo struct.
 /// 
 /// # Arguments
@@ -54,11 +141,15 @@ fn populate_fence_info(flags: vk::FenceCreateFlags) -> vk::FenceCreateInfo {
 
 /***** LIBRARY *****/
 /// Implements a Semaphore, i.e., something that gets signalled when something else is ready.
+/// 
+/// Semaphores are either binary (see `Semaphore::new()`) or timeline Semaphores (see `Semaphore::new_timeline()`), which have a monotonically increasing counter that may be waited on and signalled from both the CPU and the GPU.
 pub struct Semaphore {
     /// The device where the Semaphore lives
-    device    : Rc<Device>,
+    device    : Shared<Device>,
     /// The Semaphore itself
     semaphore : vk::Semaphore,
+    /// Whether this is a timeline Semaphore (true) or a binary one (false)
+    timeline  : bool,
 }
 
 impl Semaphore {
@@ -72,9 +163,9 @@ impl Semaphore {
     /// 
     /// # Errors
     /// This function errors if the underlying Vulkan backend could not create the Semaphore.
-    pub fn new(device: Rc<Device>) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>) -> Result<Rc<Self>, Error> {
         // Create the create info
-        let semaphore_info = populate_semaphore_info();
+        let semaphore_info = populate_semaphore_info(None);
 
         // Create the semaphore on the device
         let semaphore = unsafe {
@@ -88,14 +179,121 @@ impl Semaphore {
         Ok(Rc::new(Self {
             device,
             semaphore,
+            timeline : false,
         }))
     }
 
+    /// Constructor for a timeline Semaphore.
+    /// 
+    /// Note that this requires the `VK_KHR_timeline_semaphore` extension and the `timeline_semaphore` feature to be enabled on the Device.
+    /// 
+    /// # Arguments
+    /// - `device`: The Device where the semaphore will live.
+    /// - `initial_value`: The initial value of the Semaphore's counter.
+    /// 
+    /// # Returns
+    /// A new Semaphore instance on success.
+    /// 
+    /// # Errors
+    /// This function errors if the Device does not have the timeline semaphore extension enabled, or if the underlying Vulkan backend could not create the Semaphore.
+    pub fn new_timeline(device: Shared<Device>, initial_value: u64) -> Result<Rc<Self>, Error> {
+        // Make sure we can actually use the Semaphore once we've created it
+        if device.timeline_semaphore().is_none() { return Err(Error::TimelineSemaphoreNotEnabled); }
+
+        // Create the create infos
+        let type_info      = populate_semaphore_type_info(initial_value);
+        let semaphore_info = populate_semaphore_info(Some(&type_info));
+
+        // Create the semaphore on the device
+        let semaphore = unsafe {
+            match device.create_semaphore(&semaphore_info, None) {
+                Ok(semaphore) => semaphore,
+                Err(err)      => { return Err(Error::SemaphoreCreateError{ err }); }
+            }
+        };
+
+        // Done, wrap in an instance and return
+        Ok(Rc::new(Self {
+            device,
+            semaphore,
+            timeline : true,
+        }))
+    }
+
+
+
+    /// Returns the current value of the counter of this timeline Semaphore.
+    /// 
+    /// # Errors
+    /// This function errors if this is not a timeline Semaphore or if the underlying Vulkan backend does.
+    pub fn value(&self) -> Result<u64, Error> {
+        let loader = self.timeline_loader()?;
+        match unsafe { loader.get_semaphore_counter_value(self.semaphore) } {
+            Ok(value) => Ok(value),
+            Err(err)  => Err(Error::SemaphoreValueError{ err }),
+        }
+    }
+
+    /// Blocks the current (CPU) thread until the counter of this timeline Semaphore reaches (at least) the given value.
+    /// 
+    /// # Arguments
+    /// - `value`: The value to wait for.
+    /// - `timeout`: An optional timeout (in nanoseconds) to wait for this Semaphore. A timeout of 0 is equal to polling, and omitting it waits indefinitely.
+    /// 
+    /// # Returns
+    /// Whether the counter reached the value (true) or the timeout was reached first (false). Like for `Fence::wait()`, reaching the timeout is not considered an error.
+    /// 
+    /// # Errors
+    /// This function errors if this is not a timeline Semaphore or if the underlying Vulkan backend does (e.g., because the Device was lost).
+    pub fn wait(&self, value: u64, timeout: Option<u64>) -> Result<bool, Error> {
+        let loader = self.timeline_loader()?;
+
+        // Unpack the timeout
+        let timeout = timeout.unwrap_or(u64::MAX);
+
+        // Wait for the value
+        let semaphores: [vk::Semaphore; 1] = [self.semaphore];
+        let values: [u64; 1]               = [value];
+        let wait_info = populate_semaphore_wait_info(&semaphores, &values);
+        match unsafe { loader.wait_semaphores(&wait_info, timeout) } {
+            Ok(_)                         => Ok(true),
+            Err(ash::vk::Result::TIMEOUT) => Ok(false),
+            Err(err)                      => Err(Error::SemaphoreWaitError{ err }),
+        }
+    }
+
+    /// Sets the counter of this timeline Semaphore to the given value from the CPU.
+    /// 
+    /// # Arguments
+    /// - `value`: The value to set the counter to. Must be larger than the current value.
+    /// 
+    /// # Errors
+    /// This function errors if this is not a timeline Semaphore or if the underlying Vulkan backend does.
+    pub fn signal(&self, value: u64) -> Result<(), Error> {
+        let loader = self.timeline_loader()?;
+        let signal_info = populate_semaphore_signal_info(self.semaphore, value);
+        match unsafe { loader.signal_semaphore(&signal_info) } {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(Error::SemaphoreSignalError{ err }),
+        }
+    }
+
+    /// Returns the loader for the timeline semaphore functions, or errors if this is not a timeline Semaphore.
+    #[inline]
+    fn timeline_loader(&self) -> Result<&ash::extensions::khr::TimelineSemaphore, Error> {
+        if !self.timeline { return Err(Error::NotATimelineSemaphore); }
+        self.device.timeline_semaphore().ok_or(Error::TimelineSemaphoreNotEnabled)
+    }
+
 
 
     /// Returns the device where this Semaphore lives.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
+
+    /// Returns whether this is a timeline Semaphore (true) or a binary one (false).
+    #[inline]
+    pub fn is_timeline(&self) -> bool { self.timeline }
 
     /// Returns the internal VkSemaphore.
     #[inline]
@@ -114,7 +312,7 @@ impl Drop for Semaphore {
 /// Implements a Fence, i.e., something that the CPU manually has to set to continue.
 pub struct Fence {
     /// The device where the Fence lives
-    device : Rc<Device>,
+    device : Shared<Device>,
     /// The Fence itself
     fence  : vk::Fence,
 }
@@ -131,7 +329,7 @@ impl Fence {
     /// 
     /// # Errors
     /// This function errors if the underlying Vulkan backend could not create the Fence.
-    pub fn new(device: Rc<Device>, signalled: bool) -> Result<Rc<Self>, Error> {
+    pub fn new(device: Shared<Device>, signalled: bool) -> Result<Rc<Self>, Error> {
         // Create the create info with the proper signalled state
         let fence_info = populate_fence_info(if signalled { vk::FenceCreateFlags::SIGNALED } else { vk::FenceCreateFlags::empty() });
 
@@ -152,25 +350,81 @@ impl Fence {
 
 
 
-    /// Blocks the current (CPU) thread until the Fence is signalled.
+    /// Blocks the current (CPU) thread until the Fence is signalled or the timeout has been reached.
     /// 
     /// # Arguments
-    /// - `timeout`: An optional timeout to wait for this Fence. A timeout of 0 is equal to polling, and a timeout of `u64::MAX` is equal to an indefinite poll.
+    /// - `timeout`: An optional timeout (in nanoseconds) to wait for this Fence. A timeout of 0 is equal to polling, and omitting it waits indefinitely.
+    /// 
+    /// # Returns
+    /// Whether the Fence was signalled (true) or the timeout was reached first (false). Like for `Semaphore::wait()`, reaching the timeout is not considered an error.
     /// 
     /// # Errors
-    /// This function errors if the underlying Vulkan backend does or if a timeout has been reached.
-    pub fn wait(&self, timeout: Option<u64>) -> Result<(), Error> {
-        // Unpack the timeout
-        let timeout = timeout.unwrap_or(u64::MAX);
+    /// This function errors if the underlying Vulkan backend does (e.g., because the Device was lost).
+    #[inline]
+    pub fn wait(&self, timeout: Option<u64>) -> Result<bool, Error> {
+        wait_for_fences(&self.device, &[self.fence], true, timeout)
+    }
 
-        // Use the device function to wait
-        unsafe {
-            match self.device.wait_for_fences(&[self.fence], true, timeout) {
-                Ok(_)                         => Ok(()),
-                Err(ash::vk::Result::TIMEOUT) => Err(Error::FenceTimeout{ timeout }),
-                Err(err)                      => Err(Error::FenceWaitError{ err }),
-            }
+    /// Blocks the current (CPU) thread until all of the given Fences are signalled or the timeout has been reached.
+    /// 
+    /// # Arguments
+    /// - `fences`: The Fences to wait for. They must all live on the same Device.
+    /// - `timeout`: An optional timeout (in nanoseconds) to wait for the Fences. A timeout of 0 is equal to polling, and omitting it waits indefinitely.
+    /// 
+    /// # Returns
+    /// Whether all Fences were signalled (true) or the timeout was reached first (false). If no Fences are given, always returns true.
+    /// 
+    /// # Errors
+    /// This function errors if the Fences do not all live on the same Device, or if the underlying Vulkan backend does (e.g., because the Device was lost).
+    #[inline]
+    pub fn wait_all(fences: &[&Rc<Self>], timeout: Option<u64>) -> Result<bool, Error> {
+        Self::wait_many(fences, true, timeout)
+    }
+
+    /// Blocks the current (CPU) thread until any of the given Fences is signalled or the timeout has been reached.
+    /// 
+    /// # Arguments
+    /// - `fences`: The Fences to wait for. They must all live on the same Device.
+    /// - `timeout`: An optional timeout (in nanoseconds) to wait for the Fences. A timeout of 0 is equal to polling, and omitting it waits indefinitely.
+    /// 
+    /// # Returns
+    /// Whether at least one of the Fences was signalled (true) or the timeout was reached first (false). If no Fences are given, always returns true.
+    /// 
+    /// # Errors
+    /// This function errors if the Fences do not all live on the same Device, or if the underlying Vulkan backend does (e.g., because the Device was lost).
+    #[inline]
+    pub fn wait_any(fences: &[&Rc<Self>], timeout: Option<u64>) -> Result<bool, Error> {
+        Self::wait_many(fences, false, timeout)
+    }
+
+    /// Blocks the current (CPU) thread until all or any of the given Fences are signalled or the timeout has been reached.
+    /// 
+    /// # Arguments
+    /// - `fences`: The Fences to wait for. They must all live on the same Device.
+    /// - `wait_all`: Whether to wait for all Fences (true) or just any of them (false).
+    /// - `timeout`: An optional timeout (in nanoseconds) to wait for the Fences. If omitted, waits indefinitely.
+    /// 
+    /// # Returns
+    /// Whether the Fences were signalled (true) or the timeout was reached first (false). If no Fences are given, always returns true.
+    /// 
+    /// # Errors
+    /// This function errors if the Fences do not all live on the same Device, or if the underlying Vulkan backend does.
+    pub fn wait_many(fences: &[&Rc<Self>], wait_all: bool, timeout: Option<u64>) -> Result<bool, Error> {
+        // Nothing to wait for if there are no fences
+        let device: &Shared<Device> = match fences.first() {
+            Some(fence) => &fence.device,
+            None        => { return Ok(true); }
+        };
+
+        // Collect the fences, making sure they share the same device
+        let mut vk_fences: Vec<vk::Fence> = Vec::with_capacity(fences.len());
+        for (i, fence) in fences.iter().enumerate() {
+            if !Shared::ptr_eq(&fence.device, device) { return Err(Error::FenceDeviceMismatch{ index: i }); }
+            vk_fences.push(fence.fence);
         }
+
+        // Wait for them
+        wait_for_fences(device, &vk_fences, wait_all, timeout)
     }
 
     /// Polls the Fence if it's ready or not.
@@ -183,11 +437,22 @@ impl Fence {
     #[inline]
     pub fn poll(&self) -> Result<bool, Error> {
         // Use the device function to poll (timeout of 0)
+        wait_for_fences(&self.device, &[self.fence], true, Some(0))
+    }
+
+    /// Returns whether the Fence is currently signalled, without waiting for it.
+    /// 
+    /// # Returns
+    /// Whether or not the Fence is signalled (true) or not (false).
+    /// 
+    /// # Errors
+    /// This function errors if the underlying Vulkan backend could not get the status of the Fence (e.g., because the Device was lost).
+    #[inline]
+    pub fn is_signalled(&self) -> Result<bool, Error> {
         unsafe {
-            match self.device.wait_for_fences(&[self.fence], true, 0) {
-                Ok(_)                         => Ok(true),
-                Err(ash::vk::Result::TIMEOUT) => Ok(false),
-                Err(err)                      => Err(Error::FenceWaitError{ err }),
+            match self.device.get_fence_status(self.fence) {
+                Ok(signalled) => Ok(signalled),
+                Err(err)      => Err(Error::FenceStatusError{ err }),
             }
         }
     }
@@ -210,7 +475,7 @@ impl Fence {
 
     /// Returns the device where this Semaphore lives.
     #[inline]
-    pub fn device(&self) -> &Rc<Device> { &self.device }
+    pub fn device(&self) -> &Shared<Device> { &self.device }
 
     /// Returns the internal VkFence.
     #[inline]